@@ -0,0 +1,65 @@
+use pix_engine::prelude::*;
+
+const TITLE_HEIGHT: u32 = 30;
+const BORDER: u32 = 5;
+const BUTTONS_WIDTH: u32 = 80;
+
+struct BorderlessWindow;
+
+impl PixEngine for BorderlessWindow {
+    fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.set_window_hit_test(|pos, size| {
+            let (width, _) = size;
+            // Title bar buttons need to receive clicks instead of dragging the window
+            let in_buttons =
+                pos.y() < TITLE_HEIGHT as i32 && pos.x() >= (width - BUTTONS_WIDTH) as i32;
+            match HitTestResult::classify(pos, size, BORDER, TITLE_HEIGHT) {
+                HitTestResult::Draggable if in_buttons => HitTestResult::Normal,
+                result => result,
+            }
+        })?;
+        Ok(())
+    }
+
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.clear()?;
+        let (width, _) = s.window_dimensions()?;
+
+        // Custom title bar
+        s.stroke(None);
+        s.fill(Color::DARK_SLATE_GRAY);
+        s.rect([0, 0, width as i32, TITLE_HEIGHT as i32])?;
+        s.fill(Color::WHITE);
+        s.set_cursor_pos([8, 8]);
+        s.text("Borderless Window")?;
+
+        s.set_cursor_pos([(width - BUTTONS_WIDTH) as i32 + 8, 4]);
+        if s.button("_")? {
+            s.minimize_window()?;
+        }
+        s.same_line(None);
+        if s.button("X")? {
+            s.close_window(s.window_id())?;
+        }
+
+        s.set_cursor_pos([8, TITLE_HEIGHT as i32 + 8]);
+        let (x, y) = s.window_position()?;
+        s.text(format!("Window position: ({x}, {y})"))?;
+        s.text("Drag the title bar to move the window, or its edges to resize it.")?;
+        if s.button("Move to top-left")? {
+            s.set_window_position((0, 0))?;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> PixResult<()> {
+    let mut engine = Engine::builder()
+        .dimensions(640, 480)
+        .title("Borderless Window")
+        .borderless()
+        .resizable()
+        .build()?;
+    let mut app = BorderlessWindow;
+    engine.run(&mut app)
+}
@@ -0,0 +1,117 @@
+use pix_engine::prelude::*;
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 600;
+const PAN_SPEED: f64 = 300.0;
+const ROTATE_SPEED: f64 = 1.0;
+const ZOOM_STEP: f64 = 1.1;
+const WORLD_SIZE: i32 = 2000;
+const GRID_SIZE: i32 = 100;
+
+struct CameraDemo {
+    camera: Camera2D,
+}
+
+impl CameraDemo {
+    fn new() -> Self {
+        let mut camera = Camera2D::new();
+        camera.set_offset([f64::from(WIDTH) / 2.0, f64::from(HEIGHT) / 2.0]);
+        camera.set_target([f64::from(WORLD_SIZE) / 2.0, f64::from(WORLD_SIZE) / 2.0]);
+        camera.set_bounds(rect![
+            0.0,
+            0.0,
+            f64::from(WORLD_SIZE),
+            f64::from(WORLD_SIZE)
+        ]);
+        Self { camera }
+    }
+}
+
+impl PixEngine for CameraDemo {
+    fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.background(Color::DARK_SLATE_GRAY);
+        Ok(())
+    }
+
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        // Pan in screen directions, independent of zoom and rotation
+        let delta = s.delta_time().as_secs_f64();
+        let mut pan = [0.0, 0.0];
+        if s.key_down(Key::W) {
+            pan[1] -= 1.0;
+        }
+        if s.key_down(Key::S) {
+            pan[1] += 1.0;
+        }
+        if s.key_down(Key::A) {
+            pan[0] -= 1.0;
+        }
+        if s.key_down(Key::D) {
+            pan[0] += 1.0;
+        }
+        let (sin, cos) = self.camera.rotation().sin_cos();
+        let speed = PAN_SPEED * delta / self.camera.zoom();
+        self.camera.pan([
+            (pan[0] * cos - pan[1] * sin) * speed,
+            (pan[0] * sin + pan[1] * cos) * speed,
+        ]);
+        if s.key_down(Key::Q) {
+            self.camera
+                .set_rotation(self.camera.rotation() - ROTATE_SPEED * delta);
+        }
+        if s.key_down(Key::E) {
+            self.camera
+                .set_rotation(self.camera.rotation() + ROTATE_SPEED * delta);
+        }
+
+        s.clear()?;
+        s.set_camera(self.camera);
+
+        // World space
+        s.stroke(Color::GRAY);
+        for i in (0..=WORLD_SIZE).step_by(GRID_SIZE as usize) {
+            s.line([[i, 0], [i, WORLD_SIZE]])?;
+            s.line([[0, i], [WORLD_SIZE, i]])?;
+        }
+        s.stroke(Color::BLACK);
+        for y in (GRID_SIZE / 2..WORLD_SIZE).step_by(2 * GRID_SIZE as usize) {
+            for x in (GRID_SIZE / 2..WORLD_SIZE).step_by(2 * GRID_SIZE as usize) {
+                s.fill(Color::rgb((x / 8) as u8, (y / 8) as u8, 150));
+                s.rect([x - 20, y - 20, 40, 40])?;
+            }
+        }
+        let mouse = s.screen_to_world(s.mouse_pos());
+        s.fill(Color::YELLOW);
+        s.circle([mouse.x(), mouse.y(), 10])?;
+
+        // Screen space
+        s.set_camera(None);
+        s.fill(Color::WHITE);
+        s.text("WASD: pan, Q/E: rotate, Mouse wheel: zoom")?;
+        s.text(format!(
+            "World: ({}, {}), Zoom: {:.2}x",
+            mouse.x(),
+            mouse.y(),
+            self.camera.zoom()
+        ))?;
+        Ok(())
+    }
+
+    fn on_mouse_wheel(&mut self, s: &mut PixState, pos: Point<i32>) -> PixResult<bool> {
+        let factor = ZOOM_STEP.powi(pos.y());
+        let mouse = s.mouse_pos();
+        self.camera
+            .zoom_at(factor, [f64::from(mouse.x()), f64::from(mouse.y())]);
+        Ok(true)
+    }
+}
+
+fn main() -> PixResult<()> {
+    let mut engine = Engine::builder()
+        .dimensions(WIDTH, HEIGHT)
+        .title("Camera")
+        .target_frame_rate(60)
+        .build()?;
+    let mut app = CameraDemo::new();
+    engine.run(&mut app)
+}
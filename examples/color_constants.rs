@@ -181,7 +181,14 @@ impl PixEngine for ColorConsts {
         for (i, &color) in COLORS.iter().enumerate() {
             let pos = s.mouse_pos();
             if self.cells[i].contains(pos) {
-                s.tooltip(color.1)?;
+                let (width, height) = s.size_of(color.1)?;
+                let tooltip = rect![pos, width as i32 + 16, height as i32 + 16];
+                s.advanced_tooltip("Color", tooltip, |s: &mut PixState| {
+                    s.background(Color::DARK_GRAY);
+                    s.fill(Color::WHITE);
+                    s.text(color.1)?;
+                    Ok(())
+                })?;
             }
         }
         Ok(())
@@ -0,0 +1,121 @@
+use pix_engine::prelude::*;
+
+const SHAPES: [&str; 3] = ["Circle", "Square", "Triangle"];
+
+struct Editor {
+    tree_ratio: f32,
+    log_ratio: f32,
+    show_shapes: bool,
+    selected: usize,
+    color: Color,
+    log: Vec<String>,
+}
+
+impl Editor {
+    fn new() -> Self {
+        Self {
+            tree_ratio: 0.25,
+            log_ratio: 0.75,
+            show_shapes: true,
+            selected: 0,
+            color: Color::CADET_BLUE,
+            log: vec!["Editor started".into()],
+        }
+    }
+
+    fn toolbar(&mut self, s: &mut PixState) -> PixResult<()> {
+        if s.button("New")? {
+            self.selected = 0;
+            self.log.push("New scene".into());
+        }
+        s.same_line(None);
+        if s.button("Randomize Color")? {
+            self.color = Color::random();
+            self.log.push(format!("Color changed to {}", self.color));
+        }
+        s.same_line(None);
+        if s.button("Clear Log")? {
+            self.log.clear();
+        }
+        Ok(())
+    }
+}
+
+fn draw_shape(s: &mut PixState, shape: usize, color: Color) -> PixResult<()> {
+    let pos = s.cursor_pos();
+    let size = s.ui_width()?.min(s.ui_height()?).max(0);
+    let [x, y] = pos.coords();
+    s.push();
+    s.stroke(None);
+    s.fill(color);
+    match shape {
+        0 => s.circle([x + size / 2, y + size / 2, size / 3])?,
+        1 => s.square([x + size / 6, y + size / 6, 2 * size / 3])?,
+        _ => s.triangle([
+            point![x + size / 2, y + size / 6],
+            point![x + size / 6, y + 5 * size / 6],
+            point![x + 5 * size / 6, y + 5 * size / 6],
+        ])?,
+    }
+    s.pop();
+    Ok(())
+}
+
+impl PixEngine for Editor {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.clear()?;
+
+        // Toolbar
+        self.toolbar(s)?;
+        s.separator()?;
+
+        // Tree on the left, with the canvas above the log on the right
+        let mut selected = self.selected;
+        let color = self.color;
+        let show_shapes = &mut self.show_shapes;
+        let log = &self.log;
+        s.split_horizontal(
+            "Editor",
+            &mut self.tree_ratio,
+            |s: &mut PixState| {
+                s.collapsible_panel("Shapes", show_shapes, |s: &mut PixState| {
+                    for (i, shape) in SHAPES.iter().enumerate() {
+                        s.radio(shape, &mut selected, i)?;
+                    }
+                    Ok(())
+                })?;
+                Ok(())
+            },
+            |s: &mut PixState| {
+                s.split_vertical(
+                    "Workspace",
+                    &mut self.log_ratio,
+                    |s: &mut PixState| draw_shape(s, self.selected, color),
+                    |s: &mut PixState| {
+                        for line in log.iter().rev() {
+                            s.text(line)?;
+                        }
+                        Ok(())
+                    },
+                )?;
+                Ok(())
+            },
+        )?;
+
+        if selected != self.selected {
+            self.selected = selected;
+            self.log.push(format!("Selected {}", SHAPES[selected]));
+        }
+        Ok(())
+    }
+}
+
+fn main() -> PixResult<()> {
+    let mut engine = Engine::builder()
+        .dimensions(1024, 768)
+        .title("Editor Layout")
+        .target_frame_rate(60)
+        .build()?;
+    let mut app = Editor::new();
+    engine.run(&mut app)
+}
@@ -3,32 +3,15 @@ use pix_engine::prelude::*;
 const FONTS: [&str; 3] = ["Emulogic", "Noto", "Inconsolata"];
 const THEMES: [&str; 2] = ["Dark", "Light"];
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Category {
-    Basic,
-    FieldsSliders,
-    SelectsCollapsables,
-    Settings,
-}
-
-impl AsRef<str> for Category {
-    fn as_ref(&self) -> &str {
-        match self {
-            Self::Basic => "Basic",
-            Self::FieldsSliders => "Fields/Sliders",
-            Self::SelectsCollapsables => "Selects/Collapsables",
-            Self::Settings => "Settings",
-        }
-    }
-}
-
 struct Gui {
     disabled: bool,
-    selected_category: Category,
+    show_about: bool,
+    confirm_quit: bool,
     button_clicked: bool,
     text_clicked: bool,
     checkbox: bool,
     radio: usize,
+    color: Color,
     text_field: String,
     advanced_text_field: String,
     text_area: String,
@@ -37,7 +20,9 @@ struct Gui {
     advanced_drag: f64,
     slider: i32,
     advanced_slider: f32,
+    knob: f32,
     select_box: usize,
+    combo_box: usize,
     select_list: usize,
     collapsing_header_list: usize,
     font_size: u32,
@@ -53,11 +38,13 @@ impl Gui {
     fn new() -> Self {
         Self {
             disabled: false,
-            selected_category: Category::Basic,
+            show_about: false,
+            confirm_quit: false,
             button_clicked: false,
             text_clicked: false,
             checkbox: true,
             radio: 0,
+            color: Color::STEEL_BLUE,
             text_field: "Hello, world!".into(),
             advanced_text_field: String::new(),
             text_area: "Hello, world!".into(),
@@ -66,7 +53,9 @@ impl Gui {
             advanced_drag: 1.0,
             slider: 0,
             advanced_slider: 0.5,
+            knob: 0.0,
             select_box: 0,
+            combo_box: 0,
             select_list: 0,
             collapsing_header_list: 0,
             font_size: 12,
@@ -104,14 +93,20 @@ impl Gui {
         s.same_line(None);
         s.radio("Radio 3", &mut self.radio, 2)?;
 
+        s.color_picker("Color Picker", &mut self.color)?;
+        s.context_menu("Color Menu", |s| {
+            if s.menu_item("Reset Color")? {
+                self.color = Color::STEEL_BLUE;
+            }
+            Ok(())
+        })?;
+
         Ok(())
     }
 
     fn tooltip_widgets(&mut self, s: &mut PixState) -> PixResult<()> {
         s.text("Hover me")?;
-        if s.hovered() {
-            s.tooltip("A hot tooltip")?;
-        }
+        s.tooltip("A hot tooltip")?;
         s.same_line(None);
         s.text("- and me!")?;
         if s.hovered() {
@@ -143,10 +138,7 @@ impl Gui {
 
     fn text_widgets(&mut self, s: &mut PixState) -> PixResult<()> {
         s.text_transformed("Flipped", None, None, Flipped::Both)?;
-        if s.hovered() {
-            s.fill(Color::LIGHT_GREY);
-            s.tooltip("Flipped")?;
-        }
+        s.tooltip("Flipped")?;
         s.angle_mode(AngleMode::Degrees);
         s.fill(Color::LIGHT_YELLOW);
         s.text_transformed("Rotated\nLines", 30.0, None, None)?;
@@ -276,6 +268,34 @@ impl Gui {
             Some(|v| format!("{v:.3}").into()),
         )?;
 
+        // Knobs
+        s.group_box("Knobs")?;
+        s.knob("Knob", &mut self.knob, -1.0, 1.0)?;
+        s.same_line(None);
+        s.help_marker(
+            "Click and drag up/down to edit value.\n\
+            Double-click or CTRL+click to reset.\n\
+            (CTRL is mapped to CMD on macOs)",
+        )?;
+        s.end_group_box()?;
+
+        // Progress bars
+        s.next_width(200);
+        s.progress_bar("Progress Bar", self.advanced_drag)?;
+        s.same_line([8, 0]);
+        s.next_width(200);
+        s.advanced_progress_bar(
+            "Advanced Progress Bar",
+            f64::from(self.advanced_slider),
+            0.0,
+            3.0,
+            Some(|v| format!("{v:.2} / 3.00").into()),
+        )?;
+
+        // Plots
+        s.next_width(200);
+        s.frame_time_graph(120)?;
+
         Ok(())
     }
 
@@ -313,6 +333,10 @@ impl Gui {
         s.next_width(200);
         s.select_box("Select Box", &mut self.select_box, &items, displayed_count)?;
 
+        s.same_line(None);
+        s.next_width(200);
+        s.combo_box("Combo Box", &items, &mut self.combo_box)?;
+
         s.collapsing_tree("Basic tree", |s: &mut PixState| {
             for i in 0..3 {
                 s.collapsing_tree(format!("Child {i}"), |s: &mut PixState| {
@@ -322,6 +346,13 @@ impl Gui {
             }
             Ok(())
         })?;
+        if s.tree_node("Tree node")? {
+            if s.tree_node("Child node")? {
+                s.text("Some text")?;
+                s.tree_pop();
+            }
+            s.tree_pop();
+        }
         s.collapsing_header("Collapsing Header", |s: &mut PixState| {
             s.next_width(300);
             let items = ["Item 1", "Item 2", "Item 3", "Item 4"];
@@ -329,17 +360,60 @@ impl Gui {
             Ok(())
         })?;
 
+        s.tab_bar("Nested Tab Bar", |s: &mut PixState| {
+            s.tab("First", |s: &mut PixState| {
+                s.text("First tab content")?;
+                Ok(())
+            })?;
+            s.tab("Second", |s: &mut PixState| {
+                s.bullet("Second tab content")?;
+                Ok(())
+            })
+        })?;
+
         Ok(())
     }
 
+    fn menu_bar(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.menu_bar(|s| {
+            s.menu_dropdown("File", |s| {
+                if s.menu_item_shortcut("Reset", "Ctrl+R")? {
+                    *self = Self::new();
+                    s.set_theme(Theme::dark());
+                }
+                if s.menu_item("About")? {
+                    self.show_about = true;
+                }
+                s.menu_separator()?;
+                if s.menu_item_shortcut("Quit", "Ctrl+Q")? {
+                    self.confirm_quit = true;
+                }
+                Ok(())
+            })?;
+            s.menu_dropdown("View", |s| {
+                for (i, theme) in THEMES.iter().enumerate() {
+                    if s.menu_item(format!("{theme} Theme"))? {
+                        self.theme = i;
+                        self.apply_theme(s)?;
+                    }
+                }
+                Ok(())
+            })
+        })
+    }
+
+    fn apply_theme(&mut self, s: &mut PixState) -> PixResult<()> {
+        match THEMES[self.theme] {
+            "Dark" => s.try_set_theme(Theme::dark()),
+            "Light" => s.try_set_theme(Theme::light()),
+            _ => unreachable!("unavailable theme"),
+        }
+    }
+
     fn settings(&mut self, s: &mut PixState) -> PixResult<()> {
         s.next_width(200);
         if s.select_box("Theme", &mut self.theme, &THEMES, THEMES.len())? {
-            match THEMES[self.theme] {
-                "Dark" => s.set_theme(Theme::dark()),
-                "Light" => s.set_theme(Theme::light()),
-                _ => unreachable!("unavailable theme"),
-            }
+            self.apply_theme(s)?;
         }
 
         s.next_width(200);
@@ -389,6 +463,8 @@ impl PixEngine for Gui {
     fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
         s.clear()?;
 
+        self.menu_bar(s)?;
+
         if self.disabled {
             s.disable(true);
         }
@@ -396,34 +472,21 @@ impl PixEngine for Gui {
         s.heading("Widgets")?;
         s.spacing()?;
 
-        let mut selected = self.selected_category;
-        s.tab_bar(
-            "Tab Bar",
-            &[
-                Category::Basic,
-                Category::FieldsSliders,
-                Category::SelectsCollapsables,
-                Category::Settings,
-            ],
-            &mut selected,
-            |tab: &Category, s: &mut PixState| {
-                match tab {
-                    Category::Basic => {
-                        self.basic_widgets(s)?;
-                        self.tooltip_widgets(s)?;
-                        self.text_widgets(s)?;
-                    }
-                    Category::FieldsSliders => {
-                        self.text_field_widgets(s)?;
-                        self.drag_and_slider_widgets(s)?;
-                    }
-                    Category::SelectsCollapsables => self.select_and_tree_widgets(s)?,
-                    Category::Settings => self.settings(s)?,
-                }
-                Ok(())
-            },
-        )?;
-        self.selected_category = selected;
+        s.tab_bar("Tab Bar", |s: &mut PixState| {
+            s.tab("Basic", |s: &mut PixState| {
+                self.basic_widgets(s)?;
+                self.tooltip_widgets(s)?;
+                self.text_widgets(s)
+            })?;
+            s.tab("Fields/Sliders", |s: &mut PixState| {
+                self.text_field_widgets(s)?;
+                self.drag_and_slider_widgets(s)
+            })?;
+            s.tab("Selects/Collapsables", |s: &mut PixState| {
+                self.select_and_tree_widgets(s)
+            })?;
+            s.tab("Settings", |s: &mut PixState| self.settings(s))
+        })?;
 
         s.separator()?;
 
@@ -434,8 +497,31 @@ impl PixEngine for Gui {
         }
         s.text(format!("Mouse: {}", s.mouse_pos()))?;
 
+        s.disable(false);
+        s.modal("About", &mut self.show_about, |s: &mut PixState| {
+            s.text("A demo of the widgets provided by pix-engine.")?;
+            Ok(())
+        })?;
+        if self.confirm_quit {
+            match s.message_box("Quit", "Are you sure?", MessageBoxButtons::OkCancel)? {
+                Some(MessageBoxResult::Ok) => s.quit(),
+                Some(_) => self.confirm_quit = false,
+                None => (),
+            }
+        }
+
         Ok(())
     }
+
+    fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+        // Toggle between dark and light themes
+        if event.key == Key::F2 {
+            self.theme = (self.theme + 1) % THEMES.len();
+            self.apply_theme(s)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
 }
 
 fn main() -> PixResult<()> {
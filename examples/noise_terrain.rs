@@ -0,0 +1,90 @@
+use pix_engine::prelude::*;
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 600;
+const SCALE: u32 = 4;
+const MAP_WIDTH: u32 = WIDTH / SCALE;
+const MAP_HEIGHT: u32 = HEIGHT / SCALE;
+const ZOOM: f64 = 0.02;
+const SCROLL_SPEED: f64 = 20.0;
+const OCTAVES: u32 = 6;
+
+struct NoiseTerrain {
+    noise: Noise,
+    heightmap: Image,
+    offset: f64,
+}
+
+impl NoiseTerrain {
+    fn new() -> Self {
+        Self {
+            noise: Noise::new(0),
+            heightmap: Image::rgb(MAP_WIDTH, MAP_HEIGHT),
+            offset: 0.0,
+        }
+    }
+
+    fn terrain_color(height: f64) -> Color {
+        match height {
+            h if h < -0.2 => Color::rgb(20, 50, 140),
+            h if h < 0.0 => Color::rgb(40, 90, 190),
+            h if h < 0.05 => Color::rgb(210, 200, 140),
+            h if h < 0.3 => Color::rgb(60, 140, 60),
+            h if h < 0.5 => Color::rgb(110, 100, 90),
+            _ => Color::rgb(240, 240, 245),
+        }
+    }
+
+    fn generate(&mut self) {
+        for y in 0..MAP_HEIGHT {
+            for x in 0..MAP_WIDTH {
+                let world_x = (f64::from(x) + self.offset) * ZOOM;
+                let world_y = f64::from(y) * ZOOM;
+                // Boost contrast since summed octaves rarely reach the extremes
+                let height = self.noise.fbm([world_x, world_y], OCTAVES, 2.0, 0.5) * 1.5;
+                self.heightmap.set_pixel(x, y, Self::terrain_color(height));
+            }
+        }
+    }
+}
+
+impl PixEngine for NoiseTerrain {
+    fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.fill(Color::WHITE);
+        Ok(())
+    }
+
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        self.offset += SCROLL_SPEED * s.delta_time().as_secs_f64();
+        self.generate();
+        s.image_transformed(
+            &self.heightmap,
+            None,
+            rect![0, 0, WIDTH as i32, HEIGHT as i32],
+            None,
+            None,
+            None,
+        )?;
+        s.text(format!("Seed: {}", self.noise.seed()))?;
+        s.text("<Space>: New Seed")?;
+        Ok(())
+    }
+
+    fn on_key_pressed(&mut self, _s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+        if event.key == Key::Space {
+            self.noise = Noise::new(random!(u64::MAX));
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+fn main() -> PixResult<()> {
+    let mut engine = Engine::builder()
+        .dimensions(WIDTH, HEIGHT)
+        .title("Noise Terrain")
+        .show_frame_rate()
+        .build()?;
+    let mut app = NoiseTerrain::new();
+    engine.run(&mut app)
+}
@@ -0,0 +1,50 @@
+//! `hello_world` compiled for the web. Build with:
+//!
+//! ```sh
+//! cargo build --example wasm_example --target wasm32-unknown-unknown --release
+//! wasm-bindgen --target web --out-dir examples/web/pkg \
+//!   target/wasm32-unknown-unknown/release/examples/wasm_example.wasm
+//! ```
+//!
+//! Then serve the `examples/web` directory with any static file server and open `index.html`.
+
+use pix_engine::prelude::*;
+
+struct HelloWorld;
+
+impl PixEngine for HelloWorld {
+    // Set up any state or resources before starting main event loop.
+    fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.background(220);
+        Ok(())
+    }
+
+    // Main render loop. Called once per browser animation frame, or based on
+    // `target_frame_rate`.
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.clear()?;
+        s.text("Hello world!")?;
+        Ok(())
+    }
+
+    // Teardown any state or resources before exiting.
+    fn on_stop(&mut self, _s: &mut PixState) -> PixResult<()> {
+        Ok(())
+    }
+}
+
+fn main() -> PixResult<()> {
+    let engine = Engine::builder()
+        .dimensions(800, 600)
+        .title("Hello World")
+        .build()?;
+    #[cfg(target_arch = "wasm32")]
+    {
+        engine.run_web(HelloWorld)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut engine = engine;
+        engine.run(&mut HelloWorld)
+    }
+}
@@ -0,0 +1,71 @@
+use pix_engine::prelude::*;
+
+const MIN_SIZE: (u32, u32) = (320, 240);
+const MAX_SIZE: (u32, u32) = (1280, 720);
+
+struct WindowConstraints {
+    opacity: f32,
+    clamped: Option<(u32, u32)>,
+}
+
+impl WindowConstraints {
+    fn new() -> Self {
+        Self {
+            opacity: 1.0,
+            clamped: None,
+        }
+    }
+}
+
+impl PixEngine for WindowConstraints {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.clear()?;
+
+        let (width, height) = s.window_dimensions()?;
+        s.text(format!("Window size: {width}x{height}"))?;
+        s.text(format!(
+            "Resizable between {}x{} and {}x{}",
+            MIN_SIZE.0, MIN_SIZE.1, MAX_SIZE.0, MAX_SIZE.1
+        ))?;
+
+        if s.button("Shrink to 100x100")? {
+            self.clamped = Some(s.set_window_dimensions((100, 100))?);
+        }
+        s.same_line(None);
+        if s.button("Grow to 4000x4000")? {
+            self.clamped = Some(s.set_window_dimensions((4000, 4000))?);
+        }
+        if let Some((width, height)) = self.clamped {
+            s.text(format!("Clamped to: {width}x{height}"))?;
+        }
+
+        if s.slider("Opacity", &mut self.opacity, 0.2, 1.0)? {
+            s.set_window_opacity(self.opacity)?;
+        }
+
+        if s.button("Minimize")? {
+            s.minimize_window()?;
+        }
+        s.same_line(None);
+        if s.button("Maximize")? {
+            s.maximize_window()?;
+        }
+        s.same_line(None);
+        if s.button("Restore")? {
+            s.restore_window()?;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> PixResult<()> {
+    let mut engine = Engine::builder()
+        .dimensions(640, 480)
+        .title("Window Constraints")
+        .resizable()
+        .min_size(MIN_SIZE.0, MIN_SIZE.1)
+        .max_size(MAX_SIZE.0, MAX_SIZE.1)
+        .build()?;
+    let mut app = WindowConstraints::new();
+    engine.run(&mut app)
+}
@@ -2,10 +2,15 @@
 //!
 //! There are several methods for playing audio in your application:
 //!
-//! - Queuing pre-recorded or generated audio samples by calling [`PixState::enqueue_audio`].
+//! - Queuing pre-recorded or generated audio samples by calling [`PixState::enqueue_audio`], or
+//!   [`PixState::enqueue_audio_stereo`] for interleaved stereo samples.
 //! - Having [`Engine`] request pre-recorded or generated audio samples by implementing the
 //!   [`AudioCallback`] trait on a type and calling [`PixState::open_playback`].
-//! - Loading and playing a `.wav` or `.mp3` file. (Coming soon!).
+//! - Loading sounds from `.wav` files with [`PixState::load_sound`] and playing them with
+//!   [`PixState::play_sound`], or streaming music with [`PixState::play_music`]. Playing sounds
+//!   and music are mixed together into the audio queue once per frame, keeping at least 100ms of
+//!   audio queued, so frames that take longer than that cause gaps in playback. `.ogg` files are
+//!   supported with the `ogg` feature.
 //!
 //! You can also record audio from a capture device using [`PixState::open_capture`].
 //!
@@ -44,6 +49,38 @@
 //! }
 //! ```
 //!
+//! ## Sounds and Music
+//!
+//! ```no_run
+//! use pix_engine::prelude::*;
+//!
+//! struct MyApp {
+//!     jump: Option<SoundId>,
+//! }
+//!
+//! impl PixEngine for MyApp {
+//!     fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+//!         s.resume_audio();
+//!         self.jump = Some(s.load_sound("jump.wav")?);
+//!         // Loop music forever
+//!         s.play_music("music.wav", 0)?;
+//!         Ok(())
+//!     }
+//!
+//!     fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!         Ok(())
+//!     }
+//!
+//!     fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+//!         if let (Key::Space, Some(jump)) = (event.key, self.jump) {
+//!             s.play_sound(jump)?;
+//!             return Ok(true);
+//!         }
+//!         Ok(false)
+//!     }
+//! }
+//! ```
+//!
 //! ## Audio Callback
 //!
 //! ```no_run
@@ -183,8 +220,23 @@
 //! [`PixEngine`]: crate::prelude::PixEngine
 
 use crate::prelude::*;
+use anyhow::anyhow;
+use log::error;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt, mem,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+pub(crate) use mixer::Mixer;
+pub use mixer::{SoundParams, VoiceId};
+use sound::Sound;
+pub use sound::SoundId;
+
+mod mixer;
+mod sound;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use crate::renderer::sdl::{AudioDevice, AudioFormatNum};
@@ -239,6 +291,43 @@ where
     fn callback(&mut self, buffer: &mut [Self::Channel]);
 }
 
+/// An [`AudioCallback`] wrapping a closure passed to [`EngineBuilder::with_audio_callback`].
+///
+/// [`EngineBuilder::with_audio_callback`]: crate::engine::EngineBuilder::with_audio_callback
+#[derive(Clone)]
+pub struct AudioFn(Arc<Mutex<AudioFnCallback>>);
+
+/// Closure filling a buffer of interleaved `f32` samples.
+type AudioFnCallback = dyn FnMut(&mut [f32]) + Send;
+
+impl AudioFn {
+    /// Wraps a closure filling a buffer of interleaved `f32` samples.
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&mut [f32]) + Send + 'static,
+    {
+        Self(Arc::new(Mutex::new(f)))
+    }
+}
+
+impl fmt::Debug for AudioFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AudioFn").finish_non_exhaustive()
+    }
+}
+
+impl AudioCallback for AudioFn {
+    type Channel = f32;
+
+    fn callback(&mut self, buffer: &mut [Self::Channel]) {
+        match self.0.lock() {
+            Ok(mut f) => f(buffer),
+            // Output silence if a previous callback panicked
+            Err(_) => buffer.fill(0.0),
+        }
+    }
+}
+
 /// Audio number and endianness format for the given audio device.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -395,12 +484,16 @@ pub trait AudioDeviceDriver {
 }
 
 impl PixState {
-    /// Add samples to the current audio buffer queue.
+    /// Add samples to the current audio buffer queue, returning the number of samples queued.
+    ///
+    /// This never blocks. Once the queue nears [`PixState::audio_capacity`], only the samples
+    /// that fit are queued, rounded down to whole frames for the number of
+    /// [`PixState::audio_channels`]. Compare the returned count with the number of samples passed
+    /// in to retry the remainder later. Previously, a full queue returned an error instead.
     ///
     /// # Errors
     ///
-    /// If the audio device fails to queue samples, or if the audio buffer max size is reached,
-    /// then an error is returned.
+    /// If the audio device fails to queue samples, then an error is returned.
     ///
     /// # Example
     ///
@@ -420,17 +513,82 @@ impl PixState {
     ///         samples.push(if s <= 0.0 { -volume } else { volume });
     ///     }
     ///     // Add samples to audio queue for playback
-    ///     s.enqueue_audio(&samples)?;
+    ///     let queued = s.enqueue_audio(&samples)?;
+    ///     if queued < samples.len() {
+    ///         // Queue is full, try again with `&samples[queued..]` next frame
+    ///     }
     ///     Ok(())
     /// }
     /// # }
     /// ```
     #[inline]
-    pub fn enqueue_audio<S: AsRef<[f32]>>(&mut self, samples: S) -> PixResult<()> {
+    pub fn enqueue_audio<S: AsRef<[f32]>>(&mut self, samples: S) -> PixResult<usize> {
         self.renderer.enqueue_audio(samples.as_ref())
     }
 
-    /// Clear audio samples from the current audio buffer queue.
+    /// Add interleaved stereo samples, alternating between the left and right channels, to the
+    /// current audio buffer queue, returning the number of samples queued. The audio queue must be
+    /// opened with 2 channels using [`EngineBuilder::stereo_audio`]. Like
+    /// [`PixState::enqueue_audio`], only the sample pairs that fit are queued once the queue is
+    /// nearly full.
+    ///
+    /// # Errors
+    ///
+    /// If the audio queue isn't stereo, if `samples` has an odd length, or if the audio device
+    /// fails to queue samples, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::{math::PI, prelude::*};
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // A4 note panning from the left to the right channel
+    ///     let sample_rate = s.audio_sample_rate() as f32;
+    ///     let sample_count = 2 * sample_rate as usize;
+    ///     let frequency = 440.0;
+    ///     let mut samples = Vec::with_capacity(2 * sample_count);
+    ///     for x in 0..sample_count {
+    ///         let pan = x as f32 / sample_count as f32;
+    ///         let s = 0.2 * (2.0 * PI as f32 * frequency * x as f32 / sample_rate).sin();
+    ///         samples.extend([(1.0 - pan) * s, pan * s]);
+    ///     }
+    ///     s.enqueue_audio_stereo(&samples)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`EngineBuilder::stereo_audio`]: crate::engine::EngineBuilder::stereo_audio
+    #[inline]
+    pub fn enqueue_audio_stereo<S: AsRef<[f32]>>(&mut self, samples: S) -> PixResult<usize> {
+        let samples = samples.as_ref();
+        check_stereo(self.audio_channels(), samples.len())?;
+        self.renderer.enqueue_audio(samples)
+    }
+
+    /// Clear audio samples from the current audio buffer queue, for example when seeking or
+    /// changing scenes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { music: Vec<f32> };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if event.key == Key::Home {
+    ///         // Restart music from the beginning
+    ///         s.clear_audio();
+    ///         s.enqueue_audio(&self.music)?;
+    ///         return Ok(true);
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
     #[inline]
     pub fn clear_audio(&mut self) {
         self.renderer.clear_audio();
@@ -470,20 +628,78 @@ impl PixState {
         self.renderer.audio_driver()
     }
 
-    /// Returns the sample rate for the current audio queue device.
+    /// Returns the sample rate for the current audio queue device, as negotiated with the audio
+    /// driver. This may differ from the rate requested with [`EngineBuilder::audio_sample_rate`],
+    /// so use it when synthesizing samples to keep them in pitch.
+    ///
+    /// [`EngineBuilder::audio_sample_rate`]: crate::engine::EngineBuilder::audio_sample_rate
     #[inline]
     #[must_use]
     pub fn audio_sample_rate(&self) -> i32 {
         self.renderer.audio_sample_rate()
     }
 
-    /// Returns the queued buffer size of the current audio queue device.
+    /// Returns the number of channels for the current audio queue device, as negotiated with the
+    /// audio driver. This may differ from the number requested with
+    /// [`EngineBuilder::audio_channels`].
+    ///
+    /// [`EngineBuilder::audio_channels`]: crate::engine::EngineBuilder::audio_channels
+    #[inline]
+    #[must_use]
+    pub fn audio_channels(&self) -> u8 {
+        self.renderer.audio_channels()
+    }
+
+    /// Returns the queued buffer size, in bytes, of the current audio queue device.
+    /// [`PixState::enqueue_audio`] never blocks and stops queueing samples once the queue reaches
+    /// [`PixState::audio_capacity`], so use this to apply backpressure when generating samples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { samples: Vec<f32> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Keep about a quarter second of audio queued
+    ///     let bytes_per_second = s.audio_sample_rate() as u32
+    ///         * u32::from(s.audio_channels())
+    ///         * std::mem::size_of::<f32>() as u32;
+    ///     if s.audio_queued_size() < bytes_per_second / 4 {
+    ///         s.enqueue_audio(&self.samples)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
     pub fn audio_queued_size(&self) -> u32 {
         self.renderer.audio_queued_size()
     }
 
+    /// Returns the maximum size, in bytes, that the audio queue can hold. Samples past this size
+    /// aren't queued by [`PixState::enqueue_audio`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let remaining = s.audio_capacity() - s.audio_queued_size();
+    ///     s.text(format!("Audio queue space: {remaining} bytes"))?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn audio_capacity(&self) -> u32 {
+        MAX_QUEUE_SIZE
+    }
+
     /// Returns the buffer size of the current audio queue device.
     #[inline]
     #[must_use]
@@ -726,10 +942,229 @@ impl PixState {
     }
 }
 
+impl PixState {
+    /// Load and decode a `.wav` sound file, returning a [`SoundId`] to play it with
+    /// [`PixState::play_sound`]. `.ogg` files are supported with the `ogg` feature. Integer and
+    /// floating point samples of any sample rate are converted to match the audio queue.
+    ///
+    /// # Errors
+    ///
+    /// If the file type is unsupported or the file fails to be read or decoded, then an error is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pix_engine::prelude::*;
+    /// # struct App { jump: Option<SoundId> };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     self.jump = Some(s.load_sound("jump.wav")?);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn load_sound<P: AsRef<Path>>(&mut self, path: P) -> PixResult<SoundId> {
+        let sound = Sound::from_file(path.as_ref())?;
+        Ok(self.mixer.add_sound(sound))
+    }
+
+    /// Unload a sound loaded with [`PixState::load_sound`]. Instances of the sound already playing
+    /// continue until finished.
+    ///
+    /// # Errors
+    ///
+    /// If the sound was already unloaded or is invalid, then an error is returned.
+    #[inline]
+    pub fn unload_sound(&mut self, id: SoundId) -> PixResult<()> {
+        self.mixer.remove_sound(id)
+    }
+
+    /// Play a sound loaded with [`PixState::load_sound`], mixing it with any other sounds playing.
+    /// Returns a [`VoiceId`] to control this instance of the sound while it plays.
+    ///
+    /// Sounds are mixed into the audio queue once per frame, so playback starts at the end of the
+    /// current frame and depends on a steady frame rate. See the [module-level documentation].
+    ///
+    /// [module-level documentation]: crate::audio
+    ///
+    /// # Errors
+    ///
+    /// If the sound was unloaded or is invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { jump: SoundId };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if event.key == Key::Space {
+    ///         s.play_sound(self.jump)?;
+    ///         return Ok(true);
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn play_sound(&mut self, id: SoundId) -> PixResult<VoiceId> {
+        self.play_sound_with(id, SoundParams::default())
+    }
+
+    /// Play a sound loaded with [`PixState::load_sound`] with the given volume, pan, and pitch.
+    ///
+    /// # Errors
+    ///
+    /// If the sound was unloaded or is invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { coin: SoundId };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if event.key == Key::Space {
+    ///         // Play quieter, higher, and to the right
+    ///         s.play_sound_with(
+    ///             self.coin,
+    ///             SoundParams {
+    ///                 volume: 0.5,
+    ///                 pan: 0.75,
+    ///                 pitch: 1.5,
+    ///             },
+    ///         )?;
+    ///         return Ok(true);
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn play_sound_with(&mut self, id: SoundId, params: SoundParams) -> PixResult<VoiceId> {
+        self.mixer.play(id, params)
+    }
+
+    /// Change the volume, pan, and pitch of a playing sound. Returns `false` if the sound has
+    /// already finished playing.
+    #[inline]
+    pub fn set_sound_params(&mut self, voice: VoiceId, params: SoundParams) -> bool {
+        self.mixer.set_params(voice, params)
+    }
+
+    /// Returns whether a sound started with [`PixState::play_sound`] is still playing.
+    #[inline]
+    #[must_use]
+    pub fn sound_playing(&self, voice: VoiceId) -> bool {
+        self.mixer.is_playing(voice)
+    }
+
+    /// Stop a playing sound.
+    #[inline]
+    pub fn stop_sound(&mut self, voice: VoiceId) {
+        self.mixer.stop(voice);
+    }
+
+    /// Stream music from a `.wav` file, decoding it as it plays and replacing any music already
+    /// playing. `.ogg` files are supported with the `ogg` feature. `loops` is the number of times
+    /// to play the music, or `0` to loop forever.
+    ///
+    /// Music is decoded and mixed into the audio queue once per frame, so playback depends on a
+    /// steady frame rate. See the [module-level documentation].
+    ///
+    /// [module-level documentation]: crate::audio
+    ///
+    /// # Errors
+    ///
+    /// If the file type is unsupported or the file fails to be opened, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.resume_audio();
+    ///     s.play_music("theme.wav", 0)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn play_music<P: AsRef<Path>>(&mut self, path: P, loops: usize) -> PixResult<()> {
+        self.mixer.play_music(path.as_ref(), loops)
+    }
+
+    /// Stop any music started with [`PixState::play_music`].
+    #[inline]
+    pub fn stop_music(&mut self) {
+        self.mixer.stop_music();
+    }
+
+    /// Returns whether music started with [`PixState::play_music`] is still playing.
+    #[inline]
+    #[must_use]
+    pub fn music_playing(&self) -> bool {
+        self.mixer.is_music_playing()
+    }
+
+    /// Returns the master volume applied to mixed sounds and music.
+    #[inline]
+    #[must_use]
+    pub fn master_volume(&self) -> f32 {
+        self.mixer.master_volume()
+    }
+
+    /// Set the master volume applied to mixed sounds and music, where `1.0` is the original
+    /// volume. Mixed samples are clipped to `-1.0..=1.0`.
+    #[inline]
+    pub fn set_master_volume(&mut self, volume: f32) {
+        self.mixer.set_master_volume(volume);
+    }
+
+    /// Mix playing sounds and music into the audio queue, keeping enough audio queued to play
+    /// until the next frame.
+    pub(crate) fn mix_audio(&mut self) {
+        if !self.mixer.is_active() {
+            return;
+        }
+        let channels = usize::from(self.audio_channels().max(1));
+        let Ok(sample_rate) = u32::try_from(self.audio_sample_rate()) else {
+            return;
+        };
+        let frame_size = channels * mem::size_of::<f32>();
+        // Keep at least 100ms queued so audio doesn't run out between frames
+        let target_frames =
+            (2 * self.audio_size() as usize / frame_size).max(sample_rate as usize / 10);
+        let queued_frames = self.audio_queued_size() as usize / frame_size;
+        let Some(frames) = target_frames.checked_sub(queued_frames).filter(|&f| f > 0) else {
+            return;
+        };
+        let samples = match self.mixer.mix_frames(frames, channels, sample_rate) {
+            Ok(samples) => samples,
+            Err(err) => {
+                error!("Failed to decode music: {}", err);
+                self.mixer.stop_music();
+                return;
+            }
+        };
+        if let Err(err) = self.renderer.enqueue_audio(samples) {
+            error!("Failed to queue mixed audio: {}", err);
+        }
+    }
+}
+
 /// Trait representing audio support.
 pub(crate) trait AudioDriver {
-    /// Add audio samples to the current audio buffer queue.
-    fn enqueue_audio(&mut self, samples: &[f32]) -> PixResult<()>;
+    /// Add audio samples to the current audio buffer queue, returning the number of samples
+    /// queued.
+    fn enqueue_audio(&mut self, samples: &[f32]) -> PixResult<usize>;
 
     /// Clear audio samples from the current audio buffer queue.
     fn clear_audio(&mut self);
@@ -743,6 +1178,9 @@ pub(crate) trait AudioDriver {
     /// Return the sample rate of the current audio queue device.
     fn audio_sample_rate(&self) -> i32;
 
+    /// Return the number of channels of the current audio queue device.
+    fn audio_channels(&self) -> u8;
+
     /// Returns the queued buffer size (in bytes) of the current audio queue device.
     fn audio_queued_size(&self) -> u32;
 
@@ -781,3 +1219,82 @@ pub(crate) trait AudioDriver {
         F: FnOnce(AudioSpec) -> CB,
         D: Into<Option<&'a str>>;
 }
+
+/// Queue size, in bytes, past which a warning is logged. ~1.5 minutes of audio @ 48,000 HZ.
+pub(crate) const WARN_QUEUE_SIZE: u32 = 1 << 22;
+/// Maximum queue size, in bytes. ~11.5 minutes of audio @ 48,000 HZ.
+pub(crate) const MAX_QUEUE_SIZE: u32 = 1 << 25;
+
+/// Returns how many of `len` samples fit in an audio queue with `queued` bytes, without exceeding
+/// [`MAX_QUEUE_SIZE`]. Partial writes are rounded down to whole frames of `channels` samples.
+pub(crate) fn queueable_samples(queued: u32, channels: u8, len: usize) -> usize {
+    let available = (MAX_QUEUE_SIZE.saturating_sub(queued) as usize) / mem::size_of::<f32>();
+    if len <= available {
+        len
+    } else {
+        available - available % usize::from(channels.max(1))
+    }
+}
+
+/// Validate that interleaved stereo samples can be queued on an audio queue with `channels`.
+fn check_stereo(channels: u8, len: usize) -> PixResult<()> {
+    if channels != 2 {
+        Err(anyhow!(
+            "stereo audio requires an audio queue with 2 channels, found: {channels}"
+        ))
+    } else if len % 2 == 1 {
+        Err(anyhow!(
+            "interleaved stereo samples must come in pairs, found: {len} samples"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_fn_callback() {
+        let mut phase = 0.0;
+        let mut audio_fn = AudioFn::new(move |out: &mut [f32]| {
+            for sample in out.iter_mut() {
+                *sample = phase;
+                phase += 0.25;
+            }
+        });
+        let mut buffer = [1.0; 4];
+        audio_fn.callback(&mut buffer);
+        assert_eq!(buffer, [0.0, 0.25, 0.5, 0.75]);
+
+        // Clones share the same closure state
+        audio_fn.clone().callback(&mut buffer[..2]);
+        assert_eq!(buffer[..2], [1.0, 1.25]);
+    }
+
+    #[test]
+    fn queue_partial_writes() {
+        let max_samples = MAX_QUEUE_SIZE as usize / 4;
+        // Everything fits
+        assert_eq!(queueable_samples(0, 1, 1024), 1024);
+        assert_eq!(queueable_samples(MAX_QUEUE_SIZE - 4096, 1, 1024), 1024);
+        // Only the remaining space is queued
+        assert_eq!(queueable_samples(MAX_QUEUE_SIZE - 400, 1, 1024), 100);
+        assert_eq!(queueable_samples(0, 1, max_samples + 1), max_samples);
+        // Partial writes keep whole frames
+        assert_eq!(queueable_samples(MAX_QUEUE_SIZE - 12, 2, 8), 2);
+        // Full or overfull queue
+        assert_eq!(queueable_samples(MAX_QUEUE_SIZE, 2, 8), 0);
+        assert_eq!(queueable_samples(MAX_QUEUE_SIZE + 64, 1, 8), 0);
+        assert_eq!(queueable_samples(MAX_QUEUE_SIZE, 1, 0), 0);
+    }
+
+    #[test]
+    fn stereo_samples() {
+        assert!(check_stereo(2, 0).is_ok());
+        assert!(check_stereo(2, 4).is_ok());
+        assert!(check_stereo(2, 3).is_err());
+        assert!(check_stereo(1, 4).is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! Software mixer combining playing sounds and streaming music into a single stream of samples.
+
+use super::sound::{Decoder, Sound};
+use crate::{error::Error as PixError, prelude::*};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::Path};
+
+/// Lowest supported playback pitch, which keeps voices from stalling.
+const MIN_PITCH: f32 = 0.01;
+
+/// Playback parameters for an instance of a sound started with [`PixState::play_sound_with`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[must_use]
+pub struct SoundParams {
+    /// Volume multiplier, where `1.0` plays the sound at its original volume.
+    pub volume: f32,
+    /// Stereo position from `-1.0` (left) to `1.0` (right), where `0.0` is centered.
+    pub pan: f32,
+    /// Playback speed multiplier, where `2.0` plays the sound an octave higher and twice as fast.
+    pub pitch: f32,
+}
+
+impl Default for SoundParams {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            pan: 0.0,
+            pitch: 1.0,
+        }
+    }
+}
+
+impl SoundParams {
+    /// Left and right channel gains for these parameters.
+    fn gains(&self) -> (f32, f32) {
+        let pan = self.pan.clamp(-1.0, 1.0);
+        let volume = self.volume.max(0.0);
+        (volume * (1.0 - pan).min(1.0), volume * (1.0 + pan).min(1.0))
+    }
+}
+
+/// Identifier of a playing instance of a sound, returned by [`PixState::play_sound`].
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VoiceId(pub(crate) usize);
+
+impl fmt::Display for VoiceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A playing instance of a sound.
+#[derive(Debug)]
+struct Voice {
+    id: VoiceId,
+    sound: Sound,
+    params: SoundParams,
+    /// Position in source sample frames.
+    pos: f64,
+}
+
+/// Music streamed from a file as it plays.
+#[derive(Debug)]
+struct Music {
+    decoder: Decoder,
+    /// Decoded interleaved samples not yet played.
+    buffer: Vec<f32>,
+    /// Position in source sample frames, relative to the start of `buffer`.
+    pos: f64,
+    /// Remaining times to repeat after the current play through, or `None` to loop forever.
+    repeats: Option<usize>,
+    ended: bool,
+}
+
+impl Music {
+    /// Returns the next interpolated stereo frame, or `None` once the music is finished.
+    fn next_frame(&mut self, step: f64) -> PixResult<Option<(f32, f32)>> {
+        let channels = self.decoder.channels();
+        let mut rewound = false;
+        loop {
+            // Buffer enough frames to interpolate between the current and next frame
+            let needed = self.pos as usize + 2;
+            while !self.ended && self.buffer.len() / channels < needed {
+                self.ended = !self.decoder.read_chunk(&mut self.buffer)?;
+            }
+            if let Some(frame) = sample_at(&self.buffer, channels, self.pos) {
+                self.pos += step;
+                return Ok(Some(frame));
+            }
+            // Rewinding twice without decoding a frame means the stream is empty
+            if rewound {
+                return Ok(None);
+            }
+            match self.repeats {
+                Some(0) => return Ok(None),
+                Some(ref mut repeats) => *repeats -= 1,
+                None => (),
+            }
+            let frames = self.buffer.len() / channels;
+            rewound = true;
+            self.decoder.rewind()?;
+            self.buffer.clear();
+            self.pos = (self.pos - frames as f64).max(0.0);
+            self.ended = false;
+        }
+    }
+
+    /// Discard samples that have already been played.
+    fn discard_played(&mut self) {
+        let channels = self.decoder.channels();
+        let played = (self.pos as usize).min(self.buffer.len() / channels);
+        self.buffer.drain(..played * channels);
+        self.pos -= played as f64;
+    }
+}
+
+/// Mixes any number of playing sounds and a music stream into interleaved `f32` samples.
+#[derive(Debug)]
+pub(crate) struct Mixer {
+    sounds: Vec<Option<Sound>>,
+    voices: Vec<Voice>,
+    next_voice: usize,
+    music: Option<Music>,
+    master_volume: f32,
+    buffer: Vec<f32>,
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self {
+            sounds: Vec::new(),
+            voices: Vec::new(),
+            next_voice: 0,
+            music: None,
+            master_volume: 1.0,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl Mixer {
+    /// Add a decoded sound, returning its identifier.
+    pub(crate) fn add_sound(&mut self, sound: Sound) -> SoundId {
+        let id = SoundId(self.sounds.len());
+        self.sounds.push(Some(sound));
+        id
+    }
+
+    /// Remove a sound. Voices already playing it continue until finished.
+    pub(crate) fn remove_sound(&mut self, id: SoundId) -> PixResult<()> {
+        self.sounds
+            .get_mut(*id)
+            .and_then(Option::take)
+            .map_or(Err(PixError::InvalidSound(id).into()), |_| Ok(()))
+    }
+
+    /// Start playing a sound, returning the identifier of the new voice.
+    pub(crate) fn play(&mut self, id: SoundId, params: SoundParams) -> PixResult<VoiceId> {
+        let sound = self
+            .sounds
+            .get(*id)
+            .and_then(Option::as_ref)
+            .ok_or(PixError::InvalidSound(id))?
+            .clone();
+        let voice_id = VoiceId(self.next_voice);
+        self.next_voice += 1;
+        self.voices.push(Voice {
+            id: voice_id,
+            sound,
+            params,
+            pos: 0.0,
+        });
+        Ok(voice_id)
+    }
+
+    /// Update the parameters of a playing voice. Returns `false` if the voice has finished.
+    pub(crate) fn set_params(&mut self, id: VoiceId, params: SoundParams) -> bool {
+        self.voices
+            .iter_mut()
+            .find(|voice| voice.id == id)
+            .map(|voice| voice.params = params)
+            .is_some()
+    }
+
+    /// Stop a playing voice.
+    pub(crate) fn stop(&mut self, id: VoiceId) {
+        self.voices.retain(|voice| voice.id != id);
+    }
+
+    /// Returns whether a voice is still playing.
+    pub(crate) fn is_playing(&self, id: VoiceId) -> bool {
+        self.voices.iter().any(|voice| voice.id == id)
+    }
+
+    /// Start streaming music from a file, replacing any music already playing. `loops` is the
+    /// number of times to play the music, or `0` to loop forever.
+    pub(crate) fn play_music(&mut self, path: &Path, loops: usize) -> PixResult<()> {
+        self.music = Some(Music {
+            decoder: Decoder::open(path)?,
+            buffer: Vec::new(),
+            pos: 0.0,
+            repeats: loops.checked_sub(1),
+            ended: false,
+        });
+        Ok(())
+    }
+
+    /// Stop any music playing.
+    pub(crate) fn stop_music(&mut self) {
+        self.music = None;
+    }
+
+    /// Returns whether music is playing.
+    pub(crate) fn is_music_playing(&self) -> bool {
+        self.music.is_some()
+    }
+
+    /// Master volume applied to the mixed output.
+    pub(crate) fn master_volume(&self) -> f32 {
+        self.master_volume
+    }
+
+    /// Set the master volume applied to the mixed output.
+    pub(crate) fn set_master_volume(&mut self, volume: f32) {
+        self.master_volume = volume.max(0.0);
+    }
+
+    /// Returns whether any sounds or music are playing.
+    pub(crate) fn is_active(&self) -> bool {
+        !self.voices.is_empty() || self.music.is_some()
+    }
+
+    /// Mix the next `frames` sample frames for an output with `channels` channels at
+    /// `sample_rate`, returning the interleaved samples.
+    pub(crate) fn mix_frames(
+        &mut self,
+        frames: usize,
+        channels: usize,
+        sample_rate: u32,
+    ) -> PixResult<&[f32]> {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.resize(frames * channels.max(1), 0.0);
+        let result = self.mix(&mut buffer, channels, sample_rate);
+        self.buffer = buffer;
+        result.map(|_| self.buffer.as_slice())
+    }
+
+    /// Mix playing sounds and music into `out`, an interleaved buffer for an output with
+    /// `channels` channels at `sample_rate`. Sources at other sample rates are resampled, and
+    /// the mixed output is clipped to `-1.0..=1.0`.
+    pub(crate) fn mix(
+        &mut self,
+        out: &mut [f32],
+        channels: usize,
+        sample_rate: u32,
+    ) -> PixResult<()> {
+        out.fill(0.0);
+        let channels = channels.max(1);
+        let sample_rate = f64::from(sample_rate.max(1));
+
+        self.voices.retain_mut(|voice| {
+            let (left, right) = voice.params.gains();
+            let step = f64::from(voice.sound.sample_rate) / sample_rate
+                * f64::from(voice.params.pitch.max(MIN_PITCH));
+            for frame in out.chunks_exact_mut(channels) {
+                match sample_at(&voice.sound.samples, voice.sound.channels, voice.pos) {
+                    Some((l, r)) => add_frame(frame, l * left, r * right),
+                    None => return false,
+                }
+                voice.pos += step;
+            }
+            voice.pos < voice.sound.frames() as f64
+        });
+
+        if let Some(music) = &mut self.music {
+            let step = f64::from(music.decoder.sample_rate()) / sample_rate;
+            let mut finished = false;
+            for frame in out.chunks_exact_mut(channels) {
+                match music.next_frame(step)? {
+                    Some((l, r)) => add_frame(frame, l, r),
+                    None => {
+                        finished = true;
+                        break;
+                    }
+                }
+            }
+            if finished {
+                self.music = None;
+            } else {
+                music.discard_played();
+            }
+        }
+
+        for sample in out.iter_mut() {
+            *sample = (*sample * self.master_volume).clamp(-1.0, 1.0);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the stereo frame at fractional frame position `pos`, linearly interpolated between
+/// neighboring frames, or `None` if `pos` is past the end of `samples`.
+fn sample_at(samples: &[f32], channels: usize, pos: f64) -> Option<(f32, f32)> {
+    let frames = samples.len() / channels;
+    let index = pos as usize;
+    if index >= frames {
+        return None;
+    }
+    let frame = |index: usize| {
+        let start = index * channels;
+        let left = samples[start];
+        let right = if channels > 1 {
+            samples[start + 1]
+        } else {
+            left
+        };
+        (left, right)
+    };
+    let (l1, r1) = frame(index);
+    let (l2, r2) = if index + 1 < frames {
+        frame(index + 1)
+    } else {
+        (l1, r1)
+    };
+    let t = (pos - index as f64) as f32;
+    Some((l1 + (l2 - l1) * t, r1 + (r2 - r1) * t))
+}
+
+/// Add a stereo frame to an output frame, downmixing to mono for single channel outputs.
+fn add_frame(frame: &mut [f32], left: f32, right: f32) {
+    match frame {
+        [mono] => *mono += (left + right) / 2.0,
+        [l, r, ..] => {
+            *l += left;
+            *r += right;
+        }
+        [] => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mixer_with(sounds: &[(&[f32], usize, u32)]) -> PixResult<Mixer> {
+        let mut mixer = Mixer::default();
+        for &(samples, channels, sample_rate) in sounds {
+            let id = mixer.add_sound(Sound::new(samples.to_vec(), channels, sample_rate));
+            mixer.play(id, SoundParams::default())?;
+        }
+        Ok(mixer)
+    }
+
+    fn assert_samples(samples: &[f32], expected: &[f32]) {
+        assert_eq!(samples.len(), expected.len());
+        for (sample, expected) in samples.iter().zip(expected) {
+            assert!(
+                (sample - expected).abs() < 1e-6,
+                "{samples:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn mix_two_sounds() -> PixResult<()> {
+        let mut mixer =
+            mixer_with(&[(&[0.1, 0.2, 0.3, 0.4], 1, 4), (&[0.4, 0.3, 0.2, 0.1], 1, 4)])?;
+        let mut out = [0.0; 4];
+        mixer.mix(&mut out, 1, 4)?;
+        assert_samples(&out, &[0.5; 4]);
+        assert!(!mixer.is_active());
+        Ok(())
+    }
+
+    #[test]
+    fn mix_clips_output() -> PixResult<()> {
+        let mut mixer = mixer_with(&[(&[0.8, -0.8], 1, 2), (&[0.8, -0.8], 1, 2)])?;
+        let mut out = [0.0; 2];
+        mixer.mix(&mut out, 1, 2)?;
+        assert_samples(&out, &[1.0, -1.0]);
+
+        // Master volume is applied before clipping
+        let mut mixer = mixer_with(&[(&[0.8, -0.8], 1, 2), (&[0.8, -0.8], 1, 2)])?;
+        mixer.set_master_volume(0.5);
+        mixer.mix(&mut out, 1, 2)?;
+        assert_samples(&out, &[0.8, -0.8]);
+        Ok(())
+    }
+
+    #[test]
+    fn mix_short_sound() -> PixResult<()> {
+        let mut mixer = mixer_with(&[(&[0.5, 0.5], 1, 8)])?;
+        let mut out = [1.0; 8];
+        mixer.mix(&mut out, 1, 8)?;
+        assert_samples(&out, &[0.5, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert!(!mixer.is_active());
+        Ok(())
+    }
+
+    #[test]
+    fn mix_resamples() -> PixResult<()> {
+        // Source at half the output rate is interpolated
+        let mut mixer = mixer_with(&[(&[0.0, 0.4, 0.8], 1, 2)])?;
+        let mut out = [0.0; 6];
+        mixer.mix(&mut out, 1, 4)?;
+        assert_samples(&out, &[0.0, 0.2, 0.4, 0.6, 0.8, 0.8]);
+
+        // Source at double the output rate skips frames
+        let mut mixer = mixer_with(&[(&[0.1, 0.2, 0.3, 0.4], 1, 8)])?;
+        let mut out = [0.0; 2];
+        mixer.mix(&mut out, 1, 4)?;
+        assert_samples(&out, &[0.1, 0.3]);
+        Ok(())
+    }
+
+    #[test]
+    fn mix_stereo() -> PixResult<()> {
+        let mut mixer = Mixer::default();
+        let id = mixer.add_sound(Sound::new(vec![0.5], 1, 1));
+        let params = SoundParams {
+            pan: -1.0,
+            ..SoundParams::default()
+        };
+        mixer.play(id, params)?;
+        let mut out = [0.0; 2];
+        mixer.mix(&mut out, 2, 1)?;
+        assert_samples(&out, &[0.5, 0.0]);
+
+        // Stereo sources are downmixed for mono output
+        let mut mixer = mixer_with(&[(&[0.2, 0.6], 2, 1)])?;
+        let mut out = [0.0; 1];
+        mixer.mix(&mut out, 1, 1)?;
+        assert_samples(&out, &[0.4]);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_sound() {
+        let mut mixer = Mixer::default();
+        let id = mixer.add_sound(Sound::new(vec![0.0], 1, 1));
+        assert!(mixer.remove_sound(id).is_ok());
+        assert!(mixer.remove_sound(id).is_err());
+        assert!(mixer.play(id, SoundParams::default()).is_err());
+    }
+}
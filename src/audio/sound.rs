@@ -0,0 +1,263 @@
+//! Decoding `.wav` and `.ogg` files into `f32` samples.
+
+use crate::{error::Error as PixError, prelude::*};
+use anyhow::{anyhow, Context};
+use std::{
+    ffi::OsStr,
+    fmt,
+    fs::File,
+    io::BufReader,
+    ops::{Deref, DerefMut},
+    path::Path,
+    sync::Arc,
+};
+
+/// Number of sample frames decoded at a time when streaming music.
+const CHUNK_FRAMES: usize = 4096;
+
+/// `Sound` identifier used to reference a sound loaded with [`PixState::load_sound`].
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SoundId(pub(crate) usize);
+
+impl fmt::Display for SoundId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for SoundId {
+    type Target = usize;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SoundId {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A fully decoded sound, stored as interleaved `f32` samples at its original sample rate.
+#[derive(Debug, Clone)]
+pub(crate) struct Sound {
+    pub(crate) samples: Arc<[f32]>,
+    pub(crate) channels: usize,
+    pub(crate) sample_rate: u32,
+}
+
+impl Sound {
+    /// Create a sound from interleaved `samples`.
+    pub(crate) fn new(samples: Vec<f32>, channels: usize, sample_rate: u32) -> Self {
+        Self {
+            samples: samples.into(),
+            channels: channels.max(1),
+            sample_rate,
+        }
+    }
+
+    /// Decode an entire `.wav` or `.ogg` file.
+    pub(crate) fn from_file(path: &Path) -> PixResult<Self> {
+        let mut decoder = Decoder::open(path)?;
+        let mut samples = Vec::new();
+        while decoder.read_chunk(&mut samples)? {}
+        Ok(Self::new(
+            samples,
+            decoder.channels(),
+            decoder.sample_rate(),
+        ))
+    }
+
+    /// Number of sample frames.
+    pub(crate) fn frames(&self) -> usize {
+        self.samples.len() / self.channels
+    }
+}
+
+/// Supported sound file types.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FileType {
+    Wav,
+    #[cfg(feature = "ogg")]
+    Ogg,
+}
+
+impl FileType {
+    /// Determine the file type from a case-insensitive file extension.
+    fn from_path(path: &Path) -> PixResult<Self> {
+        let ext = path.extension();
+        match ext
+            .and_then(OsStr::to_str)
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("wav") => Ok(Self::Wav),
+            #[cfg(feature = "ogg")]
+            Some("ogg") => Ok(Self::Ogg),
+            _ => Err(PixError::UnsupportedFileType(ext.map(OsStr::to_os_string)).into()),
+        }
+    }
+}
+
+/// Streaming decoder converting any supported sample format into interleaved `f32` samples.
+pub(crate) enum Decoder {
+    Wav(hound::WavReader<BufReader<File>>),
+    #[cfg(feature = "ogg")]
+    Ogg(Box<lewton::inside_ogg::OggStreamReader<BufReader<File>>>),
+}
+
+impl fmt::Debug for Decoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Decoder")
+            .field("channels", &self.channels())
+            .field("sample_rate", &self.sample_rate())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Decoder {
+    /// Open a `.wav` or `.ogg` file for decoding, based on the file extension.
+    pub(crate) fn open(path: &Path) -> PixResult<Self> {
+        let file_type = FileType::from_path(path)?;
+        let file = BufReader::new(
+            File::open(path).with_context(|| format!("failed to open sound: {path:?}"))?,
+        );
+        let decoder = match file_type {
+            FileType::Wav => Self::Wav(
+                hound::WavReader::new(file)
+                    .with_context(|| format!("failed to read wav header: {path:?}"))?,
+            ),
+            #[cfg(feature = "ogg")]
+            FileType::Ogg => Self::Ogg(Box::new(
+                lewton::inside_ogg::OggStreamReader::new(file)
+                    .with_context(|| format!("failed to read ogg header: {path:?}"))?,
+            )),
+        };
+        if decoder.channels() == 0 || decoder.sample_rate() == 0 {
+            return Err(anyhow!("invalid sound format: {decoder:?}"));
+        }
+        Ok(decoder)
+    }
+
+    /// Number of interleaved channels.
+    pub(crate) fn channels(&self) -> usize {
+        match self {
+            Self::Wav(reader) => reader.spec().channels.into(),
+            #[cfg(feature = "ogg")]
+            Self::Ogg(reader) => reader.ident_hdr.audio_channels.into(),
+        }
+    }
+
+    /// Sample rate in Hz.
+    pub(crate) fn sample_rate(&self) -> u32 {
+        match self {
+            Self::Wav(reader) => reader.spec().sample_rate,
+            #[cfg(feature = "ogg")]
+            Self::Ogg(reader) => reader.ident_hdr.audio_sample_rate,
+        }
+    }
+
+    /// Decode the next chunk of samples, appending them to `out`. Returns `false` once the end of
+    /// the stream is reached.
+    pub(crate) fn read_chunk(&mut self, out: &mut Vec<f32>) -> PixResult<bool> {
+        let len = out.len();
+        match self {
+            Self::Wav(reader) => {
+                let spec = reader.spec();
+                let count = CHUNK_FRAMES * usize::from(spec.channels);
+                match spec.sample_format {
+                    hound::SampleFormat::Float => {
+                        for sample in reader.samples::<f32>().take(count) {
+                            out.push(sample.context("failed to decode wav samples")?);
+                        }
+                    }
+                    hound::SampleFormat::Int => {
+                        let scale = int_scale(spec.bits_per_sample);
+                        for sample in reader.samples::<i32>().take(count) {
+                            let sample = sample.context("failed to decode wav samples")?;
+                            out.push(sample as f32 * scale);
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "ogg")]
+            Self::Ogg(reader) => {
+                // Packets can decode to zero samples, so keep reading until data or the end
+                while out.len() == len {
+                    match reader
+                        .read_dec_packet_itl()
+                        .context("failed to decode ogg samples")?
+                    {
+                        Some(packet) => {
+                            let scale = int_scale(16);
+                            out.extend(packet.into_iter().map(|s| f32::from(s) * scale));
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+        Ok(out.len() > len)
+    }
+
+    /// Seek back to the start of the stream.
+    pub(crate) fn rewind(&mut self) -> PixResult<()> {
+        match self {
+            Self::Wav(reader) => reader.seek(0).context("failed to rewind wav")?,
+            #[cfg(feature = "ogg")]
+            Self::Ogg(reader) => reader.seek_absgp_pg(0).context("failed to rewind ogg")?,
+        }
+        Ok(())
+    }
+}
+
+/// Scale normalizing signed integer samples of `bits` size into the range `-1.0..=1.0`.
+fn int_scale(bits: u16) -> f32 {
+    1.0 / (1_u64 << (bits.clamp(1, 32) - 1)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn wav_round_trip() -> PixResult<()> {
+        let path = env::temp_dir().join("pix_engine_test_sound.wav");
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 22_050,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec)?;
+        for sample in [i16::MIN, 0, 16_384, -16_384] {
+            writer.write_sample(sample)?;
+        }
+        writer.finalize()?;
+        let sound = Sound::from_file(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(sound.channels, 2);
+        assert_eq!(sound.sample_rate, 22_050);
+        assert_eq!(sound.frames(), 2);
+        assert_eq!(&*sound.samples, &[-1.0, 0.0, 0.5, -0.5]);
+        Ok(())
+    }
+
+    #[test]
+    fn int_samples_normalize() {
+        assert_eq!(i16::MIN as f32 * int_scale(16), -1.0);
+        assert_eq!(i8::MIN as f32 * int_scale(8), -1.0);
+        assert_eq!((1 << 23) as f32 * int_scale(24), 1.0);
+        assert!((i32::MAX as f32 * int_scale(32) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn sound_file_types() {
+        assert_eq!(
+            FileType::from_path(Path::new("jump.WAV")).ok(),
+            Some(FileType::Wav)
+        );
+        assert!(FileType::from_path(Path::new("music.mp3")).is_err());
+    }
+}
@@ -0,0 +1,482 @@
+//! [`Camera2D`] functions for drawing in world space.
+//!
+//! While a camera is set, shapes, images, textures, and text are drawn in world space and
+//! transformed to screen space by the camera's target, offset, zoom, and rotation. UI widgets are
+//! always drawn in screen space.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::set_camera`]: Set or clear the [`Camera2D`] used to transform drawing.
+//! - [`PixState::camera`]: The current [`Camera2D`], if any.
+//! - [`PixState::camera_translate`]: Move the current [`Camera2D`] by a world space delta.
+//! - [`PixState::screen_to_world`]: Convert a screen position to a world position.
+//! - [`PixState::world_to_screen`]: Convert a world position to a screen position.
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { camera: Camera2D };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     // Keep the player centered on screen, zoomed in 2x
+//!     self.camera.set_target([100.0, 100.0]);
+//!     self.camera.set_offset([s.width()? as f64 / 2.0, s.height()? as f64 / 2.0]);
+//!     self.camera.set_zoom(2.0);
+//!     s.set_camera(self.camera);
+//!
+//!     s.rect([90, 90, 20, 20])?; // Drawn in world space
+//!     s.set_cursor_pos([90, 75]);
+//!     s.text("Player")?; // Also drawn in world space
+//!
+//!     s.set_camera(None);
+//!     s.set_cursor_pos([10, 10]);
+//!     s.text("Score: 0")?; // Drawn in screen space
+//!
+//!     // Convert the mouse position to world space for picking
+//!     let world_mouse = s.screen_to_world(s.mouse_pos());
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A 2D camera that maps world space to screen space.
+///
+/// The world `target` position is drawn at the screen `offset` position, scaled by `zoom` and
+/// rotated by `rotation` about the `offset`. An optional `bounds` rectangle constrains the
+/// `target` position.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Camera2D {
+    target: Point<f64>,
+    offset: Point<f64>,
+    zoom: f64,
+    rotation: f64,
+    bounds: Option<Rect<f64>>,
+}
+
+impl Default for Camera2D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Camera2D {
+    /// Minimum allowed zoom factor.
+    pub const MIN_ZOOM: f64 = 0.001;
+
+    /// Constructs an identity `Camera2D`, mapping world space directly to screen space.
+    pub const fn new() -> Self {
+        Self {
+            target: Point::new([0.0, 0.0]),
+            offset: Point::new([0.0, 0.0]),
+            zoom: 1.0,
+            rotation: 0.0,
+            bounds: None,
+        }
+    }
+
+    /// Returns the world position drawn at the screen `offset`.
+    #[inline]
+    pub const fn target(&self) -> Point<f64> {
+        self.target
+    }
+
+    /// Set the world position drawn at the screen `offset`, constrained to `bounds` if set.
+    #[inline]
+    pub fn set_target<P: Into<Point<f64>>>(&mut self, target: P) {
+        self.target = target.into();
+        self.clamp_target();
+    }
+
+    /// Move the `target` by a world space `offset`, constrained to `bounds` if set.
+    #[inline]
+    pub fn pan<P: Into<Point<f64>>>(&mut self, offset: P) {
+        let offset = offset.into();
+        self.set_target([self.target.x() + offset.x(), self.target.y() + offset.y()]);
+    }
+
+    /// Returns the screen position that the `target` is drawn at.
+    #[inline]
+    pub const fn offset(&self) -> Point<f64> {
+        self.offset
+    }
+
+    /// Set the screen position that the `target` is drawn at, e.g. the center of the window.
+    #[inline]
+    pub fn set_offset<P: Into<Point<f64>>>(&mut self, offset: P) {
+        self.offset = offset.into();
+    }
+
+    /// Returns the zoom factor.
+    #[inline]
+    #[must_use]
+    pub const fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Set the zoom factor. Values greater than `1.0` zoom in. Clamped to a minimum of
+    /// [`Camera2D::MIN_ZOOM`].
+    #[inline]
+    pub fn set_zoom(&mut self, zoom: f64) {
+        self.zoom = zoom.max(Self::MIN_ZOOM);
+    }
+
+    /// Multiply the zoom factor by `factor`, keeping the world position under the `screen`
+    /// position fixed. Useful for zooming towards the mouse cursor.
+    pub fn zoom_at<P: Into<Point<f64>>>(&mut self, factor: f64, screen: P) {
+        let screen = screen.into();
+        let world = self.screen_to_world(screen);
+        self.set_zoom(self.zoom * factor);
+        let moved = self.screen_to_world(screen);
+        self.pan([world.x() - moved.x(), world.y() - moved.y()]);
+    }
+
+    /// Returns the clockwise rotation in radians.
+    #[inline]
+    #[must_use]
+    pub const fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    /// Set the clockwise rotation of the camera in radians. The world appears rotated
+    /// counter-clockwise on screen.
+    #[inline]
+    pub fn set_rotation(&mut self, rotation: f64) {
+        self.rotation = rotation;
+    }
+
+    /// Returns the world space bounds that constrain the `target`, if any.
+    #[inline]
+    #[must_use]
+    pub const fn bounds(&self) -> Option<Rect<f64>> {
+        self.bounds
+    }
+
+    /// Set or clear the world space bounds that constrain the `target`.
+    #[inline]
+    pub fn set_bounds<R: Into<Option<Rect<f64>>>>(&mut self, bounds: R) {
+        self.bounds = bounds.into();
+        self.clamp_target();
+    }
+
+    /// Convert a screen position to a world position.
+    pub fn screen_to_world<P: Into<Point<f64>>>(&self, screen: P) -> Point<f64> {
+        let screen = screen.into();
+        let x = (screen.x() - self.offset.x()) / self.zoom;
+        let y = (screen.y() - self.offset.y()) / self.zoom;
+        let (sin, cos) = self.rotation.sin_cos();
+        point![
+            x.mul_add(cos, -y * sin) + self.target.x(),
+            x.mul_add(sin, y * cos) + self.target.y()
+        ]
+    }
+
+    /// Convert a world position to a screen position.
+    pub fn world_to_screen<P: Into<Point<f64>>>(&self, world: P) -> Point<f64> {
+        let world = world.into();
+        let x = world.x() - self.target.x();
+        let y = world.y() - self.target.y();
+        let (sin, cos) = self.rotation.sin_cos();
+        point![
+            x.mul_add(cos, y * sin).mul_add(self.zoom, self.offset.x()),
+            (-x).mul_add(sin, y * cos)
+                .mul_add(self.zoom, self.offset.y())
+        ]
+    }
+
+    /// Returns the [`Transform`] that maps world positions to screen positions.
+    pub fn transform(&self) -> Transform {
+        let mut transform = Transform::translation(self.offset.x(), self.offset.y());
+        transform.scale(self.zoom, self.zoom);
+        transform.rotate(-self.rotation);
+        transform.translate(-self.target.x(), -self.target.y());
+        transform
+    }
+
+    /// Constrain the `target` to the `bounds`.
+    fn clamp_target(&mut self) {
+        if let Some(bounds) = self.bounds {
+            self.target = point![
+                self.target
+                    .x()
+                    .clamp(bounds.left(), bounds.right().max(bounds.left())),
+                self.target
+                    .y()
+                    .clamp(bounds.top(), bounds.bottom().max(bounds.top()))
+            ];
+        }
+    }
+}
+
+impl PixState {
+    /// Set or clear the [`Camera2D`] used to transform shapes, images, textures, and text from
+    /// world space to screen space. UI widgets are unaffected. The camera is saved and restored
+    /// with [`PixState::push`] and [`PixState::pop`].
+    ///
+    /// Rectangles are drawn as [Quad]s and ellipses as polygons when the camera is rotated. Text is
+    /// scaled by the camera zoom by rendering at a larger or smaller font size. The camera is
+    /// applied after the current [`Transform`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { camera: Camera2D };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.set_camera(self.camera);
+    ///     s.rect([0, 0, 100, 100])?; // World space
+    ///     s.set_camera(None);
+    ///     s.rect([0, 0, 100, 100])?; // Screen space
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_camera<C>(&mut self, camera: C)
+    where
+        C: Into<Option<Camera2D>>,
+    {
+        self.settings.camera = camera.into();
+    }
+
+    /// Returns the current [`Camera2D`], if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if let Some(camera) = s.camera() {
+    ///         s.text(format!("Zoom: {:.1}x", camera.zoom()))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn camera(&self) -> Option<Camera2D> {
+        self.settings.camera
+    }
+
+    /// Move the current [`Camera2D`] target by a world space `delta`, scrolling the world in the
+    /// opposite direction on screen. If no camera is set, an identity camera is set first, so the
+    /// target becomes the top-left corner of the visible world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.key_down(Key::Right) {
+    ///         s.camera_translate([4.0, 0.0]);
+    ///     }
+    ///     s.rect([0, 0, 100, 100])?; // Scrolls left as the camera moves right
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn camera_translate<P: Into<Point<f64>>>(&mut self, delta: P) {
+        self.settings
+            .camera
+            .get_or_insert_with(Camera2D::new)
+            .pan(delta);
+    }
+
+    /// Convert a screen position, such as [`PixState::mouse_pos`], to a world position using the
+    /// current [`Camera2D`]. Returns the same position if no camera is set.
+    ///
+    /// Screen positions are already in the coordinates set by [`PixState::scale`], so only the
+    /// camera transform is applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let world_mouse = s.screen_to_world(s.mouse_pos());
+    ///     s.circle([world_mouse.x(), world_mouse.y(), 5])?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn screen_to_world<P>(&self, screen: P) -> Point<i32>
+    where
+        P: Into<Point<i32>>,
+    {
+        let screen = screen.into();
+        match self.settings.camera {
+            Some(camera) => {
+                let p = camera.screen_to_world([f64::from(screen.x()), f64::from(screen.y())]);
+                point![p.x().round() as i32, p.y().round() as i32]
+            }
+            None => screen,
+        }
+    }
+
+    /// Convert a world position to a screen position using the current [`Camera2D`]. Returns the
+    /// same position if no camera is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { player: Point<i32> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Draw a health bar above the player that doesn't scale with the camera zoom
+    ///     let pos = s.world_to_screen(self.player);
+    ///     s.push();
+    ///     s.set_camera(None);
+    ///     s.rect([pos.x() - 10, pos.y() - 20, 20, 4])?;
+    ///     s.pop();
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn world_to_screen<P>(&self, world: P) -> Point<i32>
+    where
+        P: Into<Point<i32>>,
+    {
+        let world = world.into();
+        match self.settings.camera {
+            Some(camera) => camera.transform().point(world),
+            None => world,
+        }
+    }
+
+    /// Push the current settings to the stack and clear the [`Camera2D`], [`Transform`], and text
+    /// alignment so that UI widgets are drawn in screen space.
+    #[inline]
+    pub(crate) fn push_screen_space(&mut self) {
+        self.push();
+        self.settings.camera = None;
+        self.settings.transform = Transform::IDENTITY;
+        self.settings.text_align = Align::Left;
+        self.settings.text_baseline = Baseline::Top;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn identity() {
+        let camera = Camera2D::new();
+        assert_approx_eq(camera.world_to_screen([3.0, -4.0]), point![3.0, -4.0], 1e-9);
+        assert_approx_eq(camera.screen_to_world([3.0, -4.0]), point![3.0, -4.0], 1e-9);
+    }
+
+    #[test]
+    fn world_to_screen() {
+        let mut camera = Camera2D::new();
+        camera.set_target([100.0, 50.0]);
+        camera.set_offset([400.0, 300.0]);
+        camera.set_zoom(2.0);
+        assert_approx_eq(
+            camera.world_to_screen([100.0, 50.0]),
+            point![400.0, 300.0],
+            1e-9,
+        );
+        assert_approx_eq(
+            camera.world_to_screen([110.0, 50.0]),
+            point![420.0, 300.0],
+            1e-9,
+        );
+
+        // Rotating the camera clockwise rotates the world counter-clockwise
+        camera.set_rotation(FRAC_PI_2);
+        assert_approx_eq(
+            camera.world_to_screen([110.0, 50.0]),
+            point![400.0, 280.0],
+            1e-9,
+        );
+        assert_approx_eq(
+            camera.world_to_screen([100.0, 60.0]),
+            point![420.0, 300.0],
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut camera = Camera2D::new();
+        camera.set_target([-35.5, 12.25]);
+        camera.set_offset([320.0, 240.0]);
+        camera.set_zoom(0.75);
+        for rotation in [0.0, 0.3, FRAC_PI_2, 2.5, -1.2] {
+            camera.set_rotation(rotation);
+            for p in [[0.0, 0.0], [12.5, -7.0], [-1000.0, 250.0]] {
+                let p = Point::new(p);
+                assert_approx_eq(camera.screen_to_world(camera.world_to_screen(p)), p, 1e-9);
+                assert_approx_eq(camera.world_to_screen(camera.screen_to_world(p)), p, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_transform() {
+        let mut camera = Camera2D::new();
+        camera.set_target([-35.5, 12.25]);
+        camera.set_offset([320.0, 240.0]);
+        camera.set_zoom(0.75);
+        camera.set_rotation(0.3);
+        let transform = camera.transform();
+        for p in [[0.0, 0.0], [12.5, -7.0], [-1000.0, 250.0]] {
+            assert_approx_eq(transform.apply(p), camera.world_to_screen(p), 1e-9);
+        }
+    }
+
+    #[test]
+    fn zoom_at_and_bounds() {
+        let mut camera = Camera2D::new();
+        camera.set_offset([100.0, 100.0]);
+        camera.set_rotation(0.4);
+        let cursor = point![150.0, 80.0];
+        let world = camera.screen_to_world(cursor);
+        camera.zoom_at(1.5, cursor);
+        assert!((camera.zoom() - 1.5).abs() < f64::EPSILON);
+        assert_approx_eq(camera.screen_to_world(cursor), world, 1e-9);
+
+        camera.set_bounds(rect![0.0, 0.0, 50.0, 50.0]);
+        camera.set_target([-10.0, 70.0]);
+        assert_approx_eq(camera.target(), point![0.0, 50.0], 1e-9);
+        camera.set_zoom(0.0);
+        assert!((camera.zoom() - Camera2D::MIN_ZOOM).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn transform_dst() {
+        let mut camera = Camera2D::new();
+        camera.set_offset([10.0, 10.0]);
+        camera.set_zoom(2.0);
+        let (dst, angle) = camera.transform().dst(rect![0, 0, 10, 4], 0.0, None);
+        assert_eq!(dst, rect![10, 10, 20, 8]);
+        assert!(angle.abs() < f64::EPSILON);
+
+        // Rotating 180 degrees about the top-left corner
+        camera.set_rotation(FRAC_PI_2);
+        let (dst, angle) = camera
+            .transform()
+            .dst(rect![0, 0, 10, 4], 180.0, Some(point![0, 0]));
+        assert_eq!(dst, rect![-4, 16, 20, 8]);
+        assert!((angle - 90.0).abs() < 1e-9);
+    }
+}
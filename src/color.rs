@@ -914,6 +914,34 @@ impl Color {
         self.update_channels(levels, Hsl);
     }
 
+    /// Returns the [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between
+    /// this `Color` and another, ranging from `1.0..=21.0`. Alpha is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// assert_eq!(Color::WHITE.contrast_ratio(Color::BLACK).round(), 21.0);
+    /// assert_eq!(Color::GRAY.contrast_ratio(Color::GRAY), 1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contrast_ratio(&self, other: Color) -> f64 {
+        let luminance = |color: &Color| {
+            let [r, g, b, _] = color.channels().map(|c| {
+                let c = f64::from(c) / 255.0;
+                if c <= 0.039_28 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            });
+            0.2126 * r + 0.7152 * g + 0.0722 * b
+        };
+        let (l1, l2) = (luminance(self), luminance(&other));
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
     /// Returns `Color` as a [Vec] of `[red, green, blue, alpha]`.
     ///
     /// # Example
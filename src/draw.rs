@@ -5,7 +5,12 @@
 //! Provided [`PixState`] methods:
 //!
 //! - [`PixState::clear`]: Clear the render target to the current background [Color].
-//! - [`PixState::save_canvas`]: Save the current render target out to a [png] file.
+//! - [`PixState::get_pixel`]: Read a pixel [Color] from the current render target.
+//! - [`PixState::read_pixels`]: Read pixel data from the current render target.
+//! - [`PixState::save_canvas`]: Save the current render target out to a [png] or `bmp` file.
+//! - [`PixState::save_screenshot`]: Save the current window out to a timestamped [png] file.
+//! - [`PixState::start_recording`]: Start recording the window to an animated `gif` file.
+//! - [`PixState::stop_recording`]: Stop recording and finish writing the `gif` file.
 //!
 //! # Example
 //!
@@ -25,11 +30,17 @@
 //! # }
 //! ```
 
-use anyhow::Context;
-
-use crate::{prelude::*, renderer::Rendering};
-use log::info;
-use std::{fs::File, io::BufWriter, path::Path};
+use crate::{
+    image::gif::Recording, ops::clamp_dimensions, prelude::*, renderer::Rendering,
+    texture::TextureRenderer,
+};
+use anyhow::anyhow;
+use log::{error, info};
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use time::OffsetDateTime;
 
 /// Trait for objects that can be drawn to the screen.
 pub trait Draw {
@@ -83,15 +94,98 @@ impl PixState {
         self.renderer.clear()
     }
 
-    /// Save a portion `src` of the currently rendered target to a [png] file. Passing `None` for
-    /// `src` saves the entire target.
+    /// Read the pixel [Color] at the given `(x, y)` position from the current render target.
+    ///
+    /// # Errors
+    ///
+    /// If the position is outside the bounds of the current render target, the render target is
+    /// closed or dropped, or the renderer fails to read pixels, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let color = s.get_pixel(s.mouse_pos())?;
+    ///     s.text(format!("Color under mouse: {color}"))?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn get_pixel<P>(&mut self, position: P) -> PixResult<Color>
+    where
+        P: Into<Point<i32>>,
+    {
+        let pos = position.into();
+        let (bytes, format) = self.read_pixels(rect![pos, 1, 1])?;
+        match (format, &*bytes) {
+            (PixelFormat::Rgb, &[r, g, b]) => Ok(Color::rgb(r, g, b)),
+            (PixelFormat::Rgba, &[r, g, b, a]) => Ok(Color::rgba(r, g, b, a)),
+            _ => Err(anyhow!("failed to read pixel at {pos:?}")),
+        }
+    }
+
+    /// Read pixel data from a portion `src` of the current render target. Passing `None` for
+    /// `src` reads the entire target.
+    ///
+    /// Pixel data is returned along with the [`PixelFormat`] it's stored in, with a pitch of
+    /// `width * format.channels()` bytes per row.
+    ///
+    /// # Errors
+    ///
+    /// If `src` is outside the bounds of the current render target, the render target is closed or
+    /// dropped, or the renderer fails to read pixels, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let src = rect![0, 0, 10, 10];
+    ///     let (pixels, format) = s.read_pixels(src)?;
+    ///     let image = Image::from_bytes(10, 10, pixels, format)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn read_pixels<R>(&mut self, src: R) -> PixResult<(Vec<u8>, PixelFormat)>
+    where
+        R: Into<Option<Rect<i32>>>,
+    {
+        let src = src.into();
+        if let Some(src) = src {
+            let (width, height) = self.dimensions()?;
+            let (width, height) = clamp_dimensions(width, height);
+            if src.width() <= 0
+                || src.height() <= 0
+                || src.left() < 0
+                || src.top() < 0
+                || src.right() > width
+                || src.bottom() > height
+            {
+                return Err(anyhow!(
+                    "{src:?} is outside the bounds of the current target"
+                ));
+            }
+        }
+        let bytes = self.renderer.to_bytes(src)?;
+        Ok((bytes, PixelFormat::Rgba))
+    }
+
+    /// Save a portion `src` of the currently rendered target to a [png] or `bmp` file. Passing `None`
+    /// for `src` saves the entire target.
     ///
     /// # Errors
     ///
     /// Returns an error for any of the following:
     ///     - The current render target is closed or dropped.
     ///     - The renderer fails to read pixels from the current window target.
-    ///     - An [`io::Error`] occurs attempting to create the [png] file.
+    ///     - The file extension is not `.png` or `.bmp`.
+    ///     - An [`io::Error`] occurs attempting to create the file.
     ///     - A [`png::EncodingError`] occurs attempting to write image bytes.
     ///
     /// [`io::Error`]: std::io::Error
@@ -111,17 +205,19 @@ impl PixState {
     /// }
     /// # }
     /// ```
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn save_canvas<P, R>(&mut self, src: R, path: P) -> PixResult<()>
     where
         P: AsRef<Path>,
         R: Into<Option<Rect<i32>>>,
     {
         info!("Saving canvas to {}", path.as_ref().display());
+        let (bytes, format) = self.read_pixels(None)?;
         if let Some(src) = src.into() {
             // Copy current texture target to a texture
-            let bytes = self.renderer.to_bytes()?;
-            let render_texture = self.create_texture(self.width()?, self.height()?, None)?;
-            self.update_texture(render_texture, None, bytes, self.width()? as usize * 4)?;
+            let render_texture = self.create_texture(self.width()?, self.height()?, format)?;
+            let pitch = self.width()? as usize * format.channels();
+            self.update_texture(render_texture, None, bytes, pitch)?;
             // Render the `src` rect from texture onto another texture, and save it
             let src_texture = self.create_texture(src.width() as u32, src.height() as u32, None)?;
             self.set_texture_target(src_texture)?;
@@ -132,18 +228,208 @@ impl PixState {
             self.delete_texture(src_texture)?;
             Ok(())
         } else {
-            let path = path.as_ref();
-            let png_file = BufWriter::new(File::create(path)?);
-            let mut png = png::Encoder::new(png_file, self.width()?, self.height()?);
-            png.set_color(PixelFormat::Rgba.into());
-            png.set_depth(png::BitDepth::Eight);
-            let mut writer = png
-                .write_header()
-                .with_context(|| format!("failed to write png header: {path:?}"))?;
+            Image::from_vec(self.width()?, self.height()?, bytes, format).save(path)
+        }
+    }
+
+    /// Save the current window target to a timestamped [png] file in the `dir` directory,
+    /// returning the path of the saved file. The directory is created if it does not exist.
+    ///
+    /// Files are named `screenshot_YYYY-MM-DD_HH-MM-SS.png`, with a numeric suffix appended if
+    /// a file with the same name already exists. The window is captured even if a texture target
+    /// is currently set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for any of the following:
+    ///     - The current window target is closed or dropped.
+    ///     - The renderer fails to read pixels from the current window target.
+    ///     - An [`io::Error`] occurs attempting to create the directory or [png] file.
+    ///     - A [`png::EncodingError`] occurs attempting to write image bytes.
+    ///
+    /// [`io::Error`]: std::io::Error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if let Key::F12 = event.key {
+    ///         s.save_screenshot("screenshots")?;
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_screenshot<P>(&mut self, dir: P) -> PixResult<PathBuf>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+        let name = format!(
+            "screenshot_{}-{:02}-{:02}_{:02}-{:02}-{:02}",
+            now.year(),
+            now.month() as u8,
+            now.day(),
+            now.hour(),
+            now.minute(),
+            now.second()
+        );
+        let mut path = dir.join(format!("{name}.png"));
+        let mut count = 1;
+        while path.exists() {
+            path = dir.join(format!("{name}_{count}.png"));
+            count += 1;
+        }
+
+        let texture_target = self.renderer.texture_target();
+        self.renderer.clear_texture_target();
+        let pixels = self.read_pixels(None);
+        if let Some(texture_id) = texture_target {
+            self.renderer.set_texture_target(texture_id)?;
+        }
+        let (bytes, format) = pixels?;
+        let (width, height) = self.window_dimensions()?;
+        Image::from_vec(width, height, bytes, format).save(&path)?;
+        Ok(path)
+    }
+
+    /// Start recording the current window to an animated [gif] file at `path`, capturing up to
+    /// `max_frames` frames. `frame_skip` frames are skipped between each captured frame. Frame
+    /// delays are based on the actual time elapsed between captured frames.
+    ///
+    /// Each frame is quantized to a 256-color palette and written on a background thread.
+    /// Recording stops when `max_frames` is reached, [`PixState::stop_recording`] is called, or the
+    /// window is resized. Any in-progress recording is finished before starting a new one.
+    ///
+    /// [gif]: https://en.wikipedia.org/wiki/GIF
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for any of the following:
+    ///     - The current window target is closed or dropped.
+    ///     - The window dimensions exceed the maximum [gif] dimensions.
+    ///     - An [`io::Error`] occurs attempting to create or write to the [gif] file.
+    ///
+    /// [`io::Error`]: std::io::Error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if let Key::R = event.key {
+    ///         // Record 5 seconds at 30 frames per second
+    ///         s.start_recording("recording.gif", 150, 1)?;
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    pub fn start_recording<P>(
+        &mut self,
+        path: P,
+        max_frames: usize,
+        frame_skip: usize,
+    ) -> PixResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        self.stop_recording()?;
+        let path = path.as_ref();
+        let dimensions = self.window_dimensions()?;
+        self.recording = Some(Recording::new(path, dimensions, max_frames, frame_skip)?);
+        info!("Started recording to {}", path.display());
+        Ok(())
+    }
 
-            writer
-                .write_image_data(&self.renderer.to_bytes()?)
-                .with_context(|| format!("failed to write png data: {path:?}"))
+    /// Stop any in-progress recording started with [`PixState::start_recording`] and finish
+    /// writing the [gif] file.
+    ///
+    /// [gif]: https://en.wikipedia.org/wiki/GIF
+    ///
+    /// # Errors
+    ///
+    /// If an [`io::Error`] occurs attempting to write to the [gif] file, then an error is returned.
+    ///
+    /// [`io::Error`]: std::io::Error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     match event.key {
+    ///         Key::R => s.start_recording("recording.gif", 300, 0)?,
+    ///         Key::S => s.stop_recording()?,
+    ///         _ => (),
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    pub fn stop_recording(&mut self) -> PixResult<()> {
+        match self.recording.take() {
+            Some(recording) => recording.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// Capture the current window frame if a recording is in progress.
+    pub(crate) fn record_frame(&mut self) {
+        let Some(recording) = self.recording.as_mut() else {
+            return;
+        };
+        if !recording.should_capture() {
+            return;
+        }
+        let dimensions = recording.dimensions();
+        let result = match self.window_dimensions() {
+            Ok(current) if current != dimensions => Ok(true),
+            Ok(_) => self.capture_frame(),
+            Err(err) => Err(err),
+        };
+        let finished = match result {
+            Ok(finished) => finished,
+            Err(err) => {
+                error!("Failed to record frame: {}", err);
+                true
+            }
+        };
+        if finished {
+            match self.stop_recording() {
+                Ok(()) => info!("Finished recording"),
+                Err(err) => error!("Failed to finish recording: {}", err),
+            }
+        }
+    }
+
+    /// Read the current window frame and add it to the in-progress recording, returning whether
+    /// the recording is finished.
+    fn capture_frame(&mut self) -> PixResult<bool> {
+        let texture_target = self.renderer.texture_target();
+        self.renderer.clear_texture_target();
+        let pixels = self.read_pixels(None);
+        if let Some(texture_id) = texture_target {
+            self.renderer.set_texture_target(texture_id)?;
+        }
+        let (bytes, format) = pixels?;
+        match self.recording.as_mut() {
+            Some(recording) => recording.add_frame(bytes, format),
+            None => Ok(true),
         }
     }
 }
@@ -54,13 +54,15 @@
 //! }
 //! ```
 
-use crate::{image::Icon, prelude::*, renderer::RendererSettings};
-use log::{debug, error, info};
-use std::{
-    num::NonZeroUsize,
-    thread,
-    time::{Duration, Instant},
+use crate::{
+    gui::controller::DEFAULT_DEADZONE,
+    image::Icon,
+    prelude::*,
+    renderer::{RendererSettings, WindowRenderer},
 };
+use log::{debug, error, info};
+use std::{mem, num::NonZeroUsize, path::PathBuf, thread, time::Duration};
+use web_time::Instant;
 
 /// Trait for allowing the [`Engine`] to drive your application and send notification of events,
 /// passing along a [`&mut PixState`](PixState) to allow interacting with the [`Engine`].
@@ -143,6 +145,45 @@ pub trait PixEngine {
     /// ```
     fn on_update(&mut self, s: &mut PixState) -> PixResult<()>;
 
+    /// Called zero or more times each frame prior to [`PixEngine::on_update`], once for every
+    /// [fixed timestep] elapsed since the last call, with `fixed_delta` set to the fixed timestep.
+    ///
+    /// Use this for simulation and physics that should run at a consistent rate independent of
+    /// the frame rate, and interpolate between the last two simulated states when rendering in
+    /// [`PixEngine::on_update`] using [`PixState::frame_interpolation`].
+    ///
+    /// [fixed timestep]: PixState::set_fixed_timestep
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # use std::time::Duration;
+    /// # struct App { pos: f64, prev_pos: f64, vel: f64 };
+    /// # impl PixEngine for App {
+    /// fn on_fixed_update(&mut self, s: &mut PixState, fixed_delta: Duration) -> PixResult<()> {
+    ///     self.prev_pos = self.pos;
+    ///     self.pos += self.vel * fixed_delta.as_secs_f64();
+    ///     Ok(())
+    /// }
+    ///
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let alpha = s.frame_interpolation();
+    ///     let x = self.prev_pos + (self.pos - self.prev_pos) * alpha;
+    ///     s.circle([x as i32, 100, 10])?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_fixed_update(&mut self, s: &mut PixState, fixed_delta: Duration) -> PixResult<()> {
+        Ok(())
+    }
+
     /// Called when the engine detects a close/exit event such as calling [`PixState::quit`] or if an
     /// error is returned during program execution by any [`PixEngine`] methods.
     ///
@@ -266,6 +307,47 @@ pub trait PixEngine {
         Ok(false)
     }
 
+    /// Called each time text being composed by an input method editor (IME) changes, before it's
+    /// committed and passed to [`PixEngine::on_key_typed`]. `start` and `length` are the cursor
+    /// position and selection length within `text`, in characters. An empty `text` means
+    /// composition was cancelled.
+    ///
+    /// Returning `true` consumes this event, preventing any further event triggering.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { preedit: String };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_text_editing(
+    ///     &mut self,
+    ///     s: &mut PixState,
+    ///     text: &str,
+    ///     start: i32,
+    ///     length: i32,
+    /// ) -> PixResult<bool> {
+    ///     self.preedit = text.to_owned();
+    ///     Ok(true)
+    /// }
+    /// # }
+    /// ```
+    fn on_text_editing(
+        &mut self,
+        s: &mut PixState,
+        text: &str,
+        start: i32,
+        length: i32,
+    ) -> PixResult<bool> {
+        Ok(false)
+    }
+
     /// Called each time the [`Mouse`] is moved while any mouse button is being held.
     ///
     /// You can inspect which button is being held by calling [`PixState::mouse_down`] with the desired
@@ -613,7 +695,8 @@ pub trait PixEngine {
         Ok(false)
     }
 
-    /// Called each time a [`Mouse`] button is clicked twice within 500ms.
+    /// Called each time a [`Mouse`] button is clicked again within the double click interval,
+    /// which defaults to 400ms. See [`PixState::set_dbl_click_interval`].
     ///
     /// Returning `true` consumes this event, preventing any further event triggering.
     ///
@@ -717,6 +800,184 @@ pub trait PixEngine {
         Ok(false)
     }
 
+    /// Called each time a window is resized by the user or window manager, with the new
+    /// dimensions of the window in pixels. Called before [`PixEngine::on_window_event`].
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { aspect_ratio: f32 };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_resize(&mut self, s: &mut PixState, width: u32, height: u32) -> PixResult<()> {
+    ///     self.aspect_ratio = width as f32 / height as f32;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_resize(&mut self, s: &mut PixState, width: u32, height: u32) -> PixResult<()> {
+        Ok(())
+    }
+
+    /// Called each time a window gains or loses input focus. Useful for pausing audio, timers or
+    /// lowering the frame rate while the application is in the background. Called before
+    /// [`PixEngine::on_window_event`].
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_focus_changed(&mut self, s: &mut PixState, focused: bool) -> PixResult<()> {
+    ///     if focused {
+    ///         s.resume_audio();
+    ///         s.frame_rate(None);
+    ///     } else {
+    ///         s.pause_audio();
+    ///         s.frame_rate(10);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_focus_changed(&mut self, s: &mut PixState, focused: bool) -> PixResult<()> {
+        Ok(())
+    }
+
+    /// Called each time a window is moved by the user or window manager, with the new position of
+    /// the window on the display. Called before [`PixEngine::on_window_event`].
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { position: (i32, i32) };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_window_moved(
+    ///     &mut self,
+    ///     s: &mut PixState,
+    ///     window_id: WindowId,
+    ///     x: i32,
+    ///     y: i32,
+    /// ) -> PixResult<()> {
+    ///     // Save the position to restore it on the next launch
+    ///     self.position = (x, y);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_window_moved(
+        &mut self,
+        s: &mut PixState,
+        window_id: WindowId,
+        x: i32,
+        y: i32,
+    ) -> PixResult<()> {
+        Ok(())
+    }
+
+    /// Called each time a window is minimized. While the primary window is minimized, rendering
+    /// is skipped but [`PixEngine::on_update`] continues to be called, see
+    /// [`PixState::pause_rendering_when_minimized`]. Called before
+    /// [`PixEngine::on_window_event`].
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_window_minimized(&mut self, s: &mut PixState, window_id: WindowId) -> PixResult<()> {
+    ///     s.pause_audio();
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_window_minimized(&mut self, s: &mut PixState, window_id: WindowId) -> PixResult<()> {
+        Ok(())
+    }
+
+    /// Called each time a minimized or maximized window is restored to its previous size and
+    /// position. Called before [`PixEngine::on_window_event`].
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_window_restored(&mut self, s: &mut PixState, window_id: WindowId) -> PixResult<()> {
+    ///     s.resume_audio();
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_window_restored(&mut self, s: &mut PixState, window_id: WindowId) -> PixResult<()> {
+        Ok(())
+    }
+
+    /// Called when the user requests to close a window, e.g. by clicking the close button.
+    /// Returning `false` cancels closing the window, which is useful for prompting to save
+    /// unsaved changes. Called before [`PixEngine::on_window_event`].
+    ///
+    /// If the window is the last one open, cancelling also cancels quitting the application.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error will start exiting the application and call [`PixEngine::on_stop`]. See
+    /// the `Errors` section in [`PixEngine::on_update`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { unsaved_changes: bool, show_save_prompt: bool };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_close_requested(&mut self, s: &mut PixState, window_id: WindowId) -> PixResult<bool> {
+    ///     if self.unsaved_changes {
+    ///         self.show_save_prompt = true;
+    ///         return Ok(false);
+    ///     }
+    ///     Ok(true)
+    /// }
+    /// # }
+    /// ```
+    fn on_close_requested(&mut self, s: &mut PixState, window_id: WindowId) -> PixResult<bool> {
+        Ok(true)
+    }
+
     /// Called each time a window event occurs.
     ///
     /// # Errors
@@ -827,6 +1088,9 @@ pub struct EngineBuilder {
     settings: RendererSettings,
     theme: Theme,
     joystick_deadzone: i32,
+    fixed_timestep: Option<Duration>,
+    audio_callback: Option<(AudioSpecDesired, AudioFn)>,
+    screenshot: Option<(Key, PathBuf)>,
 }
 
 impl Default for EngineBuilder {
@@ -834,7 +1098,10 @@ impl Default for EngineBuilder {
         Self {
             settings: RendererSettings::default(),
             theme: Theme::default(),
-            joystick_deadzone: 8000,
+            joystick_deadzone: DEFAULT_DEADZONE,
+            fixed_timestep: None,
+            audio_callback: None,
+            screenshot: None,
         }
     }
 }
@@ -923,6 +1190,12 @@ impl EngineBuilder {
         self
     }
 
+    /// Open a stereo audio queue with 2 channels, for queuing interleaved samples with
+    /// [`PixState::enqueue_audio_stereo`]. Equivalent to `audio_channels(2)`.
+    pub fn stereo_audio(&mut self) -> &mut Self {
+        self.audio_channels(2)
+    }
+
     /// Set audio buffer size in samples. Defaults to device fallback sample size.
     pub fn audio_buffer_size(&mut self, buffer_size: u16) -> &mut Self {
         self.settings.audio_buffer_size = Some(buffer_size);
@@ -943,6 +1216,14 @@ impl EngineBuilder {
         self
     }
 
+    /// Request a graphics backend, overriding the `opengl` feature. Falls back to the platform
+    /// default if the driver is unavailable. Use [`PixState::render_driver`] to check which driver
+    /// was selected.
+    pub fn with_render_driver(&mut self, driver: RenderDriver) -> &mut Self {
+        self.settings.render_driver = Some(driver);
+        self
+    }
+
     /// Allow window resizing.
     pub fn resizable(&mut self) -> &mut Self {
         self.settings.resizable = true;
@@ -955,6 +1236,28 @@ impl EngineBuilder {
         self
     }
 
+    /// Open the window on the display at `index`. Centered windows are centered on the display
+    /// and positions set with [`EngineBuilder::position`] are relative to the top-left of the
+    /// display.
+    pub fn on_display(&mut self, index: u32) -> &mut Self {
+        self.settings.display = Some(index);
+        self
+    }
+
+    /// Set the minimum dimensions the window can be resized to.
+    #[doc(alias = "min_dimensions")]
+    pub fn min_size(&mut self, width: u32, height: u32) -> &mut Self {
+        self.settings.min_size = Some((width, height));
+        self
+    }
+
+    /// Set the maximum dimensions the window can be resized to.
+    #[doc(alias = "max_dimensions")]
+    pub fn max_size(&mut self, width: u32, height: u32) -> &mut Self {
+        self.settings.max_size = Some((width, height));
+        self
+    }
+
     /// Alter the joystick axis deadzone.
     pub fn deadzone(&mut self, value: i32) -> &mut Self {
         self.joystick_deadzone = value;
@@ -986,6 +1289,13 @@ impl EngineBuilder {
         self
     }
 
+    /// Set the fixed timestep [`PixEngine::on_fixed_update`] is called with, other than the
+    /// default of `1/60` of a second.
+    pub fn fixed_timestep(&mut self, timestep: Duration) -> &mut Self {
+        self.fixed_timestep = Some(timestep);
+        self
+    }
+
     /// Set a custom texture cache size other than the default of `20`.
     /// Affects font family and image rendering caching operations.
     pub fn texture_cache(&mut self, size: NonZeroUsize) -> &mut Self {
@@ -1000,6 +1310,69 @@ impl EngineBuilder {
         self
     }
 
+    /// Save a timestamped [png] screenshot of the current window to the `dir` directory whenever
+    /// `key` is pressed. Key presses, repeats and releases of `key` are not passed on to the
+    /// application.
+    ///
+    /// See [`PixState::save_screenshot`].
+    pub fn screenshot_key<P>(&mut self, key: Key, dir: P) -> &mut Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.screenshot = Some((key, dir.into()));
+        self
+    }
+
+    /// Open an additional audio playback device in callback mode, which calls `callback` from the
+    /// audio thread whenever it needs `buffer_size` frames of interleaved `f32` samples at
+    /// `sample_rate` Hz across `channels` channels. Playback starts after
+    /// [`PixEngine::on_start`] returns and stops when the [`Engine`] is dropped.
+    ///
+    /// Unlike [`PixState::enqueue_audio`], samples are generated on demand, avoiding the latency
+    /// and jitter of a queue filled once per frame. Use [`PixState::open_playback`] instead to
+    /// open a callback device from within the application.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pix_engine::prelude::*;
+    /// # struct MyApp;
+    /// # impl PixEngine for MyApp {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// fn main() -> PixResult<()> {
+    ///     let mut phase = 0.0_f32;
+    ///     let mut engine = Engine::builder()
+    ///         .with_audio_callback(48_000, 1, 512, move |out: &mut [f32]| {
+    ///             // 440 Hz sine wave
+    ///             for sample in out.iter_mut() {
+    ///                 *sample = 0.25 * (phase * std::f32::consts::TAU).sin();
+    ///                 phase = (phase + 440.0 / 48_000.0) % 1.0;
+    ///             }
+    ///         })
+    ///         .build()?;
+    ///     engine.run(&mut MyApp)
+    /// }
+    /// ```
+    pub fn with_audio_callback<F>(
+        &mut self,
+        sample_rate: i32,
+        channels: u8,
+        buffer_size: u16,
+        callback: F,
+    ) -> &mut Self
+    where
+        F: FnMut(&mut [f32]) + Send + 'static,
+    {
+        let desired_spec = AudioSpecDesired {
+            freq: Some(sample_rate),
+            channels: Some(channels),
+            samples: Some(buffer_size),
+        };
+        self.audio_callback = Some((desired_spec, AudioFn::new(callback)));
+        self
+    }
+
     /// Convert [EngineBuilder] to a [`Engine`] instance.
     ///
     /// # Errors
@@ -1010,9 +1383,23 @@ impl EngineBuilder {
     /// being invalid values or overlowing and an internal renderer error such as running out of
     /// memory or a software driver issue.
     pub fn build(&self) -> PixResult<Engine> {
+        let mut state = PixState::new(self.settings.clone(), self.theme.clone())?;
+        state.set_gamepad_deadzone(self.joystick_deadzone);
+        if let Some(timestep) = self.fixed_timestep {
+            state.set_fixed_timestep(timestep);
+        }
+        let audio_device = match &self.audio_callback {
+            Some((desired_spec, callback)) => {
+                let callback = callback.clone();
+                Some(state.open_playback(None, desired_spec, |_| callback)?)
+            }
+            None => None,
+        };
         Ok(Engine {
-            state: PixState::new(self.settings.clone(), self.theme.clone())?,
-            joystick_deadzone: self.joystick_deadzone,
+            state,
+            audio_device,
+            screenshot: self.screenshot.clone(),
+            screenshot_requested: false,
         })
     }
 }
@@ -1022,7 +1409,9 @@ impl EngineBuilder {
 #[derive(Debug)]
 pub struct Engine {
     state: PixState,
-    joystick_deadzone: i32,
+    audio_device: Option<AudioDevice<AudioFn>>,
+    screenshot: Option<(Key, PathBuf)>,
+    screenshot_requested: bool,
 }
 
 impl Engine {
@@ -1077,6 +1466,9 @@ impl Engine {
             return app.on_stop(&mut self.state).and(on_start);
         }
         self.state.present();
+        if let Some(device) = &self.audio_device {
+            device.resume();
+        }
 
         // on_stop loop enables on_stop to prevent application close if necessary
         'on_stop: loop {
@@ -1084,25 +1476,8 @@ impl Engine {
             // running loop continues until an event or on_update returns false or errors
             let result = 'running: loop {
                 let start_time = Instant::now();
-                let time_since_last = start_time - self.state.last_frame_time();
-
-                self.handle_events(app)?;
-                if self.state.should_quit() {
-                    break 'running Ok(());
-                }
-
-                if self.state.is_running() {
-                    self.state.pre_update();
-                    let on_update = app.on_update(&mut self.state);
-                    if on_update.is_err() {
-                        self.state.quit();
-                        break 'running on_update;
-                    }
-                    self.state.on_update()?;
-                    self.state.post_update();
-                    self.state.present();
-                    self.state.set_delta_time(start_time, time_since_last);
-                    self.state.increment_frame(time_since_last)?;
+                if let Some(result) = self.next_frame(app, start_time)? {
+                    break 'running result;
                 }
 
                 if !self.state.vsync_enabled() {
@@ -1124,9 +1499,149 @@ impl Engine {
             }
         }
     }
+
+    /// Starts the `Engine` application in a web browser and begins executing the frame loop on
+    /// a given application once per browser animation frame. Unlike [`Engine::run`], this returns
+    /// as soon as the application has started, after which errors are logged instead of returned.
+    ///
+    /// # Errors
+    ///
+    /// If the application fails to start, then an error is returned. See the
+    /// [error](crate::error) module for details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use pix_engine::prelude::*;
+    /// # struct MyApp;
+    /// # impl PixEngine for MyApp {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// fn main() -> PixResult<()> {
+    ///     let engine = Engine::builder().build()?;
+    ///     engine.run_web(MyApp)
+    /// }
+    /// ```
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_web<A>(mut self, mut app: A) -> PixResult<()>
+    where
+        A: PixEngine + 'static,
+    {
+        info!("Starting `Engine`...");
+
+        // Handle events before on_start to initialize window
+        self.handle_events(&mut app)?;
+
+        debug!("Starting with `Engine::on_start`");
+        self.state.clear()?;
+        let on_start = app.on_start(&mut self.state);
+        if on_start.is_err() || self.state.should_quit() {
+            debug!("Quitting during startup with `Engine::on_stop`");
+            if let Err(ref err) = on_start {
+                error!("Error: {}", err);
+            }
+            return app.on_stop(&mut self.state).and(on_start);
+        }
+        self.state.present();
+        if let Some(device) = &self.audio_device {
+            device.resume();
+        }
+
+        debug!("Starting `Engine::on_update` loop.");
+        crate::renderer::wasm::request_frames(move || {
+            let start_time = Instant::now();
+            if let Some(target_delta_time) = self.state.target_delta_time() {
+                // Skip animation frames until the target frame rate is reached
+                if start_time - self.state.last_frame_time() < target_delta_time {
+                    return Ok(true);
+                }
+            }
+            let Some(result) = self.next_frame(&mut app, start_time)? else {
+                return Ok(true);
+            };
+
+            // on_stop can prevent application close if necessary
+            debug!("Quitting with `Engine::on_stop`");
+            let on_stop = app.on_stop(&mut self.state);
+            if self.state.should_quit() {
+                info!("Quitting `Engine`...");
+                on_stop.and(result)?;
+                return Ok(false);
+            }
+            Ok(true)
+        })
+    }
 }
 
 impl Engine {
+    /// Handle events and update the application for a single frame started at `start_time`.
+    /// Returns the result to stop with once the application should quit.
+    #[inline]
+    fn next_frame<A>(
+        &mut self,
+        app: &mut A,
+        start_time: Instant,
+    ) -> PixResult<Option<PixResult<()>>>
+    where
+        A: PixEngine,
+    {
+        let time_since_last = start_time - self.state.last_frame_time();
+
+        self.handle_events(app)?;
+        if self.state.should_quit() {
+            return Ok(Some(Ok(())));
+        }
+
+        if self.state.is_running() {
+            self.state.pre_update();
+            let fixed_delta = self.state.fixed_timestep();
+            let mut on_update = Ok(());
+            for _ in 0..self.state.advance_fixed_timestep(time_since_last) {
+                on_update = app.on_fixed_update(&mut self.state, fixed_delta);
+                if on_update.is_err() {
+                    break;
+                }
+            }
+            let on_update = on_update.and_then(|_| app.on_update(&mut self.state));
+            if on_update.is_err() {
+                self.state.quit();
+                return Ok(Some(on_update));
+            }
+            let render = !self.state.rendering_paused();
+            if render {
+                self.state.on_update()?;
+            }
+            self.state.post_update();
+            if render {
+                self.capture_screenshot();
+                self.state.record_frame();
+                self.state.present();
+            }
+            self.state.set_delta_time(start_time, time_since_last);
+            self.state.increment_frame(time_since_last)?;
+        }
+        // Keep mixing while paused so playing sounds and music don't run out
+        self.state.mix_audio();
+        Ok(None)
+    }
+
+    /// Save a screenshot of the current frame if the screenshot key was pressed.
+    #[inline]
+    fn capture_screenshot(&mut self) {
+        if !mem::take(&mut self.screenshot_requested) {
+            return;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some((_, dir)) = &self.screenshot {
+            match self.state.save_screenshot(dir) {
+                Ok(path) => info!("Saved screenshot to {}", path.display()),
+                Err(err) => error!("Failed to save screenshot: {}", err),
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        error!("Failed to save screenshot: saving files is not supported on the web");
+    }
+
     /// Handle user and system events.
     #[inline]
     fn handle_events<A>(&mut self, app: &mut A) -> PixResult<()>
@@ -1134,7 +1649,26 @@ impl Engine {
         A: PixEngine,
     {
         let state = &mut self.state;
+        // Closing the last window also sends a quit event, which is ignored if the close was
+        // cancelled
+        let mut close_cancelled = false;
+        state.ui.events.clear();
         while let Some(event) = state.poll_event() {
+            // The screenshot key is reserved, so none of its presses, repeats or releases reach
+            // the application
+            if let (
+                Event::KeyDown { key: Some(key), .. } | Event::KeyUp { key: Some(key), .. },
+                Some((screenshot_key, _)),
+            ) = (&event, &self.screenshot)
+            {
+                if key == screenshot_key {
+                    if matches!(event, Event::KeyDown { repeat: false, .. }) {
+                        self.screenshot_requested = true;
+                    }
+                    continue;
+                }
+            }
+            state.ui.events.push(event.clone());
             if let Event::ControllerAxisMotion { .. }
             | Event::JoyAxisMotion { .. }
             | Event::MouseMotion { .. }
@@ -1149,6 +1683,9 @@ impl Engine {
             let handled = app.on_event(state, &event)?;
             if !handled {
                 match event {
+                    Event::Quit if close_cancelled => {
+                        debug!("Quit cancelled by `PixEngine::on_close_requested`");
+                    }
                     Event::Quit { .. } | Event::AppTerminating { .. } => state.quit(),
                     Event::Window {
                         window_id,
@@ -1156,11 +1693,38 @@ impl Engine {
                     } => {
                         let window_id = WindowId(window_id);
                         match win_event {
-                            WindowEvent::FocusGained => state.focus_window(Some(window_id)),
-                            WindowEvent::FocusLost => state.focus_window(None),
-                            WindowEvent::Close => state.close_window(window_id)?,
+                            WindowEvent::FocusGained => {
+                                state.focus_window(Some(window_id));
+                                app.on_focus_changed(state, true)?;
+                            }
+                            WindowEvent::FocusLost => {
+                                state.focus_window(None);
+                                app.on_focus_changed(state, false)?;
+                            }
+                            WindowEvent::Enter => state.ui.hovered_window = Some(window_id),
+                            WindowEvent::Leave => state.ui.leave_window(window_id),
+                            WindowEvent::Close => {
+                                if app.on_close_requested(state, window_id)? {
+                                    state.close_window(window_id)?;
+                                } else {
+                                    close_cancelled = true;
+                                }
+                            }
+                            WindowEvent::Resized(width, height) => {
+                                let width = u32::try_from(width).unwrap_or(0);
+                                let height = u32::try_from(height).unwrap_or(0);
+                                app.on_resize(state, width, height)?;
+                            }
+                            WindowEvent::Moved(x, y) => {
+                                app.on_window_moved(state, window_id, x, y)?;
+                            }
+                            WindowEvent::Minimized => app.on_window_minimized(state, window_id)?,
+                            WindowEvent::Restored => app.on_window_restored(state, window_id)?,
                             _ => (),
                         }
+                        if window_id == state.renderer.primary_window_id() {
+                            state.env.update_minimized(win_event);
+                        }
                         app.on_window_event(state, window_id, win_event)?;
                     }
                     Event::KeyDown {
@@ -1168,10 +1732,11 @@ impl Engine {
                         keymod,
                         repeat,
                         scan: Some(scan),
+                        ..
                     } => {
                         let evt = KeyEvent::new(key, keymod, repeat, scan);
                         if !app.on_key_pressed(state, evt)? {
-                            state.ui.keys.press(key, keymod);
+                            state.ui.keys.press(key, keymod, repeat);
                         }
                     }
                     Event::KeyUp {
@@ -1179,6 +1744,7 @@ impl Engine {
                         keymod,
                         repeat,
                         scan: Some(scan),
+                        ..
                     } => {
                         let evt = KeyEvent::new(key, keymod, repeat, scan);
                         if !app.on_key_released(state, evt)? {
@@ -1190,29 +1756,29 @@ impl Engine {
                         button,
                     } => {
                         let evt = ControllerEvent::new(controller_id, button);
-                        app.on_controller_pressed(state, evt)?;
+                        if !app.on_controller_pressed(state, evt)? {
+                            state.ui.controllers.press(evt.controller_id, button);
+                        }
                     }
                     Event::ControllerUp {
                         controller_id,
                         button,
                     } => {
                         let evt = ControllerEvent::new(controller_id, button);
-                        app.on_controller_released(state, evt)?;
+                        if !app.on_controller_released(state, evt)? {
+                            state.ui.controllers.release(evt.controller_id, button);
+                        }
                     }
                     Event::ControllerAxisMotion {
                         controller_id,
                         axis,
                         value,
                     } => {
-                        let value = i32::from(value);
-                        let value =
-                            if (-self.joystick_deadzone..self.joystick_deadzone).contains(&value) {
-                                0
-                            } else {
-                                value
-                            };
+                        let value = state.ui.controllers.apply_deadzone(value);
                         let id = ControllerId(controller_id);
-                        app.on_controller_axis_motion(state, id, axis, value)?;
+                        if !app.on_controller_axis_motion(state, id, axis, i32::from(value))? {
+                            state.ui.controllers.set_axis(id, axis, value);
+                        }
                     }
                     Event::ControllerAdded { controller_id } => {
                         let id = ControllerId(controller_id);
@@ -1228,14 +1794,18 @@ impl Engine {
                     }
                     Event::ControllerRemoved { controller_id } => {
                         let id = ControllerId(controller_id);
+                        // Removed controllers never report releasing held input, so always clear it
+                        state.ui.controllers.remove(id);
                         if !app.on_controller_update(state, id, ControllerUpdate::Removed)? {
                             state.close_controller(id);
                         }
                     }
                     Event::JoyDeviceRemoved { joy_id } => {
                         let id = ControllerId(joy_id);
+                        // Removed controllers never report releasing held input, so always clear it
+                        state.ui.controllers.remove(id);
                         if !app.on_controller_update(state, id, ControllerUpdate::Removed)? {
-                            state.open_controller(id)?;
+                            state.close_controller(id);
                         }
                     }
                     Event::ControllerRemapped { controller_id } => {
@@ -1247,8 +1817,25 @@ impl Engine {
                             state.ui.keys.typed(text);
                         }
                     }
-                    Event::MouseMotion { x, y, xrel, yrel } => {
+                    Event::TextEditing {
+                        text,
+                        start,
+                        length,
+                        ..
+                    } => {
+                        if !app.on_text_editing(state, &text, start, length)? {
+                            state.ui.keys.edit(text, start);
+                        }
+                    }
+                    Event::MouseMotion {
+                        window_id,
+                        x,
+                        y,
+                        xrel,
+                        yrel,
+                    } => {
                         let pos = point!(x, y);
+                        state.ui.set_mouse_window(WindowId(window_id), pos);
                         let rel_pos = point!(xrel, yrel);
                         if state.ui.mouse.is_pressed() {
                             app.on_mouse_dragged(state, pos, rel_pos)?;
@@ -1257,23 +1844,28 @@ impl Engine {
                             state.on_mouse_motion(pos);
                         }
                     }
-                    Event::MouseDown { button, x, y } => {
+                    Event::MouseDown {
+                        window_id,
+                        button,
+                        x,
+                        y,
+                    } => {
+                        state.ui.set_mouse_window(WindowId(window_id), point!(x, y));
                         if !app.on_mouse_pressed(state, button, point!(x, y))? {
                             state.on_mouse_pressed(button);
                         }
                     }
-                    Event::MouseUp { button, x, y } => {
+                    Event::MouseUp { button, x, y, .. } => {
                         if state.ui.mouse.is_down(button) {
                             let now = Instant::now();
-                            if let Some(clicked) = state.ui.mouse.last_clicked(button) {
-                                if now - *clicked < Duration::from_millis(500)
-                                    && !app.on_mouse_dbl_clicked(state, button, point!(x, y))?
-                                {
-                                    state.on_mouse_dbl_click(button, now);
-                                }
+                            let pos = point!(x, y);
+                            if state.ui.mouse.next_click_count(button, pos, now) > 1
+                                && !app.on_mouse_dbl_clicked(state, button, pos)?
+                            {
+                                state.on_mouse_dbl_click(button, now);
                             }
-                            if !app.on_mouse_clicked(state, button, point!(x, y))? {
-                                state.on_mouse_click(button, now);
+                            if !app.on_mouse_clicked(state, button, pos)? {
+                                state.on_mouse_click(button, pos, now);
                             }
                         }
                         if !app.on_mouse_released(state, button, point!(x, y))? {
@@ -1285,6 +1877,23 @@ impl Engine {
                             state.on_mouse_wheel(x, y);
                         }
                     }
+                    Event::FingerDown {
+                        finger_id,
+                        x,
+                        y,
+                        pressure,
+                        ..
+                    }
+                    | Event::FingerMotion {
+                        finger_id,
+                        x,
+                        y,
+                        pressure,
+                        ..
+                    } => {
+                        state.ui.touch(finger_id, TouchPoint::new(x, y, pressure));
+                    }
+                    Event::FingerUp { finger_id, .. } => state.ui.release_touch(finger_id),
                     _ => (),
                 }
             }
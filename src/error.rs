@@ -17,6 +17,27 @@ pub enum Error {
     /// Invalid Window ID. Window either doesn't exist or was closed.
     #[error("invalid window id `{0}`")]
     InvalidWindow(WindowId),
+    /// Invalid display index. Display is not connected.
+    #[error("invalid display index `{0}`")]
+    InvalidDisplay(u32),
+    /// Invalid Sound ID. Sound either wasn't loaded with [`PixState::load_sound`] or was unloaded.
+    #[error("invalid sound id `{0}`")]
+    InvalidSound(SoundId),
+    /// Invalid Font ID. Font was not added with [`PixState::add_font`] or [`PixState::load_font`].
+    #[error("invalid font id `{0}`")]
+    InvalidFont(FontId),
+    /// Invalid [`SpriteSheet`] region name.
+    #[error("invalid sprite region `{name}`, available regions: {}", .available.join(", "))]
+    InvalidSpriteRegion {
+        /// Requested region name.
+        name: String,
+        /// Available region names.
+        available: Vec<String>,
+    },
+    /// Invalid or unsupported [`TileMap`] document.
+    #[cfg(feature = "tiled")]
+    #[error("invalid tile map: {0}")]
+    InvalidTileMap(String),
     /// Hexadecimal [Color] string parsing error. String doesn't match any of `3`, `4`, `6`, or `8`
     /// digit hexadecimal (radix `16`) values with a leading `#` character.
     #[error("hexadecimal color string parsing error")]
@@ -38,6 +59,14 @@ pub enum Error {
         /// `Image` format.
         format: PixelFormat,
     },
+    /// Invalid [Cursor] hot spot. The hot spot lies outside of the cursor image.
+    #[error("invalid cursor hot spot {hotspot:?} for image dimensions {dimensions:?}")]
+    InvalidCursorHotspot {
+        /// Cursor hot spot `(x, y)`.
+        hotspot: (i32, i32),
+        /// Cursor `Image` dimensions as `(width, height)`.
+        dimensions: (u32, u32),
+    },
     /// Unsupported [Image] format.
     #[error("unsupported image format {{ bit_depth: {bit_depth:?}, color_type: {color_type:?} }}")]
     UnsupportedImageFormat {
@@ -46,6 +75,14 @@ pub enum Error {
         /// `Image` [png::ColorType].
         color_type: png::ColorType,
     },
+    /// Unsupported BMP [Image] format. Only uncompressed 24-bit and 32-bit images are supported.
+    #[error("unsupported bmp format {{ bit_count: {bit_count}, compression: {compression} }}")]
+    UnsupportedBmpFormat {
+        /// Bits per pixel.
+        bit_count: u16,
+        /// Compression method.
+        compression: u32,
+    },
     /// Unsupported file type.
     #[error("unsupported file type with extension `{0:?}`")]
     UnsupportedFileType(Option<OsString>),
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     fmt,
     ops::{Deref, DerefMut},
+    path::PathBuf,
 };
 
 /// System or User `Event`.
@@ -26,6 +27,8 @@ pub enum Event {
     },
     /// User key press event.
     KeyDown {
+        /// Window identifier for this event.
+        window_id: u32,
         /// Specific key being pressed.
         key: Option<Key>,
         /// Key modifiers being held upon press, e.g. Shift or Ctrl, etc.
@@ -37,6 +40,8 @@ pub enum Event {
     },
     /// User key release event.
     KeyUp {
+        /// Window identifier for this event.
+        window_id: u32,
         /// Specific key being released.
         key: Option<Key>,
         /// Key modifiers being held upon release, e.g. Shift or Ctrl, etc.
@@ -48,11 +53,27 @@ pub enum Event {
     },
     /// User text entry event.
     TextInput {
+        /// Window identifier for this event.
+        window_id: u32,
         /// The user-entered text.
         text: String,
     },
+    /// User text composition event from an input method editor (IME), before the text is
+    /// committed with [`Event::TextInput`].
+    TextEditing {
+        /// Window identifier for this event.
+        window_id: u32,
+        /// The text being composed. Empty when composition is cancelled.
+        text: String,
+        /// The cursor position within the composed text, in characters.
+        start: i32,
+        /// The length of the selection within the composed text, in characters.
+        length: i32,
+    },
     /// User mouse movement event.
     MouseMotion {
+        /// Window identifier for this event.
+        window_id: u32,
         /// Current horizontal mouse position after motion.
         x: i32,
         /// Current vertical mouse position after motion.
@@ -64,6 +85,8 @@ pub enum Event {
     },
     /// User mouse click event.
     MouseDown {
+        /// Window identifier for this event.
+        window_id: u32,
         /// Specific mouse button being clicked.
         button: Mouse,
         /// Current horizontal mouse position after click.
@@ -73,6 +96,8 @@ pub enum Event {
     },
     /// User mouse release event.
     MouseUp {
+        /// Window identifier for this event.
+        window_id: u32,
         /// Specific mouse button being released.
         button: Mouse,
         /// Current horizontal mouse position after release.
@@ -82,6 +107,8 @@ pub enum Event {
     },
     /// User mouse wheel event.
     MouseWheel {
+        /// Window identifier for this event.
+        window_id: u32,
         /// Relative horizontal wheel offset.
         x: i32,
         /// Relative vertical wheel offset.
@@ -243,6 +270,32 @@ pub enum Event {
         /// Whether this device is a capture device or not.
         iscapture: bool,
     },
+    /// Start of a drag and drop of one or more files or text onto a window.
+    DropBegin {
+        /// Window identifier for this event.
+        window_id: u32,
+    },
+    /// User dropped a file onto a window. Dropping multiple files sends one event per file.
+    DropFile {
+        /// Window identifier for this event.
+        window_id: u32,
+        /// Path of the dropped file.
+        path: PathBuf,
+    },
+    /// User dropped text onto a window.
+    DropText {
+        /// Window identifier for this event.
+        window_id: u32,
+        /// The dropped text.
+        text: String,
+    },
+    /// End of a drag and drop, after all [`Event::DropFile`] and [`Event::DropText`] events.
+    DropComplete {
+        /// Window identifier for this event.
+        window_id: u32,
+    },
+    /// System clipboard contents changed.
+    ClipboardUpdate,
     /// An unknown/unsupported event.
     Unhandled,
 }
@@ -253,6 +306,29 @@ impl Default for Event {
     }
 }
 
+impl Event {
+    /// Returns a mutable reference to the window identifier of this event, if it has one.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn window_id_mut(&mut self) -> Option<&mut u32> {
+        match self {
+            Self::Window { window_id, .. }
+            | Self::KeyDown { window_id, .. }
+            | Self::KeyUp { window_id, .. }
+            | Self::TextInput { window_id, .. }
+            | Self::TextEditing { window_id, .. }
+            | Self::MouseMotion { window_id, .. }
+            | Self::MouseDown { window_id, .. }
+            | Self::MouseUp { window_id, .. }
+            | Self::MouseWheel { window_id, .. }
+            | Self::DropBegin { window_id }
+            | Self::DropFile { window_id, .. }
+            | Self::DropText { window_id, .. }
+            | Self::DropComplete { window_id } => Some(window_id),
+            _ => None,
+        }
+    }
+}
+
 /// A specific [Event] representing a keypress.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -312,6 +388,11 @@ pub enum WindowEvent {
     FocusLost,
     /// Window closed.
     Close,
+    /// Window was recreated by the renderer, e.g. after changing [`PixState::vsync`]. Texture
+    /// contents are preserved, but this can be used to re-upload them if preferred.
+    ///
+    /// [`PixState::vsync`]: crate::prelude::PixState::vsync
+    Recreated,
     /// An unknown/unsupported window event.
     Unhandled,
 }
@@ -658,6 +739,25 @@ pub enum ControllerUpdate {
     Remapped,
 }
 
+/// Current state of a finger touching a touch device.
+#[non_exhaustive]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TouchPoint {
+    /// Horizontal finger position, normalized to `0.0..=1.0`.
+    pub x: f32,
+    /// Vertical finger position, normalized to `0.0..=1.0`.
+    pub y: f32,
+    /// Amount of finger pressure being applied, normalized to `0.0..=1.0`.
+    pub pressure: f32,
+}
+
+impl TouchPoint {
+    pub(crate) const fn new(x: f32, y: f32, pressure: f32) -> Self {
+        Self { x, y, pressure }
+    }
+}
+
 /// A specific [Event] representing a controller button press.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -95,7 +95,7 @@
 //! # }
 //! ```
 
-use self::state::ElementId;
+use self::{state::ElementId, theme::Colors};
 use crate::{
     ops::{clamp_dimensions, clamp_size},
     prelude::*,
@@ -107,10 +107,15 @@ pub mod system;
 pub mod theme;
 pub mod widgets;
 
+pub(crate) mod controller;
+pub(crate) mod drag_drop;
 pub(crate) mod keys;
+pub(crate) mod menu;
 pub(crate) mod mouse;
 pub(crate) mod scroll;
+pub(crate) mod selection;
 pub(crate) mod state;
+pub(crate) mod tooltip;
 
 /// Platform-specific control modifier key. `CTRL` on most platforms.
 #[cfg(not(target_os = "macos"))]
@@ -134,6 +139,7 @@ pub enum Direction {
 
 impl PixState {
     /// Return usable UI width given the current UI cursor position and padding clamped to i32.
+    /// Within a split pane, the usable width ends at the pane edge.
     ///
     /// # Errors
     ///
@@ -142,10 +148,15 @@ impl PixState {
     pub fn ui_width(&self) -> PixResult<i32> {
         let pos = self.cursor_pos();
         let fpad = self.theme.spacing.frame_pad;
-        Ok(clamp_size(self.width()?) - pos.x() - fpad.x())
+        let right = match self.ui.panes.last() {
+            Some(pane) => pane.right(),
+            None => clamp_size(self.width()?),
+        };
+        Ok(right - pos.x() - fpad.x())
     }
 
     /// Return usable UI height given the current UI cursor position and padding clamped to i32.
+    /// Within a split pane, the usable height ends at the pane edge.
     ///
     /// # Errors
     ///
@@ -154,7 +165,11 @@ impl PixState {
     pub fn ui_height(&self) -> PixResult<i32> {
         let pos = self.cursor_pos();
         let fpad = self.theme.spacing.frame_pad;
-        Ok(clamp_size(self.height()?) - pos.y() - fpad.y())
+        let bottom = match self.ui.panes.last() {
+            Some(pane) => pane.bottom(),
+            None => clamp_size(self.height()?),
+        };
+        Ok(bottom - pos.y() - fpad.y())
     }
 }
 
@@ -162,83 +177,24 @@ impl PixState {
     /// Set and return default colors based on widget state for the given surface type.
     #[inline]
     pub(crate) fn widget_colors(&mut self, id: ElementId, surface_color: ColorType) -> [Color; 3] {
-        // "On" overlay opacity:
-        // - High emphasis: 87%
-        // - Med emphasis: 60%
-        // - Disabled: 38%
-        // - Error: 100%
-        // Stroke & Fill opacity:
-        // - Focused: 12%
-        // - Hovered: 4%
-        // - Active: 8%
-
         let s = self;
         let focused = s.ui.is_focused(id);
         let active = s.ui.is_active(id);
         let hovered = s.ui.is_hovered(id);
         let disabled = s.ui.disabled;
-        let c = s.theme.colors;
-
-        let (bg, overlay) = match surface_color {
-            ColorType::Background => (c.background, c.on_background),
-            ColorType::Surface => (c.surface, c.on_surface),
-            ColorType::Primary => (c.primary, c.on_primary),
-            ColorType::PrimaryVariant => (c.primary_variant, c.on_primary),
-            ColorType::Secondary => (c.secondary, c.on_secondary),
-            ColorType::SecondaryVariant => (c.secondary_variant, c.on_secondary),
-            ColorType::Error => (c.error, c.on_error),
-            _ => panic!("invalid surface color"),
-        };
-        let branded = matches!(
+        state_colors(
+            s.theme.colors,
             surface_color,
-            ColorType::Primary
-                | ColorType::PrimaryVariant
-                | ColorType::Secondary
-                | ColorType::SecondaryVariant,
-        );
-
-        let stroke_overlay = if branded {
-            bg.blended(Color::WHITE, 0.60)
-        } else {
-            overlay
-        };
-        let stroke = if focused {
-            stroke_overlay
-        } else if disabled {
-            stroke_overlay.blended(bg, 0.18)
-        } else {
-            stroke_overlay.blended(bg, 0.38)
-        };
-
-        let bg_overlay = if branded { Color::WHITE } else { overlay };
-        let bg = if focused {
-            bg_overlay.blended(bg, 0.12)
-        } else if active {
-            bg_overlay.blended(bg, 0.08)
-        } else if hovered {
-            if branded {
-                bg_overlay.blended(bg, 0.12)
-            } else {
-                bg_overlay.blended(bg, 0.04)
-            }
-        } else if branded && disabled {
-            overlay.blended(bg, 0.38)
-        } else {
-            bg
-        };
-
-        let fg = if disabled {
-            overlay.blended(bg, 0.38)
-        } else {
-            overlay.blended(bg, 0.87)
-        };
-
-        [stroke, bg, fg]
+            focused,
+            active,
+            hovered,
+            disabled,
+        )
     }
 
-    /// Return the size of text, clamped to i32.
+    /// Return the size of text with widget padding, clamped to i32.
     #[inline]
-    pub(crate) fn text_size(&self, text: &str) -> PixResult<(i32, i32)> {
+    pub(crate) fn padded_text_size(&self, text: &str) -> PixResult<(i32, i32)> {
         let s = &self.settings;
         let wrap_width = s.wrap_width;
         let ipad = self.theme.spacing.item_pad;
@@ -259,3 +215,101 @@ impl PixState {
         Ok(clamp_dimensions(w + 3, h + 3))
     }
 }
+
+/// Return default `[stroke, background, foreground]` colors from the theme `c` for the given
+/// surface type and widget state.
+fn state_colors(
+    c: Colors,
+    surface_color: ColorType,
+    focused: bool,
+    active: bool,
+    hovered: bool,
+    disabled: bool,
+) -> [Color; 3] {
+    // "On" overlay opacity:
+    // - High emphasis: 87%
+    // - Med emphasis: 60%
+    // - Disabled: 38%
+    // - Error: 100%
+    // Stroke & Fill opacity:
+    // - Focused: 12%
+    // - Hovered: 4%
+    // - Active: 8%
+
+    let (bg, overlay) = match surface_color {
+        ColorType::Background => (c.background, c.on_background),
+        ColorType::Surface => (c.surface, c.on_surface),
+        ColorType::Primary => (c.primary, c.on_primary),
+        ColorType::PrimaryVariant => (c.primary_variant, c.on_primary),
+        ColorType::Secondary => (c.secondary, c.on_secondary),
+        ColorType::SecondaryVariant => (c.secondary_variant, c.on_secondary),
+        ColorType::Error => (c.error, c.on_error),
+        _ => panic!("invalid surface color"),
+    };
+    let branded = matches!(
+        surface_color,
+        ColorType::Primary
+            | ColorType::PrimaryVariant
+            | ColorType::Secondary
+            | ColorType::SecondaryVariant,
+    );
+
+    let stroke_overlay = if branded {
+        bg.blended(Color::WHITE, 0.60)
+    } else {
+        overlay
+    };
+    let stroke = if focused {
+        stroke_overlay
+    } else if disabled {
+        stroke_overlay.blended(bg, 0.18)
+    } else {
+        stroke_overlay.blended(bg, 0.38)
+    };
+
+    let bg_overlay = if branded { Color::WHITE } else { overlay };
+    let bg = if focused {
+        bg_overlay.blended(bg, 0.12)
+    } else if active {
+        bg_overlay.blended(bg, 0.08)
+    } else if hovered {
+        if branded {
+            bg_overlay.blended(bg, 0.12)
+        } else {
+            bg_overlay.blended(bg, 0.04)
+        }
+    } else if branded && disabled {
+        overlay.blended(bg, 0.38)
+    } else {
+        bg
+    };
+
+    let fg = if disabled {
+        overlay.blended(bg, 0.38)
+    } else {
+        overlay.blended(bg, 0.87)
+    };
+
+    [stroke, bg, fg]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_colors_follow_theme() {
+        let dark = Colors::dark();
+        let light = Colors::light();
+        let [_, bg, _] = state_colors(dark, ColorType::Surface, false, false, false, false);
+        assert_eq!(bg, dark.surface);
+
+        // Swapping themes takes effect on the next call, with nothing cached in between
+        let [_, bg, fg] = state_colors(light, ColorType::Surface, false, false, false, false);
+        assert_eq!(bg, light.surface);
+        assert_eq!(fg, light.on_surface.blended(light.surface, 0.87));
+        let [stroke, bg, _] = state_colors(light, ColorType::Primary, true, false, false, false);
+        assert_eq!(stroke, light.primary.blended(Color::WHITE, 0.60));
+        assert_eq!(bg, Color::WHITE.blended(light.primary, 0.12));
+    }
+}
@@ -0,0 +1,155 @@
+//! Controller state management.
+
+use crate::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Default joystick axis deadzone.
+pub(crate) const DEFAULT_DEADZONE: i32 = 8000;
+
+/// Keep track of connected controllers and their axis and button states between frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ControllerState {
+    pub(crate) connected: Vec<ControllerId>,
+    pub(crate) axes: HashMap<(ControllerId, Axis), i16>,
+    pub(crate) pressed: HashSet<(ControllerId, ControllerButton)>,
+    pub(crate) deadzone: i32,
+}
+
+impl Default for ControllerState {
+    fn default() -> Self {
+        Self {
+            connected: vec![],
+            axes: HashMap::new(),
+            pressed: HashSet::new(),
+            deadzone: DEFAULT_DEADZONE,
+        }
+    }
+}
+
+impl ControllerState {
+    /// Returns the IDs of the connected controllers, in the order they were connected.
+    #[inline]
+    #[must_use]
+    pub(crate) fn connected(&self) -> &[ControllerId] {
+        &self.connected
+    }
+
+    /// Returns an [Axis] `value` with the deadzone applied, which is `0` if within the deadzone.
+    #[inline]
+    #[must_use]
+    pub(crate) fn apply_deadzone(&self, value: i16) -> i16 {
+        if (-self.deadzone..self.deadzone).contains(&i32::from(value)) {
+            0
+        } else {
+            value
+        }
+    }
+
+    /// Returns the current value of an [Axis] for a given controller.
+    #[inline]
+    #[must_use]
+    pub(crate) fn axis(&self, id: ControllerId, axis: Axis) -> i16 {
+        self.axes.get(&(id, axis)).copied().unwrap_or_default()
+    }
+
+    /// Returns if a specific [`ControllerButton`] is currently being held for a given controller.
+    #[inline]
+    #[must_use]
+    pub(crate) fn is_down(&self, id: ControllerId, button: ControllerButton) -> bool {
+        self.pressed.contains(&(id, button))
+    }
+
+    /// Store the value of a moved [Axis].
+    #[inline]
+    pub(crate) fn set_axis(&mut self, id: ControllerId, axis: Axis, value: i16) {
+        if value == 0 {
+            self.axes.remove(&(id, axis));
+        } else {
+            self.axes.insert((id, axis), value);
+        }
+    }
+
+    /// Store a pressed [`ControllerButton`].
+    #[inline]
+    pub(crate) fn press(&mut self, id: ControllerId, button: ControllerButton) {
+        self.pressed.insert((id, button));
+    }
+
+    /// Remove a pressed [`ControllerButton`].
+    #[inline]
+    pub(crate) fn release(&mut self, id: ControllerId, button: ControllerButton) {
+        self.pressed.remove(&(id, button));
+    }
+
+    /// Store a connected controller.
+    #[inline]
+    pub(crate) fn connect(&mut self, id: ControllerId) {
+        if !self.connected.contains(&id) {
+            self.connected.push(id);
+        }
+    }
+
+    /// Remove all state for a disconnected controller.
+    #[inline]
+    pub(crate) fn remove(&mut self, id: ControllerId) {
+        self.connected.retain(|&connected_id| connected_id != id);
+        self.axes.retain(|&(axis_id, _), _| axis_id != id);
+        self.pressed.retain(|&(button_id, _)| button_id != id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn controller_state() {
+        let (first, second) = (ControllerId(0), ControllerId(1));
+        let mut state = ControllerState::default();
+        state.press(first, ControllerButton::A);
+        state.set_axis(first, Axis::LeftX, -12_000);
+        state.set_axis(second, Axis::LeftX, 8_000);
+        assert!(state.is_down(first, ControllerButton::A));
+        assert!(!state.is_down(second, ControllerButton::A));
+        assert_eq!(state.axis(first, Axis::LeftX), -12_000);
+        assert_eq!(state.axis(first, Axis::LeftY), 0);
+
+        state.release(first, ControllerButton::A);
+        state.set_axis(second, Axis::LeftX, 0);
+        assert!(!state.is_down(first, ControllerButton::A));
+        assert_eq!(state.axis(second, Axis::LeftX), 0);
+
+        state.press(second, ControllerButton::B);
+        state.remove(first);
+        assert_eq!(state.axis(first, Axis::LeftX), 0);
+        assert!(state.is_down(second, ControllerButton::B));
+    }
+
+    #[test]
+    fn controller_connections() {
+        let (first, second) = (ControllerId(0), ControllerId(1));
+        let mut state = ControllerState::default();
+        state.connect(first);
+        state.connect(second);
+        state.connect(first);
+        assert_eq!(state.connected(), &[first, second]);
+
+        state.press(first, ControllerButton::Start);
+        state.remove(first);
+        assert_eq!(state.connected(), &[second]);
+        assert!(!state.is_down(first, ControllerButton::Start));
+    }
+
+    #[test]
+    fn controller_deadzone() {
+        let mut state = ControllerState::default();
+        assert_eq!(state.apply_deadzone(DEFAULT_DEADZONE as i16 - 1), 0);
+        assert_eq!(state.apply_deadzone(-DEFAULT_DEADZONE as i16), 0);
+        assert_eq!(state.apply_deadzone(DEFAULT_DEADZONE as i16), 8000);
+        assert_eq!(state.apply_deadzone(i16::MIN), i16::MIN);
+
+        state.deadzone = 0;
+        assert_eq!(state.apply_deadzone(1), 1);
+        assert_eq!(state.apply_deadzone(-1), -1);
+    }
+}
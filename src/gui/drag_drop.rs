@@ -0,0 +1,212 @@
+//! Drag and drop state management.
+
+use super::state::ElementId;
+use crate::prelude::*;
+use std::any::Any;
+
+/// Distance in pixels the mouse must move while pressed before a drag begins.
+pub(crate) const DRAG_THRESHOLD: i32 = 4;
+
+/// A payload currently being dragged.
+#[derive(Debug)]
+pub(crate) struct DragPayload {
+    /// Drag source element ID.
+    pub(crate) source: ElementId,
+    /// Offset of the mouse from the top-left of the drag source when the drag began.
+    pub(crate) grab: Point<i32>,
+    /// Size of the drag source.
+    pub(crate) size: Point<i32>,
+    /// Dragged value.
+    value: Box<dyn Any>,
+}
+
+impl DragPayload {
+    /// Returns the dragged value if it is of type `T`.
+    #[inline]
+    pub(crate) fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+}
+
+/// Tracks the mouse press a drag starts from and the payload being dragged, if any. Positions are
+/// in window coordinates.
+#[derive(Default, Debug)]
+pub(crate) struct DragState {
+    /// Position where [`Mouse::Left`] was pressed.
+    origin: Option<Point<i32>>,
+    /// Payload currently being dragged.
+    payload: Option<DragPayload>,
+}
+
+impl DragState {
+    /// Store the position where [`Mouse::Left`] was pressed.
+    #[inline]
+    pub(crate) fn press(&mut self, pos: Point<i32>) {
+        self.origin = Some(pos);
+    }
+
+    /// Returns the payload currently being dragged, if any.
+    #[inline]
+    pub(crate) const fn payload(&self) -> Option<&DragPayload> {
+        self.payload.as_ref()
+    }
+
+    /// Take the payload currently being dragged, if any, to be restored with
+    /// [`DragState::restore`].
+    #[inline]
+    pub(crate) fn take_payload(&mut self) -> Option<DragPayload> {
+        self.payload.take()
+    }
+
+    /// Restore a payload taken with [`DragState::take_payload`].
+    #[inline]
+    pub(crate) fn restore(&mut self, payload: Option<DragPayload>) {
+        self.payload = payload;
+    }
+
+    /// Whether a payload is being dragged from a given drag source.
+    #[inline]
+    pub(crate) fn is_dragging(&self, source: ElementId) -> bool {
+        matches!(&self.payload, Some(payload) if payload.source == source)
+    }
+
+    /// Begin dragging `value` from a drag source with bounds `rect` if [`Mouse::Left`] was pressed
+    /// within `rect` and has since moved beyond [`DRAG_THRESHOLD`] to `mouse`. Returns whether
+    /// the drag began.
+    pub(crate) fn try_begin<T: Any>(
+        &mut self,
+        source: ElementId,
+        rect: Rect<i32>,
+        mouse: Point<i32>,
+        value: T,
+    ) -> bool {
+        let Some(origin) = self.origin else {
+            return false;
+        };
+        let moved = (mouse.x() - origin.x())
+            .abs()
+            .max((mouse.y() - origin.y()).abs());
+        if self.payload.is_some() || !rect.contains(origin) || moved <= DRAG_THRESHOLD {
+            return false;
+        }
+        self.payload = Some(DragPayload {
+            source,
+            grab: point![origin.x() - rect.x(), origin.y() - rect.y()],
+            size: rect.size(),
+            value: Box::new(value),
+        });
+        true
+    }
+
+    /// Returns the dragged value if it is of type `T`.
+    #[inline]
+    pub(crate) fn hovering<T: Any>(&self) -> Option<&T> {
+        self.payload.as_ref().and_then(DragPayload::downcast_ref)
+    }
+
+    /// Take the dragged value if it is of type `T`, [`Mouse::Left`] was `released`, and `mouse`
+    /// is within a drop target with bounds `rect`.
+    pub(crate) fn try_drop<T: Any>(
+        &mut self,
+        rect: Rect<i32>,
+        mouse: Point<i32>,
+        released: bool,
+    ) -> Option<T> {
+        if !released || !rect.contains(mouse) || self.hovering::<T>().is_none() {
+            return None;
+        }
+        let payload = self.payload.take()?;
+        payload.value.downcast().ok().map(|value| *value)
+    }
+
+    /// Handle state changes at the end of a frame, cancelling any drag that was released without
+    /// being dropped on a drop target.
+    #[inline]
+    pub(crate) fn end_frame(&mut self, mouse_down: bool) {
+        if !mouse_down {
+            self.origin = None;
+            self.payload = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: ElementId = ElementId(1);
+
+    fn source_rect() -> Rect<i32> {
+        rect![0, 0, 100, 20]
+    }
+
+    fn target_rect() -> Rect<i32> {
+        rect![200, 0, 100, 100]
+    }
+
+    #[test]
+    fn drag_delivers_payload() {
+        let mut drag = DragState::default();
+        drag.press(point![10, 10]);
+        drag.end_frame(true);
+
+        // Moving within the threshold doesn't begin a drag
+        assert!(!drag.try_begin(SOURCE, source_rect(), point![12, 12], "item"));
+        drag.end_frame(true);
+
+        assert!(drag.try_begin(SOURCE, source_rect(), point![30, 10], "item"));
+        assert!(drag.is_dragging(SOURCE));
+        assert_eq!(
+            drag.payload().map(|payload| payload.grab),
+            Some(point![10, 10])
+        );
+        drag.end_frame(true);
+
+        // Only compatible targets see the payload, and only once released over them
+        assert_eq!(drag.hovering::<&str>(), Some(&"item"));
+        assert_eq!(drag.hovering::<usize>(), None);
+        assert_eq!(
+            drag.try_drop::<&str>(target_rect(), point![250, 50], false),
+            None
+        );
+        assert_eq!(
+            drag.try_drop::<usize>(target_rect(), point![250, 50], true),
+            None
+        );
+        assert_eq!(
+            drag.try_drop::<&str>(target_rect(), point![250, 50], true),
+            Some("item")
+        );
+        assert!(!drag.is_dragging(SOURCE));
+        drag.end_frame(false);
+        assert!(drag.payload().is_none());
+    }
+
+    #[test]
+    fn drag_cancelled_outside_target() {
+        let mut drag = DragState::default();
+        drag.press(point![10, 10]);
+        assert!(drag.try_begin(SOURCE, source_rect(), point![150, 150], 42_usize));
+        drag.end_frame(true);
+
+        assert_eq!(
+            drag.try_drop::<usize>(target_rect(), point![150, 150], true),
+            None
+        );
+        drag.end_frame(false);
+        assert!(drag.payload().is_none());
+        assert_eq!(
+            drag.try_drop::<usize>(target_rect(), point![250, 50], true),
+            None
+        );
+    }
+
+    #[test]
+    fn drag_requires_press_on_source() {
+        let mut drag = DragState::default();
+        assert!(!drag.try_begin(SOURCE, source_rect(), point![50, 10], ()));
+
+        drag.press(point![150, 10]);
+        assert!(!drag.try_begin(SOURCE, source_rect(), point![50, 10], ()));
+    }
+}
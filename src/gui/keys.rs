@@ -1,15 +1,47 @@
 //! Keyboard state management.
 
 use crate::prelude::*;
-use std::collections::HashSet;
+use std::{borrow::Cow, collections::HashSet, ops::Range};
+
+/// Text being composed by an input method editor (IME) before it's committed.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Composition {
+    /// The text being composed.
+    pub(crate) text: String,
+    /// The byte index of the cursor within the composed text.
+    pub(crate) cursor: usize,
+}
+
+impl Composition {
+    /// Returns `value` with the composed text inserted at byte `index`, and the byte range of the
+    /// composed text within it.
+    pub(crate) fn insert_into<'a>(
+        &self,
+        value: &'a str,
+        index: usize,
+    ) -> (Cow<'a, str>, Range<usize>) {
+        let index = index.min(value.len());
+        let mut preedit = String::with_capacity(value.len() + self.text.len());
+        preedit.push_str(&value[..index]);
+        preedit.push_str(&self.text);
+        preedit.push_str(&value[index..]);
+        (Cow::Owned(preedit), index..index + self.text.len())
+    }
+}
 
 /// Keep track of key states between frames
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub(crate) struct KeyState {
     pub(crate) entered: Option<Key>,
     pub(crate) typed: Option<String>,
+    pub(crate) composition: Option<Composition>,
     pub(crate) pressed: HashSet<Key>,
+    pub(crate) just_pressed: HashSet<Key>,
+    pub(crate) released: HashSet<Key>,
+    pub(crate) repeated: HashSet<Key>,
+    pub(crate) last_pressed: Option<Key>,
     pub(crate) keymod: KeyMod,
+    pub(crate) shortcuts: Vec<(Key, KeyMod, &'static str)>,
 }
 
 impl KeyState {
@@ -31,6 +63,30 @@ impl KeyState {
         self.pressed.contains(&key)
     }
 
+    /// Returns if a specific [Key] went down this frame, ignoring key repeat.
+    #[inline]
+    pub(crate) fn was_just_pressed(&self, key: Key) -> bool {
+        self.just_pressed.contains(&key)
+    }
+
+    /// Returns if a specific [Key] was released this frame.
+    #[inline]
+    pub(crate) fn was_released(&self, key: Key) -> bool {
+        self.released.contains(&key)
+    }
+
+    /// Returns if a specific [Key] went down or was repeated by the operating system this frame.
+    #[inline]
+    pub(crate) fn was_typed(&self, key: Key) -> bool {
+        self.just_pressed.contains(&key) || self.repeated.contains(&key)
+    }
+
+    /// Returns the last [Key] that went down, ignoring key repeat.
+    #[inline]
+    pub(crate) const fn last_pressed(&self) -> Option<Key> {
+        self.last_pressed
+    }
+
     /// Returns if a [Key] was entered last frame.
     #[inline]
     pub(crate) fn was_entered(&self, key: Key) -> bool {
@@ -49,11 +105,41 @@ impl KeyState {
         &self.keymod
     }
 
-    /// Store a pressed [Key].
+    /// Returns if a [Key] was entered last frame while holding exactly the given [`KeyMod`]s.
     #[inline]
-    pub(crate) fn press(&mut self, key: Key, keymod: KeyMod) {
+    pub(crate) fn shortcut_entered(&self, key: Key, keymod: KeyMod) -> bool {
+        self.was_entered(key) && self.keymod == keymod
+    }
+
+    /// Map a [Key] and [`KeyMod`] combination to a named action. An action can have multiple
+    /// shortcuts.
+    #[inline]
+    pub(crate) fn register_shortcut(&mut self, key: Key, keymod: KeyMod, action: &'static str) {
+        let shortcut = (key, keymod, action);
+        if !self.shortcuts.contains(&shortcut) {
+            self.shortcuts.push(shortcut);
+        }
+    }
+
+    /// Returns if any shortcut registered for a named action was entered last frame.
+    #[inline]
+    pub(crate) fn shortcut_triggered(&self, action: &str) -> bool {
+        self.shortcuts
+            .iter()
+            .any(|&(key, keymod, a)| a == action && self.shortcut_entered(key, keymod))
+    }
+
+    /// Store a pressed [Key], which is `repeat`ed by the operating system if held.
+    #[inline]
+    pub(crate) fn press(&mut self, key: Key, keymod: KeyMod, repeat: bool) {
         self.entered = Some(key);
         self.pressed.insert(key);
+        if repeat {
+            self.repeated.insert(key);
+        } else {
+            self.just_pressed.insert(key);
+            self.last_pressed = Some(key);
+        }
         self.keymod = keymod;
     }
 
@@ -61,12 +147,115 @@ impl KeyState {
     #[inline]
     pub(crate) fn release(&mut self, key: Key, keymod: KeyMod) {
         self.pressed.remove(&key);
+        self.released.insert(key);
         self.keymod = keymod;
     }
 
-    /// Store a pressed [Key].
+    /// Clear keys pressed, repeated, and released this frame.
+    #[inline]
+    pub(crate) fn end_frame(&mut self) {
+        self.just_pressed.clear();
+        self.released.clear();
+        self.repeated.clear();
+    }
+
+    /// Store typed text, committing any text being composed.
     #[inline]
     pub(crate) fn typed(&mut self, text: String) {
+        self.composition = None;
         self.typed = Some(text);
     }
+
+    /// Store text being composed by an input method editor (IME), with the cursor at the `start`
+    /// character. Empty `text` cancels composition.
+    #[inline]
+    pub(crate) fn edit(&mut self, text: String, start: i32) {
+        if text.is_empty() {
+            self.composition = None;
+        } else {
+            let start = usize::try_from(start).unwrap_or_default();
+            let cursor = text
+                .char_indices()
+                .nth(start)
+                .map_or(text.len(), |(i, _)| i);
+            self.composition = Some(Composition { text, cursor });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::selection::TextSelection;
+
+    #[test]
+    fn pressed_held_released() {
+        let mut keys = KeyState::default();
+
+        // Frame 1: A goes down, and B is tapped within the same frame
+        keys.press(Key::A, KeyMod::NONE, false);
+        keys.press(Key::B, KeyMod::NONE, false);
+        keys.release(Key::B, KeyMod::NONE);
+        assert!(keys.was_just_pressed(Key::A));
+        assert!(keys.is_down(Key::A));
+        assert!(!keys.was_released(Key::A));
+        assert!(keys.was_just_pressed(Key::B));
+        assert!(!keys.is_down(Key::B));
+        assert!(keys.was_released(Key::B));
+        assert_eq!(keys.last_pressed(), Some(Key::B));
+        keys.end_frame();
+
+        // Frame 2: A is held and repeated, then released
+        keys.press(Key::A, KeyMod::NONE, true);
+        assert!(!keys.was_just_pressed(Key::A));
+        assert!(keys.was_typed(Key::A));
+        assert!(keys.is_down(Key::A));
+        assert!(!keys.was_just_pressed(Key::B));
+        assert!(!keys.was_released(Key::B));
+        keys.release(Key::A, KeyMod::NONE);
+        assert!(!keys.is_down(Key::A));
+        assert!(keys.was_released(Key::A));
+        assert_eq!(keys.last_pressed(), Some(Key::B));
+        keys.end_frame();
+
+        assert!(!keys.was_typed(Key::A));
+        assert!(!keys.was_released(Key::A));
+        assert_eq!(keys.last_pressed(), Some(Key::B));
+    }
+
+    #[test]
+    fn text_composition() {
+        let mut keys = KeyState::default();
+        let mut value = String::from("ab");
+        let mut selection = TextSelection::at_end(&value);
+        selection.move_to(1, false);
+
+        // Composition sequence updates the pre-edit text without changing the value
+        keys.edit("n".into(), 1);
+        keys.edit("に".into(), 1);
+        keys.edit("にほん".into(), 2);
+        assert!(keys.composition.is_some());
+        let composition = keys.composition.clone().unwrap_or_default();
+        assert_eq!(composition.cursor, "にほ".len());
+        let (preedit, range) = composition.insert_into(&value, selection.cursor);
+        assert_eq!(preedit, "aにほんb");
+        assert_eq!(&preedit[range], "にほん");
+        assert!(keys.typed.is_none());
+        assert_eq!(value, "ab");
+
+        // Committed text replaces the composition
+        keys.typed("日本".into());
+        assert!(keys.composition.is_none());
+        assert_eq!(keys.typed.as_deref(), Some("日本"));
+        let typed = keys.typed.take().unwrap_or_default();
+        selection.insert(&mut value, &typed);
+        assert_eq!(value, "a日本b");
+        assert_eq!(selection.cursor, "a日本".len());
+
+        // Empty text cancels composition
+        keys.edit("ご".into(), 1);
+        assert!(keys.composition.is_some());
+        keys.edit(String::new(), 0);
+        assert!(keys.composition.is_none());
+    }
 }
@@ -5,6 +5,11 @@
 //! - [`PixState::same_line`]
 //! - [`PixState::next_width`]
 //! - [`PixState::tab_bar`]
+//! - [`PixState::tab`]
+//! - [`PixState::group_box`]
+//! - [`PixState::end_group_box`]
+//! - [`PixState::split_horizontal`]
+//! - [`PixState::split_vertical`]
 //! - [`PixState::spacing`]
 //! - [`PixState::indent`]
 //! - [`PixState::separator`]
@@ -13,7 +18,7 @@
 //!
 //! ```
 //! # use pix_engine::prelude::*;
-//! # struct App { checkbox: bool, text_field: String, selected: &'static str };
+//! # struct App { checkbox: bool, text_field: String };
 //! # impl PixEngine for App {
 //! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
 //!     s.text("Text")?;
@@ -33,29 +38,41 @@
 //!         // was clicked
 //!     }
 //!
-//!     s.tab_bar(
-//!         "Tab bar",
-//!         &["Tab 1", "Tab 2"],
-//!         &mut self.selected,
-//!         |tab: &&str, s: &mut PixState| {
-//!             match tab {
-//!                 &"Tab 1" => {
-//!                     s.text("Tab 1 Content")?;
-//!                 },
-//!                 &"Tab 2" => {
-//!                     s.text("Tab 2 Content")?;
-//!                 },
-//!                 _ => (),
-//!             }
+//!     s.tab_bar("Tab bar", |s: &mut PixState| {
+//!         s.tab("Tab 1", |s: &mut PixState| {
+//!             s.text("Tab 1 Content")?;
 //!             Ok(())
-//!         }
-//!     )?;
+//!         })?;
+//!         s.tab("Tab 2", |s: &mut PixState| {
+//!             s.text("Tab 2 Content")?;
+//!             Ok(())
+//!         })
+//!     })?;
 //!     Ok(())
 //! }
 //! # }
 //! ```
 
-use crate::{ops::clamp_size, prelude::*};
+use crate::{
+    gui::state::{GroupBox, TabBar},
+    ops::clamp_size,
+    prelude::*,
+};
+use anyhow::anyhow;
+
+/// Thickness of the splitter bar between split panes.
+const SPLITTER_SIZE: i32 = 6;
+/// Minimum size of a split pane along the split direction.
+const MIN_PANE_SIZE: i32 = 32;
+
+/// Direction panes are split in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Split {
+    /// Panes are side by side.
+    Horizontal,
+    /// Panes are stacked vertically.
+    Vertical,
+}
 
 impl PixState {
     /// Reset current UI rendering position back to the previous line with item padding, and
@@ -114,11 +131,14 @@ impl PixState {
         self.ui.next_width = Some(clamp_size(width));
     }
 
-    /// Draw a tabbed view to the current canvas. It accepts a list of tabs to be rendered, which
-    /// one is selected and a closure that is passed the current tab and [`&mut
-    /// PixState`][`PixState`] which you can use to draw all the standard drawing primitives and
-    /// change any drawing settings. Settings changed inside the closure will not persist. Returns
-    /// `true` if a tab selection was changed.
+    /// Draw a tabbed view to the current canvas, where each tab is added by calling
+    /// [`PixState::tab`] within the closure. Only the content of the selected tab is drawn, below
+    /// a row of tab headers. The selected tab is stored across frames for this label and changed by
+    /// clicking its header.
+    ///
+    /// While a tab header is focused, the [`Key::Left`] and [`Key::Right`] keys select the
+    /// previous or next tab. Tab headers that overflow the available width wrap onto the next
+    /// line.
     ///
     /// # Errors
     ///
@@ -128,145 +148,605 @@ impl PixState {
     ///
     /// ```
     /// # use pix_engine::prelude::*;
-    /// # struct App { selected: &'static str };
+    /// # struct App;
     /// # impl PixEngine for App {
     /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
-    ///     s.tab_bar(
-    ///         "Tab bar",
-    ///         &["Tab 1", "Tab 2"],
-    ///         &mut self.selected,
-    ///         |tab: &&str, s: &mut PixState| {
-    ///             match tab {
-    ///                 &"Tab 1" => {
-    ///                     s.text("Tab 1")?;
-    ///                     s.separator();
-    ///                     s.text("Some Content")?;
-    ///                 }
-    ///                 &"Tab 2" => {
-    ///                     s.next_width(200);
-    ///                     if s.button("Click me")? {
-    ///                         // was clicked
-    ///                     }
-    ///                 }
-    ///                 _ => (),
+    ///     s.tab_bar("Tab bar", |s: &mut PixState| {
+    ///         s.tab("Tab 1", |s: &mut PixState| {
+    ///             s.text("Tab 1")?;
+    ///             s.separator();
+    ///             s.text("Some Content")?;
+    ///             Ok(())
+    ///         })?;
+    ///         s.tab("Tab 2", |s: &mut PixState| {
+    ///             s.next_width(200);
+    ///             if s.button("Click me")? {
+    ///                 // was clicked
     ///             }
     ///             Ok(())
-    ///         }
-    ///     )?;
+    ///         })?;
+    ///         Ok(())
+    ///     })?;
     ///     Ok(())
     /// }
     /// # }
     /// ```
-    pub fn tab_bar<S, I, F>(
-        &mut self,
-        label: S,
-        tabs: &[I],
-        selected: &mut I,
-        f: F,
-    ) -> PixResult<bool>
+    pub fn tab_bar<S, F>(&mut self, label: S, f: F) -> PixResult<()>
     where
         S: AsRef<str>,
-        I: AsRef<str> + Copy,
-        F: FnOnce(&I, &mut PixState) -> PixResult<()>,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
     {
         let label = label.as_ref();
 
         let s = self;
-        let tab_id = s.ui.get_id(&label);
-        let font_size = s.theme.font_size;
+        let id = s.ui.get_id(&label);
+        let pos = s.cursor_pos();
+        let font_size = clamp_size(s.theme.font_size);
         let fpad = s.theme.spacing.frame_pad;
         let ipad = s.theme.spacing.item_pad;
+        let (win_width, _) = s.window_dimensions()?;
 
-        let mut changed = false;
-        for (i, tab) in tabs.iter().enumerate() {
-            if i > 0 {
-                s.same_line([-ipad.x() + 2, 0]);
-            } else {
-                let pos = s.cursor_pos();
-                s.set_cursor_pos([pos.x() + fpad.x(), pos.y()]);
-            }
-            let tab_label = tab.as_ref();
-            let id = s.ui.get_id(&tab_label);
-            let tab_label = s.ui.get_label(tab_label);
-            let pos = s.cursor_pos();
-            let colors = s.theme.colors;
-
-            // Calculate tab size
-            let (width, height) = s.text_size(tab_label)?;
-            let tab_rect = rect![pos, width, height].offset_size(4 * ipad);
-
-            // Check hover/active/keyboard focus
-            let hovered = s.focused() && s.ui.try_hover(id, &tab_rect);
-            let focused = s.focused() && s.ui.try_focus(id);
-            let disabled = s.ui.disabled;
-            let active = s.ui.is_active(id);
-
-            s.push();
-            s.ui.push_cursor();
-
-            // Render
-            s.rect_mode(RectMode::Corner);
-            let clip = tab_rect.offset_size([1, 0]);
-            s.clip(clip)?;
-            if hovered {
-                s.frame_cursor(&Cursor::hand())?;
+        // Reserve room for the tab headers above the selected tab content, using the height
+        // measured last frame in case the headers wrapped
+        let (_, height) = s.padded_text_size("")?;
+        let header_height = (height + 4 * ipad.y()).max(s.ui.content_height(id));
+        s.set_cursor_pos([pos.x(), pos.y() + header_height - font_size / 2]);
+        s.separator()?;
+        s.spacing()?;
+        let content_pos = s.cursor_pos();
+
+        let header_pos = point![pos.x() + fpad.x(), pos.y()];
+        s.ui.tab_bars.push(TabBar {
+            id,
+            header_start: header_pos,
+            header_pos,
+            header_bottom: pos.y(),
+            max_x: clamp_size(win_width) - fpad.x(),
+            content_pos,
+            tabs: vec![],
+            focused: None,
+            clicked: None,
+            end_pos: None,
+        });
+        let result = f(s);
+        let tab_bar = s.ui.tab_bars.pop();
+        result?;
+
+        if let Some(tab_bar) = tab_bar {
+            let len = tab_bar.tabs.len();
+            // Select the first tab if the selected tab was removed
+            let current = Some(s.ui.current_tab(id))
+                .filter(|&current| current < len)
+                .unwrap_or_default();
+            // Keyboard switching only applies while a tab header is focused
+            let key = tab_bar.focused.and(s.ui.key_entered());
+            let selected = select_tab(current, len, tab_bar.clicked, key);
+            if selected != current && tab_bar.clicked.is_none() {
+                s.ui.clear_entered();
+                s.ui.focus(tab_bar.tabs[selected]);
             }
-            let [stroke, fg, bg] = s.widget_colors(id, ColorType::SecondaryVariant);
-            if active || focused {
-                s.stroke(stroke);
-            } else {
-                s.stroke(None);
+            s.ui.set_current_tab(id, selected);
+            s.ui.set_content_height(id, tab_bar.header_bottom - pos.y());
+            s.set_cursor_pos(tab_bar.end_pos.unwrap_or(content_pos));
+        }
+        Ok(())
+    }
+
+    /// Draw a tab header within a [`PixState::tab_bar`], calling the closure to draw the tab
+    /// content if the tab is selected. Labels of elements drawn within the closure are scoped to
+    /// this tab, so the same label may be used under different tabs.
+    ///
+    /// # Errors
+    ///
+    /// If called outside of [`PixState::tab_bar`], or the renderer fails to draw to the current
+    /// render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.tab_bar("Tab bar", |s: &mut PixState| {
+    ///         s.tab("Tab 1", |s: &mut PixState| {
+    ///             s.text("Tab 1 Content")?;
+    ///             Ok(())
+    ///         })
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn tab<S, F>(&mut self, label: S, f: F) -> PixResult<()>
+    where
+        S: AsRef<str>,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let label = label.as_ref();
+
+        let s = self;
+        let tab_bar =
+            s.ui.tab_bars
+                .last()
+                .cloned()
+                .ok_or_else(|| anyhow!("`tab` must be called within `tab_bar`"))?;
+        let ipad = s.theme.spacing.item_pad;
+        let index = tab_bar.tabs.len();
+        let selected = s.ui.current_tab(tab_bar.id) == index;
+
+        // Tab header, wrapping onto the next line if it would overflow
+        let (width, _) = s.padded_text_size(s.ui.get_label(label))?;
+        let mut header_pos = tab_bar.header_pos;
+        if index > 0 && header_pos.x() + width + 4 * ipad.x() > tab_bar.max_x {
+            header_pos = point![tab_bar.header_start.x(), tab_bar.header_bottom + 2];
+        }
+        s.set_cursor_pos(header_pos);
+        s.push_id(tab_bar.id);
+        let id = s.ui.get_id(&label);
+        let (tab_rect, clicked) = s.tab_header(label, selected)?;
+        let focused = s.ui.is_focused(id);
+        s.pop_id();
+
+        // Tab content
+        let mut end_pos = tab_bar.end_pos;
+        if selected {
+            s.set_cursor_pos(tab_bar.content_pos);
+            s.push_id(tab_bar.id);
+            s.push_id(label);
+            let result = f(s);
+            s.pop_id();
+            s.pop_id();
+            result?;
+            end_pos = Some(s.cursor_pos());
+        }
+
+        if let Some(tab_bar) = s.ui.tab_bars.last_mut() {
+            tab_bar.tabs.push(id);
+            if clicked {
+                tab_bar.clicked = Some(index);
             }
-            if hovered {
-                s.fill(fg.blended(colors.background, 0.04));
-            } else {
-                s.fill(colors.background);
+            if focused {
+                tab_bar.focused = Some(index);
             }
-            if active {
-                s.clip(tab_rect.offset_size([2, 0]))?;
-                s.rect(tab_rect.offset([1, 1]))?;
-            } else {
-                s.rect(tab_rect)?;
+            tab_bar.header_pos = point![tab_rect.right() + 2, tab_rect.top()];
+            tab_bar.header_bottom = tab_bar.header_bottom.max(tab_rect.bottom());
+            tab_bar.end_pos = end_pos;
+        }
+        Ok(())
+    }
+}
+
+impl PixState {
+    /// Begin a group box on the current canvas, framing all content drawn until
+    /// [`PixState::end_group_box`] with a rounded outline and `label` embedded in its top border.
+    /// The group box is sized to fit its content.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { name: String, subscribe: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.group_box("Account")?;
+    ///     s.text_field("Name", &mut self.name)?;
+    ///     s.checkbox("Subscribe", &mut self.subscribe)?;
+    ///     s.end_group_box()?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn group_box<S>(&mut self, label: S) -> PixResult<()>
+    where
+        S: AsRef<str>,
+    {
+        let label = label.as_ref();
+
+        let s = self;
+        let label = s.ui.get_label(label).to_owned();
+        let pos = s.cursor_pos();
+        let fpad = s.theme.spacing.frame_pad;
+        let ipad = s.theme.spacing.item_pad;
+
+        // Indent content within the frame, below the label
+        let (_, label_height) = s.padded_text_size(&label)?;
+        s.ui.group_boxes.push(GroupBox {
+            label,
+            pos,
+            right: pos.x(),
+        });
+        s.ui.set_column_offset(fpad.x());
+        s.set_cursor_pos([pos.x() + fpad.x(), pos.y() + label_height + ipad.y()]);
+        Ok(())
+    }
+
+    /// End a group box started with [`PixState::group_box`], drawing its frame and label around
+    /// the content drawn since.
+    ///
+    /// # Errors
+    ///
+    /// If called without a matching [`PixState::group_box`], or the renderer fails to draw to the
+    /// current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { volume: f32 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.group_box("Audio")?;
+    ///     s.slider("Volume", &mut self.volume, 0.0, 1.0)?;
+    ///     s.end_group_box()?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn end_group_box(&mut self) -> PixResult<()> {
+        let s = self;
+        let group =
+            s.ui.group_boxes
+                .pop()
+                .ok_or_else(|| anyhow!("`end_group_box` must be called after `group_box`"))?;
+        s.ui.reset_column_offset();
+        let pos = group.pos;
+        let end = s.cursor_pos();
+        let colors = s.theme.colors;
+        let fpad = s.theme.spacing.frame_pad;
+        let ipad = s.theme.spacing.item_pad;
+
+        // Calculate frame rect, with the top border passing through the middle of the label
+        let (label_width, label_height) = s.padded_text_size(&group.label)?;
+        let label_width = label_width + 2 * ipad.x();
+        let width = (group.right + fpad.x() - pos.x()).max(label_width + 2 * fpad.x());
+        let height = end.y() - ipad.y() + fpad.y() - pos.y();
+        let top = pos.y() + label_height / 2;
+        let frame = rect![pos.x(), top, width, height - label_height / 2];
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Frame, with the top border clipped around the label so whatever is behind the group box
+        // shows through
+        s.rect_mode(RectMode::Corner);
+        s.stroke(colors.disabled());
+        s.fill(None);
+        let radius = ipad.y().max(2);
+        if group.label.is_empty() {
+            s.rounded_rect(frame, radius)?;
+        } else {
+            let label_rect = rect![pos.x() + fpad.x(), pos.y(), label_width, label_height];
+            // Include the right and bottom edges of the stroke
+            let (right, bottom) = (frame.right() + 1, frame.bottom() + 1);
+            let (gap_left, gap_right) = (label_rect.left(), label_rect.right());
+            for clip in [
+                rect![
+                    frame.x(),
+                    frame.y(),
+                    gap_left - frame.x(),
+                    bottom - frame.y()
+                ],
+                rect![gap_right, frame.y(), right - gap_right, bottom - frame.y()],
+                rect![
+                    gap_left,
+                    label_rect.bottom(),
+                    label_width,
+                    bottom - label_rect.bottom()
+                ],
+            ] {
+                s.push_clip(clip)?;
+                s.rounded_rect(frame, radius)?;
+                s.pop_clip()?;
             }
 
-            // Tab text
-            s.rect_mode(RectMode::Center);
-            s.set_cursor_pos(tab_rect.center());
+            // Label, drawn within the gap in the top border
+            s.set_cursor_pos([label_rect.x() + ipad.x(), label_rect.y()]);
             s.stroke(None);
-            let is_active_tab = tab_label == selected.as_ref();
-            if is_active_tab {
-                s.fill(colors.secondary_variant);
-            } else if hovered | focused {
-                s.fill(fg);
-            } else {
-                s.fill(colors.secondary_variant.blended(bg, 0.60));
-            }
-            s.text(tab_label)?;
-            s.clip(None)?;
+            s.fill(colors.on_background);
+            s.text(&group.label)?;
+        }
 
-            s.ui.pop_cursor();
-            s.pop();
+        s.ui.pop_cursor();
+        s.pop();
 
-            // Process input
-            s.ui.handle_focus(id);
-            s.advance_cursor(tab_rect.size());
-            if !disabled && s.ui.was_clicked(id) {
-                changed = true;
-                *selected = *tab;
-            }
+        s.set_cursor_pos(pos);
+        s.advance_cursor([width, height]);
+        Ok(())
+    }
+}
+
+impl PixState {
+    /// Draw a tab header to the current canvas, returning the header rect and whether it was
+    /// clicked.
+    fn tab_header(&mut self, label: &str, selected: bool) -> PixResult<(Rect<i32>, bool)> {
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let pos = s.cursor_pos();
+        let colors = s.theme.colors;
+        let ipad = s.theme.spacing.item_pad;
+
+        // Calculate tab size
+        let (width, height) = s.padded_text_size(label)?;
+        let tab_rect = rect![pos, width, height].offset_size(4 * ipad);
+
+        // Check hover/active/keyboard focus
+        let hovered = s.focused() && s.ui.try_hover(id, &tab_rect);
+        let focused = s.focused() && s.ui.try_focus(id);
+        let disabled = s.ui.disabled;
+        let active = s.ui.is_active(id);
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Render
+        s.rect_mode(RectMode::Corner);
+        let clip = tab_rect.offset_size([1, 0]);
+        s.clip(clip)?;
+        if hovered {
+            s.frame_cursor(&Cursor::hand())?;
+        }
+        let [stroke, fg, bg] = s.widget_colors(id, ColorType::SecondaryVariant);
+        if active || focused {
+            s.stroke(stroke);
+        } else {
+            s.stroke(None);
+        }
+        if hovered {
+            s.fill(fg.blended(colors.background, 0.04));
+        } else {
+            s.fill(colors.background);
+        }
+        if active {
+            s.clip(tab_rect.offset_size([2, 0]))?;
+            s.rect(tab_rect.offset([1, 1]))?;
+        } else {
+            s.rect(tab_rect)?;
         }
 
+        // Tab text
+        s.rect_mode(RectMode::Center);
+        s.set_cursor_pos(tab_rect.center());
+        s.stroke(None);
+        if selected {
+            s.fill(colors.secondary_variant);
+        } else if hovered | focused {
+            s.fill(fg);
+        } else {
+            s.fill(colors.secondary_variant.blended(bg, 0.60));
+        }
+        s.text(label)?;
+        s.clip(None)?;
+
+        s.ui.pop_cursor();
+        s.pop();
+
+        // Process input
+        s.ui.handle_focus(id);
+        s.advance_cursor(tab_rect.size());
+        Ok((tab_rect, !disabled && s.ui.was_clicked(id)))
+    }
+}
+
+impl PixState {
+    /// Draw two panes side by side to the current canvas, filling the remaining canvas area, with
+    /// a draggable splitter bar between them. `ratio` is the fraction of the width given to the
+    /// left pane, and is updated while the splitter is dragged. Returns `true` if `ratio` changed.
+    ///
+    /// Each pane clips its content, which is laid out from the top-left of the pane. Splits can
+    /// be nested, and labels of elements drawn within each pane are scoped to that pane.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { ratio: f32 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.split_horizontal(
+    ///         "Editor",
+    ///         &mut self.ratio,
+    ///         |s: &mut PixState| {
+    ///             s.text("Left")?;
+    ///             Ok(())
+    ///         },
+    ///         |s: &mut PixState| {
+    ///             s.text("Right")?;
+    ///             Ok(())
+    ///         },
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn split_horizontal<S, L, R>(
+        &mut self,
+        label: S,
+        ratio: &mut f32,
+        left: L,
+        right: R,
+    ) -> PixResult<bool>
+    where
+        S: AsRef<str>,
+        L: FnOnce(&mut PixState) -> PixResult<()>,
+        R: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        self.split(label.as_ref(), ratio, Split::Horizontal, left, right)
+    }
+
+    /// Draw two panes stacked vertically to the current canvas, filling the remaining canvas
+    /// area, with a draggable splitter bar between them. `ratio` is the fraction of the height
+    /// given to the top pane, and is updated while the splitter is dragged. Returns `true` if
+    /// `ratio` changed.
+    ///
+    /// Each pane clips its content, which is laid out from the top-left of the pane. Splits can
+    /// be nested, and labels of elements drawn within each pane are scoped to that pane.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { ratio: f32 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.split_vertical(
+    ///         "Editor",
+    ///         &mut self.ratio,
+    ///         |s: &mut PixState| {
+    ///             s.text("Top")?;
+    ///             Ok(())
+    ///         },
+    ///         |s: &mut PixState| {
+    ///             s.text("Bottom")?;
+    ///             Ok(())
+    ///         },
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn split_vertical<S, T, B>(
+        &mut self,
+        label: S,
+        ratio: &mut f32,
+        top: T,
+        bottom: B,
+    ) -> PixResult<bool>
+    where
+        S: AsRef<str>,
+        T: FnOnce(&mut PixState) -> PixResult<()>,
+        B: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        self.split(label.as_ref(), ratio, Split::Vertical, top, bottom)
+    }
+}
+
+impl PixState {
+    /// Draw two panes split by a draggable splitter bar to the current canvas.
+    fn split<F, S>(
+        &mut self,
+        label: &str,
+        ratio: &mut f32,
+        split: Split,
+        first: F,
+        second: S,
+    ) -> PixResult<bool>
+    where
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+        S: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let s = self;
+        let id = s.ui.get_id(&label);
         let pos = s.cursor_pos();
-        s.set_cursor_pos([pos.x(), pos.y() - ipad.y() - font_size as i32 / 2]);
-        s.separator()?;
-        s.spacing()?;
+        let colors = s.theme.colors;
+
+        // Calculate split rect, filling the remaining area
+        let width =
+            s.ui.next_width
+                .take()
+                .unwrap_or_else(|| s.ui_width().unwrap_or(0));
+        let height = s.ui_height()?;
+        let rect = rect![pos, width.max(0), height.max(0)];
+        let (total, start) = match split {
+            Split::Horizontal => (rect.width(), rect.x()),
+            Split::Vertical => (rect.height(), rect.y()),
+        };
 
-        s.push_id(tab_id);
-        f(selected, s)?;
+        // Check hover/active
+        let (size, _) = split_sizes(total, *ratio, MIN_PANE_SIZE);
+        let hovered = s.focused() && s.ui.try_hover(id, &splitter_rect(rect, size, split));
+        let active = s.ui.is_active(id);
+
+        // Process drag
+        let mut changed = false;
+        if active {
+            let mouse = s.mouse_pos();
+            let offset = match split {
+                Split::Horizontal => mouse.x(),
+                Split::Vertical => mouse.y(),
+            } - start;
+            let new_ratio = split_ratio(offset, total, MIN_PANE_SIZE);
+            if new_ratio != *ratio {
+                *ratio = new_ratio;
+                changed = true;
+            }
+        }
+        let (size, _) = split_sizes(total, *ratio, MIN_PANE_SIZE);
+        let splitter = splitter_rect(rect, size, split);
+        let (first_rect, second_rect) = pane_rects(rect, size, split);
+
+        // Panes
+        s.ui.push_cursor();
+        s.push_id(id);
+        let result = s
+            .pane(0, first_rect, first)
+            .and_then(|_| s.pane(1, second_rect, second));
         s.pop_id();
+        s.ui.pop_cursor();
+        result?;
+
+        // Splitter
+        s.push_screen_space();
+        if hovered || active {
+            let cursor = match split {
+                Split::Horizontal => SystemCursor::SizeWE,
+                Split::Vertical => SystemCursor::SizeNS,
+            };
+            s.frame_cursor(&Cursor::System(cursor))?;
+        }
+        s.rect_mode(RectMode::Corner);
+        s.stroke(None);
+        if active {
+            s.fill(colors.primary);
+        } else if hovered {
+            s.fill(colors.primary_variant);
+        } else {
+            s.fill(colors.disabled());
+        }
+        s.rect(splitter)?;
+        s.pop();
+
+        s.advance_cursor(rect.size());
 
         Ok(changed)
     }
+
+    /// Draw the contents of a pane within `rect`, clipped to and laid out from the pane bounds.
+    fn pane<F>(&mut self, index: usize, rect: Rect<i32>, f: F) -> PixResult<()>
+    where
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let s = self;
+        let fpad = s.theme.spacing.frame_pad;
+
+        s.push();
+        s.push_clip(rect)?;
+        s.ui.panes.push(rect);
+        s.ui.set_column_offset(rect.x() - s.ui.column_offset());
+        s.set_cursor_pos(rect.top_left() + fpad);
+        s.push_id(index);
+        let result = f(s);
+        s.pop_id();
+        s.ui.reset_column_offset();
+        s.ui.panes.pop();
+        s.pop_clip()?;
+        s.pop();
+
+        result
+    }
 }
 
 impl PixState {
@@ -293,7 +773,7 @@ impl PixState {
     pub fn spacing(&mut self) -> PixResult<()> {
         let s = self;
         let width = s.ui_width()?;
-        let (_, height) = s.text_size(" ")?;
+        let (_, height) = s.padded_text_size(" ")?;
         s.advance_cursor([width, height]);
         Ok(())
     }
@@ -319,7 +799,7 @@ impl PixState {
     /// ```
     pub fn indent(&mut self) -> PixResult<()> {
         let s = self;
-        let (width, height) = s.text_size("    ")?;
+        let (width, height) = s.padded_text_size("    ")?;
         s.advance_cursor([width, height]);
         s.same_line(None);
         Ok(())
@@ -354,7 +834,7 @@ impl PixState {
         let height = clamp_size(s.theme.font_size);
         let y = pos.y() + height / 2;
 
-        s.push();
+        s.push_screen_space();
 
         s.stroke(colors.disabled());
         let width = s.ui_width()?;
@@ -366,3 +846,182 @@ impl PixState {
         Ok(())
     }
 }
+
+/// Returns the sizes of the two panes splitting `total` pixels, minus the splitter, by `ratio`.
+/// Each pane is at least `min` pixels, if there's room.
+fn split_sizes(total: i32, ratio: f32, min: i32) -> (i32, i32) {
+    let available = (total - SPLITTER_SIZE).max(0);
+    let min = min.min(available / 2);
+    let ratio = if ratio.is_finite() {
+        ratio.clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    let first = ((available as f32 * ratio).round() as i32).clamp(min, available - min);
+    (first, available - first)
+}
+
+/// Returns the ratio of the first pane when the splitter is dragged to `offset` pixels from the
+/// start of `total` pixels, keeping each pane at least `min` pixels.
+fn split_ratio(offset: i32, total: i32, min: i32) -> f32 {
+    let available = total - SPLITTER_SIZE;
+    if available <= 0 {
+        return 0.5;
+    }
+    let min = min.min(available / 2);
+    let first = (offset - SPLITTER_SIZE / 2).clamp(min, available - min);
+    first as f32 / available as f32
+}
+
+/// Returns the splitter bar rect after a first pane of `size` pixels within `rect`.
+fn splitter_rect(rect: Rect<i32>, size: i32, split: Split) -> Rect<i32> {
+    match split {
+        Split::Horizontal => rect![rect.x() + size, rect.y(), SPLITTER_SIZE, rect.height()],
+        Split::Vertical => rect![rect.x(), rect.y() + size, rect.width(), SPLITTER_SIZE],
+    }
+}
+
+/// Returns the pane rects on either side of the splitter bar after a first pane of `size` pixels
+/// within `rect`.
+fn pane_rects(rect: Rect<i32>, size: i32, split: Split) -> (Rect<i32>, Rect<i32>) {
+    let offset = size + SPLITTER_SIZE;
+    match split {
+        Split::Horizontal => (
+            rect![rect.x(), rect.y(), size, rect.height()],
+            rect![
+                rect.x() + offset,
+                rect.y(),
+                rect.width() - offset,
+                rect.height()
+            ],
+        ),
+        Split::Vertical => (
+            rect![rect.x(), rect.y(), rect.width(), size],
+            rect![
+                rect.x(),
+                rect.y() + offset,
+                rect.width(),
+                rect.height() - offset
+            ],
+        ),
+    }
+}
+
+/// Returns the newly selected tab index out of `len` tabs, preferring a clicked tab over a
+/// [`Key::Left`] or [`Key::Right`] press. Key presses stop at the first and last tabs.
+fn select_tab(selected: usize, len: usize, clicked: Option<usize>, key: Option<Key>) -> usize {
+    match (clicked, key) {
+        (Some(index), _) if index < len => index,
+        (_, Some(Key::Left)) => selected.saturating_sub(1),
+        (_, Some(Key::Right)) if selected + 1 < len => selected + 1,
+        _ => selected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::state::UiState;
+
+    #[test]
+    fn split_sizes_clamped() {
+        assert_eq!(split_sizes(206, 0.5, 32), (100, 100));
+        assert_eq!(split_sizes(206, 0.25, 32), (50, 150));
+        assert_eq!(split_sizes(206, 0.0, 32), (32, 168));
+        assert_eq!(split_sizes(206, 1.0, 32), (168, 32));
+        assert_eq!(split_sizes(206, f32::NAN, 32), (100, 100));
+        // Not enough room for both minimums
+        assert_eq!(split_sizes(46, 0.0, 32), (20, 20));
+        assert_eq!(split_sizes(2, 0.5, 32), (0, 0));
+    }
+
+    #[test]
+    fn split_ratio_clamped() {
+        assert_eq!(split_ratio(103, 206, 32), 0.5);
+        assert_eq!(split_ratio(53, 206, 32), 0.25);
+        assert_eq!(split_ratio(-50, 206, 32), 0.16);
+        assert_eq!(split_ratio(500, 206, 32), 0.84);
+        assert_eq!(split_ratio(10, 4, 32), 0.5);
+    }
+
+    #[test]
+    fn splitter_hit_test() {
+        let rect = rect![10, 20, 206, 100];
+        let (size, _) = split_sizes(rect.width(), 0.5, 32);
+        let splitter = splitter_rect(rect, size, Split::Horizontal);
+        assert_eq!(splitter, rect![110, 20, 6, 100]);
+        assert!(splitter.contains(point![112, 50]));
+        assert!(!splitter.contains(point![109, 50]));
+        assert!(!splitter.contains(point![116, 50]));
+
+        let (left, right) = pane_rects(rect, size, Split::Horizontal);
+        assert_eq!(left, rect![10, 20, 100, 100]);
+        assert_eq!(right, rect![116, 20, 100, 100]);
+
+        let (size, _) = split_sizes(rect.height(), 0.5, 32);
+        let splitter = splitter_rect(rect, size, Split::Vertical);
+        assert_eq!(splitter, rect![10, 67, 206, 6]);
+        let (top, bottom) = pane_rects(rect, size, Split::Vertical);
+        assert_eq!(top, rect![10, 20, 206, 47]);
+        assert_eq!(bottom, rect![10, 73, 206, 47]);
+    }
+
+    #[test]
+    fn tab_ids_differ() {
+        let button_id = |tab: &str| {
+            let mut ui = UiState::default();
+            let tab_bar = ui.get_id(&"Tab bar");
+            ui.push_id(&tab_bar);
+            ui.push_id(&tab);
+            ui.get_id(&"Button")
+        };
+        assert_ne!(button_id("Tab 1"), button_id("Tab 2"));
+        assert_eq!(button_id("Tab 1"), button_id("Tab 1"));
+    }
+
+    #[test]
+    fn select_tab_with_keys() {
+        assert_eq!(select_tab(1, 3, None, Some(Key::Left)), 0);
+        assert_eq!(select_tab(0, 3, None, Some(Key::Left)), 0);
+        assert_eq!(select_tab(1, 3, None, Some(Key::Right)), 2);
+        assert_eq!(select_tab(2, 3, None, Some(Key::Right)), 2);
+        assert_eq!(select_tab(1, 3, None, Some(Key::Up)), 1);
+        assert_eq!(select_tab(1, 3, None, None), 1);
+    }
+
+    #[test]
+    fn select_tab_with_clicks() {
+        let mut ui = UiState::default();
+        let theme = Theme::default();
+        let headers = [rect![0, 0, 50, 20], rect![52, 0, 50, 20]];
+        let ids = [ui.get_id(&"Tab 1"), ui.get_id(&"Tab 2")];
+        let frame = |ui: &mut UiState, selected: usize| {
+            ui.pre_update(&theme);
+            let mut clicked = None;
+            for (i, (id, header)) in ids.iter().zip(&headers).enumerate() {
+                ui.try_hover(*id, header);
+                if ui.was_clicked(*id) {
+                    clicked = Some(i);
+                }
+            }
+            let selected = select_tab(selected, ids.len(), clicked, ui.key_entered());
+            ui.post_update();
+            selected
+        };
+
+        // Press and release over the second tab header
+        ui.mouse.pos = point![60, 10];
+        ui.mouse.press(Mouse::Left);
+        assert_eq!(frame(&mut ui, 0), 0);
+        ui.mouse.release(Mouse::Left);
+        assert_eq!(frame(&mut ui, 0), 1);
+
+        // Releasing outside of the tab header it was pressed on doesn't select it
+        ui.mouse.pos = point![10, 10];
+        ui.mouse.press(Mouse::Left);
+        assert_eq!(frame(&mut ui, 1), 1);
+        ui.mouse.pos = point![10, 50];
+        ui.mouse.release(Mouse::Left);
+        assert_eq!(frame(&mut ui, 1), 1);
+    }
+}
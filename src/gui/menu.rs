@@ -0,0 +1,381 @@
+//! Menu bar and context menu state management.
+
+use super::state::ElementId;
+use crate::prelude::*;
+
+/// A currently open menu panel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct OpenMenu {
+    /// Menu element ID.
+    pub(crate) id: ElementId,
+    /// Top-left position of the drop-down panel, in window coordinates.
+    pub(crate) pos: Point<i32>,
+    /// Panel size measured the last time it was rendered.
+    pub(crate) size: Point<i32>,
+    /// Whether this menu belongs to the menu bar and can be switched to by hovering.
+    pub(crate) in_bar: bool,
+}
+
+impl OpenMenu {
+    /// The drop-down panel bounds, in window coordinates.
+    #[inline]
+    pub(crate) fn rect(&self) -> Rect<i32> {
+        rect![self.pos, self.size.x(), self.size.y()]
+    }
+}
+
+/// Menu bar currently being rendered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct MenuBar {
+    /// Menu bar bounds, in window coordinates.
+    pub(crate) rect: Rect<i32>,
+    /// Position of the next menu header.
+    pub(crate) x: i32,
+}
+
+/// Drop-down panel currently being rendered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct MenuPanel {
+    /// Width of the panel items.
+    pub(crate) width: i32,
+    /// Position of the next panel item.
+    pub(crate) y: i32,
+    /// Size required to fit all rendered items.
+    pub(crate) measured: Point<i32>,
+}
+
+impl MenuPanel {
+    /// Create a new panel layout with the given item width, starting at `y`.
+    #[inline]
+    pub(crate) fn new(width: i32, y: i32) -> Self {
+        Self {
+            width,
+            y,
+            measured: point![0, y],
+        }
+    }
+
+    /// Advance to the next item after an item of `width` and `height`.
+    #[inline]
+    pub(crate) fn advance(&mut self, width: i32, height: i32) {
+        self.y += height;
+        self.measured = point![self.measured.x().max(width), self.y];
+    }
+}
+
+/// Tracks which menu, if any, is open.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MenuState {
+    /// Currently open menu.
+    open: Option<OpenMenu>,
+    /// Menu bar currently being rendered.
+    pub(crate) bar: Option<MenuBar>,
+    /// Drop-down panel currently being rendered.
+    pub(crate) panel: Option<MenuPanel>,
+}
+
+impl MenuState {
+    /// Returns the currently open menu, if any.
+    #[inline]
+    pub(crate) const fn open_menu(&self) -> Option<OpenMenu> {
+        self.open
+    }
+
+    /// Whether a given menu is open.
+    #[inline]
+    #[must_use]
+    pub(crate) fn is_open(&self, id: ElementId) -> bool {
+        matches!(self.open, Some(menu) if menu.id == id)
+    }
+
+    /// Open a menu with its panel at `pos`, closing any other open menu.
+    #[inline]
+    pub(crate) fn open(&mut self, id: ElementId, pos: Point<i32>, in_bar: bool) {
+        let size = match self.open {
+            Some(menu) if menu.id == id => menu.size,
+            _ => point![],
+        };
+        self.open = Some(OpenMenu {
+            id,
+            pos,
+            size,
+            in_bar,
+        });
+    }
+
+    /// Close any open menu.
+    #[inline]
+    pub(crate) fn close(&mut self) {
+        self.open = None;
+    }
+
+    /// Open a menu if it's closed, otherwise close it.
+    #[inline]
+    pub(crate) fn toggle(&mut self, id: ElementId, pos: Point<i32>, in_bar: bool) {
+        if self.is_open(id) {
+            self.close();
+        } else {
+            self.open(id, pos, in_bar);
+        }
+    }
+
+    /// Switch to a hovered menu bar menu if another menu bar menu is already open. Returns `true`
+    /// if the open menu changed.
+    #[inline]
+    pub(crate) fn hover(&mut self, id: ElementId, pos: Point<i32>) -> bool {
+        match self.open {
+            Some(menu) if menu.in_bar && menu.id != id => {
+                self.open(id, pos, true);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Store the measured panel size of an open menu for the next frame.
+    #[inline]
+    pub(crate) fn resize(&mut self, id: ElementId, size: Point<i32>) {
+        if let Some(menu) = self.open.as_mut().filter(|menu| menu.id == id) {
+            menu.size = size;
+        }
+    }
+
+    /// Close a menu if the mouse is pressed outside of its panel and, for menu bar menus, outside
+    /// of the menu bar. Returns `true` if the menu was closed.
+    #[inline]
+    pub(crate) fn press_outside(
+        &mut self,
+        id: ElementId,
+        mouse: Point<i32>,
+        bar: Rect<i32>,
+    ) -> bool {
+        match self.open {
+            Some(menu)
+                if menu.id == id
+                    && !menu.rect().contains(mouse)
+                    && !(menu.in_bar && bar.contains(mouse)) =>
+            {
+                self.close();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Parse a keyboard shortcut such as `Ctrl+S` or `Ctrl+Shift+Z` into a [Key] and [`KeyMod`].
+/// Modifier and key names are case-insensitive.
+pub(crate) fn parse_shortcut(shortcut: &str) -> Option<(Key, KeyMod)> {
+    let mut parts = shortcut.split('+').map(str::trim).collect::<Vec<_>>();
+    // Allow `+` itself as the key, e.g. `Ctrl++`
+    if shortcut.ends_with("++") {
+        parts.truncate(parts.len().saturating_sub(2));
+        parts.push("+");
+    }
+    let (key, mods) = parts.split_last()?;
+    let mut keymod = KeyMod::NONE;
+    for name in mods {
+        keymod |= match name.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyMod::CTRL,
+            "shift" => KeyMod::SHIFT,
+            "alt" | "option" => KeyMod::ALT,
+            "cmd" | "command" | "gui" | "super" | "win" => KeyMod::GUI,
+            _ => return None,
+        };
+    }
+    Some((parse_key(key)?, keymod))
+}
+
+/// Parse a single [Key] name used in a keyboard shortcut.
+fn parse_key(name: &str) -> Option<Key> {
+    const LETTERS: [Key; 26] = [
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+        Key::G,
+        Key::H,
+        Key::I,
+        Key::J,
+        Key::K,
+        Key::L,
+        Key::M,
+        Key::N,
+        Key::O,
+        Key::P,
+        Key::Q,
+        Key::R,
+        Key::S,
+        Key::T,
+        Key::U,
+        Key::V,
+        Key::W,
+        Key::X,
+        Key::Y,
+        Key::Z,
+    ];
+    const NUMBERS: [Key; 10] = [
+        Key::Num0,
+        Key::Num1,
+        Key::Num2,
+        Key::Num3,
+        Key::Num4,
+        Key::Num5,
+        Key::Num6,
+        Key::Num7,
+        Key::Num8,
+        Key::Num9,
+    ];
+    const FUNCTION: [Key; 12] = [
+        Key::F1,
+        Key::F2,
+        Key::F3,
+        Key::F4,
+        Key::F5,
+        Key::F6,
+        Key::F7,
+        Key::F8,
+        Key::F9,
+        Key::F10,
+        Key::F11,
+        Key::F12,
+    ];
+
+    let name = name.to_ascii_lowercase();
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_lowercase() {
+            return Some(LETTERS[(c as u8 - b'a') as usize]);
+        } else if c.is_ascii_digit() {
+            return Some(NUMBERS[(c as u8 - b'0') as usize]);
+        }
+    }
+    if let Some(n) = name.strip_prefix('f').and_then(|n| n.parse::<usize>().ok()) {
+        return (1..=12).contains(&n).then(|| FUNCTION[n - 1]);
+    }
+    let key = match name.as_str() {
+        "backspace" => Key::Backspace,
+        "tab" => Key::Tab,
+        "enter" | "return" => Key::Return,
+        "esc" | "escape" => Key::Escape,
+        "space" => Key::Space,
+        "del" | "delete" => Key::Delete,
+        "ins" | "insert" => Key::Insert,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" | "pgup" => Key::PageUp,
+        "pagedown" | "pgdn" => Key::PageDown,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "+" | "plus" => Key::Plus,
+        "-" | "minus" => Key::Minus,
+        "=" | "equals" => Key::Equals,
+        "," | "comma" => Key::Comma,
+        "." | "period" => Key::Period,
+        "/" | "slash" => Key::Slash,
+        _ => return None,
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: ElementId = ElementId(1);
+    const EDIT: ElementId = ElementId(2);
+    const CONTEXT: ElementId = ElementId(3);
+
+    #[test]
+    fn toggle_open_close() {
+        let mut menu = MenuState::default();
+        assert!(!menu.is_open(FILE));
+        menu.toggle(FILE, point![0, 20], true);
+        assert!(menu.is_open(FILE));
+        assert!(!menu.is_open(EDIT));
+        menu.toggle(FILE, point![0, 20], true);
+        assert!(!menu.is_open(FILE));
+        assert_eq!(menu.open_menu(), None);
+    }
+
+    #[test]
+    fn hover_switches_bar_menus() {
+        let mut menu = MenuState::default();
+        // Hovering does nothing while no menu is open
+        assert!(!menu.hover(EDIT, point![40, 20]));
+        assert!(!menu.is_open(EDIT));
+
+        menu.open(FILE, point![0, 20], true);
+        menu.resize(FILE, point![100, 80]);
+        assert!(!menu.hover(FILE, point![0, 20]));
+        assert!(menu.hover(EDIT, point![40, 20]));
+        assert!(menu.is_open(EDIT));
+        assert_eq!(menu.open_menu().map(|m| m.pos), Some(point![40, 20]));
+        // Switching menus forgets the previous panel size
+        assert_eq!(menu.open_menu().map(|m| m.size), Some(point![0, 0]));
+
+        // Context menus aren't switched away from by hovering the menu bar
+        menu.open(CONTEXT, point![200, 200], false);
+        assert!(!menu.hover(FILE, point![0, 20]));
+        assert!(menu.is_open(CONTEXT));
+    }
+
+    #[test]
+    fn press_outside_closes() {
+        let bar = rect![0, 0, 640, 20];
+        let mut menu = MenuState::default();
+        menu.open(FILE, point![0, 20], true);
+        menu.resize(FILE, point![100, 80]);
+
+        // Inside the panel or the menu bar keeps the menu open
+        assert!(!menu.press_outside(FILE, point![50, 50], bar));
+        assert!(!menu.press_outside(FILE, point![300, 10], bar));
+        // Other menus are unaffected
+        assert!(!menu.press_outside(EDIT, point![300, 300], bar));
+        assert!(menu.is_open(FILE));
+
+        assert!(menu.press_outside(FILE, point![300, 300], bar));
+        assert!(!menu.is_open(FILE));
+
+        // Context menus close when pressing on the menu bar
+        menu.open(CONTEXT, point![200, 200], false);
+        menu.resize(CONTEXT, point![100, 80]);
+        assert!(!menu.press_outside(CONTEXT, point![250, 250], bar));
+        assert!(menu.press_outside(CONTEXT, point![300, 10], bar));
+        assert_eq!(menu.open_menu(), None);
+    }
+
+    #[test]
+    fn panel_measures_items() {
+        let mut panel = MenuPanel::new(0, 4);
+        panel.advance(80, 20);
+        panel.advance(120, 20);
+        panel.advance(40, 9);
+        assert_eq!(panel.y, 53);
+        assert_eq!(panel.measured, point![120, 53]);
+    }
+
+    #[test]
+    fn shortcuts() {
+        assert_eq!(parse_shortcut("Ctrl+S"), Some((Key::S, KeyMod::CTRL)));
+        assert_eq!(
+            parse_shortcut("ctrl+shift+z"),
+            Some((Key::Z, KeyMod::CTRL | KeyMod::SHIFT))
+        );
+        assert_eq!(parse_shortcut("Alt+F4"), Some((Key::F4, KeyMod::ALT)));
+        assert_eq!(parse_shortcut("Cmd+1"), Some((Key::Num1, KeyMod::GUI)));
+        assert_eq!(parse_shortcut("Delete"), Some((Key::Delete, KeyMod::NONE)));
+        assert_eq!(parse_shortcut("Ctrl++"), Some((Key::Plus, KeyMod::CTRL)));
+        assert_eq!(
+            parse_shortcut("Ctrl + PageUp"),
+            Some((Key::PageUp, KeyMod::CTRL))
+        );
+        assert_eq!(parse_shortcut("Hyper+S"), None);
+        assert_eq!(parse_shortcut("Ctrl+F13"), None);
+        assert_eq!(parse_shortcut(""), None);
+    }
+}
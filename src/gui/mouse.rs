@@ -3,11 +3,17 @@
 use crate::prelude::*;
 use std::{
     collections::{HashMap, HashSet},
-    time::{Duration, Instant},
+    time::Duration,
 };
+use web_time::Instant;
+
+/// Default maximum time between clicks counted as a double click.
+pub(crate) const DBL_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+/// Distance in pixels the mouse can move between clicks counted as a double click.
+pub(crate) const DBL_CLICK_TOLERANCE: i32 = 4;
 
 /// Keep track of mouse position and clicks between frames.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct MouseState {
     pub(crate) pos: Point<i32>,
     pub(crate) xrel: i32,
@@ -16,6 +22,26 @@ pub(crate) struct MouseState {
     pub(crate) clicked: HashSet<Mouse>,
     pub(crate) last_clicked: HashMap<Mouse, Instant>,
     pub(crate) last_dbl_clicked: HashMap<Mouse, Instant>,
+    pub(crate) last_click_pos: HashMap<Mouse, Point<i32>>,
+    pub(crate) clicks: HashMap<Mouse, u8>,
+    pub(crate) dbl_click_interval: Duration,
+}
+
+impl Default for MouseState {
+    fn default() -> Self {
+        Self {
+            pos: Point::default(),
+            xrel: 0,
+            yrel: 0,
+            pressed: HashSet::new(),
+            clicked: HashSet::new(),
+            last_clicked: HashMap::new(),
+            last_dbl_clicked: HashMap::new(),
+            last_click_pos: HashMap::new(),
+            clicks: HashMap::new(),
+            dbl_click_interval: DBL_CLICK_INTERVAL,
+        }
+    }
 }
 
 impl MouseState {
@@ -39,7 +65,7 @@ impl MouseState {
     pub(crate) fn was_dbl_clicked(&self, btn: Mouse) -> bool {
         match (self.last_dbl_clicked(btn), self.last_clicked(btn)) {
             (Some(dbl), Some(clicked)) => {
-                dbl >= clicked && (*dbl - *clicked) < Duration::from_millis(500)
+                dbl >= clicked && (*dbl - *clicked) < self.dbl_click_interval
             }
             _ => false,
         }
@@ -77,11 +103,49 @@ impl MouseState {
         self.pressed.remove(&btn);
     }
 
-    /// Store last time a [Mouse] button was clicked.
+    /// Returns the number of consecutive clicks of a [Mouse] button ending with a click this
+    /// frame, or `0` if it wasn't clicked this frame.
     #[inline]
-    pub(crate) fn click(&mut self, btn: Mouse, time: Instant) {
+    #[must_use]
+    pub(crate) fn clicks(&self, btn: Mouse) -> u8 {
+        if self.was_clicked(btn) {
+            self.clicks.get(&btn).copied().unwrap_or_default()
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of consecutive clicks a click of a [Mouse] button at `pos` and `time`
+    /// would count as. Clicks are consecutive if they're within the double click interval and
+    /// tolerance of the previous click.
+    #[must_use]
+    pub(crate) fn next_click_count(&self, btn: Mouse, pos: Point<i32>, time: Instant) -> u8 {
+        match (self.last_clicked.get(&btn), self.last_click_pos.get(&btn)) {
+            (Some(&last_time), Some(&last_pos))
+                if time.saturating_duration_since(last_time) < self.dbl_click_interval
+                    && (pos.x() - last_pos.x()).abs() <= DBL_CLICK_TOLERANCE
+                    && (pos.y() - last_pos.y()).abs() <= DBL_CLICK_TOLERANCE =>
+            {
+                self.clicks
+                    .get(&btn)
+                    .copied()
+                    .unwrap_or_default()
+                    .saturating_add(1)
+            }
+            _ => 1,
+        }
+    }
+
+    /// Store last time and position a [Mouse] button was clicked, returning the number of
+    /// consecutive clicks.
+    #[inline]
+    pub(crate) fn click(&mut self, btn: Mouse, pos: Point<i32>, time: Instant) -> u8 {
+        let clicks = self.next_click_count(btn, pos, time);
         self.clicked.insert(btn);
         self.last_clicked.insert(btn, time);
+        self.last_click_pos.insert(btn, pos);
+        self.clicks.insert(btn, clicks);
+        clicks
     }
 
     /// Store last time a [Mouse] button was double clicked.
@@ -102,3 +166,46 @@ impl MouseState {
         self.last_dbl_clicked.get(&btn)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn click_counting() {
+        let mut mouse = MouseState::default();
+        let start = Instant::now();
+        let pos = point![100, 100];
+        let far = point![100 + DBL_CLICK_TOLERANCE + 1, 100];
+        assert_eq!(mouse.clicks(Mouse::Left), 0);
+
+        assert_eq!(mouse.click(Mouse::Left, pos, start), 1);
+        let time = start + Duration::from_millis(200);
+        assert_eq!(mouse.click(Mouse::Left, point![103, 98], time), 2);
+        let time = time + Duration::from_millis(200);
+        assert_eq!(mouse.click(Mouse::Left, pos, time), 3);
+        assert_eq!(mouse.clicks(Mouse::Left), 3);
+        assert_eq!(mouse.clicks(Mouse::Right), 0);
+
+        // Other buttons count separately
+        assert_eq!(mouse.click(Mouse::Right, pos, time), 1);
+
+        // Resets once the interval elapses
+        let time = time + DBL_CLICK_INTERVAL;
+        assert_eq!(mouse.click(Mouse::Left, pos, time), 1);
+
+        // Resets once the mouse moves beyond the tolerance
+        let time = time + Duration::from_millis(100);
+        assert_eq!(mouse.click(Mouse::Left, pos, time), 2);
+        let time = time + Duration::from_millis(100);
+        assert_eq!(mouse.click(Mouse::Left, far, time), 1);
+
+        // Interval is configurable
+        mouse.dbl_click_interval = Duration::from_millis(100);
+        let time = time + Duration::from_millis(150);
+        assert_eq!(mouse.click(Mouse::Left, far, time), 1);
+
+        mouse.clicked.clear();
+        assert_eq!(mouse.clicks(Mouse::Left), 0);
+    }
+}
@@ -39,7 +39,7 @@ impl PixState {
 
         // Calculate rect
         let [x, mut y] = pos.coords();
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         if !label.is_empty() {
             y += label_height + ipad.y();
         }
@@ -51,7 +51,7 @@ impl PixState {
             s.ui.try_focus(id);
         }
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Label
@@ -225,7 +225,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let active = s.ui.is_active(id);
 
-        s.push();
+        s.push_screen_space();
 
         // Clamp value
         *value = (*value).clamp(0, max);
@@ -0,0 +1,425 @@
+//! Text cursor and selection state management.
+
+use crate::{gui::MOD_CTRL, prelude::*};
+use std::ops::Range;
+
+/// How much text to delete before the cursor when nothing is selected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Delete {
+    /// The previous character.
+    Char,
+    /// The previous word and any whitespace following it.
+    Word,
+    /// All text before the cursor.
+    Line,
+}
+
+/// Cursor position and selected range of editable text, as byte offsets.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct TextSelection {
+    /// Position of the text cursor.
+    pub(crate) cursor: usize,
+    /// Position the selection extends from to the cursor. Equal to `cursor` when nothing is
+    /// selected.
+    pub(crate) anchor: usize,
+    /// Whether a selection is being dragged with the mouse.
+    pub(crate) dragging: bool,
+}
+
+impl TextSelection {
+    /// Constructs a `TextSelection` with the cursor at `pos` and nothing selected.
+    #[inline]
+    pub(crate) const fn new(pos: usize) -> Self {
+        Self {
+            cursor: pos,
+            anchor: pos,
+            dragging: false,
+        }
+    }
+
+    /// Constructs a `TextSelection` with the cursor at the end of `text`.
+    #[inline]
+    pub(crate) fn at_end(text: &str) -> Self {
+        Self::new(text.len())
+    }
+
+    /// Returns the selected byte range, which is empty when nothing is selected.
+    #[inline]
+    pub(crate) fn range(&self) -> Range<usize> {
+        self.cursor.min(self.anchor)..self.cursor.max(self.anchor)
+    }
+
+    /// Returns whether any text is selected.
+    #[inline]
+    pub(crate) fn has_selection(&self) -> bool {
+        self.cursor != self.anchor
+    }
+
+    /// Returns the selected text.
+    #[inline]
+    pub(crate) fn selected<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.range()]
+    }
+
+    /// Clamp the cursor and anchor to valid character boundaries of `text`, which may have been
+    /// changed outside of editing.
+    pub(crate) fn clamp(&mut self, text: &str) {
+        let clamp = |mut pos: usize| {
+            pos = pos.min(text.len());
+            while !text.is_char_boundary(pos) {
+                pos -= 1;
+            }
+            pos
+        };
+        self.cursor = clamp(self.cursor);
+        self.anchor = clamp(self.anchor);
+    }
+
+    /// Move the cursor to `pos`, extending the selection if `extend` is `true`.
+    #[inline]
+    pub(crate) fn move_to(&mut self, pos: usize, extend: bool) {
+        self.cursor = pos;
+        if !extend {
+            self.anchor = pos;
+        }
+    }
+
+    /// Move the cursor one character left. Collapses the selection to its start instead, unless
+    /// `extend` is `true`.
+    pub(crate) fn move_left(&mut self, text: &str, extend: bool) {
+        if self.has_selection() && !extend {
+            self.move_to(self.range().start, false);
+        } else {
+            self.move_to(prev_char(text, self.cursor), extend);
+        }
+    }
+
+    /// Move the cursor one character right. Collapses the selection to its end instead, unless
+    /// `extend` is `true`.
+    pub(crate) fn move_right(&mut self, text: &str, extend: bool) {
+        if self.has_selection() && !extend {
+            self.move_to(self.range().end, false);
+        } else {
+            self.move_to(next_char(text, self.cursor), extend);
+        }
+    }
+
+    /// Select all of `text`.
+    #[inline]
+    pub(crate) fn select_all(&mut self, text: &str) {
+        self.anchor = 0;
+        self.cursor = text.len();
+    }
+
+    /// Select the word, or run of whitespace, containing `pos`.
+    pub(crate) fn select_word(&mut self, text: &str, pos: usize) {
+        let is_space = |ch: Option<char>| ch.map(char::is_whitespace);
+        let class =
+            is_space(text[pos..].chars().next()).or_else(|| is_space(text[..pos].chars().last()));
+        let start = text[..pos]
+            .char_indices()
+            .rev()
+            .take_while(|&(_, ch)| Some(ch.is_whitespace()) == class)
+            .last()
+            .map_or(pos, |(i, _)| i);
+        let end = text[pos..]
+            .char_indices()
+            .find(|&(_, ch)| Some(ch.is_whitespace()) != class)
+            .map_or(text.len(), |(i, _)| pos + i);
+        self.anchor = start;
+        self.cursor = end;
+    }
+
+    /// Replace the selection, if any, with `insert` and move the cursor after it.
+    pub(crate) fn insert(&mut self, text: &mut String, insert: &str) {
+        let range = self.range();
+        text.replace_range(range.clone(), insert);
+        self.move_to(range.start + insert.len(), false);
+    }
+
+    /// Delete the selected text, returning whether anything was deleted.
+    #[inline]
+    pub(crate) fn delete_selection(&mut self, text: &mut String) -> bool {
+        if self.has_selection() {
+            self.insert(text, "");
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Delete the selected text, or text before the cursor if nothing is selected, returning
+    /// whether anything was deleted.
+    pub(crate) fn backspace(&mut self, text: &mut String, delete: Delete) -> bool {
+        if self.delete_selection(text) {
+            return true;
+        }
+        let start = match delete {
+            Delete::Char => prev_char(text, self.cursor),
+            Delete::Word => prev_word(text, self.cursor),
+            Delete::Line => 0,
+        };
+        self.anchor = start;
+        self.delete_selection(text)
+    }
+
+    /// Delete the selected text, or the character after the cursor if nothing is selected,
+    /// returning whether anything was deleted.
+    pub(crate) fn delete(&mut self, text: &mut String) -> bool {
+        if !self.has_selection() {
+            self.anchor = next_char(text, self.cursor);
+        }
+        self.delete_selection(text)
+    }
+
+    /// Handle a mouse press at `pos`, extending the selection if `extend` is `true`.
+    #[inline]
+    pub(crate) fn press(&mut self, pos: usize, extend: bool) {
+        self.move_to(pos, extend);
+        self.dragging = true;
+    }
+
+    /// Handle the mouse being dragged to `pos` while pressed.
+    #[inline]
+    pub(crate) fn drag(&mut self, pos: usize) {
+        if self.dragging {
+            self.cursor = pos;
+        }
+    }
+
+    /// Handle a mouse release.
+    #[inline]
+    pub(crate) fn release(&mut self) {
+        self.dragging = false;
+    }
+
+    /// Handle cursor movement and deletion keys, returning whether `text` was changed.
+    pub(crate) fn handle_key(&mut self, text: &mut String, key: Key, keymod: KeyMod) -> bool {
+        let extend = keymod.intersects(KeyMod::SHIFT);
+        match key {
+            Key::Left => self.move_left(text, extend),
+            Key::Right => self.move_right(text, extend),
+            Key::Home => self.move_to(0, extend),
+            Key::End => self.move_to(text.len(), extend),
+            Key::A if keymod.intersects(MOD_CTRL) => self.select_all(text),
+            Key::Backspace => {
+                let delete = if keymod.intersects(MOD_CTRL) {
+                    Delete::Line
+                } else if keymod.intersects(KeyMod::ALT) {
+                    Delete::Word
+                } else {
+                    Delete::Char
+                };
+                return self.backspace(text, delete);
+            }
+            Key::Delete => return self.delete(text),
+            _ => (),
+        }
+        false
+    }
+}
+
+/// Returns the byte index of the character boundary in `text` nearest to `x`, using `measure` to
+/// get the width of text preceding each boundary.
+pub(crate) fn index_at<F>(text: &str, x: i32, mut measure: F) -> PixResult<usize>
+where
+    F: FnMut(&str) -> PixResult<i32>,
+{
+    let mut prev = (0, 0);
+    for (i, _) in text.char_indices().skip(1).chain([(text.len(), ' ')]) {
+        let width = measure(&text[..i])?;
+        if width >= x {
+            // Pick whichever boundary is closer
+            return Ok(if x - prev.1 <= width - x { prev.0 } else { i });
+        }
+        prev = (i, width);
+    }
+    Ok(prev.0)
+}
+
+/// Returns the byte index of the character before `pos`.
+fn prev_char(text: &str, pos: usize) -> usize {
+    text[..pos].char_indices().last().map_or(0, |(i, _)| i)
+}
+
+/// Returns the byte index of the character after `pos`.
+fn next_char(text: &str, pos: usize) -> usize {
+    text[pos..]
+        .chars()
+        .next()
+        .map_or(pos, |ch| pos + ch.len_utf8())
+}
+
+/// Returns the byte index of the start of the word before `pos`, skipping any whitespace
+/// preceding `pos`.
+fn prev_word(text: &str, pos: usize) -> usize {
+    let before = text[..pos].trim_end();
+    before.rfind(char::is_whitespace).map_or(0, |i| {
+        i + before[i..].chars().next().map_or(0, char::len_utf8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply a sequence of key presses, returning the resulting `(text, cursor, selection)`.
+    fn keys(
+        text: &str,
+        selection: &mut TextSelection,
+        keys: &[(Key, KeyMod)],
+    ) -> (String, usize, Range<usize>) {
+        let mut text = text.to_string();
+        for &(key, keymod) in keys {
+            selection.handle_key(&mut text, key, keymod);
+        }
+        (text, selection.cursor, selection.range())
+    }
+
+    const NONE: KeyMod = KeyMod::NONE;
+    const SHIFT: KeyMod = KeyMod::SHIFT;
+
+    #[test]
+    fn shift_arrows_extend_selection() {
+        let text = "hello world";
+        let mut sel = TextSelection::at_end(text);
+        let shift_left = [(Key::Left, SHIFT); 5];
+        assert_eq!(keys(text, &mut sel, &shift_left), (text.into(), 6, 6..11));
+        assert_eq!(sel.selected(text), "world");
+
+        // Moving without shift collapses the selection
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::Right, NONE)]),
+            (text.into(), 11, 11..11)
+        );
+
+        let mut sel = TextSelection::new(3);
+        let home_end = [(Key::Home, SHIFT), (Key::End, SHIFT)];
+        assert_eq!(keys(text, &mut sel, &home_end), (text.into(), 11, 3..11));
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::Home, NONE)]),
+            (text.into(), 0, 0..0)
+        );
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::A, MOD_CTRL)]),
+            (text.into(), 11, 0..11)
+        );
+    }
+
+    #[test]
+    fn typing_replaces_selection() {
+        let mut text = String::from("hello world");
+        let mut sel = TextSelection::at_end(&text);
+        for _ in 0..5 {
+            sel.handle_key(&mut text, Key::Left, SHIFT);
+        }
+        sel.insert(&mut text, "there");
+        assert_eq!(
+            (text.as_str(), sel.cursor, sel.range()),
+            ("hello there", 11, 11..11)
+        );
+
+        sel.move_to(0, false);
+        sel.insert(&mut text, "> ");
+        assert_eq!(
+            (text.as_str(), sel.cursor, sel.range()),
+            ("> hello there", 2, 2..2)
+        );
+    }
+
+    #[test]
+    fn backspace_deletes_selection() {
+        let text = "héllo wörld";
+        let mut sel = TextSelection::new(7);
+        let delete = [
+            (Key::Right, SHIFT),
+            (Key::Right, SHIFT),
+            (Key::Backspace, NONE),
+        ];
+        assert_eq!(keys(text, &mut sel, &delete), ("héllo rld".into(), 7, 7..7));
+
+        // Without a selection, delete the previous character, word, or line
+        let mut sel = TextSelection::at_end(text);
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::Backspace, NONE)]),
+            ("héllo wörl".into(), 12, 12..12)
+        );
+        let mut sel = TextSelection::at_end(text);
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::Backspace, KeyMod::ALT)]),
+            ("héllo ".into(), 7, 7..7)
+        );
+        let mut sel = TextSelection::new(6);
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::Backspace, MOD_CTRL)]),
+            (" wörld".into(), 0, 0..0)
+        );
+        let mut sel = TextSelection::new(0);
+        assert_eq!(
+            keys(text, &mut sel, &[(Key::Delete, NONE)]),
+            ("éllo wörld".into(), 0, 0..0)
+        );
+    }
+
+    #[test]
+    fn mouse_drag_selects_range() {
+        let mut text = String::from("hello world");
+        let mut sel = TextSelection::at_end(&text);
+        sel.press(2, false);
+        sel.drag(4);
+        sel.drag(0);
+        sel.release();
+        sel.drag(8);
+        assert_eq!((sel.cursor, sel.range(), sel.dragging), (0, 0..2, false));
+
+        // Shift+press extends the existing selection
+        sel.press(5, true);
+        sel.release();
+        assert_eq!((sel.cursor, sel.range()), (5, 2..5));
+
+        assert!(sel.backspace(&mut text, Delete::Char));
+        assert_eq!(
+            (text.as_str(), sel.cursor, sel.range()),
+            ("he world", 2, 2..2)
+        );
+    }
+
+    #[test]
+    fn double_click_selects_word() {
+        let text = "one two  three";
+        let mut sel = TextSelection::default();
+        sel.select_word(text, 5);
+        assert_eq!(sel.selected(text), "two");
+        sel.select_word(text, 4);
+        assert_eq!(sel.selected(text), "two");
+        sel.select_word(text, 8);
+        assert_eq!(sel.selected(text), "  ");
+        sel.select_word(text, text.len());
+        assert_eq!((sel.cursor, sel.selected(text)), (14, "three"));
+    }
+
+    #[test]
+    fn clamp_to_char_boundary() {
+        let mut sel = TextSelection {
+            cursor: 20,
+            anchor: 2,
+            dragging: false,
+        };
+        sel.clamp("héllo");
+        assert_eq!((sel.cursor, sel.anchor), (6, 1));
+    }
+
+    #[test]
+    fn index_at_nearest_boundary() -> PixResult<()> {
+        // Monospaced text with 10 pixel wide characters
+        let measure = |text: &str| Ok(text.chars().count() as i32 * 10);
+        let text = "aé c";
+        assert_eq!(index_at(text, -5, measure)?, 0);
+        assert_eq!(index_at(text, 4, measure)?, 0);
+        assert_eq!(index_at(text, 6, measure)?, 1);
+        assert_eq!(index_at(text, 16, measure)?, 3);
+        assert_eq!(index_at(text, 100, measure)?, text.len());
+        assert_eq!(index_at("", 10, measure)?, 0);
+        Ok(())
+    }
+}
@@ -2,12 +2,15 @@
 
 use super::theme::FontId;
 use crate::{
-    gui::{keys::KeyState, mouse::MouseState},
+    gui::{
+        controller::ControllerState, drag_drop::DragState, keys::KeyState, menu::MenuState,
+        mouse::MouseState, selection::TextSelection, tooltip::TooltipState,
+    },
     prelude::*,
 };
 use lru::LruCache;
 use std::{
-    collections::{hash_map::DefaultHasher, HashSet},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
     convert::TryInto,
     error::Error,
     fmt,
@@ -59,7 +62,10 @@ pub(crate) struct Texture {
     pub(crate) element_id: ElementId,
     pub(crate) src: Option<Rect<i32>>,
     pub(crate) dst: Option<Rect<i32>>,
+    pub(crate) size: (u32, u32),
     pub(crate) visible: bool,
+    /// Whether this texture is drawn above all other UI textures.
+    pub(crate) overlay: bool,
     pub(crate) font_id: FontId,
     pub(crate) font_size: u32,
 }
@@ -70,6 +76,7 @@ impl Texture {
         element_id: ElementId,
         src: Option<Rect<i32>>,
         dst: Option<Rect<i32>>,
+        size: (u32, u32),
         font_id: FontId,
         font_size: u32,
     ) -> Self {
@@ -78,13 +85,22 @@ impl Texture {
             element_id,
             src,
             dst,
+            size,
             visible: true,
+            overlay: false,
             font_id,
             font_size,
         }
     }
 }
 
+/// Focus cycling requested with [`PixState::focus_next`] or [`PixState::focus_previous`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FocusCycle {
+    Next,
+    Previous,
+}
+
 /// Internal tracked UI state.
 #[derive(Debug)]
 pub(crate) struct UiState {
@@ -106,6 +122,20 @@ pub(crate) struct UiState {
     id_stack: Vec<u64>,
     /// Override for max-width elements.
     pub(crate) next_width: Option<i32>,
+    /// Stack of tab bars currently rendering tabs.
+    pub(crate) tab_bars: Vec<TabBar>,
+    /// Stack of split panes currently rendering content, bounding the usable UI area.
+    pub(crate) panes: Vec<Rect<i32>>,
+    /// Stack of group boxes currently rendering content.
+    pub(crate) group_boxes: Vec<GroupBox>,
+    /// Recent frame times recorded for `frame_time_graph`.
+    pub(crate) frame_times: FrameTimes,
+    /// Open menu and menu layout state.
+    pub(crate) menu: MenuState,
+    /// Modal rendered this frame.
+    pub(crate) modal: Option<ElementId>,
+    /// Whether elements outside of an open modal are blocked from interaction.
+    pub(crate) blocked: bool,
     /// UI texture to be drawn over rendered frame, in rendered order.
     pub(crate) textures: Vec<Texture>,
     /// Whether UI elements are disabled.
@@ -118,6 +148,24 @@ pub(crate) struct UiState {
     pub(crate) pmouse: MouseState,
     /// Keyboard state for the current frame.
     pub(crate) keys: KeyState,
+    /// Controller state for the current frame.
+    pub(crate) controllers: ControllerState,
+    /// All events received this frame, in order.
+    pub(crate) events: Vec<Event>,
+    /// Fingers currently touching a touch device, by finger identifier.
+    pub(crate) touches: HashMap<i64, TouchPoint>,
+    /// Last mouse position within each window, by window identifier.
+    pub(crate) window_mouse: HashMap<WindowId, Point<i32>>,
+    /// Which window the mouse is over.
+    pub(crate) hovered_window: Option<WindowId>,
+    /// Which window has keyboard focus.
+    pub(crate) focused_window: Option<WindowId>,
+    /// Which window is currently being rendered to.
+    pub(crate) target_window: Option<WindowId>,
+    /// Drag and drop state.
+    pub(crate) drag: DragState,
+    /// Tooltip hover state.
+    pub(crate) tooltip: TooltipState,
     /// Element state for the current frame,
     pub(crate) elements: LruCache<ElementId, ElementState>,
     /// Which element is active.
@@ -132,6 +180,10 @@ pub(crate) struct UiState {
     focus_enabled: bool,
     /// Last focusable element rendered.
     last_focusable: Option<ElementId>,
+    /// Whether the next focusable element is excluded from focus.
+    skip_focus: bool,
+    /// Focus cycling requested this frame.
+    focus_cycle: Option<FocusCycle>,
     /// Last bounding box rendered.
     last_size: Option<Rect<i32>>,
 }
@@ -149,12 +201,28 @@ impl Default for UiState {
             offset_stack: vec![],
             id_stack: vec![],
             next_width: None,
+            tab_bars: vec![],
+            panes: vec![],
+            group_boxes: vec![],
+            frame_times: FrameTimes::default(),
+            menu: MenuState::default(),
+            modal: None,
+            blocked: false,
             textures: vec![],
             disabled: false,
             mouse: MouseState::default(),
             mouse_offset: None,
             pmouse: MouseState::default(),
             keys: KeyState::default(),
+            controllers: ControllerState::default(),
+            events: vec![],
+            touches: HashMap::new(),
+            window_mouse: HashMap::new(),
+            hovered_window: None,
+            focused_window: None,
+            target_window: None,
+            drag: DragState::default(),
+            tooltip: TooltipState::default(),
             elements: LruCache::new(ELEMENT_CACHE_SIZE.try_into().expect("valid cache size")),
             active: None,
             hovered: None,
@@ -162,6 +230,8 @@ impl Default for UiState {
             editing: None,
             focus_enabled: true,
             last_focusable: None,
+            skip_focus: false,
+            focus_cycle: None,
             last_size: None,
         }
     }
@@ -184,6 +254,10 @@ impl UiState {
         for texture in &mut self.textures {
             texture.visible = false;
         }
+        // Block elements next frame for as long as a modal is being rendered
+        self.blocked = self.modal.take().is_some();
+        self.drag.end_frame(self.mouse.is_down(Mouse::Left));
+        self.tooltip.end_frame();
 
         self.pmouse.pos = self.mouse.pos;
         if !self.mouse.is_down(Mouse::Left) {
@@ -192,6 +266,9 @@ impl UiState {
             // Disable focused state while mouse is down from previous frame
             self.set_active(ElementId(0));
         }
+        self.skip_focus = false;
+        self.focus_cycle = None;
+        self.keys.end_frame();
         self.clear_entered();
     }
 
@@ -207,6 +284,23 @@ impl UiState {
         ElementId(hasher.finish())
     }
 
+    /// Push a seed to the ID stack, combined with the seed currently on top of the stack.
+    #[inline]
+    pub(crate) fn push_id<H: Hash>(&mut self, id: &H) {
+        let mut hasher = DefaultHasher::new();
+        if let Some(parent) = self.id_stack.last() {
+            parent.hash(&mut hasher);
+        }
+        id.hash(&mut hasher);
+        self.id_stack.push(hasher.finish());
+    }
+
+    /// Pop a seed from the ID stack. If the ID stack is empty, this does nothing.
+    #[inline]
+    pub(crate) fn pop_id(&mut self) {
+        self.id_stack.pop();
+    }
+
     /// Helper to strip out any ID-specific patterns from a label.
     #[inline]
     #[must_use]
@@ -298,6 +392,75 @@ impl UiState {
         pos
     }
 
+    /// Track the mouse at `pos` within a given window, which becomes the hovered window.
+    #[inline]
+    pub(crate) fn set_mouse_window(&mut self, id: WindowId, pos: Point<i32>) {
+        self.hovered_window = Some(id);
+        self.window_mouse.insert(id, pos);
+    }
+
+    /// Clear the hovered window if the mouse left it.
+    #[inline]
+    pub(crate) fn leave_window(&mut self, id: WindowId) {
+        if self.hovered_window == Some(id) {
+            self.hovered_window = None;
+        }
+    }
+
+    /// Stop tracking a closed window.
+    #[inline]
+    pub(crate) fn remove_window(&mut self, id: WindowId) {
+        self.window_mouse.remove(&id);
+        self.leave_window(id);
+        if self.focused_window == Some(id) {
+            self.focused_window = None;
+        }
+    }
+
+    /// Set the focused window. Losing focus releases all touches, since fingers lifted while
+    /// unfocused are never reported.
+    #[inline]
+    pub(crate) fn focus_window(&mut self, id: Option<WindowId>) {
+        if id.is_none() {
+            self.touches.clear();
+        }
+        self.focused_window = id;
+    }
+
+    /// Track a finger touching a touch device, updating it if already touching.
+    #[inline]
+    pub(crate) fn touch(&mut self, finger_id: i64, touch: TouchPoint) {
+        self.touches.insert(finger_id, touch);
+    }
+
+    /// Stop tracking a finger lifted from a touch device.
+    #[inline]
+    pub(crate) fn release_touch(&mut self, finger_id: i64) {
+        self.touches.remove(&finger_id);
+    }
+
+    /// Whether mouse input applies to the window being rendered to. Always `true` when either
+    /// window is unknown.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_in_target(&self) -> bool {
+        match (self.hovered_window, self.target_window) {
+            (Some(hovered), Some(target)) => hovered == target,
+            _ => true,
+        }
+    }
+
+    /// Whether keyboard input applies to the window being rendered to. Always `true` when either
+    /// window is unknown.
+    #[inline]
+    #[must_use]
+    pub(crate) fn focus_in_target(&self) -> bool {
+        match (self.focused_window, self.target_window) {
+            (Some(focused), Some(target)) => focused == target,
+            _ => true,
+        }
+    }
+
     /// Returns if any [Mouse] button was pressed this frame.
     #[inline]
     #[must_use]
@@ -319,6 +482,13 @@ impl UiState {
         self.mouse.was_dbl_clicked(btn)
     }
 
+    /// Returns the number of consecutive [Mouse] clicks ending with a click this frame.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_clicks(&self, btn: Mouse) -> u8 {
+        self.mouse.clicks(btn)
+    }
+
     /// Returns if a specific [Mouse] button was pressed this frame.
     #[inline]
     #[must_use]
@@ -347,6 +517,34 @@ impl UiState {
         self.keys.is_down(key)
     }
 
+    /// Returns if a specific [Key] went down this frame, ignoring key repeat.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_just_pressed(&self, key: Key) -> bool {
+        self.keys.was_just_pressed(key)
+    }
+
+    /// Returns if a specific [Key] was released this frame.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_released(&self, key: Key) -> bool {
+        self.keys.was_released(key)
+    }
+
+    /// Returns if a specific [Key] went down or was repeated this frame.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_typed(&self, key: Key) -> bool {
+        self.keys.was_typed(key)
+    }
+
+    /// Returns the last [Key] that went down, ignoring key repeat.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn last_key_pressed(&self) -> Option<Key> {
+        self.keys.last_pressed()
+    }
+
     /// Returns a list of the current keys pressed this frame.
     #[inline]
     #[must_use]
@@ -367,6 +565,19 @@ impl UiState {
         self.keys.keymod()
     }
 
+    /// Map a [Key] and [`KeyMod`] combination to a named action.
+    #[inline]
+    pub(crate) fn register_shortcut(&mut self, key: Key, keymod: KeyMod, action: &'static str) {
+        self.keys.register_shortcut(key, keymod, action);
+    }
+
+    /// Returns if a shortcut for a named action was pressed this frame.
+    #[inline]
+    #[must_use]
+    pub(crate) fn shortcut_triggered(&self, action: &str) -> bool {
+        self.keys.shortcut_triggered(action)
+    }
+
     /// Set a mouse offset for rendering within textures or viewports.
     #[inline]
     pub(crate) fn offset_mouse<P: Into<Point<i32>>>(&mut self, offset: P) {
@@ -444,7 +655,12 @@ impl UiState {
     /// Try to capture `hover` if no other element is currently `hovered`.
     #[inline]
     pub(crate) fn try_hover<S: Contains<Point<i32>>>(&mut self, id: ElementId, shape: &S) -> bool {
-        if !self.has_hover() && !self.disabled && shape.contains(self.mouse_pos()) {
+        if !self.has_hover()
+            && !self.disabled
+            && !self.blocked
+            && self.mouse_in_target()
+            && shape.contains(self.mouse_pos())
+        {
             self.hover(id);
         }
         self.is_hovered(id)
@@ -455,7 +671,7 @@ impl UiState {
     #[inline]
     #[must_use]
     pub(crate) fn is_focused(&self, id: ElementId) -> bool {
-        !self.disabled && matches!(self.focused, Some(el) if el == id)
+        !self.disabled && self.focus_in_target() && matches!(self.focused, Some(el) if el == id)
     }
 
     /// Whether any element currently has `focus`.
@@ -475,7 +691,10 @@ impl UiState {
     /// through elements with the keyboard.
     #[inline]
     pub(crate) fn try_focus(&mut self, id: ElementId) -> bool {
-        if !self.disabled && !self.has_focused() {
+        if self.skip_focus {
+            return false;
+        }
+        if !self.disabled && !self.blocked && !self.has_focused() {
             self.focus(id);
         }
         self.is_focused(id)
@@ -487,6 +706,48 @@ impl UiState {
         self.focused = Some(ElementId::NONE);
     }
 
+    /// Begin rendering a modal, blocking all other elements from interaction starting next frame
+    /// and releasing any focus held by elements behind it.
+    #[inline]
+    pub(crate) fn begin_modal(&mut self, id: ElementId) {
+        if !self.blocked {
+            self.blur();
+        }
+        self.modal = Some(id);
+    }
+
+    /// Whether the current modal was dismissed with [`Key::Escape`] this frame.
+    #[inline]
+    pub(crate) fn modal_dismissed(&mut self) -> bool {
+        let dismissed = self.keys.was_entered(Key::Escape);
+        if dismissed {
+            self.clear_entered();
+        }
+        dismissed
+    }
+
+    /// Returns the result of a message box given the button `clicked` this frame, if any, and
+    /// whether the message box is still `open`. [`Key::Return`] selects the default result when
+    /// no button was clicked.
+    #[inline]
+    pub(crate) fn message_box_result(
+        &mut self,
+        buttons: MessageBoxButtons,
+        clicked: Option<MessageBoxResult>,
+        open: bool,
+    ) -> Option<MessageBoxResult> {
+        if clicked.is_some() {
+            clicked
+        } else if !open {
+            Some(buttons.cancel_result())
+        } else if self.keys.was_entered(Key::Return) {
+            self.clear_entered();
+            Some(buttons.default_result())
+        } else {
+            None
+        }
+    }
+
     /// Whether an element is being edited or not.
     #[inline]
     #[must_use]
@@ -521,30 +782,30 @@ impl UiState {
     /// Handles global element inputs for `focused` checks.
     #[inline]
     pub(crate) fn handle_focus(&mut self, id: ElementId) {
-        if !self.focus_enabled {
+        if mem::take(&mut self.skip_focus) || !self.focus_enabled || self.blocked {
             return;
         }
         let active = self.is_active(id);
         let hovered = self.is_hovered(id);
         let focused = self.is_focused(id);
-        if self.keys.was_entered(Key::Tab) {
+        if let Some(cycle) = self.pending_focus_cycle() {
             // Tab-focus cycling
             // If element is focused when Tab pressed, clear it so the next element can capture focus.
             // If SHIFT was held, re-focus the last element rendered
             // Clear keys, so next element doesn't trigger tab logic
             let none_focused = self.focused == Some(ElementId::NONE);
             if none_focused || focused {
-                if self.keys.mod_down(KeyMod::SHIFT) {
-                    self.focused = self.last_focusable;
-                    self.clear_entered();
-                } else if focused {
-                    self.focused = None;
-                    self.clear_entered();
-                } else if none_focused {
-                    self.focused = Some(id);
-                    self.clear_entered();
-                }
+                self.focused = match cycle {
+                    FocusCycle::Previous => self.last_focusable,
+                    FocusCycle::Next if focused => None,
+                    FocusCycle::Next => Some(id),
+                };
+                self.focus_cycle = None;
+                self.clear_entered();
             }
+        } else if focused && !self.is_editing(id) && self.keys.was_entered(Key::Escape) {
+            // Escape clears focus, leaving the key entered for any parent modal or menu
+            self.blur();
         } else if !self.mouse.is_down(Mouse::Left) && active && hovered {
             // Click focusing on release
             self.focus(id);
@@ -555,8 +816,23 @@ impl UiState {
         self.last_focusable = Some(id);
     }
 
-    /// Whether this element was `clicked` this frame. Treats [`Key::Return`] being pressed while
-    /// focused a a `click`.
+    /// Returns the focus cycling requested this frame by [`Key::Tab`], or by
+    /// [`PixState::focus_next`] or [`PixState::focus_previous`].
+    #[inline]
+    fn pending_focus_cycle(&self) -> Option<FocusCycle> {
+        if self.keys.was_entered(Key::Tab) {
+            if self.keys.mod_down(KeyMod::SHIFT) {
+                Some(FocusCycle::Previous)
+            } else {
+                Some(FocusCycle::Next)
+            }
+        } else {
+            self.focus_cycle
+        }
+    }
+
+    /// Whether this element was `clicked` this frame. Treats [`Key::Return`] or [`Key::Space`]
+    /// being pressed while focused as a `click`.
     ///
     /// If element is hovered and active, it means it was clicked last frame
     /// If mouse isn't down this frame, it means mouse was both clicked and released above an
@@ -564,8 +840,10 @@ impl UiState {
     #[inline]
     #[must_use]
     pub(crate) fn was_clicked(&mut self, id: ElementId) -> bool {
-        // Enter simulates a click
-        if self.is_focused(id) && self.keys.was_entered(Key::Return) {
+        // Enter or Space simulates a click
+        if self.is_focused(id)
+            && (self.keys.was_entered(Key::Return) || self.keys.was_entered(Key::Space))
+        {
             self.clear_entered();
             true
         } else {
@@ -574,6 +852,33 @@ impl UiState {
         }
     }
 
+    /// Toggle `value` if an element was clicked and elements aren't disabled. Returns whether it
+    /// was toggled.
+    #[inline]
+    pub(crate) fn was_toggled(&mut self, id: ElementId, value: &mut bool) -> bool {
+        let clicked = !self.disabled && self.was_clicked(id);
+        if clicked {
+            *value = !*value;
+        }
+        clicked
+    }
+
+    /// Set `selected` to `index` if an element was clicked and elements aren't disabled. Returns
+    /// whether it was selected.
+    #[inline]
+    pub(crate) fn was_selected(
+        &mut self,
+        id: ElementId,
+        selected: &mut usize,
+        index: usize,
+    ) -> bool {
+        let clicked = !self.disabled && self.was_clicked(id);
+        if clicked {
+            *selected = index;
+        }
+        clicked
+    }
+
     /// Return what, if any, [Key] was entered this frame. This is cleared at the end of each
     /// frame.
     #[inline]
@@ -658,6 +963,111 @@ impl UiState {
             .map_or(default, |state| state.text_edit.parse().unwrap_or(default))
     }
 
+    /// Returns the current text cursor and selection state for this element, clamped to `text`.
+    /// Defaults to the cursor at the end of `text`.
+    #[inline]
+    #[must_use]
+    pub(crate) fn text_selection(&mut self, id: ElementId, text: &str) -> TextSelection {
+        let mut selection = self
+            .elements
+            .get_mut(&id)
+            .and_then(|state| state.text_selection)
+            .unwrap_or_else(|| TextSelection::at_end(text));
+        selection.clamp(text);
+        selection
+    }
+
+    /// Updates the current text cursor and selection state for this element.
+    #[inline]
+    pub(crate) fn set_text_selection(&mut self, id: ElementId, selection: TextSelection) {
+        if let Some(state) = self.elements.get_mut(&id) {
+            state.text_selection = Some(selection);
+        } else {
+            self.elements.put(
+                id,
+                ElementState {
+                    text_selection: Some(selection),
+                    ..ElementState::default()
+                },
+            );
+        }
+    }
+
+    /// Returns the index of the selected tab for this element.
+    #[inline]
+    #[must_use]
+    pub(crate) fn current_tab(&mut self, id: ElementId) -> usize {
+        self.elements
+            .get_mut(&id)
+            .map_or(0, |state| state.current_tab)
+    }
+
+    /// Set the index of the selected tab for this element.
+    #[inline]
+    pub(crate) fn set_current_tab(&mut self, id: ElementId, current_tab: usize) {
+        if let Some(state) = self.elements.get_mut(&id) {
+            state.current_tab = current_tab;
+        } else {
+            self.elements.put(
+                id,
+                ElementState {
+                    current_tab,
+                    ..ElementState::default()
+                },
+            );
+        }
+    }
+
+    /// Returns the accumulated drag offset in pixels not yet applied to this element.
+    #[inline]
+    #[must_use]
+    pub(crate) fn drag_offset(&mut self, id: ElementId) -> i32 {
+        self.elements
+            .get_mut(&id)
+            .map_or(0, |state| state.drag_offset)
+    }
+
+    /// Set the accumulated drag offset in pixels not yet applied to this element.
+    #[inline]
+    pub(crate) fn set_drag_offset(&mut self, id: ElementId, drag_offset: i32) {
+        if let Some(state) = self.elements.get_mut(&id) {
+            state.drag_offset = drag_offset;
+        } else {
+            self.elements.put(
+                id,
+                ElementState {
+                    drag_offset,
+                    ..ElementState::default()
+                },
+            );
+        }
+    }
+
+    /// Returns the content height measured the last time this element was rendered.
+    #[inline]
+    #[must_use]
+    pub(crate) fn content_height(&mut self, id: ElementId) -> i32 {
+        self.elements
+            .get_mut(&id)
+            .map_or(0, |state| state.content_height)
+    }
+
+    /// Set the content height measured while rendering this element.
+    #[inline]
+    pub(crate) fn set_content_height(&mut self, id: ElementId, content_height: i32) {
+        if let Some(state) = self.elements.get_mut(&id) {
+            state.content_height = content_height;
+        } else {
+            self.elements.put(
+                id,
+                ElementState {
+                    content_height,
+                    ..ElementState::default()
+                },
+            );
+        }
+    }
+
     /// Returns whether the current element is expanded or not.
     #[inline]
     #[must_use]
@@ -690,23 +1100,52 @@ impl UiState {
     pub(crate) fn last_width(&self) -> i32 {
         self.last_size.map(|s| s.width()).unwrap_or_default()
     }
+
+    /// Returns the bounding box of the last rendered UI element, if any.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn last_size(&self) -> Option<Rect<i32>> {
+        self.last_size
+    }
 }
 
 impl PixState {
     /// Push a new seed to the UI ID stack. Helps in generating unique widget identifiers that have
-    /// the same text label. Pushing a unique ID to the stack will seed the hash of the label.
+    /// the same text label. Pushing a unique ID to the stack will seed the hash of the label along
+    /// with every other ID already on the stack, allowing reusable widget components to be nested.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { players: Vec<String> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     for player in &self.players {
+    ///         s.push_id(player);
+    ///         s.text(player)?;
+    ///         s.same_line(None);
+    ///         if s.button("Remove")? { // Unique for each player
+    ///             // Remove player
+    ///         }
+    ///         s.pop_id();
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
     #[inline]
-    pub fn push_id<I>(&mut self, id: I)
+    pub fn push_id<H>(&mut self, id: H)
     where
-        I: TryInto<u64>,
+        H: Hash,
     {
-        self.ui.id_stack.push(id.try_into().unwrap_or(1));
+        self.ui.push_id(&id);
     }
 
-    /// Pop a seed from the UI ID stack.
+    /// Pop a seed from the UI ID stack. If the ID stack is empty, this does nothing.
     #[inline]
     pub fn pop_id(&mut self) {
-        self.ui.id_stack.pop();
+        self.ui.pop_id();
     }
 
     /// Returns the current UI rendering position.
@@ -787,7 +1226,7 @@ impl PixState {
     #[must_use]
     pub fn hovered(&self) -> bool {
         self.ui.last_size.map_or(false, |rect| {
-            !self.ui.disabled && rect.contains(self.mouse_pos())
+            !self.ui.disabled && !self.ui.blocked && rect.contains(self.mouse_pos())
         })
     }
 
@@ -812,7 +1251,10 @@ impl PixState {
     #[must_use]
     pub fn clicked(&self) -> bool {
         self.ui.last_size.map_or(false, |rect| {
-            !self.ui.disabled && self.mouse_clicked(Mouse::Left) && rect.contains(self.mouse_pos())
+            !self.ui.disabled
+                && !self.ui.blocked
+                && self.mouse_clicked(Mouse::Left)
+                && rect.contains(self.mouse_pos())
         })
     }
 
@@ -838,11 +1280,84 @@ impl PixState {
     pub fn dbl_clicked(&self) -> bool {
         self.ui.last_size.map_or(false, |rect| {
             !self.ui.disabled
+                && !self.ui.blocked
                 && self.mouse_clicked(Mouse::Left)
                 && self.mouse_dbl_clicked(Mouse::Left)
                 && rect.contains(self.mouse_pos())
         })
     }
+
+    /// Exclude the next focusable element drawn from receiving focus, so it's skipped when
+    /// cycling focus with [`Key::Tab`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { name: String };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.text_field("Name", &mut self.name)?;
+    ///     s.no_focus();
+    ///     if s.button("Help")? {
+    ///         // Help is only reachable with the mouse
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn no_focus(&mut self) {
+        self.ui.skip_focus = true;
+    }
+
+    /// Move focus to the next focusable element drawn this frame, as if [`Key::Tab`] was
+    /// pressed. Only elements drawn after calling this method are affected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { name: String };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.key_down(Key::PageDown) {
+    ///         s.focus_next();
+    ///     }
+    ///     s.text_field("First Name", &mut self.name)?;
+    ///     s.text_field("Last Name", &mut self.name)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn focus_next(&mut self) {
+        self.ui.focus_cycle = Some(FocusCycle::Next);
+    }
+
+    /// Move focus to the previous focusable element drawn this frame, as if `Shift+Tab` was
+    /// pressed. Only elements drawn after calling this method are affected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { name: String };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.key_down(Key::PageUp) {
+    ///         s.focus_previous();
+    ///     }
+    ///     s.text_field("First Name", &mut self.name)?;
+    ///     s.text_field("Last Name", &mut self.name)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn focus_previous(&mut self) {
+        self.ui.focus_cycle = Some(FocusCycle::Previous);
+    }
 }
 
 impl PixState {
@@ -878,9 +1393,12 @@ impl PixState {
         self.ui.pline_height = line_height;
         self.ui.line_height = 0;
         self.ui.last_size = Some(rect![pos, size.x(), size.y()]);
+        if let Some(group) = self.ui.group_boxes.last_mut() {
+            group.right = group.right.max(pos.x() + size.x());
+        }
     }
 
-    /// Get or create a UI texture to render to
+    /// Get or create a UI texture to render to. The texture is recreated if `dst` changes size.
     #[inline]
     pub(crate) fn get_or_create_texture<R>(
         &mut self,
@@ -893,28 +1411,32 @@ impl PixState {
     {
         let font_id = self.theme.fonts.body.id();
         let font_size = self.theme.font_size;
-        if let Some(texture) = self
-            .ui
-            .textures
-            .iter_mut()
-            .find(|t| t.element_id == id && t.font_id == font_id && t.font_size == font_size)
+        let size = (dst.width() as u32, dst.height() as u32);
+        if let Some(index) =
+            self.ui.textures.iter().position(|t| {
+                t.element_id == id && t.font_id == font_id && t.font_size == font_size
+            })
         {
-            texture.visible = true;
-            texture.dst = Some(dst);
-            Ok(texture.id)
-        } else {
-            let texture_id =
-                self.create_texture(dst.width() as u32, dst.height() as u32, PixelFormat::Rgba)?;
-            self.ui.textures.push(Texture::new(
-                texture_id,
-                id,
-                src.into(),
-                Some(dst),
-                font_id,
-                font_size,
-            ));
-            Ok(texture_id)
+            let texture = &mut self.ui.textures[index];
+            if texture.size == size {
+                texture.visible = true;
+                texture.dst = Some(dst);
+                return Ok(texture.id);
+            }
+            let texture = self.ui.textures.remove(index);
+            self.delete_texture(texture.id)?;
         }
+        let texture_id = self.create_texture(size.0, size.1, PixelFormat::Rgba)?;
+        self.ui.textures.push(Texture::new(
+            texture_id,
+            id,
+            src.into(),
+            Some(dst),
+            size,
+            font_id,
+            font_size,
+        ));
+        Ok(texture_id)
     }
 }
 
@@ -923,6 +1445,360 @@ impl PixState {
 pub(crate) struct ElementState {
     scroll: Vector<i32>,
     text_edit: String,
+    text_selection: Option<TextSelection>,
     current_tab: usize,
+    drag_offset: i32,
+    content_height: i32,
     expanded: bool,
 }
+
+/// Internal tracked state of a tab bar while its tabs are rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TabBar {
+    /// Tab bar element ID.
+    pub(crate) id: ElementId,
+    /// Position of the first tab header.
+    pub(crate) header_start: Point<i32>,
+    /// Position of the next tab header.
+    pub(crate) header_pos: Point<i32>,
+    /// Bottom of the tab headers rendered so far.
+    pub(crate) header_bottom: i32,
+    /// Maximum right edge of a tab header before wrapping.
+    pub(crate) max_x: i32,
+    /// Position of the selected tab content.
+    pub(crate) content_pos: Point<i32>,
+    /// Element IDs of the tab headers rendered so far.
+    pub(crate) tabs: Vec<ElementId>,
+    /// Index of the focused tab header, if any.
+    pub(crate) focused: Option<usize>,
+    /// Index of the clicked tab header, if any.
+    pub(crate) clicked: Option<usize>,
+    /// Position after the selected tab content, once rendered.
+    pub(crate) end_pos: Option<Point<i32>>,
+}
+
+/// Internal tracked state of a group box while its content is rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GroupBox {
+    /// Group box label.
+    pub(crate) label: String,
+    /// Top-left position of the group box.
+    pub(crate) pos: Point<i32>,
+    /// Right-most edge of the content rendered so far.
+    pub(crate) right: i32,
+}
+
+/// Internal ring buffer of recent frame times, in milliseconds.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub(crate) struct FrameTimes {
+    /// Frame the last frame time was recorded.
+    frame: Option<usize>,
+    /// Recorded frame times, oldest first.
+    values: VecDeque<f32>,
+}
+
+impl FrameTimes {
+    /// Record the frame time for `frame`, once per frame, keeping at most `len` frame times.
+    pub(crate) fn record(&mut self, frame: usize, value: f32, len: usize) {
+        if self.frame != Some(frame) {
+            self.frame = Some(frame);
+            self.values.push_back(value);
+        }
+        while self.values.len() > len {
+            self.values.pop_front();
+        }
+    }
+
+    /// Returns the recorded frame times, oldest first.
+    pub(crate) fn values(&self) -> Vec<f32> {
+        self.values.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_times_ring_buffer() {
+        let mut frame_times = FrameTimes::default();
+        frame_times.record(0, 16.0, 3);
+        frame_times.record(0, 20.0, 3);
+        assert_eq!(frame_times.values(), [16.0]);
+
+        frame_times.record(1, 17.0, 3);
+        frame_times.record(2, 18.0, 3);
+        frame_times.record(3, 19.0, 3);
+        assert_eq!(frame_times.values(), [17.0, 18.0, 19.0]);
+
+        frame_times.record(3, 19.0, 2);
+        assert_eq!(frame_times.values(), [18.0, 19.0]);
+    }
+
+    #[test]
+    fn shortcut_triggered() {
+        let mut ui = UiState::default();
+        ui.register_shortcut(Key::S, KeyMod::CTRL, "save");
+        ui.register_shortcut(Key::S, KeyMod::CTRL | KeyMod::SHIFT, "save_as");
+        ui.register_shortcut(Key::F2, KeyMod::NONE, "save");
+        assert!(!ui.shortcut_triggered("save"));
+
+        ui.keys.press(Key::S, KeyMod::CTRL, false);
+        assert!(ui.shortcut_triggered("save"));
+        assert!(!ui.shortcut_triggered("save_as"));
+        assert!(!ui.shortcut_triggered("open"));
+        ui.clear_entered();
+
+        ui.keys.press(Key::S, KeyMod::CTRL | KeyMod::SHIFT, false);
+        assert!(!ui.shortcut_triggered("save"));
+        assert!(ui.shortcut_triggered("save_as"));
+        ui.clear_entered();
+
+        ui.keys.press(Key::F2, KeyMod::NONE, false);
+        assert!(ui.shortcut_triggered("save"));
+    }
+
+    #[test]
+    fn id_stack() {
+        let mut ui = UiState::default();
+        let root = ui.get_id(&"Click");
+
+        ui.push_id(&1);
+        let first = ui.get_id(&"Click");
+        ui.id_stack.pop();
+        ui.push_id(&2);
+        let second = ui.get_id(&"Click");
+        assert_ne!(root, first);
+        assert_ne!(first, second);
+
+        // Nested IDs depend on every ID in the stack
+        ui.push_id(&"nested");
+        let nested = ui.get_id(&"Click");
+        ui.id_stack.clear();
+        ui.push_id(&1);
+        ui.push_id(&"nested");
+        assert_ne!(nested, ui.get_id(&"Click"));
+
+        ui.id_stack.clear();
+        assert_eq!(root, ui.get_id(&"Click"));
+    }
+
+    /// Render a frame of focusable elements, returning the focused element, if any.
+    fn focus_frame(
+        ui: &mut UiState,
+        theme: &Theme,
+        ids: &[ElementId],
+        skip: Option<ElementId>,
+    ) -> Option<ElementId> {
+        ui.pre_update(theme);
+        for &id in ids {
+            if skip == Some(id) {
+                ui.skip_focus = true;
+            }
+            ui.try_focus(id);
+            ui.handle_focus(id);
+        }
+        ui.post_update();
+        ids.iter().copied().find(|&id| ui.is_focused(id))
+    }
+
+    #[test]
+    fn tab_cycles_focus() {
+        let mut ui = UiState::default();
+        let theme = Theme::default();
+        let ids = [
+            ui.get_id(&"Name"),
+            ui.get_id(&"Volume"),
+            ui.get_id(&"Submit"),
+        ];
+
+        // Nothing is focused until Tab is pressed
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), None);
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[0]));
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[1]));
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[2]));
+
+        // Wraps around to the first element next frame
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), None);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[0]));
+
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[1]));
+        ui.keys.press(Key::Tab, KeyMod::SHIFT, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[0]));
+    }
+
+    #[test]
+    fn programmatic_focus() {
+        let mut ui = UiState::default();
+        let theme = Theme::default();
+        let ids = [ui.get_id(&"Name"), ui.get_id(&"Help"), ui.get_id(&"Submit")];
+        ui.focus_cycle = Some(FocusCycle::Next);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[0]));
+
+        // Excluded elements are skipped
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(
+            focus_frame(&mut ui, &theme, &ids, Some(ids[1])),
+            Some(ids[2])
+        );
+
+        ui.focus_cycle = Some(FocusCycle::Previous);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[1]));
+        ui.focus_cycle = Some(FocusCycle::Next);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), Some(ids[2]));
+
+        // Escape clears focus
+        ui.keys.press(Key::Escape, KeyMod::NONE, false);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), None);
+        assert_eq!(focus_frame(&mut ui, &theme, &ids, None), None);
+    }
+
+    #[test]
+    fn modal_blocks_elements() {
+        let mut ui = UiState::default();
+        let theme = Theme::default();
+        let button = ui.get_id(&"Button");
+        let rect = rect![0, 0, 100, 20];
+        ui.mouse.pos = point![10, 10];
+
+        // Modal rendered this frame blocks elements next frame
+        ui.modal = Some(ui.get_id(&"Modal"));
+        ui.post_update();
+        ui.pre_update(&theme);
+        assert!(!ui.try_hover(button, &rect));
+        assert!(!ui.try_focus(button));
+
+        // Clicks on elements outside the modal are ignored
+        ui.mouse.press(Mouse::Left);
+        ui.try_hover(button, &rect);
+        assert!(!ui.is_active(button));
+        ui.modal = Some(ui.get_id(&"Modal"));
+        ui.post_update();
+        ui.pre_update(&theme);
+        ui.mouse.release(Mouse::Left);
+        ui.try_hover(button, &rect);
+        assert!(!ui.was_clicked(button));
+        ui.post_update();
+        ui.pre_update(&theme);
+
+        // Elements are unblocked once the modal is no longer rendered
+        ui.post_update();
+        ui.pre_update(&theme);
+        assert!(ui.try_hover(button, &rect));
+    }
+
+    #[test]
+    fn toggle_and_select_activation() {
+        let mut ui = UiState::default();
+        let theme = Theme::default();
+        let toggle = ui.get_id(&"Toggle");
+        let radio = ui.get_id(&"Radio");
+        let rect = rect![0, 0, 100, 20];
+        let mut on = false;
+        let mut selected = 0;
+
+        // Mouse click: pressed while hovered, then released
+        ui.mouse.pos = point![10, 10];
+        ui.mouse.press(Mouse::Left);
+        ui.try_hover(toggle, &rect);
+        assert!(!ui.was_toggled(toggle, &mut on));
+        ui.post_update();
+        ui.pre_update(&theme);
+        ui.mouse.release(Mouse::Left);
+        ui.try_hover(toggle, &rect);
+        assert!(ui.was_toggled(toggle, &mut on));
+        assert!(on);
+        ui.post_update();
+        ui.pre_update(&theme);
+
+        // Enter while focused
+        ui.mouse.pos = point![200, 200];
+        ui.focus(radio);
+        ui.keys.press(Key::Return, KeyMod::NONE, false);
+        assert!(ui.was_selected(radio, &mut selected, 2));
+        assert_eq!(selected, 2);
+        ui.post_update();
+        ui.pre_update(&theme);
+
+        // Disabled elements never fire
+        ui.focus(toggle);
+        ui.keys.press(Key::Return, KeyMod::NONE, false);
+        ui.disabled = true;
+        assert!(!ui.was_toggled(toggle, &mut on));
+        assert!(on);
+    }
+
+    #[test]
+    fn routes_input_to_target_window() {
+        let mut ui = UiState::default();
+        let theme = Theme::default();
+        let button = ui.get_id(&"Button");
+        let rect = rect![0, 0, 100, 20];
+        let (primary, secondary) = (WindowId(1), WindowId(2));
+
+        // Mouse over the secondary window only hovers elements rendered to it
+        ui.set_mouse_window(secondary, point![10, 10]);
+        ui.mouse.pos = point![10, 10];
+        ui.target_window = Some(primary);
+        assert!(!ui.try_hover(button, &rect));
+        ui.target_window = Some(secondary);
+        assert!(ui.try_hover(button, &rect));
+        ui.post_update();
+        ui.pre_update(&theme);
+
+        // Last mouse position is tracked per window
+        ui.set_mouse_window(primary, point![50, 5]);
+        assert_eq!(ui.window_mouse.get(&primary), Some(&point![50, 5]));
+        assert_eq!(ui.window_mouse.get(&secondary), Some(&point![10, 10]));
+        assert_eq!(ui.hovered_window, Some(primary));
+        ui.leave_window(secondary);
+        assert_eq!(ui.hovered_window, Some(primary));
+        ui.leave_window(primary);
+        assert_eq!(ui.hovered_window, None);
+
+        // Keyboard focus only applies to elements in the focused window
+        ui.focus(button);
+        ui.focused_window = Some(primary);
+        ui.target_window = Some(secondary);
+        assert!(!ui.is_focused(button));
+        ui.target_window = Some(primary);
+        assert!(ui.is_focused(button));
+
+        ui.remove_window(primary);
+        assert_eq!(ui.focused_window, None);
+        assert_eq!(ui.window_mouse.get(&primary), None);
+    }
+
+    #[test]
+    fn touches() {
+        let mut ui = UiState::default();
+        ui.focus_window(Some(WindowId(1)));
+
+        // Down
+        ui.touch(0, TouchPoint::new(0.25, 0.5, 1.0));
+        ui.touch(1, TouchPoint::new(0.75, 0.5, 0.5));
+        assert_eq!(ui.touches.len(), 2);
+
+        // Motion
+        ui.touch(0, TouchPoint::new(0.3, 0.6, 0.8));
+        assert_eq!(ui.touches.len(), 2);
+        assert_eq!(ui.touches.get(&0), Some(&TouchPoint::new(0.3, 0.6, 0.8)));
+
+        // Up
+        ui.release_touch(0);
+        assert_eq!(ui.touches.get(&0), None);
+        assert_eq!(ui.touches.get(&1), Some(&TouchPoint::new(0.75, 0.5, 0.5)));
+
+        // Focus loss
+        ui.focus_window(Some(WindowId(2)));
+        assert_eq!(ui.touches.len(), 1);
+        ui.focus_window(None);
+        assert!(ui.touches.is_empty());
+        assert_eq!(ui.focused_window, None);
+    }
+}
@@ -5,6 +5,10 @@
 //! - [`PixState::clipboard_text`]
 //! - [`PixState::set_clipboard_text`]
 //! - [`PixState::open_url`]
+//! - [`PixState::begin_text_input`]
+//! - [`PixState::end_text_input`]
+//! - [`PixState::text_input_active`]
+//! - [`PixState::set_text_input_rect`]
 //!
 //! # Example
 //!
@@ -108,4 +112,86 @@ impl PixState {
     {
         self.renderer.open_url(url.as_ref())
     }
+
+    /// Start accepting [`Event::TextInput`] events, showing an on-screen keyboard on touch
+    /// devices or enabling an input method editor (IME) for languages such as Chinese, Japanese or
+    /// Korean, if supported by the platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { text: String };
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.begin_text_input();
+    ///     Ok(())
+    /// }
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    ///
+    /// fn on_key_typed(&mut self, s: &mut PixState, text: &str) -> PixResult<bool> {
+    ///     self.text.push_str(text);
+    ///     Ok(true)
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn begin_text_input(&mut self) {
+        self.renderer.start_text_input();
+    }
+
+    /// Stop accepting [`Event::TextInput`] events, hiding any on-screen keyboard or input method
+    /// editor (IME).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.button("Done")? {
+    ///         s.end_text_input();
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn end_text_input(&mut self) {
+        self.renderer.stop_text_input();
+    }
+
+    /// Returns whether [`Event::TextInput`] events are enabled.
+    #[inline]
+    #[must_use]
+    pub fn text_input_active(&self) -> bool {
+        self.renderer.text_input_active()
+    }
+
+    /// Set the rectangle, in window coordinates, where text is being entered, so an on-screen
+    /// keyboard or input method editor (IME) candidate list can be positioned nearby without
+    /// covering it. [`PixState::text_field`] sets this while focused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { input: Rect<i32>, text: String };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.set_text_input_rect(self.input);
+    ///     s.rect(self.input)?;
+    ///     s.text(&self.text)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_text_input_rect<R>(&mut self, rect: R)
+    where
+        R: Into<Rect<i32>>,
+    {
+        self.renderer.set_text_input_rect(rect.into());
+    }
 }
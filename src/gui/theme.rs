@@ -20,7 +20,8 @@
 //! # }
 //! ```
 
-use crate::prelude::*;
+use crate::{prelude::*, renderer::Rendering};
+use log::warn;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(not(target_arch = "wasm32"))]
@@ -33,8 +34,8 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-/// A hashed identifier for internal state management.
-pub(crate) type FontId = u64;
+/// A hashed identifier for a [Font] family.
+pub type FontId = u64;
 
 /// A builder to generate custom [Theme]s.
 ///
@@ -91,7 +92,25 @@ pub struct ThemeBuilder {
 
 impl Default for ThemeBuilder {
     fn default() -> Self {
-        let theme = Theme::default();
+        Self::from(Theme::default())
+    }
+}
+
+/// Start building from an existing [Theme], overriding only the values that need to change.
+///
+/// # Example
+///
+/// ```
+/// # use pix_engine::prelude::*;
+/// use pix_engine::gui::theme::*;
+/// let theme = ThemeBuilder::from(Theme::light())
+///     .color(ColorType::Primary, Color::INDIGO)
+///     .build();
+/// assert_eq!(theme.colors.primary, Color::INDIGO);
+/// assert_eq!(theme.colors.background, Theme::light().colors.background);
+/// ```
+impl From<Theme> for ThemeBuilder {
+    fn from(theme: Theme) -> Self {
         Self {
             name: theme.name,
             fonts: theme.fonts,
@@ -201,6 +220,12 @@ impl ThemeBuilder {
         self
     }
 
+    /// Set all theme [Colors] at once.
+    pub fn colors(&mut self, colors: Colors) -> &mut Self {
+        self.colors = colors;
+        self
+    }
+
     /// Set element padding space.
     pub fn spacing(&mut self, spacing: Spacing) -> &mut Self {
         self.spacing = spacing;
@@ -436,6 +461,16 @@ pub struct Colors {
 
 impl Colors {
     /// A dark color theme.
+    ///
+    /// [WCAG contrast ratios](Color::contrast_ratio) of each text color over the color it's
+    /// rendered on, where `4.5` is the minimum recommended for body text and `3.0` for large text:
+    ///
+    /// - `on_background` over `background` and `on_surface` over `surface`: `18.73`
+    /// - `on_primary` over `primary`: `3.75`
+    /// - `on_primary` over `primary_variant`: `7.61`
+    /// - `on_secondary` over `secondary`: `6.07`
+    /// - `on_secondary` over `secondary_variant`: `12.00`
+    /// - `on_error` over `error`: `5.83`
     #[allow(clippy::unreadable_literal)]
     pub const fn dark() -> Self {
         Self {
@@ -455,6 +490,16 @@ impl Colors {
     }
 
     /// A light color theme.
+    ///
+    /// [WCAG contrast ratios](Color::contrast_ratio) of each text color over the color it's
+    /// rendered on, where `4.5` is the minimum recommended for body text and `3.0` for large text:
+    ///
+    /// - `on_background` over `background` and `on_surface` over `surface`: `21.00`
+    /// - `on_primary` over `primary`: `5.32`
+    /// - `on_primary` over `primary_variant`: `2.44`
+    /// - `on_secondary` over `secondary`: `11.54`
+    /// - `on_secondary` over `secondary_variant`: `5.01`
+    /// - `on_error` over `error`: `7.33`
     #[allow(clippy::unreadable_literal)]
     pub const fn light() -> Self {
         Self {
@@ -636,7 +681,7 @@ impl Theme {
         ThemeBuilder::default()
     }
 
-    /// Constructs a default dark `Theme`.
+    /// Constructs a default dark `Theme`. See [`Colors::dark`] for contrast ratios.
     #[inline]
     pub fn dark() -> Self {
         Self {
@@ -649,7 +694,7 @@ impl Theme {
         }
     }
 
-    /// Constructs a default light `Theme`.
+    /// Constructs a default light `Theme`. See [`Colors::light`] for contrast ratios.
     #[inline]
     pub fn light() -> Self {
         Self {
@@ -661,6 +706,13 @@ impl Theme {
             spacing: Spacing::default(),
         }
     }
+
+    /// Whether rendering text with `other` requires different fonts than this `Theme`.
+    fn fonts_differ(&self, other: &Self) -> bool {
+        self.fonts != other.fonts
+            || self.font_size != other.font_size
+            || self.styles != other.styles
+    }
 }
 
 impl PixState {
@@ -676,12 +728,136 @@ impl PixState {
         &mut self.theme
     }
 
-    /// Sets a new theme.
+    /// Sets a new theme, replacing the current colors, fonts, and spacing at once. Widgets drawn
+    /// after calling this use the new theme immediately, even within the same frame.
+    ///
+    /// If the new theme's body font fails to load, a warning is logged. Use
+    /// [`PixState::try_set_theme`] to handle the error instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { dark: bool };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if event.key == Key::F2 {
+    ///         self.dark = !self.dark;
+    ///         s.set_theme(if self.dark { Theme::dark() } else { Theme::light() });
+    ///         return Ok(true);
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
     #[inline]
     pub fn set_theme(&mut self, theme: Theme) {
+        if let Err(err) = self.try_set_theme(theme) {
+            warn!("Failed to load theme font: {:?}", err);
+        }
+    }
+
+    /// Sets a new theme like [`PixState::set_theme`], returning an error if the new theme's body
+    /// font fails to load.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to load the new theme's body font, then an error is returned. The
+    /// rest of the theme is still applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// use pix_engine::gui::theme::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let theme = ThemeBuilder::from(Theme::light())
+    ///         .font_size(14)
+    ///         .build();
+    ///     s.try_set_theme(theme)?;
+    ///     Ok(())
+    /// }
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// ```
+    pub fn try_set_theme(&mut self, theme: Theme) -> PixResult<()> {
+        let fonts_differ = self.theme.fonts_differ(&theme);
         self.theme = theme;
         let colors = self.theme.colors;
         self.background(colors.background);
         self.fill(colors.on_background());
+        if fonts_differ {
+            let (size, style, font) = (
+                self.theme.font_size,
+                self.theme.styles.body,
+                self.theme.fonts.body.clone(),
+            );
+            self.font_size(size)?;
+            self.font_style(style);
+            self.font_family(font)?;
+            // Cached text is keyed without its style, so it must be re-rendered
+            self.renderer.clear_text_cache();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_builder_overrides() {
+        let theme = ThemeBuilder::from(Theme::light())
+            .color(ColorType::Primary, Color::INDIGO)
+            .build();
+        let light = Theme::light();
+        assert_eq!(theme.colors.primary, Color::INDIGO);
+        assert_eq!(theme.colors.background, light.colors.background);
+        assert_eq!(theme.fonts, light.fonts);
+        assert_eq!(theme.spacing, light.spacing);
+        assert_eq!(theme.name, light.name);
+    }
+
+    #[test]
+    fn theme_fonts_differ() {
+        let dark = Theme::dark();
+        // Text cache doesn't need clearing when only colors change
+        assert!(!dark.fonts_differ(&Theme::light()));
+        let theme = ThemeBuilder::from(dark.clone()).font_size(16).build();
+        assert!(dark.fonts_differ(&theme));
+        let theme = ThemeBuilder::from(dark.clone())
+            .font(FontType::Body, Font::NOTO, FontStyle::BOLD)
+            .build();
+        assert!(dark.fonts_differ(&theme));
+    }
+
+    #[test]
+    fn theme_contrast_ratios() {
+        let round = |ratio: f64| (ratio * 100.0).round() / 100.0;
+        let c = Colors::dark();
+        assert_eq!(round(c.on_background.contrast_ratio(c.background)), 18.73);
+        assert_eq!(round(c.on_primary.contrast_ratio(c.primary)), 3.75);
+        assert_eq!(round(c.on_primary.contrast_ratio(c.primary_variant)), 7.61);
+        assert_eq!(round(c.on_secondary.contrast_ratio(c.secondary)), 6.07);
+        assert_eq!(
+            round(c.on_secondary.contrast_ratio(c.secondary_variant)),
+            12.0
+        );
+        assert_eq!(round(c.on_error.contrast_ratio(c.error)), 5.83);
+
+        let c = Colors::light();
+        assert_eq!(round(c.on_background.contrast_ratio(c.background)), 21.0);
+        assert_eq!(round(c.on_primary.contrast_ratio(c.primary)), 5.32);
+        assert_eq!(round(c.on_primary.contrast_ratio(c.primary_variant)), 2.44);
+        assert_eq!(round(c.on_secondary.contrast_ratio(c.secondary)), 11.54);
+        assert_eq!(
+            round(c.on_secondary.contrast_ratio(c.secondary_variant)),
+            5.01
+        );
+        assert_eq!(round(c.on_error.contrast_ratio(c.error)), 7.33);
     }
 }
@@ -0,0 +1,152 @@
+//! Tooltip state management.
+
+use crate::prelude::*;
+use std::time::Duration;
+use web_time::Instant;
+
+/// Default time the mouse must hover an element before its tooltip is shown.
+pub(crate) const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// Distance in pixels between the mouse and a tooltip.
+pub(crate) const TOOLTIP_OFFSET: i32 = 15;
+
+/// Tracks how long the element a tooltip is attached to has been hovered.
+#[derive(Debug)]
+pub(crate) struct TooltipState {
+    /// Time the mouse must hover an element before its tooltip is shown.
+    pub(crate) delay: Duration,
+    /// Bounds of the hovered element and when hovering began.
+    hover: Option<(Rect<i32>, Instant)>,
+    /// Whether the hovered element was rendered this frame.
+    seen: bool,
+}
+
+impl Default for TooltipState {
+    fn default() -> Self {
+        Self {
+            delay: TOOLTIP_DELAY,
+            hover: None,
+            seen: false,
+        }
+    }
+}
+
+impl TooltipState {
+    /// Update hover state for an element with bounds `rect` at time `now`. Returns whether its
+    /// tooltip should be shown.
+    pub(crate) fn update(&mut self, rect: Rect<i32>, hovered: bool, now: Instant) -> bool {
+        let since = match self.hover {
+            Some((hover, since)) if hover == rect => {
+                if !hovered {
+                    self.hover = None;
+                    return false;
+                }
+                since
+            }
+            _ if hovered => {
+                self.hover = Some((rect, now));
+                now
+            }
+            _ => return false,
+        };
+        self.seen = true;
+        now.saturating_duration_since(since) >= self.delay
+    }
+
+    /// Handle state changes at the end of a frame, resetting the hover time if the hovered
+    /// element wasn't rendered.
+    #[inline]
+    pub(crate) fn end_frame(&mut self) {
+        if !self.seen {
+            self.hover = None;
+        }
+        self.seen = false;
+    }
+}
+
+/// Position a tooltip of a given `size` next to `mouse`, flipping it to the other side of the
+/// mouse when it would extend past the right or bottom of the window, and clamping it within the
+/// window.
+pub(crate) fn tooltip_rect(mouse: Point<i32>, size: Point<i32>, window: Point<i32>) -> Rect<i32> {
+    let place = |mouse: i32, size: i32, window: i32| {
+        let pos = mouse + TOOLTIP_OFFSET;
+        let pos = if pos + size > window {
+            mouse - TOOLTIP_OFFSET - size
+        } else {
+            pos
+        };
+        pos.min(window - size).max(0)
+    };
+    rect![
+        place(mouse.x(), size.x(), window.x()),
+        place(mouse.y(), size.y(), window.y()),
+        size.x(),
+        size.y()
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tooltip_hover_delay() {
+        let mut tooltip = TooltipState::default();
+        let widget = rect![0, 0, 100, 20];
+        let other = rect![0, 30, 100, 20];
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        assert!(!tooltip.update(widget, true, at(0)));
+        tooltip.end_frame();
+        assert!(!tooltip.update(widget, true, at(499)));
+        tooltip.end_frame();
+        assert!(tooltip.update(widget, true, at(500)));
+        tooltip.end_frame();
+
+        // Hides immediately when the mouse leaves, and the delay restarts
+        assert!(!tooltip.update(widget, false, at(600)));
+        tooltip.end_frame();
+        assert!(!tooltip.update(widget, true, at(700)));
+        tooltip.end_frame();
+        assert!(tooltip.update(widget, true, at(1200)));
+        tooltip.end_frame();
+
+        // Hovering another element restarts the delay
+        assert!(!tooltip.update(widget, false, at(1300)));
+        assert!(!tooltip.update(other, true, at(1300)));
+        tooltip.end_frame();
+        assert!(!tooltip.update(other, true, at(1700)));
+        tooltip.end_frame();
+
+        // Delay restarts if the hovered element stops being rendered
+        tooltip.end_frame();
+        assert!(!tooltip.update(other, true, at(2000)));
+    }
+
+    #[test]
+    fn tooltip_rect_within_window() {
+        let size = point![100, 40];
+        let window = point![400, 300];
+
+        // Offset from the mouse
+        assert_eq!(
+            tooltip_rect(point![10, 10], size, window),
+            rect![25, 25, 100, 40]
+        );
+        // Flips to the other side of the mouse near the right and bottom edges
+        assert_eq!(
+            tooltip_rect(point![350, 280], size, window),
+            rect![235, 225, 100, 40]
+        );
+        // Clamped when it doesn't fit on either side
+        assert_eq!(
+            tooltip_rect(point![50, 10], point![380, 40], window),
+            rect![0, 25, 380, 40]
+        );
+        assert_eq!(
+            tooltip_rect(point![50, 10], point![500, 40], window),
+            rect![0, 25, 500, 40]
+        );
+    }
+}
@@ -3,6 +3,9 @@
 //! Provided [`PixState`] methods:
 //!
 //! - [`PixState::button`]
+//! - [`PixState::toggle_button`]
+//! - [`PixState::image_button`]
+//! - [`PixState::image_button_labeled`]
 //! - [`PixState::checkbox`]
 //! - [`PixState::radio`]
 //!
@@ -27,7 +30,13 @@
 
 use crate::{gui::Direction, ops::clamp_size, prelude::*};
 
+pub mod color_picker;
+pub mod drag_drop;
 pub mod field;
+pub mod menu;
+pub mod modal;
+pub mod plot;
+pub mod progress;
 pub mod select;
 pub mod slider;
 pub mod text;
@@ -55,21 +64,108 @@ impl PixState {
     /// # }
     /// ```
     pub fn button<L>(&mut self, label: L) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+    {
+        self.button_widget(label.as_ref(), None)
+    }
+
+    /// Draw a button to the current canvas that toggles `on` when clicked and stays pressed while
+    /// `on` is `true`, useful for toolbars. Returns `true` when clicked.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { bold: bool, italic: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.toggle_button("Bold", &mut self.bold)?;
+    ///     s.same_line(None);
+    ///     s.toggle_button("Italic", &mut self.italic)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn toggle_button<L>(&mut self, label: L, on: &mut bool) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+    {
+        self.button_widget(label.as_ref(), Some(on))
+    }
+
+    /// Draw a button displaying a texture with a given `size` to the current canvas that returns
+    /// `true` when clicked.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, or the texture is invalid, then
+    /// an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { icon: TextureId };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.image_button(self.icon, [32, 32])? {
+    ///         // was clicked
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn image_button(&mut self, texture_id: TextureId, size: [u32; 2]) -> PixResult<bool> {
+        self.image_button_labeled("", texture_id, size)
+    }
+
+    /// Draw a button displaying a texture with a given `size` and a `label` below it to the current
+    /// canvas that returns `true` when clicked.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, or the texture is invalid, then
+    /// an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { sword: TextureId };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.image_button_labeled("Sword", self.sword, [48, 48])? {
+    ///         // was clicked
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn image_button_labeled<L>(
+        &mut self,
+        label: L,
+        texture_id: TextureId,
+        size: [u32; 2],
+    ) -> PixResult<bool>
     where
         L: AsRef<str>,
     {
         let label = label.as_ref();
 
         let s = self;
-        let id = s.ui.get_id(&label);
+        let id = s.ui.get_id(&(label, texture_id));
         let label = s.ui.get_label(label);
         let pos = s.cursor_pos();
         let fpad = s.theme.spacing.frame_pad;
 
         // Calculate button size
-        let (label_width, label_height) = s.text_size(label)?;
-        let width = s.ui.next_width.take().unwrap_or(label_width);
-        let button = rect![pos, width, label_height].offset_size(2 * fpad);
+        let [width, height] = size.map(clamp_size);
+        let button = rect![pos, width, height].offset_size(2 * fpad);
 
         // Check hover/active/keyboard focus
         let hovered = s.focused() && s.ui.try_hover(id, &button);
@@ -79,38 +175,40 @@ impl PixState {
         let disabled = s.ui.disabled;
         let active = s.ui.is_active(id);
 
-        s.push();
-        s.ui.push_cursor();
+        s.push_screen_space();
 
         // Render
         s.rect_mode(RectMode::Corner);
         if hovered {
             s.frame_cursor(&Cursor::hand())?;
         }
-        let [stroke, bg, fg] = s.widget_colors(id, ColorType::Primary);
+        let [stroke, bg, _] = s.widget_colors(id, ColorType::Primary);
         s.stroke(stroke);
         s.fill(bg);
-        if active {
-            s.rect(button.offset([1, 1]))?;
+        let button = if active {
+            button.offset([1, 1])
         } else {
-            s.rect(button)?;
-        }
+            button
+        };
+        s.rect(button)?;
 
-        // Button text
-        s.rect_mode(RectMode::Center);
-        s.clip(button)?;
-        s.set_cursor_pos(button.center());
-        s.stroke(None);
-        s.fill(fg);
-        s.text(label)?;
-        s.clip(None)?;
+        // Button image
+        let image = rect![button.top_left() + fpad, width, height];
+        s.texture(texture_id, None, image)?;
+
+        // Button label
+        s.advance_cursor(button.size());
+        if !label.is_empty() {
+            let [_, _, fg] = s.widget_colors(id, ColorType::Background);
+            s.stroke(None);
+            s.fill(fg);
+            s.text(label)?;
+        }
 
-        s.ui.pop_cursor();
         s.pop();
 
         // Process input
         s.ui.handle_focus(id);
-        s.advance_cursor(button.size());
         Ok(!disabled && s.ui.was_clicked(id))
     }
 
@@ -147,7 +245,7 @@ impl PixState {
         let pad = s.theme.spacing.item_pad;
 
         // Calculate button size
-        let (width, height) = s.text_size(text)?;
+        let (width, height) = s.padded_text_size(text)?;
         let bounding_box = rect![pos, width, height].grow(pad / 2);
 
         // Check hover/active/keyboard focus
@@ -156,7 +254,7 @@ impl PixState {
         let disabled = s.ui.disabled;
         let active = s.ui.is_active(id);
 
-        s.push();
+        s.push_screen_space();
 
         // Render
         if hovered {
@@ -213,7 +311,7 @@ impl PixState {
         let id = s.ui.get_id(&label);
         let label = s.ui.get_label(label);
         let pos = s.cursor_pos();
-        let (_, checkbox_size) = s.text_size(label)?;
+        let (_, checkbox_size) = s.padded_text_size(label)?;
 
         // Calculate checkbox rect
         let checkbox = square![pos, checkbox_size];
@@ -223,9 +321,8 @@ impl PixState {
         if s.focused() {
             s.ui.try_focus(id);
         }
-        let disabled = s.ui.disabled;
 
-        s.push();
+        s.push_screen_space();
 
         // Checkbox
         s.rect_mode(RectMode::Corner);
@@ -259,19 +356,13 @@ impl PixState {
 
         // Label
         s.same_line(None);
+        s.push_screen_space();
         s.text(label)?;
+        s.pop();
 
         // Process input
         s.ui.handle_focus(id);
-        if disabled {
-            Ok(false)
-        } else {
-            let clicked = s.ui.was_clicked(id);
-            if clicked {
-                *checked = !(*checked);
-            }
-            Ok(clicked)
-        }
+        Ok(s.ui.was_toggled(id, checked))
     }
 
     /// Draw a set of radio buttons to the current canvas.
@@ -304,7 +395,7 @@ impl PixState {
         let id = s.ui.get_id(&label);
         let label = s.ui.get_label(label);
         let pos = s.cursor_pos();
-        let (_, label_height) = s.text_size(label)?;
+        let (_, label_height) = s.padded_text_size(label)?;
         let radio_size = label_height / 2;
 
         // Calculate radio rect
@@ -315,9 +406,8 @@ impl PixState {
         if s.focused() {
             s.ui.try_focus(id);
         }
-        let disabled = s.ui.disabled;
 
-        s.push();
+        s.push_screen_space();
 
         // Radio
         s.rect_mode(RectMode::Corner);
@@ -350,19 +440,13 @@ impl PixState {
 
         // Label
         s.same_line(None);
+        s.push_screen_space();
         s.text(label)?;
+        s.pop();
 
         // Process input
         s.ui.handle_focus(id);
-        if disabled {
-            Ok(false)
-        } else {
-            let clicked = s.ui.was_clicked(id);
-            if clicked {
-                *selected = index;
-            }
-            Ok(clicked)
-        }
+        Ok(s.ui.was_selected(id, selected, index))
     }
 
     /// Render an arrow aligned with the current font size.
@@ -410,3 +494,69 @@ impl PixState {
         Ok(())
     }
 }
+
+impl PixState {
+    /// Draw a button that returns `true` when clicked, toggling and staying pressed while
+    /// `toggled` is `Some(true)`.
+    fn button_widget(&mut self, label: &str, toggled: Option<&mut bool>) -> PixResult<bool> {
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let pos = s.cursor_pos();
+        let fpad = s.theme.spacing.frame_pad;
+
+        // Calculate button size
+        let (label_width, label_height) = s.padded_text_size(label)?;
+        let width = s.ui.next_width.take().unwrap_or(label_width);
+        let button = rect![pos, width, label_height].offset_size(2 * fpad);
+
+        // Check hover/active/keyboard focus
+        let hovered = s.focused() && s.ui.try_hover(id, &button);
+        if s.focused() {
+            s.ui.try_focus(id);
+        }
+        let active = s.ui.is_active(id);
+        let on = matches!(toggled.as_deref(), Some(true));
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Render
+        s.rect_mode(RectMode::Corner);
+        if hovered {
+            s.frame_cursor(&Cursor::hand())?;
+        }
+        let [stroke, bg, fg] = if on {
+            s.widget_colors(id, ColorType::PrimaryVariant)
+        } else {
+            s.widget_colors(id, ColorType::Primary)
+        };
+        s.stroke(stroke);
+        s.fill(bg);
+        if active || on {
+            s.rect(button.offset([1, 1]))?;
+        } else {
+            s.rect(button)?;
+        }
+
+        // Button text
+        s.rect_mode(RectMode::Center);
+        s.clip(button)?;
+        s.set_cursor_pos(button.center());
+        s.stroke(None);
+        s.fill(fg);
+        s.text(label)?;
+        s.clip(None)?;
+
+        s.ui.pop_cursor();
+        s.pop();
+
+        // Process input
+        s.ui.handle_focus(id);
+        s.advance_cursor(button.size());
+        match toggled {
+            Some(on) => Ok(s.ui.was_toggled(id, on)),
+            None => Ok(!s.ui.disabled && s.ui.was_clicked(id)),
+        }
+    }
+}
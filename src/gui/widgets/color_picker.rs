@@ -0,0 +1,182 @@
+//! Color picker widget rendering methods.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::color_picker`]
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { color: Color };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     s.color_picker("Color", &mut self.color)?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::prelude::*;
+use std::{borrow::Cow, str::FromStr};
+
+const HEX_LABEL: &str = "Hex";
+
+impl PixState {
+    /// Draw a color swatch to the current canvas that expands into a color picker with hue,
+    /// saturation, and brightness sliders and a hexadecimal input field when clicked. Returns
+    /// `true` when `color` is modified.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { color: Color };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.color_picker("Color", &mut self.color)? {
+    ///         // color changed
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn color_picker<L>(&mut self, label: L, color: &mut Color) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+    {
+        let label = label.as_ref();
+
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let pos = s.cursor_pos();
+        let (_, swatch_size) = s.padded_text_size(label)?;
+
+        // Calculate swatch rect
+        let swatch = rect![pos, 2 * swatch_size, swatch_size];
+
+        // Check hover/active/keyboard focus
+        let hovered = s.focused() && s.ui.try_hover(id, &swatch);
+        if s.focused() {
+            s.ui.try_focus(id);
+        }
+        let disabled = s.ui.disabled;
+
+        s.push_screen_space();
+
+        // Swatch
+        s.rect_mode(RectMode::Corner);
+        if hovered {
+            s.frame_cursor(&Cursor::hand())?;
+        }
+        let [stroke, ..] = s.widget_colors(id, ColorType::Background);
+        s.stroke(stroke);
+        s.fill(*color);
+        s.rect(swatch)?;
+        s.advance_cursor(swatch.size());
+        s.pop();
+
+        // Label
+        s.same_line(None);
+        s.push_screen_space();
+        s.text(label)?;
+        s.pop();
+
+        // Process input
+        s.ui.handle_focus(id);
+        let expanded = s.ui.expanded(id);
+        if !disabled && s.ui.was_clicked(id) {
+            s.ui.set_expanded(id, !expanded);
+        }
+        if s.ui.expanded(id) {
+            s.push_id(id);
+            let changed = s.color_picker_controls(color);
+            s.pop_id();
+            changed
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl PixState {
+    /// Draw the hue, saturation, and brightness sliders and hexadecimal field of an expanded
+    /// color picker.
+    fn color_picker_controls(&mut self, color: &mut Color) -> PixResult<bool> {
+        let s = self;
+        let format_value: fn(&f64) -> Cow<'static, str> = |value| format!("{value:.0}").into();
+
+        let mut changed = false;
+        let mut hue = color.hue();
+        if s.advanced_slider("H", &mut hue, 0.0, 360.0, Some(format_value))? {
+            color.set_hue(hue);
+            changed = true;
+        }
+        let mut saturation = color.saturation();
+        if s.advanced_slider("S", &mut saturation, 0.0, 100.0, Some(format_value))? {
+            color.set_saturation(saturation);
+            changed = true;
+        }
+        let mut brightness = color.brightness();
+        if s.advanced_slider("B", &mut brightness, 0.0, 100.0, Some(format_value))? {
+            color.set_brightness(brightness);
+            changed = true;
+        }
+
+        // Keep partially typed hex values while editing, otherwise display the current color
+        let hex_id = s.ui.get_id(&HEX_LABEL);
+        let mut hex = if s.ui.is_focused(hex_id) {
+            s.ui.text_edit(hex_id, hex_string(*color))
+        } else {
+            hex_string(*color)
+        };
+        if s.advanced_text_field(
+            HEX_LABEL,
+            "#RRGGBB",
+            &mut hex,
+            Some(|c| c.is_ascii_hexdigit() || c == '#'),
+        )? {
+            if let Ok(new_color) = Color::from_str(&hex) {
+                if new_color != *color {
+                    *color = new_color;
+                    changed = true;
+                }
+            }
+        }
+        s.ui.set_text_edit(hex_id, hex);
+
+        Ok(changed)
+    }
+}
+
+/// Format a [Color] as a `#RRGGBB` hexadecimal string, or `#RRGGBBAA` if it's not opaque.
+fn hex_string(color: Color) -> String {
+    let [r, g, b, a] = color.channels();
+    if a == 255 {
+        format!("#{r:02X}{g:02X}{b:02X}")
+    } else {
+        format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_string_round_trip() -> PixResult<()> {
+        let color = Color::rgb(240, 5, 191);
+        assert_eq!(hex_string(color), "#F005BF");
+        assert_eq!(Color::from_str(&hex_string(color))?, color);
+
+        let color = Color::rgba(0, 128, 255, 64);
+        assert_eq!(hex_string(color), "#0080FF40");
+        assert_eq!(Color::from_str(&hex_string(color))?, color);
+        Ok(())
+    }
+}
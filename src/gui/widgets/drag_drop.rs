@@ -0,0 +1,217 @@
+//! Drag and drop rendering methods.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::drag_source`]
+//! - [`PixState::drop_target`]
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { inventory: Vec<String>, equipped: Vec<String> };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     for (i, item) in self.inventory.iter().enumerate() {
+//!         s.text(item)?;
+//!         s.drag_source(item, i, |s: &mut PixState| {
+//!             s.text(item)?;
+//!             Ok(())
+//!         })?;
+//!     }
+//!     let dropped = s.drop_target("Equipped", |s: &mut PixState, _: Option<&usize>| {
+//!         for item in &self.equipped {
+//!             s.text(item)?;
+//!         }
+//!         Ok(())
+//!     })?;
+//!     if let Some(i) = dropped {
+//!         self.equipped.push(self.inventory.remove(i));
+//!     }
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::prelude::*;
+use std::any::Any;
+
+const PREVIEW_LABEL: &str = "##drag preview";
+
+impl PixState {
+    /// Make the previously drawn element a drag source for `payload`. Dragging begins once the
+    /// element is pressed and the mouse moves a few pixels, after which the preview drawn by `f`
+    /// follows the mouse until released. Returns whether this drag source is being dragged.
+    ///
+    /// `payload` is delivered to the [`PixState::drop_target`] accepting its type that it's
+    /// released over. Releasing anywhere else cancels the drag.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.button("Sword")?;
+    ///     s.drag_source("Sword", String::from("Sword"), |s: &mut PixState| {
+    ///         s.text("Sword")?;
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn drag_source<L, T, F>(&mut self, label: L, payload: T, f: F) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+        T: Any,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let label = label.as_ref();
+
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let Some(rect) = s.ui.last_size() else {
+            return Ok(false);
+        };
+
+        // Check drag start, in window coordinates
+        if !s.ui.disabled && !s.ui.blocked && s.ui.mouse.is_down(Mouse::Left) {
+            let offset = s.ui.mouse_offset.unwrap_or_default();
+            s.ui.drag
+                .try_begin(id, rect.offset(offset), s.ui.mouse.pos, payload);
+        }
+        let Some(drag) = s.ui.drag.payload() else {
+            return Ok(false);
+        };
+        let (grab, size) = (drag.grab, drag.size);
+        if !s.ui.drag.is_dragging(id) {
+            return Ok(false);
+        }
+
+        // Preview follows the mouse
+        let mouse = s.ui.mouse.pos;
+        let preview = rect![
+            mouse.x() - grab.x(),
+            mouse.y() - grab.y(),
+            size.x(),
+            size.y()
+        ];
+        s.frame_cursor(&Cursor::hand())?;
+
+        s.push_id(id);
+        let preview_id = s.ui.get_id(&PREVIEW_LABEL);
+        s.pop_id();
+        let texture_id = s.get_or_create_texture(preview_id, None, preview)?;
+
+        // Preview contents are always drawn in screen space
+        s.push_screen_space();
+        s.set_texture_target(texture_id)?;
+        s.ui.push_cursor();
+        let [stroke, bg, fg] = s.widget_colors(preview_id, ColorType::Surface);
+        s.background(bg);
+        s.rect_mode(RectMode::Corner);
+        s.stroke(stroke);
+        s.fill(None);
+        s.rect([0, 0, size.x(), size.y()])?;
+        s.set_cursor_pos(s.theme.spacing.item_pad);
+        s.stroke(None);
+        s.fill(fg);
+        let result = f(s);
+        s.ui.pop_cursor();
+        s.clear_texture_target();
+        s.pop();
+        result?;
+
+        Ok(true)
+    }
+
+    /// Draw a drop target containing the elements drawn by `f`, which is passed the payload being
+    /// dragged if it is of type `T`. The drop target is highlighted while hovered by a payload of
+    /// type `T`, and returns the payload when released over it. Labels of elements drawn within
+    /// the closure are scoped to this drop target.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { equipped: Option<String> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let dropped = s.drop_target("Weapon", |s: &mut PixState, dragged: Option<&String>| {
+    ///         match (&self.equipped, dragged) {
+    ///             (_, Some(weapon)) => s.text(format!("Equip {weapon}?"))?,
+    ///             (Some(weapon), None) => s.text(weapon)?,
+    ///             (None, None) => s.text("Drop a weapon here")?,
+    ///         };
+    ///         Ok(())
+    ///     })?;
+    ///     if dropped.is_some() {
+    ///         self.equipped = dropped;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn drop_target<L, T, F>(&mut self, label: L, f: F) -> PixResult<Option<T>>
+    where
+        L: AsRef<str>,
+        T: Any,
+        F: FnOnce(&mut PixState, Option<&T>) -> PixResult<()>,
+    {
+        let label = label.as_ref();
+
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let pos = s.cursor_pos();
+        let width = s.ui_width()?;
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Lend the dragged payload to the drop target contents
+        let payload = s.ui.drag.take_payload();
+        let dragged = payload
+            .as_ref()
+            .and_then(|payload| payload.downcast_ref::<T>());
+        s.push_id(id);
+        let result = f(s, dragged);
+        s.pop_id();
+        let compatible = dragged.is_some();
+        s.ui.drag.restore(payload);
+        result?;
+
+        let height = (s.cursor_pos().y() - ipad.y() - pos.y()).max(0);
+        let target = rect![pos, width, height];
+        let hovered =
+            compatible && !s.ui.disabled && !s.ui.blocked && target.contains(s.mouse_pos());
+
+        // Highlight
+        if hovered {
+            s.push_screen_space();
+            s.rect_mode(RectMode::Corner);
+            s.stroke(colors.primary);
+            s.fill(None);
+            s.rect(target.grow([1, 1]))?;
+            s.pop();
+        }
+
+        // Process drop, in window coordinates
+        if hovered && !s.ui.mouse.is_down(Mouse::Left) {
+            let offset = s.ui.mouse_offset.unwrap_or_default();
+            Ok(s.ui
+                .drag
+                .try_drop(target.offset(offset), s.ui.mouse.pos, true))
+        } else {
+            Ok(None)
+        }
+    }
+}
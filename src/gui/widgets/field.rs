@@ -36,7 +36,16 @@
 //! # }
 //! ```
 
-use crate::{gui::MOD_CTRL, ops::clamp_size, prelude::*};
+use crate::{
+    gui::{
+        selection::{index_at, TextSelection},
+        MOD_CTRL,
+    },
+    ops::clamp_size,
+    prelude::*,
+    renderer::Rendering,
+};
+use std::borrow::Cow;
 
 const TEXT_CURSOR: &str = "_";
 
@@ -115,7 +124,7 @@ impl PixState {
             s.ui.next_width
                 .take()
                 .unwrap_or_else(|| s.ui_width().unwrap_or(100));
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         let [mut x, y] = pos.coords();
         if !label.is_empty() {
             x += label_width + ipad.x();
@@ -127,7 +136,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let disabled = s.ui.disabled;
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Label
@@ -148,20 +157,70 @@ impl PixState {
 
         // Text
         let clip = input.shrink(ipad);
-        let (text_width, text_height) = s.text_size(value)?;
-        let (cursor_width, _) = s.text_size(TEXT_CURSOR)?;
-        let width = text_width + cursor_width;
-        let (mut x, y) = (clip.x(), input.center().y() - text_height / 2);
-        if width > clip.width() {
-            x -= width - clip.width();
+        let mut selection = s.ui.text_selection(id, value);
+        if !focused {
+            selection.move_to(selection.cursor, false);
         }
 
+        // Text being composed by an input method is shown at the cursor until it's committed
+        let composition = if focused {
+            s.ui.keys.composition.clone()
+        } else {
+            None
+        };
+        let (text, preedit) = match &composition {
+            Some(composition) => composition.insert_into(value, selection.cursor),
+            None => (
+                Cow::Borrowed(value.as_str()),
+                selection.cursor..selection.cursor,
+            ),
+        };
+        let cursor = composition
+            .as_ref()
+            .map_or(selection.cursor, |composition| {
+                preedit.start + composition.cursor
+            });
+        if focused {
+            let offset = s.ui.mouse_offset.unwrap_or_default();
+            s.set_text_input_rect(input.offset(offset));
+        }
+
+        let (_, text_height) = s.padded_text_size(&text)?;
+        let (cursor_width, _) = s.padded_text_size(TEXT_CURSOR)?;
+        let text_width = s.text_width(&text)?;
+        let cursor_x = s.text_width(&text[..cursor])?;
+
+        // Keep the cursor within the visible region
+        let mut scroll = s.ui.scroll(id);
+        if cursor_x - scroll.x() > clip.width() - cursor_width {
+            scroll.set_x(cursor_x + cursor_width - clip.width());
+        } else if cursor_x < scroll.x() {
+            scroll.set_x(cursor_x);
+        }
+        scroll.set_x(
+            scroll
+                .x()
+                .clamp(0, (text_width + cursor_width - clip.width()).max(0)),
+        );
+        s.ui.set_scroll(id, scroll);
+        let (x, y) = (clip.x() - scroll.x(), input.center().y() - text_height / 2);
+
         s.wrap(None);
-        s.set_cursor_pos([x, y]);
         s.clip(clip)?;
         s.stroke(None);
+
+        // Selection highlight
+        if composition.is_none() && selection.has_selection() {
+            let range = selection.range();
+            let start = s.text_width(&value[..range.start])?;
+            let end = s.text_width(&value[..range.end])?;
+            s.fill(s.theme.colors.secondary);
+            s.rect([x + start, y, end - start, text_height])?;
+        }
+
+        s.set_cursor_pos([x, y]);
         s.fill(fg);
-        if value.is_empty() {
+        if text.is_empty() {
             // FIXME: push and pop disabled state instead
             s.ui.push_cursor();
             s.disable(true);
@@ -173,29 +232,63 @@ impl PixState {
             if focused {
                 s.text(TEXT_CURSOR)?;
             }
-        } else if focused {
-            s.text(format!("{value}{TEXT_CURSOR}"))?;
         } else {
-            s.text(&value)?;
+            s.text(&text)?;
+            // Underline composed text
+            if !preedit.is_empty() {
+                let start = s.text_width(&text[..preedit.start])?;
+                let end = s.text_width(&text[..preedit.end])?;
+                let underline_y = y + text_height - 2;
+                s.stroke(fg);
+                s.line([x + start, underline_y, x + end, underline_y])?;
+                s.stroke(None);
+            }
+            if focused {
+                s.set_cursor_pos([x + cursor_x, y]);
+                s.text(TEXT_CURSOR)?;
+            }
         }
 
         s.clip(None)?;
         s.ui.pop_cursor();
         s.pop();
 
+        // Process mouse selection
+        let active = s.ui.is_active(id);
+        if !disabled && (active || hovered) {
+            let mouse_x = s.mouse_pos().x() - x;
+            let index = index_at(value, mouse_x, |text| s.text_width(text))?;
+            if active && s.mouse_down(Mouse::Left) {
+                if selection.dragging {
+                    selection.drag(index);
+                } else {
+                    selection.press(index, s.keymod_down(KeyMod::SHIFT));
+                }
+            } else if hovered
+                && s.ui.mouse_clicked(Mouse::Left)
+                && s.ui.mouse_dbl_clicked(Mouse::Left)
+            {
+                selection.select_word(value, index);
+            }
+        }
+        if !s.mouse_down(Mouse::Left) {
+            selection.release();
+        }
+
         // Process input
+        let filter = filter.unwrap_or(|_| true);
+        let filter = |c: char| !c.is_control() && filter(c);
         let changed = focused && {
             if let Some(Key::Return | Key::Escape) = s.ui.key_entered() {
                 s.ui.blur();
             }
-            s.handle_text_events(value)?
+            s.handle_text_events(value, &mut selection, filter)?
         };
         if changed {
-            value.retain(|c| !c.is_control());
-            if let Some(filter) = filter {
-                value.retain(filter);
-            }
+            value.retain(filter);
+            selection.clamp(value);
         }
+        s.ui.set_text_selection(id, selection);
         s.ui.handle_focus(id);
         s.advance_cursor([input.right() - pos.x(), input.height()]);
 
@@ -282,7 +375,7 @@ impl PixState {
         let ipad = spacing.item_pad;
 
         // Calculate input rect
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         let [x, mut y] = pos.coords();
         if !label.is_empty() {
             y += label_height + 2 * ipad.y();
@@ -294,7 +387,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let disabled = s.ui.disabled;
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Label
@@ -355,7 +448,10 @@ impl PixState {
                     s.ui.blur();
                     false
                 }
-                _ => s.handle_text_events(value)?,
+                _ => {
+                    let mut selection = TextSelection::at_end(value);
+                    s.handle_text_events(value, &mut selection, |c| c == '\n' || !c.is_control())?
+                }
             }
         };
 
@@ -364,7 +460,7 @@ impl PixState {
             if let Some(filter) = filter {
                 value.retain(filter);
             }
-            let (_, height) = s.text_size(&format!("{value}{TEXT_CURSOR}"))?;
+            let (_, height) = s.padded_text_size(&format!("{value}{TEXT_CURSOR}"))?;
             text_height = height + 2 * ipad.y();
 
             // Keep cursor within scroll region
@@ -391,50 +487,61 @@ impl PixState {
 }
 
 impl PixState {
-    /// Helper to handle text entry and text shortcuts.
-    fn handle_text_events(&mut self, value: &mut String) -> PixResult<bool> {
+    /// Helper to handle text entry, cursor movement, and text shortcuts. Typed and pasted text
+    /// is filtered by `filter` before replacing the current selection.
+    fn handle_text_events<F>(
+        &mut self,
+        value: &mut String,
+        selection: &mut TextSelection,
+        filter: F,
+    ) -> PixResult<bool>
+    where
+        F: Fn(char) -> bool,
+    {
         let s = self;
         let mut changed = false;
-        if let Some(key) = s.ui.key_entered() {
+        // Keys are handled by the input method while composing text
+        let key =
+            s.ui.key_entered()
+                .filter(|_| s.ui.keys.composition.is_none());
+        if let Some(key) = key {
             match key {
-                Key::Backspace if !value.is_empty() => {
-                    if s.keymod_down(MOD_CTRL) {
-                        value.clear();
-                    } else if s.keymod_down(KeyMod::ALT) {
-                        // If last char is whitespace, remove it so we find the next previous
-                        // word
-                        if value.chars().last().map(char::is_whitespace) == Some(true) {
-                            value.pop();
-                        }
-                        if let Some(idx) = value.rfind(char::is_whitespace) {
-                            value.truncate(idx + 1);
-                        } else {
-                            value.clear();
-                        }
-                    } else {
-                        value.pop();
-                    }
-                    changed = true;
-                }
                 Key::X if s.keymod_down(MOD_CTRL) => {
-                    s.set_clipboard_text(&value)?;
-                    value.clear();
-                    changed = true;
+                    // Cut all text if nothing is selected
+                    if !selection.has_selection() {
+                        selection.select_all(value);
+                    }
+                    s.set_clipboard_text(selection.selected(value))?;
+                    changed = selection.delete_selection(value);
                 }
                 Key::C if s.keymod_down(MOD_CTRL) => {
-                    s.set_clipboard_text(&value)?;
+                    // Copy all text if nothing is selected
+                    if selection.has_selection() {
+                        s.set_clipboard_text(selection.selected(value))?;
+                    } else {
+                        s.set_clipboard_text(&value)?;
+                    }
                 }
                 Key::V if s.keymod_down(MOD_CTRL) => {
-                    value.push_str(&s.clipboard_text());
+                    let text: String = s.clipboard_text().chars().filter(|&c| filter(c)).collect();
+                    selection.insert(value, &text);
                     changed = true;
                 }
-                _ => (),
+                _ => changed = selection.handle_key(value, key, *s.keymod()),
             }
         }
         if let Some(text) = s.ui.keys.typed.take() {
-            value.push_str(&text);
+            let text: String = text.chars().filter(|&c| filter(c)).collect();
+            selection.insert(value, &text);
             changed = true;
         }
         Ok(changed)
     }
+
+    /// Return the width of text on a single line, clamped to i32.
+    #[inline]
+    fn text_width(&self, text: &str) -> PixResult<i32> {
+        let (width, _) = self.renderer.size_of(text, None)?;
+        Ok(clamp_size(width))
+    }
 }
@@ -0,0 +1,568 @@
+//! Menu widget rendering methods.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::menu_bar`]
+//! - [`PixState::menu_dropdown`]
+//! - [`PixState::menu_item`]
+//! - [`PixState::menu_item_shortcut`]
+//! - [`PixState::menu_separator`]
+//! - [`PixState::context_menu`]
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { text: String };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     s.menu_bar(|s| {
+//!         s.menu_dropdown("File", |s| {
+//!             if s.menu_item("Open…")? {
+//!                 // open a file
+//!             }
+//!             if s.menu_item_shortcut("Save", "Ctrl+S")? {
+//!                 // save the file
+//!             }
+//!             s.menu_separator()?;
+//!             if s.menu_item("Quit")? {
+//!                 s.quit();
+//!             }
+//!             Ok(())
+//!         })
+//!     })?;
+//!     s.text_field("Text", &mut self.text)?;
+//!     s.context_menu("Text Menu", |s| {
+//!         if s.menu_item("Clear")? {
+//!             self.text.clear();
+//!         }
+//!         Ok(())
+//!     })?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::{
+    gui::{
+        menu::{parse_shortcut, MenuBar, MenuPanel},
+        state::ElementId,
+    },
+    ops::{clamp_dimensions, clamp_size},
+    prelude::*,
+};
+use anyhow::anyhow;
+
+impl PixState {
+    /// Draw a menu bar across the top of the window containing the [`PixState::menu_dropdown`]s
+    /// rendered by `f`. The UI cursor is moved below the menu bar.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, or if menu bars are nested,
+    /// then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.menu_bar(|s| {
+    ///         s.menu_dropdown("File", |s| {
+    ///             if s.menu_item("New")? {
+    ///                 // create a new file
+    ///             }
+    ///             Ok(())
+    ///         })?;
+    ///         s.menu_dropdown("Edit", |s| {
+    ///             if s.menu_item_shortcut("Undo", "Ctrl+Z")? {
+    ///                 // undo the last change
+    ///             }
+    ///             Ok(())
+    ///         })
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn menu_bar<F>(&mut self, f: F) -> PixResult<()>
+    where
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        if self.ui.menu.bar.is_some() {
+            return Err(anyhow!("menu bars can not be nested"));
+        }
+
+        let s = self;
+        let pos = s.cursor_pos();
+        let font_size = clamp_size(s.theme.font_size);
+        let fpad = s.theme.spacing.frame_pad;
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Calculate menu bar rect
+        let (win_width, _) = s.window_dimensions()?;
+        let bar = rect![0, 0, clamp_size(win_width), font_size + 2 * ipad.y()];
+
+        s.push_screen_space();
+        s.rect_mode(RectMode::Corner);
+        s.stroke(None);
+        s.fill(colors.surface);
+        s.rect(bar)?;
+        s.pop();
+
+        s.ui.menu.bar = Some(MenuBar {
+            rect: bar,
+            x: fpad.x(),
+        });
+        let result = f(s);
+        s.ui.menu.bar = None;
+        result?;
+
+        if pos.y() < bar.bottom() + fpad.y() {
+            s.set_cursor_pos([pos.x(), bar.bottom() + fpad.y()]);
+        }
+
+        Ok(())
+    }
+
+    /// Draw a menu header to the current [`PixState::menu_bar`] that opens a drop-down panel
+    /// containing the menu items rendered by `f` when clicked. Hovering over another menu header
+    /// while a menu is open switches to that menu.
+    ///
+    /// `f` is called every frame, even while the menu is closed, so that
+    /// [`PixState::menu_item_shortcut`] hotkeys are always handled.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, or if called outside of a
+    /// [`PixState::menu_bar`] or within another menu, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.menu_bar(|s| {
+    ///         s.menu_dropdown("View", |s| {
+    ///             if s.menu_item("Zoom In")? {
+    ///                 // zoom in
+    ///             }
+    ///             Ok(())
+    ///         })
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn menu_dropdown<L, F>(&mut self, label: L, f: F) -> PixResult<()>
+    where
+        L: AsRef<str>,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let label = label.as_ref();
+
+        let Some(bar) = self.ui.menu.bar else {
+            return Err(anyhow!("menu `{label}` must be called within a menu bar"));
+        };
+        if self.ui.menu.panel.is_some() {
+            return Err(anyhow!(
+                "menu `{label}` can not be nested within another menu"
+            ));
+        }
+
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let fpad = s.theme.spacing.frame_pad;
+        let colors = s.theme.colors;
+
+        // Calculate header rect
+        let (label_width, label_height) = s.padded_text_size(label)?;
+        let header = rect![
+            bar.x,
+            bar.rect.y(),
+            label_width + 2 * fpad.x(),
+            bar.rect.height()
+        ];
+        s.ui.menu.bar = Some(MenuBar {
+            x: header.right(),
+            ..bar
+        });
+        let panel_pos = header.bottom_left();
+
+        // Check hover/active
+        let hovered = s.focused() && s.ui.try_hover(id, &header);
+        let disabled = s.ui.disabled;
+        if hovered && !disabled {
+            s.ui.menu.hover(id, panel_pos);
+        }
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Header
+        s.rect_mode(RectMode::Corner);
+        if hovered {
+            s.frame_cursor(&Cursor::hand())?;
+        }
+        let [_, bg, fg] = s.widget_colors(id, ColorType::Surface);
+        let open = s.ui.menu.is_open(id);
+        s.stroke(None);
+        if open {
+            s.fill(colors.primary);
+            s.rect(header)?;
+        } else if hovered {
+            s.fill(bg);
+            s.rect(header)?;
+        }
+
+        // Label
+        s.set_cursor_pos([
+            header.x() + fpad.x(),
+            header.center().y() - label_height / 2,
+        ]);
+        s.fill(if open { colors.on_primary } else { fg });
+        s.text(label)?;
+
+        s.ui.pop_cursor();
+        s.pop();
+
+        // Process input
+        if !disabled && s.ui.was_clicked(id) {
+            s.ui.menu.toggle(id, panel_pos, true);
+        }
+        s.menu_panel(id, bar.rect, f)
+    }
+
+    /// Draw a menu item to the current menu panel. Returns `true` if clicked.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.menu_bar(|s| {
+    ///         s.menu_dropdown("File", |s| {
+    ///             if s.menu_item("Open…")? {
+    ///                 // open a file
+    ///             }
+    ///             Ok(())
+    ///         })
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn menu_item<L>(&mut self, label: L) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+    {
+        self.menu_entry(label.as_ref(), None)
+    }
+
+    /// Draw a menu item with a keyboard shortcut, such as `Ctrl+S` or `Ctrl+Shift+Z`, displayed
+    /// to the right of the label to the current menu panel. Returns `true` if clicked or if the
+    /// shortcut was pressed this frame, even while the menu is closed.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, or if `shortcut` is not a
+    /// valid keyboard shortcut, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.menu_bar(|s| {
+    ///         s.menu_dropdown("File", |s| {
+    ///             if s.menu_item_shortcut("Save", "Ctrl+S")? {
+    ///                 // save the file
+    ///             }
+    ///             Ok(())
+    ///         })
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn menu_item_shortcut<L, K>(&mut self, label: L, shortcut: K) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+        K: AsRef<str>,
+    {
+        self.menu_entry(label.as_ref(), Some(shortcut.as_ref()))
+    }
+
+    /// Draw a horizontal line separating groups of items to the current menu panel.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.menu_bar(|s| {
+    ///         s.menu_dropdown("File", |s| {
+    ///             s.menu_item("Save")?;
+    ///             s.menu_separator()?;
+    ///             s.menu_item("Quit")?;
+    ///             Ok(())
+    ///         })
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn menu_separator(&mut self) -> PixResult<()> {
+        let s = self;
+        let Some(mut panel) = s.ui.menu.panel else {
+            return Ok(());
+        };
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        let height = 2 * ipad.y() + 1;
+        let y = panel.y + height / 2;
+        s.push();
+        s.stroke(colors.disabled());
+        s.line(line_![1, y, panel.width - 2, y])?;
+        s.pop();
+
+        panel.advance(0, height);
+        s.ui.menu.panel = Some(panel);
+
+        Ok(())
+    }
+
+    /// Open a context menu at the mouse position containing the menu items rendered by `f` when
+    /// the previously drawn widget is right-clicked.
+    ///
+    /// `f` is called every frame, even while the menu is closed, so that
+    /// [`PixState::menu_item_shortcut`] hotkeys are always handled.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, or if called within another
+    /// menu, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { text: String };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.text_field("Text", &mut self.text)?;
+    ///     s.context_menu("Text Menu", |s| {
+    ///         if s.menu_item("Clear")? {
+    ///             self.text.clear();
+    ///         }
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn context_menu<L, F>(&mut self, label: L, f: F) -> PixResult<()>
+    where
+        L: AsRef<str>,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let label = label.as_ref();
+        if self.ui.menu.panel.is_some() {
+            return Err(anyhow!(
+                "context menu `{label}` can not be nested within another menu"
+            ));
+        }
+
+        let s = self;
+        let id = s.ui.get_id(&label);
+
+        // Open on right-click within the previous widget
+        if !s.ui.disabled && s.focused() && s.ui.mouse_clicked(Mouse::Right) {
+            let mpos = s.mouse_pos();
+            if let Some(last) = s.ui.last_size() {
+                if last.contains(mpos) {
+                    s.ui.menu.open(id, mpos, false);
+                }
+            }
+        }
+
+        s.menu_panel(id, rect![0, 0, 0, 0], f)
+    }
+}
+
+impl PixState {
+    /// Draw the drop-down panel for a menu if it's open and process dismissal, otherwise only
+    /// handle menu item shortcuts.
+    fn menu_panel<F>(&mut self, id: ElementId, bar: Rect<i32>, f: F) -> PixResult<()>
+    where
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let s = self;
+        let Some(mut menu) = s.ui.menu.open_menu().filter(|menu| menu.id == id) else {
+            return f(s);
+        };
+        let fpad = s.theme.spacing.frame_pad;
+
+        // Ensure panel stays inside window
+        let (win_width, win_height) = s.window_dimensions()?;
+        let (win_width, win_height) = clamp_dimensions(win_width, win_height);
+        let mut pos = menu.pos;
+        pos.set_x(pos.x().min(win_width - menu.size.x()).max(0));
+        pos.set_y(pos.y().min(win_height - menu.size.y()).max(0));
+        if pos != menu.pos {
+            s.ui.menu.open(id, pos, menu.in_bar);
+            menu.pos = pos;
+        }
+        let panel = menu.rect();
+
+        // Panels are sized by their items, so render to a window-sized texture and only display
+        // the measured panel region.
+        let texture_id = s.get_or_create_texture(id, None, rect![0, 0, win_width, win_height])?;
+        if let Some(texture) = s.ui.textures.iter_mut().find(|t| t.id == texture_id) {
+            texture.src = Some(rect![0, 0, panel.width(), panel.height()]);
+            texture.dst = Some(panel);
+            texture.visible = panel.width() > 0 && panel.height() > 0;
+        }
+
+        s.ui.offset_mouse(panel.top_left());
+
+        s.push_screen_space();
+        s.set_texture_target(texture_id)?;
+        s.clear()?;
+
+        s.rect_mode(RectMode::Corner);
+        let [stroke, ..] = s.widget_colors(id, ColorType::Background);
+        s.stroke(stroke);
+        s.fill(None);
+        s.rect([0, 0, panel.width(), panel.height()])?;
+
+        s.ui.menu.panel = Some(MenuPanel::new(panel.width(), fpad.y()));
+        s.ui.disable_focus();
+        s.push_id(id);
+        let result = f(s);
+        s.pop_id();
+        s.ui.enable_focus();
+        let layout = s.ui.menu.panel.take();
+
+        s.clear_texture_target();
+        s.pop();
+        s.ui.clear_mouse_offset();
+        result?;
+
+        if let Some(layout) = layout {
+            let size = point![layout.measured.x(), layout.measured.y() + fpad.y()];
+            s.ui.menu.resize(id, size);
+        }
+
+        // Dismiss on Escape or pressing outside
+        if s.ui.keys.was_entered(Key::Escape) {
+            s.ui.clear_entered();
+            s.ui.menu.close();
+        } else if s.ui.mouse_down(Mouse::Left) || s.ui.mouse_down(Mouse::Right) {
+            let mpos = s.mouse_pos();
+            s.ui.menu.press_outside(id, mpos, bar);
+        }
+
+        Ok(())
+    }
+
+    /// Draw a menu item with an optional keyboard shortcut to the current menu panel. Returns
+    /// `true` if clicked or if the shortcut was pressed this frame.
+    fn menu_entry(&mut self, label: &str, shortcut: Option<&str>) -> PixResult<bool> {
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let disabled = s.ui.disabled;
+
+        // Shortcuts are handled whether the menu is open or not
+        let mut activated = false;
+        if let Some(shortcut) = shortcut {
+            let (key, keymod) =
+                parse_shortcut(shortcut).ok_or_else(|| anyhow!("invalid shortcut `{shortcut}`"))?;
+            if !disabled && s.ui.keys.shortcut_entered(key, keymod) {
+                s.ui.clear_entered();
+                s.ui.menu.close();
+                activated = true;
+            }
+        }
+
+        let Some(mut panel) = s.ui.menu.panel else {
+            return Ok(activated);
+        };
+        let font_size = clamp_size(s.theme.font_size);
+        let fpad = s.theme.spacing.frame_pad;
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Calculate item rect
+        let (label_width, _) = s.padded_text_size(label)?;
+        let shortcut_width = match shortcut {
+            Some(shortcut) => s.padded_text_size(shortcut)?.0,
+            None => 0,
+        };
+        let line_height = font_size + 2 * ipad.y();
+        let item = rect![1, panel.y, panel.width - 2, line_height];
+        let mut width = label_width + 2 * fpad.x();
+        if shortcut_width > 0 {
+            width += shortcut_width + 2 * fpad.x();
+        }
+
+        // Check hover/active
+        let hovered = s.ui.try_hover(id, &item);
+
+        s.ui.push_cursor();
+
+        // Background
+        if hovered {
+            s.frame_cursor(&Cursor::hand())?;
+            s.stroke(None);
+            s.fill(colors.primary);
+            s.rect(item)?;
+        }
+
+        // Label and shortcut
+        let [_, _, fg] = s.widget_colors(id, ColorType::Background);
+        s.stroke(None);
+        s.fill(if hovered { colors.on_primary } else { fg });
+        s.set_cursor_pos([fpad.x(), panel.y + ipad.y()]);
+        s.text(label)?;
+        if let Some(shortcut) = shortcut {
+            s.set_cursor_pos([item.right() - fpad.x() - shortcut_width, panel.y + ipad.y()]);
+            s.text(shortcut)?;
+        }
+
+        s.ui.pop_cursor();
+
+        panel.advance(width, line_height);
+        s.ui.menu.panel = Some(panel);
+
+        // Process input
+        if !disabled && s.ui.was_clicked(id) {
+            s.ui.menu.close();
+            activated = true;
+        }
+
+        Ok(activated)
+    }
+}
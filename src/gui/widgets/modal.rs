@@ -0,0 +1,623 @@
+//! Modal widget rendering methods.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::modal`]
+//! - [`PixState::message_box`]
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { show_settings: bool, volume: f32 };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     if s.button("Settings")? {
+//!         self.show_settings = true;
+//!     }
+//!     s.modal("Settings", &mut self.show_settings, |s: &mut PixState| {
+//!         s.slider("Volume", &mut self.volume, 0.0, 1.0)?;
+//!         Ok(())
+//!     })?;
+//!     if self.volume > 0.9 {
+//!         let result = s.message_box(
+//!             "Warning",
+//!             "Volume is very loud. Keep it?",
+//!             MessageBoxButtons::YesNo,
+//!         )?;
+//!         if result == Some(MessageBoxResult::No) {
+//!             self.volume = 0.5;
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::{
+    gui::state::{ElementId, UiState},
+    ops::{clamp_dimensions, clamp_size},
+    prelude::*,
+};
+use std::mem;
+
+const CLOSE_LABEL: &str = "##close";
+
+/// Buttons displayed by [`PixState::message_box`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageBoxButtons {
+    /// A single `OK` button.
+    Ok,
+    /// `OK` and `Cancel` buttons.
+    OkCancel,
+    /// `Yes` and `No` buttons.
+    YesNo,
+    /// `Yes`, `No`, and `Cancel` buttons.
+    YesNoCancel,
+}
+
+impl MessageBoxButtons {
+    /// The results for each button, in displayed order. The first button is the default.
+    #[must_use]
+    pub const fn results(self) -> &'static [MessageBoxResult] {
+        use MessageBoxResult::{Cancel, No, Ok, Yes};
+        match self {
+            Self::Ok => &[Ok],
+            Self::OkCancel => &[Ok, Cancel],
+            Self::YesNo => &[Yes, No],
+            Self::YesNoCancel => &[Yes, No, Cancel],
+        }
+    }
+
+    /// The result when the message box is dismissed with [`Key::Escape`] or the close button.
+    #[must_use]
+    pub const fn cancel_result(self) -> MessageBoxResult {
+        match self {
+            Self::Ok => MessageBoxResult::Ok,
+            Self::YesNo => MessageBoxResult::No,
+            Self::OkCancel | Self::YesNoCancel => MessageBoxResult::Cancel,
+        }
+    }
+
+    /// The result when [`Key::Return`] is pressed without a focused button.
+    #[must_use]
+    pub const fn default_result(self) -> MessageBoxResult {
+        self.results()[0]
+    }
+}
+
+/// Button clicked in a [`PixState::message_box`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MessageBoxResult {
+    /// `OK` was clicked.
+    Ok,
+    /// `Cancel` was clicked or the message box was dismissed.
+    Cancel,
+    /// `Yes` was clicked.
+    Yes,
+    /// `No` was clicked.
+    No,
+}
+
+impl AsRef<str> for MessageBoxResult {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Ok => "OK",
+            Self::Cancel => "Cancel",
+            Self::Yes => "Yes",
+            Self::No => "No",
+        }
+    }
+}
+
+impl PixState {
+    /// Draw a modal panel with a title bar and close button centered over the current window,
+    /// containing the widgets rendered by `f`, and dim everything drawn before it. While `open` is
+    /// `true`, all other UI elements are blocked from hover, click, and focus interactions, so
+    /// [`Key::Tab`] only cycles through elements in the modal. Clicking the close button or
+    /// pressing [`Key::Escape`] sets `open` to `false`. Returns whether the modal is still open.
+    ///
+    /// The panel is half the window width by default, which can be changed with
+    /// [`PixState::next_width`], and its height fits the content rendered by `f`.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { show_about: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.button("About")? {
+    ///         self.show_about = true;
+    ///     }
+    ///     s.modal("About", &mut self.show_about, |s: &mut PixState| {
+    ///         s.text("pix-engine GUI demo")?;
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn modal<S, F>(&mut self, title: S, open: &mut bool, f: F) -> PixResult<bool>
+    where
+        S: AsRef<str>,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        if !*open {
+            return Ok(false);
+        }
+
+        let title = title.as_ref();
+
+        let s = self;
+        let id = s.ui.get_id(&title);
+        let title = s.ui.get_label(title);
+        let font_size = clamp_size(s.theme.font_size);
+        let fpad = s.theme.spacing.frame_pad;
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Calculate panel rect, centered in the window
+        let (win_width, win_height) = s.window_dimensions()?;
+        let (win_width, win_height) = clamp_dimensions(win_width, win_height);
+        let width =
+            s.ui.next_width
+                .take()
+                .unwrap_or(win_width / 2)
+                .min(win_width);
+        let title_height = font_size + 2 * ipad.y();
+        let content_height = s.ui.content_height(id).max(2 * fpad.y());
+        let height = (title_height + content_height).min(win_height);
+        let panel = rect![
+            (win_width - width) / 2,
+            (win_height - height) / 2,
+            width,
+            height
+        ];
+
+        // Dim everything drawn so far
+        s.push_screen_space();
+        s.blend_mode(BlendMode::Blend);
+        s.rect_mode(RectMode::Corner);
+        s.stroke(None);
+        s.fill(Color::rgba(0, 0, 0, 128));
+        s.rect([0, 0, win_width, win_height])?;
+        s.pop();
+
+        let texture_id = s.get_or_create_texture(id, None, panel)?;
+        s.ui.offset_mouse(panel.top_left());
+
+        // Modal contents are always drawn in screen space
+        s.push_screen_space();
+        s.set_texture_target(texture_id)?;
+        s.ui.push_cursor();
+        s.background(colors.surface);
+
+        // Border
+        s.rect_mode(RectMode::Corner);
+        let [stroke, ..] = s.widget_colors(id, ColorType::Surface);
+        s.stroke(stroke);
+        s.fill(None);
+        s.rect([0, 0, width, height])?;
+
+        // Title bar
+        s.stroke(None);
+        s.fill(colors.primary);
+        s.rect([0, 0, width, title_height])?;
+        s.set_cursor_pos([fpad.x(), ipad.y()]);
+        s.fill(colors.on_primary);
+        s.text(title)?;
+
+        let close = rect![width - title_height, 0, title_height, title_height];
+        let result = modal_frame(s, id, close, open, |s: &mut PixState| {
+            s.set_cursor_pos([fpad.x(), title_height + fpad.y()]);
+            f(s)
+        });
+        let content_height = s.cursor_pos().y() - ipad.y() + fpad.y() - title_height;
+
+        s.ui.pop_cursor();
+        s.clear_texture_target();
+        s.pop();
+        s.ui.clear_mouse_offset();
+        result?;
+
+        s.ui.set_content_height(id, content_height);
+
+        Ok(*open)
+    }
+
+    /// Draw a modal message box with `text` and a row of `buttons`. Returns which button was
+    /// clicked, or `None` while waiting for input. Call this every frame for as long as the
+    /// message box should be shown.
+    ///
+    /// [`Key::Return`] selects the first button unless another button is focused, and
+    /// [`Key::Escape`] or the close button selects [`MessageBoxButtons::cancel_result`].
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { confirm_quit: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if self.confirm_quit {
+    ///         let result = s.message_box("Quit", "Are you sure?", MessageBoxButtons::OkCancel)?;
+    ///         match result {
+    ///             Some(MessageBoxResult::Ok) => s.quit(),
+    ///             Some(_) => self.confirm_quit = false,
+    ///             None => (),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn message_box<S, T>(
+        &mut self,
+        title: S,
+        text: T,
+        buttons: MessageBoxButtons,
+    ) -> PixResult<Option<MessageBoxResult>>
+    where
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        let text = text.as_ref();
+
+        let mut open = true;
+        let mut clicked = None;
+        self.modal(title, &mut open, |s: &mut PixState| {
+            s.text(text)?;
+            s.spacing()?;
+            clicked = message_box_buttons(s, buttons)?;
+            Ok(())
+        })?;
+
+        Ok(self.ui.message_box_result(buttons, clicked, open))
+    }
+}
+
+/// Widgets drawn by [`modal_frame`] and [`message_box_buttons`], which are implemented by
+/// [`PixState`] and by a headless [`UiState`] in tests.
+trait ModalUi {
+    /// Returns the UI state.
+    fn ui(&mut self) -> &mut UiState;
+
+    /// Draw the modal close button at `rect`. Returns `true` when clicked.
+    fn close_button(&mut self, rect: Rect<i32>) -> PixResult<bool>;
+
+    /// Draw the message box button at `index` for `result`. Returns `true` when clicked.
+    fn message_box_button(&mut self, index: usize, result: MessageBoxResult) -> PixResult<bool>;
+}
+
+impl ModalUi for PixState {
+    fn ui(&mut self) -> &mut UiState {
+        &mut self.ui
+    }
+
+    fn close_button(&mut self, rect: Rect<i32>) -> PixResult<bool> {
+        self.modal_close_button(rect)
+    }
+
+    fn message_box_button(&mut self, index: usize, result: MessageBoxResult) -> PixResult<bool> {
+        if index > 0 {
+            self.same_line(None);
+        }
+        self.button(result)
+    }
+}
+
+/// Process the interactions of an open modal with the given `id`, drawing its close button at
+/// `close` and its content with `f`. Elements outside of the modal are blocked while elements
+/// inside are scoped to the modal. Clicking the close button or pressing [`Key::Escape`] sets
+/// `open` to `false`.
+fn modal_frame<T, F>(
+    ui: &mut T,
+    id: ElementId,
+    close: Rect<i32>,
+    open: &mut bool,
+    f: F,
+) -> PixResult<()>
+where
+    T: ModalUi,
+    F: FnOnce(&mut T) -> PixResult<()>,
+{
+    ui.ui().begin_modal(id);
+
+    // Elements inside the modal are never blocked
+    let blocked = mem::replace(&mut ui.ui().blocked, false);
+    ui.ui().push_id(&id);
+    let result = ui.close_button(close).and_then(|closed| {
+        if closed {
+            *open = false;
+        }
+        f(ui)
+    });
+    ui.ui().pop_id();
+    ui.ui().blocked = blocked;
+    result?;
+
+    if ui.ui().modal_dismissed() {
+        *open = false;
+    }
+    Ok(())
+}
+
+/// Draw a row of message box `buttons`, returning the result of the button clicked this frame,
+/// if any.
+fn message_box_buttons<T: ModalUi>(
+    ui: &mut T,
+    buttons: MessageBoxButtons,
+) -> PixResult<Option<MessageBoxResult>> {
+    let mut clicked = None;
+    for (i, &button) in buttons.results().iter().enumerate() {
+        if ui.message_box_button(i, button)? {
+            clicked = Some(button);
+        }
+    }
+    Ok(clicked)
+}
+
+impl PixState {
+    /// Draw the close button of a modal title bar. Returns `true` when clicked.
+    fn modal_close_button(&mut self, button: Rect<i32>) -> PixResult<bool> {
+        let s = self;
+        let id = s.ui.get_id(&CLOSE_LABEL);
+        let ipad = s.theme.spacing.item_pad;
+
+        // Check hover/active/keyboard focus
+        let hovered = s.focused() && s.ui.try_hover(id, &button);
+        let focused = s.focused() && s.ui.try_focus(id);
+        let disabled = s.ui.disabled;
+
+        // Render
+        if hovered {
+            s.frame_cursor(&Cursor::hand())?;
+        }
+        let [stroke, bg, fg] = s.widget_colors(id, ColorType::Primary);
+        if focused {
+            s.stroke(stroke);
+        } else {
+            s.stroke(None);
+        }
+        s.fill(bg);
+        s.rect(button)?;
+
+        let cross = button.shrink([ipad.y() + 2, ipad.y() + 2]);
+        s.stroke(fg);
+        s.line(line_![cross.top_left(), cross.bottom_right()])?;
+        s.line(line_![cross.bottom_left(), cross.top_right()])?;
+
+        // Process input
+        s.ui.handle_focus(id);
+        Ok(!disabled && s.ui.was_clicked(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a `UiState` with a message box already opened last frame.
+    fn open_message_box() -> UiState {
+        let mut ui = UiState::default();
+        ui.begin_modal(ui.get_id(&"Message"));
+        ui.post_update();
+        ui.mouse.pos = point![10, 10];
+        ui
+    }
+
+    /// Hit tests the close button at `rect` and message box buttons in a row below it.
+    impl ModalUi for UiState {
+        fn ui(&mut self) -> &mut UiState {
+            self
+        }
+
+        fn close_button(&mut self, rect: Rect<i32>) -> PixResult<bool> {
+            let id = self.get_id(&CLOSE_LABEL);
+            self.try_hover(id, &rect);
+            self.try_focus(id);
+            self.handle_focus(id);
+            Ok(self.was_clicked(id))
+        }
+
+        fn message_box_button(
+            &mut self,
+            index: usize,
+            result: MessageBoxResult,
+        ) -> PixResult<bool> {
+            let id = self.get_id(&result.as_ref());
+            self.try_hover(id, &rect![100 * index as i32, 100, 80, 20]);
+            self.try_focus(id);
+            self.handle_focus(id);
+            Ok(self.was_clicked(id))
+        }
+    }
+
+    /// Render a frame with an element behind an open message box, returning the message box
+    /// result and the index of the focused element, if any, where `0` is the close button followed
+    /// by the message box buttons. Asserts that the element behind the message box never captures
+    /// hover or focus.
+    fn message_box_frame(
+        ui: &mut UiState,
+        theme: &Theme,
+        buttons: MessageBoxButtons,
+    ) -> PixResult<(Option<MessageBoxResult>, Option<usize>)> {
+        ui.pre_update(theme);
+        let behind = ui.get_id(&"Behind");
+        assert!(!ui.try_hover(behind, &rect![0, 0, 400, 400]));
+        assert!(!ui.try_focus(behind));
+        ui.handle_focus(behind);
+
+        let id = ui.get_id(&"Message");
+        let mut open = true;
+        let mut clicked = None;
+        modal_frame(
+            ui,
+            id,
+            rect![380, 0, 20, 20],
+            &mut open,
+            |ui: &mut UiState| {
+                clicked = message_box_buttons(ui, buttons)?;
+                Ok(())
+            },
+        )?;
+        let result = ui.message_box_result(buttons, clicked, open);
+
+        ui.push_id(&id);
+        let ids: Vec<_> = std::iter::once(CLOSE_LABEL)
+            .chain(buttons.results().iter().map(AsRef::as_ref))
+            .map(|label| ui.get_id(&label))
+            .collect();
+        ui.pop_id();
+        let focused = ids.iter().position(|&id| ui.is_focused(id));
+        assert!(!ui.is_focused(behind));
+        ui.post_update();
+        Ok((result, focused))
+    }
+
+    #[test]
+    fn message_box_results() {
+        use MessageBoxResult::{Cancel, No, Ok, Yes};
+
+        assert_eq!(MessageBoxButtons::Ok.results(), &[Ok]);
+        assert_eq!(MessageBoxButtons::OkCancel.results(), &[Ok, Cancel]);
+        assert_eq!(MessageBoxButtons::YesNo.results(), &[Yes, No]);
+        assert_eq!(MessageBoxButtons::YesNoCancel.results(), &[Yes, No, Cancel]);
+
+        assert_eq!(MessageBoxButtons::Ok.cancel_result(), Ok);
+        assert_eq!(MessageBoxButtons::OkCancel.cancel_result(), Cancel);
+        assert_eq!(MessageBoxButtons::YesNo.cancel_result(), No);
+        assert_eq!(MessageBoxButtons::YesNoCancel.cancel_result(), Cancel);
+
+        assert_eq!(MessageBoxButtons::OkCancel.default_result(), Ok);
+        assert_eq!(MessageBoxButtons::YesNoCancel.default_result(), Yes);
+    }
+
+    #[test]
+    fn message_box_click() -> PixResult<()> {
+        let mut ui = open_message_box();
+        let theme = Theme::default();
+        let buttons = MessageBoxButtons::YesNoCancel;
+        assert_eq!(message_box_frame(&mut ui, &theme, buttons)?.0, None);
+
+        // Click `No`: pressed while hovered, then released
+        ui.mouse.pos = point![110, 110];
+        ui.mouse.press(Mouse::Left);
+        assert_eq!(message_box_frame(&mut ui, &theme, buttons)?.0, None);
+        ui.mouse.release(Mouse::Left);
+        assert_eq!(
+            message_box_frame(&mut ui, &theme, buttons)?.0,
+            Some(MessageBoxResult::No)
+        );
+
+        // Click `Cancel`
+        ui.mouse.pos = point![210, 110];
+        ui.mouse.press(Mouse::Left);
+        assert_eq!(message_box_frame(&mut ui, &theme, buttons)?.0, None);
+        ui.mouse.release(Mouse::Left);
+        assert_eq!(
+            message_box_frame(&mut ui, &theme, buttons)?.0,
+            Some(MessageBoxResult::Cancel)
+        );
+
+        // Clicking the close button cancels
+        let buttons = MessageBoxButtons::YesNo;
+        ui.mouse.pos = point![390, 10];
+        ui.mouse.press(Mouse::Left);
+        assert_eq!(message_box_frame(&mut ui, &theme, buttons)?.0, None);
+        ui.mouse.release(Mouse::Left);
+        assert_eq!(
+            message_box_frame(&mut ui, &theme, buttons)?.0,
+            Some(MessageBoxResult::No)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn message_box_keys() -> PixResult<()> {
+        let mut ui = open_message_box();
+        let theme = Theme::default();
+        for buttons in [
+            MessageBoxButtons::Ok,
+            MessageBoxButtons::OkCancel,
+            MessageBoxButtons::YesNo,
+            MessageBoxButtons::YesNoCancel,
+        ] {
+            assert_eq!(message_box_frame(&mut ui, &theme, buttons)?, (None, None));
+
+            // Return without a focused button selects the default
+            ui.keys.press(Key::Return, KeyMod::NONE, false);
+            assert_eq!(
+                message_box_frame(&mut ui, &theme, buttons)?.0,
+                Some(buttons.default_result())
+            );
+
+            // Escape dismisses
+            ui.keys.press(Key::Escape, KeyMod::NONE, false);
+            assert_eq!(
+                message_box_frame(&mut ui, &theme, buttons)?.0,
+                Some(buttons.cancel_result())
+            );
+        }
+
+        // Return selects the focused button instead of the default
+        let buttons = MessageBoxButtons::YesNo;
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        message_box_frame(&mut ui, &theme, buttons)?;
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        message_box_frame(&mut ui, &theme, buttons)?;
+        ui.keys.press(Key::Tab, KeyMod::NONE, false);
+        assert_eq!(
+            message_box_frame(&mut ui, &theme, buttons)?,
+            (None, Some(2))
+        );
+        ui.keys.press(Key::Return, KeyMod::NONE, false);
+        assert_eq!(
+            message_box_frame(&mut ui, &theme, buttons)?.0,
+            Some(MessageBoxResult::No)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn message_box_traps_focus() -> PixResult<()> {
+        let mut ui = open_message_box();
+        let theme = Theme::default();
+        let buttons = MessageBoxButtons::OkCancel;
+        assert_eq!(message_box_frame(&mut ui, &theme, buttons)?, (None, None));
+
+        // Tab cycles through the close button and message box buttons, wrapping around without
+        // ever focusing the element behind the message box. Once focus has wrapped, Tab focuses
+        // the second element, since the first captures focus and passes it on in the same frame
+        let mut focused = vec![];
+        for _ in 0..8 {
+            ui.keys.press(Key::Tab, KeyMod::NONE, false);
+            focused.push(message_box_frame(&mut ui, &theme, buttons)?.1);
+        }
+        assert_eq!(
+            focused,
+            [
+                Some(0),
+                Some(1),
+                Some(2),
+                None,
+                Some(1),
+                Some(2),
+                None,
+                Some(1)
+            ]
+        );
+        Ok(())
+    }
+}
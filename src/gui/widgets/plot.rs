@@ -0,0 +1,442 @@
+//! Plot widget rendering methods.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::plot_lines`]
+//! - [`PixState::plot_histogram`]
+//! - [`PixState::frame_time_graph`]
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { samples: Vec<f32> };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     s.plot_lines("Samples", &self.samples, None, None, None, None)?;
+//!     s.plot_histogram("Histogram", &self.samples, Some("Samples"), Some(0.0), None, [200, 80])?;
+//!     s.frame_time_graph(120)?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::{ops::clamp_size, prelude::*};
+
+const TOOLTIP_LABEL: &str = "##plot tooltip";
+
+/// How plot values are drawn.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PlotKind {
+    /// A polyline connecting each value.
+    Lines,
+    /// A bar for each value.
+    Histogram,
+}
+
+impl PixState {
+    /// Draw a line graph of `values` to the current canvas, with optional `overlay_text` drawn at
+    /// the top. Values are scaled between `scale_min` and `scale_max`, which default to the
+    /// minimum and maximum of `values`. Hovering shows the value under the cursor.
+    ///
+    /// `size` defaults to the remaining width of the current canvas, which can be changed with
+    /// [`PixState::next_width`], and four lines of text tall.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { samples: Vec<f32> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.plot_lines("Samples", &self.samples, Some("Samples"), -1.0, 1.0, [200, 80])?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn plot_lines<L, N, X, S>(
+        &mut self,
+        label: L,
+        values: &[f32],
+        overlay_text: Option<&str>,
+        scale_min: N,
+        scale_max: X,
+        size: S,
+    ) -> PixResult<()>
+    where
+        L: AsRef<str>,
+        N: Into<Option<f32>>,
+        X: Into<Option<f32>>,
+        S: Into<Option<[i32; 2]>>,
+    {
+        let scale = (scale_min.into(), scale_max.into());
+        self.plot(
+            label.as_ref(),
+            values,
+            overlay_text,
+            scale,
+            size.into(),
+            PlotKind::Lines,
+        )
+    }
+
+    /// Draw a histogram of `values` to the current canvas, with optional `overlay_text` drawn at
+    /// the top. Values are scaled between `scale_min` and `scale_max`, which default to the
+    /// minimum and maximum of `values`. Hovering shows the value under the cursor.
+    ///
+    /// `size` defaults to the remaining width of the current canvas, which can be changed with
+    /// [`PixState::next_width`], and four lines of text tall.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { samples: Vec<f32> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.plot_histogram("Histogram", &self.samples, None, 0.0, None, None)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn plot_histogram<L, N, X, S>(
+        &mut self,
+        label: L,
+        values: &[f32],
+        overlay_text: Option<&str>,
+        scale_min: N,
+        scale_max: X,
+        size: S,
+    ) -> PixResult<()>
+    where
+        L: AsRef<str>,
+        N: Into<Option<f32>>,
+        X: Into<Option<f32>>,
+        S: Into<Option<[i32; 2]>>,
+    {
+        let scale = (scale_min.into(), scale_max.into());
+        self.plot(
+            label.as_ref(),
+            values,
+            overlay_text,
+            scale,
+            size.into(),
+            PlotKind::Histogram,
+        )
+    }
+
+    /// Draw a line graph of the last `len` frame times in milliseconds to the current canvas,
+    /// with the average frame time and frame rate drawn at the top. Call this once per frame to
+    /// record frame times.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.frame_time_graph(120)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn frame_time_graph(&mut self, len: usize) -> PixResult<()> {
+        let s = self;
+        let frame = s.frame_count();
+        let delta = s.delta_time().as_secs_f32() * 1000.0;
+        s.ui.frame_times.record(frame, delta, len);
+
+        let values = s.ui.frame_times.values();
+        let average = if values.is_empty() {
+            0.0
+        } else {
+            values.iter().sum::<f32>() / values.len() as f32
+        };
+        let overlay = format!("{average:.2} ms ({:.0} FPS)", s.avg_frame_rate());
+        s.plot_lines("Frame Time", &values, Some(&overlay), 0.0, None, None)
+    }
+}
+
+impl PixState {
+    /// Draw a plot of `values` to the current canvas.
+    fn plot(
+        &mut self,
+        label: &str,
+        values: &[f32],
+        overlay_text: Option<&str>,
+        (scale_min, scale_max): (Option<f32>, Option<f32>),
+        size: Option<[i32; 2]>,
+        kind: PlotKind,
+    ) -> PixResult<()> {
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let pos = s.cursor_pos();
+        let font_size = clamp_size(s.theme.font_size);
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Calculate plot rect
+        let (label_width, label_height) = s.padded_text_size(label)?;
+        let [mut x, y] = pos.coords();
+        if !label.is_empty() {
+            x += label_width + ipad.x();
+        }
+        let [width, height] = match size {
+            Some(size) => size,
+            None => {
+                let width =
+                    s.ui.next_width
+                        .take()
+                        .unwrap_or_else(|| s.ui_width().unwrap_or(100));
+                [width, 4 * font_size + 2 * ipad.y()]
+            }
+        };
+        let plot = rect![x, y, width.max(3), height.max(3)];
+        let inner = rect![
+            plot.x() + 1,
+            plot.y() + 1,
+            plot.width() - 2,
+            plot.height() - 2
+        ];
+        let (min, max) = plot_range(values, scale_min, scale_max);
+
+        // Check hover
+        let hovered = s.focused() && s.ui.try_hover(id, &plot);
+        let hovered_index = if hovered {
+            plot_index(
+                s.mouse_pos().x() - inner.x(),
+                inner.width(),
+                values.len(),
+                kind,
+            )
+        } else {
+            None
+        };
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Label
+        if !label.is_empty() {
+            s.set_cursor_pos([pos.x(), pos.y() + plot.height() / 2 - label_height / 2]);
+            s.text(label)?;
+        }
+
+        // Frame
+        s.rect_mode(RectMode::Corner);
+        let [stroke, bg, fg] = s.widget_colors(id, ColorType::Surface);
+        s.stroke(stroke);
+        s.fill(bg);
+        s.rect(plot)?;
+
+        // Values
+        match kind {
+            PlotKind::Lines => {
+                let count = values.len();
+                let point = |i: usize, value: f32| {
+                    let px = if count > 1 {
+                        inner.x() + (i * (inner.width() - 1) as usize / (count - 1)) as i32
+                    } else {
+                        inner.x() + inner.width() / 2
+                    };
+                    point![px, plot_y(value, min, max, inner.y(), inner.height())]
+                };
+                s.stroke(colors.primary);
+                for (i, pair) in values.windows(2).enumerate() {
+                    s.line(line_![point(i, pair[0]), point(i + 1, pair[1])])?;
+                }
+                if let Some(i) = hovered_index {
+                    s.stroke(None);
+                    s.fill(colors.secondary);
+                    s.circle(circle![point(i, values[i]), 2])?;
+                }
+            }
+            PlotKind::Histogram => {
+                let count = values.len() as i32;
+                let bottom = inner.bottom();
+                s.stroke(None);
+                for (i, &value) in values.iter().enumerate() {
+                    let left = inner.x() + i as i32 * inner.width() / count;
+                    let right = inner.x() + (i as i32 + 1) * inner.width() / count;
+                    let bar_height =
+                        (plot_fraction(value, min, max) * inner.height() as f32).round() as i32;
+                    if bar_height > 0 {
+                        if hovered_index == Some(i) {
+                            s.fill(colors.secondary);
+                        } else {
+                            s.fill(colors.primary);
+                        }
+                        let bar_width = (right - left - 1).max(1);
+                        s.rect([left, bottom - bar_height, bar_width, bar_height])?;
+                    }
+                }
+            }
+        }
+
+        // Overlay
+        if let Some(text) = overlay_text {
+            let (text_width, _) = s.text_size(text)?;
+            s.set_cursor_pos([
+                plot.center().x() - clamp_size(text_width) / 2,
+                plot.y() + ipad.y(),
+            ]);
+            s.stroke(None);
+            s.fill(fg);
+            s.text(text)?;
+        }
+
+        s.ui.pop_cursor();
+        s.pop();
+
+        // Value under the cursor
+        if let Some(i) = hovered_index {
+            s.push_id(id);
+            s.plot_tooltip(&format!("{i}: {:.3}", values[i]))?;
+            s.pop_id();
+        }
+
+        s.advance_cursor([plot.right() - pos.x(), plot.height()]);
+
+        Ok(())
+    }
+
+    /// Draw a tooltip with `text` at the mouse cursor. Unlike [`PixState::tooltip`], the tooltip
+    /// texture is reused as the text changes.
+    fn plot_tooltip(&mut self, text: &str) -> PixResult<()> {
+        let s = self;
+        let id = s.ui.get_id(&TOOLTIP_LABEL);
+        let pad = s.theme.spacing.frame_pad;
+
+        let (text_width, text_height) = s.padded_text_size(text)?;
+        let text_width = text_width + 2 * pad.x();
+        let text_height = text_height + 2 * pad.y();
+
+        s.advanced_tooltip(
+            TOOLTIP_LABEL,
+            rect![s.mouse_pos(), text_width, text_height],
+            |s: &mut PixState| {
+                let [stroke, bg, fg] = s.widget_colors(id, ColorType::Surface);
+                s.background(bg);
+
+                s.stroke(stroke);
+                s.fill(None);
+                s.rect([0, 0, text_width - 1, text_height - 1])?;
+
+                s.stroke(None);
+                s.fill(fg);
+                s.text(text)?;
+                Ok(())
+            },
+        )
+    }
+}
+
+/// Returns the `(min, max)` range to scale plot values by, using the minimum or maximum of the
+/// finite `values` when not provided.
+fn plot_range(values: &[f32], scale_min: Option<f32>, scale_max: Option<f32>) -> (f32, f32) {
+    let finite = || values.iter().copied().filter(|v| v.is_finite());
+    let min = scale_min.unwrap_or_else(|| finite().fold(f32::INFINITY, f32::min));
+    let max = scale_max.unwrap_or_else(|| finite().fold(f32::NEG_INFINITY, f32::max));
+    match (min.is_finite(), max.is_finite()) {
+        (true, true) => (min, max),
+        (true, false) => (min, min),
+        (false, true) => (max, max),
+        (false, false) => (0.0, 0.0),
+    }
+}
+
+/// Returns the fraction of `value` between `min` and `max`, clamped to `0.0..=1.0`. Values are
+/// centered when the range is empty, such as for constant data.
+fn plot_fraction(value: f32, min: f32, max: f32) -> f32 {
+    let range = max - min;
+    if !value.is_finite() {
+        0.0
+    } else if range > 0.0 {
+        ((value - min) / range).clamp(0.0, 1.0)
+    } else {
+        0.5
+    }
+}
+
+/// Returns the y-coordinate of `value` scaled between `min` and `max` within a plot area, where
+/// `min` maps to the bottom row and `max` maps to the `top` row.
+fn plot_y(value: f32, min: f32, max: f32, top: i32, height: i32) -> i32 {
+    let rows = (height - 1).max(0);
+    top + rows - (plot_fraction(value, min, max) * rows as f32).round() as i32
+}
+
+/// Returns the index of the value at horizontal offset `x` within a plot area `width` pixels
+/// wide displaying `count` values.
+fn plot_index(x: i32, width: i32, count: usize, kind: PlotKind) -> Option<usize> {
+    if count == 0 || width <= 0 || !(0..width).contains(&x) {
+        return None;
+    }
+    let (x, width, count) = (x as usize, width as usize, count);
+    let index = match kind {
+        PlotKind::Lines if count > 1 => (x * (count - 1) + (width - 1) / 2) / (width - 1).max(1),
+        PlotKind::Lines => 0,
+        PlotKind::Histogram => x * count / width,
+    };
+    Some(index.min(count - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_range_auto_scales() {
+        let values = [2.0, -1.0, f32::NAN, 4.0];
+        assert_eq!(plot_range(&values, None, None), (-1.0, 4.0));
+        assert_eq!(plot_range(&values, Some(0.0), None), (0.0, 4.0));
+        assert_eq!(plot_range(&values, None, Some(10.0)), (-1.0, 10.0));
+        assert_eq!(plot_range(&[], None, None), (0.0, 0.0));
+        assert_eq!(plot_range(&[], Some(1.0), None), (1.0, 1.0));
+    }
+
+    #[test]
+    fn plot_y_scaling() {
+        assert_eq!(plot_y(0.0, 0.0, 10.0, 5, 101), 105);
+        assert_eq!(plot_y(10.0, 0.0, 10.0, 5, 101), 5);
+        assert_eq!(plot_y(5.0, 0.0, 10.0, 5, 101), 55);
+        assert_eq!(plot_y(-5.0, 0.0, 10.0, 5, 101), 105);
+        assert_eq!(plot_y(15.0, 0.0, 10.0, 5, 101), 5);
+    }
+
+    #[test]
+    fn plot_constant_data() {
+        let values = [3.0; 4];
+        let (min, max) = plot_range(&values, None, None);
+        assert_eq!((min, max), (3.0, 3.0));
+        assert_eq!(plot_fraction(3.0, min, max), 0.5);
+        assert_eq!(plot_y(3.0, min, max, 0, 101), 50);
+        assert_eq!(plot_fraction(f32::NAN, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn plot_index_under_cursor() {
+        assert_eq!(plot_index(0, 100, 10, PlotKind::Histogram), Some(0));
+        assert_eq!(plot_index(55, 100, 10, PlotKind::Histogram), Some(5));
+        assert_eq!(plot_index(99, 100, 10, PlotKind::Histogram), Some(9));
+        assert_eq!(plot_index(0, 101, 11, PlotKind::Lines), Some(0));
+        assert_eq!(plot_index(14, 101, 11, PlotKind::Lines), Some(1));
+        assert_eq!(plot_index(100, 101, 11, PlotKind::Lines), Some(10));
+        assert_eq!(plot_index(-1, 100, 10, PlotKind::Lines), None);
+        assert_eq!(plot_index(100, 100, 10, PlotKind::Histogram), None);
+        assert_eq!(plot_index(10, 100, 0, PlotKind::Lines), None);
+    }
+}
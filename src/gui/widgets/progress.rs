@@ -0,0 +1,183 @@
+//! Progress bar widget rendering methods.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::progress_bar`]
+//! - [`PixState::advanced_progress_bar`]
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { progress: f64, downloaded: f64 };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     s.progress_bar("Progress", self.progress)?;
+//!     s.advanced_progress_bar(
+//!         "Download",
+//!         self.downloaded,
+//!         0.0,
+//!         512.0,
+//!         Some(|val| format!("{val:.0} / 512 KB").into()),
+//!     )?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::{ops::clamp_size, prelude::*};
+use std::borrow::Cow;
+
+impl PixState {
+    /// Draw a progress bar to the current canvas, filled by `value` ranging from `0.0..=1.0`.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { progress: f64 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.progress_bar("Progress", self.progress)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn progress_bar<L>(&mut self, label: L, value: f64) -> PixResult<()>
+    where
+        L: AsRef<str>,
+    {
+        self.advanced_progress_bar(label, value, 0.0, 1.0, None)
+    }
+
+    /// Draw a progress bar to the current canvas, filled by `value` ranging from `min..=max`, with
+    /// an optional `formatter` for the displayed value. Displays a percentage by default.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { downloaded: f64 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.advanced_progress_bar(
+    ///         "Download",
+    ///         self.downloaded,
+    ///         0.0,
+    ///         512.0,
+    ///         Some(|val| format!("{val:.0} / 512 KB").into()),
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn advanced_progress_bar<'a, L>(
+        &mut self,
+        label: L,
+        value: f64,
+        min: f64,
+        max: f64,
+        formatter: Option<fn(&f64) -> Cow<'a, str>>,
+    ) -> PixResult<()>
+    where
+        L: AsRef<str>,
+    {
+        let label = label.as_ref();
+
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let pos = s.cursor_pos();
+        let font_size = clamp_size(s.theme.font_size);
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Calculate progress bar rect
+        let width =
+            s.ui.next_width
+                .take()
+                .unwrap_or_else(|| s.ui_width().unwrap_or(100));
+        let (label_width, label_height) = s.padded_text_size(label)?;
+        let [mut x, y] = pos.coords();
+        if !label.is_empty() {
+            x += label_width + ipad.x();
+        }
+        let bar = rect![x, y, width, font_size + 2 * ipad.y()];
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Label
+        if !label.is_empty() {
+            s.set_cursor_pos([pos.x(), pos.y() + bar.height() / 2 - label_height / 2]);
+            s.text(label)?;
+        }
+
+        // Unfilled region
+        s.rect_mode(RectMode::Corner);
+        let [stroke, _, fg] = s.widget_colors(id, ColorType::Background);
+        s.stroke(stroke);
+        s.fill(colors.disabled());
+        s.rect(bar)?;
+
+        // Filled region
+        let progress = progress(value, min, max);
+        let filled_width = (f64::from(bar.width() - 2) * progress).round() as i32;
+        if filled_width > 0 {
+            s.stroke(None);
+            s.fill(colors.primary);
+            s.rect([bar.x() + 1, bar.y() + 1, filled_width, bar.height() - 2])?;
+        }
+
+        // Value
+        let text =
+            formatter.map_or_else(|| format!("{:.0}%", progress * 100.0).into(), |f| f(&value));
+        let (vw, vh) = s.padded_text_size(&text)?;
+        let center = bar.center() - point![vw, vh] / 2;
+        s.set_cursor_pos(center);
+        s.stroke(None);
+        s.fill(fg);
+        s.text(&text)?;
+
+        s.ui.pop_cursor();
+        s.pop();
+
+        s.advance_cursor([bar.right() - pos.x(), bar.height()]);
+
+        Ok(())
+    }
+}
+
+/// Returns the fraction of `value` between `min` and `max`, clamped to `0.0..=1.0`.
+fn progress(value: f64, min: f64, max: f64) -> f64 {
+    let range = max - min;
+    if range > 0.0 && value.is_finite() {
+        ((value - min) / range).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_clamped() {
+        assert_eq!(progress(0.25, 0.0, 1.0), 0.25);
+        assert_eq!(progress(384.0, 0.0, 512.0), 0.75);
+        assert_eq!(progress(15.0, 10.0, 20.0), 0.5);
+        assert_eq!(progress(-1.0, 0.0, 1.0), 0.0);
+        assert_eq!(progress(2.0, 0.0, 1.0), 1.0);
+        assert_eq!(progress(f64::NAN, 0.0, 1.0), 0.0);
+        assert_eq!(progress(0.5, 1.0, 1.0), 0.0);
+    }
+}
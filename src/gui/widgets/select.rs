@@ -3,6 +3,7 @@
 //! Provided [`PixState`] methods:
 //!
 //! - [`PixState::select_box`]
+//! - [`PixState::combo_box`]
 //! - [`PixState::select_list`]
 //!
 //! # Example
@@ -88,9 +89,10 @@ impl PixState {
         let ipad = spacing.item_pad;
 
         // Calculate rect
-        let (item_width, item_height) = s.text_size(items.get(0).map_or("", AsRef::as_ref))?;
+        let (item_width, item_height) =
+            s.padded_text_size(items.get(0).map_or("", AsRef::as_ref))?;
         let width = s.ui.next_width.take().unwrap_or(item_width);
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         let [mut x, y] = pos.coords();
         if !label.is_empty() {
             x += label_width + ipad.x();
@@ -101,7 +103,7 @@ impl PixState {
         let hovered = s.focused() && s.ui.try_hover(id, &select_box);
         let focused = s.focused() && s.ui.try_focus(id);
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Label
@@ -224,6 +226,51 @@ impl PixState {
         Ok(original_selected != *selected)
     }
 
+    /// Draw a compact dropdown to the current canvas showing the selected item that returns
+    /// `true` when selection is changed. Clicking opens a list of all items, up to
+    /// [`MAX_DISPLAYED`] at once. Arrow keys change the selection while focused, and `Return` or
+    /// `Escape` toggle the list open or closed.
+    ///
+    /// This is a [`PixState::select_box`] that displays as many items as possible.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { combo_box: usize };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let items = ["Item 1", "Item 2", "Item 3"];
+    ///     if s.combo_box("Combo Box", &items, &mut self.combo_box)? {
+    ///         // selection changed
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn combo_box<L, I>(
+        &mut self,
+        label: L,
+        items: &[I],
+        selected: &mut usize,
+    ) -> PixResult<bool>
+    where
+        L: AsRef<str>,
+        I: AsRef<str>,
+    {
+        if items.is_empty() {
+            let empty: [&str; 1] = [""];
+            let mut none = 0;
+            return self.select_box(label, &mut none, &empty, 1).map(|_| false);
+        }
+        let displayed_count = cmp::min(items.len(), MAX_DISPLAYED);
+        self.select_box(label, selected, items, displayed_count)
+    }
+
     /// Draw a select list to the current canvas with a scrollable region that returns `true` when
     /// selection is changed.
     ///
@@ -278,7 +325,7 @@ impl PixState {
         let ipad = spacing.item_pad;
 
         // Calculate rect
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         let width = s.ui.next_width.take().unwrap_or(label_width);
         let [x, mut y] = pos.coords();
         if !label.is_empty() {
@@ -295,7 +342,7 @@ impl PixState {
         // Check hover/active/keyboard focus
         let focused = s.focused() && s.ui.try_focus(id);
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Select List
@@ -342,7 +389,7 @@ impl PixState {
         // Scrollbars
         let total_height = items.len() as i32 * line_height + 2;
         let total_width = items.iter().fold(0, |max_width, item| {
-            let (w, _) = s.text_size(item.as_ref()).unwrap_or((0, 0));
+            let (w, _) = s.padded_text_size(item.as_ref()).unwrap_or((0, 0));
             cmp::max(w, max_width)
         });
 
@@ -470,7 +517,7 @@ impl PixState {
             let item_rect = rect!(select_list.x(), y, select_list.width(), line_height);
             let clickable =
                 item_rect.bottom() > content_clip.y() || item_rect.top() < select_list.height();
-            s.push();
+            s.push_screen_space();
             s.clip(item_clip)?;
             if hovered && clickable && item_rect.contains(mpos) {
                 s.frame_cursor(&Cursor::hand())?;
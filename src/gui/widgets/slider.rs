@@ -6,12 +6,13 @@
 //! - [`PixState::advanced_drag`]
 //! - [`PixState::slider`]
 //! - [`PixState::advanced_slider`]
+//! - [`PixState::knob`]
 //!
 //! # Example
 //!
 //! ```
 //! # use pix_engine::prelude::*;
-//! # struct App { drag: i32, advanced_drag: f32, slider: i32, advanced_slider: f32};
+//! # struct App { drag: i32, advanced_drag: f32, slider: i32, advanced_slider: f32, knob: f32};
 //! # impl PixEngine for App {
 //! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
 //!     s.drag("Drag", &mut self.drag, 1)?;
@@ -31,6 +32,7 @@
 //!         1.0,
 //!         Some(|val| format!("ratio = {:.3}", val).into()),
 //!     )?;
+//!     s.knob("Knob", &mut self.knob, -1.0, 1.0)?;
 //!     Ok(())
 //! }
 //! # }
@@ -42,7 +44,10 @@ use crate::{
     prelude::*,
 };
 use num_traits::{clamp, Bounded};
-use std::{borrow::Cow, error::Error as StdError, fmt, str::FromStr};
+use std::{borrow::Cow, error::Error as StdError, f64::consts::PI, fmt, str::FromStr};
+
+/// Vertical drag distance in pixels to turn a knob through its full range.
+const KNOB_DRAG_RANGE: i32 = 200;
 
 impl PixState {
     /// Draw a draggable number widget to the current canvas.
@@ -125,7 +130,7 @@ impl PixState {
             s.ui.next_width
                 .take()
                 .unwrap_or_else(|| s.ui_width().unwrap_or(100));
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         let [mut x, y] = pos.coords();
         if !label.is_empty() {
             x += label_width + ipad.x();
@@ -162,7 +167,7 @@ impl PixState {
         }
         let mut new_value = clamp(s.ui.parse_text_edit(id, *value), min, max);
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Label
@@ -183,7 +188,7 @@ impl PixState {
 
         // Value
         let text = formatter.map_or_else(|| value.to_string().into(), |f| f(value));
-        let (vw, vh) = s.text_size(&text)?;
+        let (vw, vh) = s.padded_text_size(&text)?;
         let center = drag.center() - point![vw, vh] / 2;
         s.set_cursor_pos(center);
         s.stroke(None);
@@ -308,7 +313,7 @@ impl PixState {
             s.ui.next_width
                 .take()
                 .unwrap_or_else(|| s.ui_width().unwrap_or(100));
-        let (label_width, label_height) = s.text_size(label)?;
+        let (label_width, label_height) = s.padded_text_size(label)?;
         let [mut x, y] = pos.coords();
         if !label.is_empty() {
             x += label_width + ipad.x();
@@ -345,7 +350,7 @@ impl PixState {
         }
         let mut new_value = clamp(s.ui.parse_text_edit(id, *value), min, max);
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Label
@@ -389,7 +394,7 @@ impl PixState {
 
         // Value
         let text = formatter.map_or_else(|| value.to_string().into(), |f| f(value));
-        let (vw, vh) = s.text_size(&text)?;
+        let (vw, vh) = s.padded_text_size(&text)?;
         let center = slider.center() - point![vw, vh] / 2;
         s.set_cursor_pos(center);
         s.stroke(None);
@@ -410,6 +415,16 @@ impl PixState {
                 new_value =
                     num_traits::NumCast::from(mx.mul_add(vmax - vmin, vmin)).unwrap_or(*value);
             }
+        } else if focused && !disabled {
+            match s.ui.key_entered() {
+                Some(Key::Right | Key::Up) => {
+                    new_value = clamp(*value + key_step(min, max), min, max);
+                }
+                Some(Key::Left | Key::Down) => {
+                    new_value = clamp(*value - key_step(min, max), min, max);
+                }
+                _ => (),
+            }
         }
         s.ui.handle_focus(id);
         s.advance_cursor([slider.right() - pos.x(), slider.height()]);
@@ -421,4 +436,202 @@ impl PixState {
             Ok(true)
         }
     }
+
+    /// Draw a rotary knob widget to the current canvas. Dragging up increases the value and
+    /// dragging down decreases it. Double-clicking or holding `Ctrl` while clicking resets the
+    /// value to the midpoint between `min` and `max`.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { pan: f32, octave: i32 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.knob("Pan", &mut self.pan, -1.0, 1.0)?;
+    ///     s.knob("Octave", &mut self.octave, 0, 8)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn knob<T, L>(&mut self, label: L, value: &mut T, min: T, max: T) -> PixResult<bool>
+    where
+        T: Num + num_traits::NumCast + fmt::Display,
+        L: AsRef<str>,
+    {
+        let label = label.as_ref();
+        let s = self;
+        let id = s.ui.get_id(&label);
+        let label = s.ui.get_label(label);
+        let pos = s.cursor_pos();
+        let font_size = clamp_size(s.theme.font_size);
+        let ipad = s.theme.spacing.item_pad;
+        let colors = s.theme.colors;
+
+        // Calculate knob circle
+        let diameter = 2 * (font_size + ipad.y());
+        let radius = diameter / 2;
+        let (label_width, label_height) = s.padded_text_size(label)?;
+        let [mut x, y] = pos.coords();
+        if !label.is_empty() {
+            x += label_width + ipad.x();
+        }
+        let knob = circle![x + radius, y + radius, radius];
+
+        // Check hover/active/keyboard focus
+        let hovered = s.focused() && s.ui.try_hover(id, &knob);
+        let focused = s.focused() && s.ui.try_focus(id);
+        let active = s.ui.is_active(id);
+        let disabled = s.ui.disabled;
+
+        s.push_screen_space();
+        s.ui.push_cursor();
+
+        // Label
+        if !label.is_empty() {
+            s.set_cursor_pos([pos.x(), pos.y() + radius - label_height / 2]);
+            s.text(label)?;
+        }
+
+        // Dial
+        s.ellipse_mode(EllipseMode::Center);
+        if hovered || active {
+            s.frame_cursor(&Cursor::hand())?;
+        }
+        let [stroke, bg, fg] = s.widget_colors(id, ColorType::Primary);
+        s.stroke(stroke);
+        s.fill(bg);
+        s.circle(knob)?;
+
+        // Indicator, sweeping clockwise from bottom-left to bottom-right
+        let vmin: f64 = num_traits::NumCast::from(min).unwrap_or(0.0);
+        let vmax: f64 = num_traits::NumCast::from(max).unwrap_or(1.0);
+        let val: f64 = num_traits::NumCast::from(*value).unwrap_or(0.0);
+        let t = if vmax > vmin {
+            ((val - vmin) / (vmax - vmin)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let angle = 0.75 * PI + t * 1.5 * PI;
+        let center = knob.center();
+        let length = f64::from(radius - 2);
+        let end = point![
+            center.x() + (angle.cos() * length).round() as i32,
+            center.y() + (angle.sin() * length).round() as i32,
+        ];
+        s.stroke(fg);
+        s.line(line_![center, end])?;
+
+        // Value
+        let text = value.to_string();
+        let (_, vh) = s.padded_text_size(&text)?;
+        s.set_cursor_pos([knob.right() + ipad.x(), center.y() - vh / 2]);
+        s.stroke(None);
+        s.fill(colors.on_background);
+        let (vw, _) = s.text(&text)?;
+
+        s.ui.pop_cursor();
+        s.pop();
+
+        // Process input
+        let mut new_value = *value;
+        let reset = !disabled
+            && hovered
+            && s.ui.mouse_clicked(Mouse::Left)
+            && (s.ui.mouse_dbl_clicked(Mouse::Left) || s.keymod_down(MOD_CTRL));
+        if reset {
+            new_value = min + (max - min) / (T::one() + T::one());
+            s.ui.set_drag_offset(id, 0);
+        } else if active {
+            let offset = s.ui.drag_offset(id) + s.pmouse_pos().y() - s.mouse_pos().y();
+            let (delta, remaining) = knob_delta(offset, min, max);
+            new_value = clamp(*value + delta, min, max);
+            s.ui.set_drag_offset(id, remaining);
+        } else if focused {
+            match s.ui.key_entered() {
+                Some(Key::Up | Key::Right) => {
+                    new_value = clamp(*value + key_step(min, max), min, max);
+                }
+                Some(Key::Down | Key::Left) => {
+                    new_value = clamp(*value - key_step(min, max), min, max);
+                }
+                _ => (),
+            }
+        }
+        s.ui.handle_focus(id);
+        s.advance_cursor([knob.right() + ipad.x() + vw as i32 - pos.x(), diameter]);
+
+        if new_value == *value {
+            Ok(false)
+        } else {
+            *value = new_value;
+            Ok(true)
+        }
+    }
+}
+
+/// Returns the change in value for a knob dragged vertically by `offset` pixels, along with the
+/// remaining pixels too small to change the value, such as for integer values.
+fn knob_delta<T>(offset: i32, min: T, max: T) -> (T, i32)
+where
+    T: Num + num_traits::NumCast,
+{
+    let range: f64 = num_traits::NumCast::from(max - min).unwrap_or(0.0);
+    if offset == 0 || range <= 0.0 {
+        return (T::zero(), offset);
+    }
+    let drag_range = f64::from(KNOB_DRAG_RANGE);
+    let delta: f64 = f64::from(offset) * range / drag_range;
+    let delta: T = num_traits::NumCast::from(delta).unwrap_or_else(T::zero);
+    let applied: f64 = num_traits::NumCast::from(delta).unwrap_or(0.0);
+    let used = (applied * drag_range / range).round() as i32;
+    (delta, offset - used)
+}
+
+/// Returns the value change for a single arrow key step, 1% of the range or at least `1` for
+/// integers.
+fn key_step<T>(min: T, max: T) -> T
+where
+    T: Num + num_traits::NumCast,
+{
+    let range: f64 = num_traits::NumCast::from(max - min).unwrap_or(0.0);
+    let step: T = num_traits::NumCast::from(range / 100.0).unwrap_or_else(T::zero);
+    if step == T::zero() {
+        T::one()
+    } else {
+        step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knob_delta_float() {
+        assert_eq!(knob_delta(20, 0.0, 1.0), (0.1, 0));
+        assert_eq!(knob_delta(-100, -1.0, 1.0), (-1.0, 0));
+        assert_eq!(knob_delta(0, 0.0, 1.0), (0.0, 0));
+    }
+
+    #[test]
+    fn knob_delta_integer() {
+        // 20 pixels per step over a range of 10
+        assert_eq!(knob_delta(15, 0, 10), (0, 15));
+        assert_eq!(knob_delta(25, 0, 10), (1, 5));
+        assert_eq!(knob_delta(-45, 0, 10), (-2, -5));
+        assert_eq!(knob_delta(400, 0, 1000), (2000, 0));
+        assert_eq!(knob_delta(10, 5, 5), (0, 10));
+    }
+
+    #[test]
+    fn key_step_minimum() {
+        assert_eq!(key_step(0.0, 2.0), 0.02);
+        assert_eq!(key_step(0, 10), 1);
+        assert_eq!(key_step(0, 1000), 10);
+    }
 }
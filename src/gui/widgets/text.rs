@@ -3,10 +3,16 @@
 //! Provided [`PixState`] methods:
 //!
 //! - [`PixState::text`]
+//! - [`PixState::text_size`]
+//! - [`PixState::text_bounds`]
+//! - [`PixState::text_wrapped`]
 //! - [`PixState::text_transformed`]
 //! - [`PixState::bullet`]
 //! - [`PixState::collapsing_tree`]
+//! - [`PixState::tree_node`]
+//! - [`PixState::tree_pop`]
 //! - [`PixState::collapsing_header`]
+//! - [`PixState::collapsible_panel`]
 //!
 //! # Example
 //!
@@ -26,7 +32,12 @@
 //! # }
 //! ```
 
-use crate::{gui::Direction, ops::clamp_size, prelude::*, renderer::Rendering};
+use crate::{
+    gui::{Direction, ElementId},
+    ops::{clamp_size, wrap_lines},
+    prelude::*,
+    renderer::Rendering,
+};
 
 impl PixState {
     /// Return the dimensions of given text for drawing to the current canvas.
@@ -57,7 +68,78 @@ impl PixState {
             .size_of(text.as_ref(), self.settings.wrap_width)
     }
 
-    /// Draw body text to the current canvas.
+    /// Return the `(width, height)` of given text as if drawn to the current canvas with
+    /// [`PixState::text`], using the current font, size, style, and wrap width. Multi-line text is
+    /// as wide as its widest line. Unlike [`PixState::text_bounds`], the stroke outline and UI
+    /// padding are not included.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to load the current font, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let text = "Game Over";
+    ///     let (w, h) = s.text_size(text)?;
+    ///     let x = (s.width()? - w) as i32 / 2;
+    ///     let y = (s.height()? - h) as i32 / 2;
+    ///     s.set_cursor_pos([x, y]);
+    ///     s.text(text)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn text_size<S: AsRef<str>>(&self, text: S) -> PixResult<(u32, u32)> {
+        let layout = layout_text(
+            text.as_ref(),
+            self.settings.wrap_width,
+            Align::Left,
+            Baseline::Top,
+            |line| self.renderer.size_of(line, None),
+        )?;
+        Ok(layout.size)
+    }
+
+    /// Return the `(width, height)` bounds of given text as if drawn to the current canvas with
+    /// [`PixState::text`], using the current font, size, style, stroke, and wrap width. Nothing is
+    /// drawn and the UI cursor is not advanced.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to load the current font, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let text = "Right-aligned text";
+    ///     let (w, _) = s.text_bounds(text)?;
+    ///     s.set_cursor_pos([s.width()? as i32 - w as i32, 0]);
+    ///     s.text(text)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn text_bounds<S: AsRef<str>>(&self, text: S) -> PixResult<(u32, u32)> {
+        let s = &self.settings;
+        let (width, height) = self.padded_text_size(text.as_ref())?;
+        let outline = match (s.stroke, s.stroke_weight) {
+            (Some(_), weight) if weight > 0 => 2 * i32::from(weight),
+            _ => 0,
+        };
+        Ok(((width + outline) as u32, (height + outline) as u32))
+    }
+
+    /// Draw body text to the current canvas. Text is drawn in world space while a [`Camera2D`] is
+    /// set.
     ///
     /// Returns the rendered `(width, height)` of the text, including any newlines or text
     /// wrapping.
@@ -85,6 +167,38 @@ impl PixState {
         self.text_transformed(text, None, None, None)
     }
 
+    /// Draw body text to the current canvas, breaking lines at word boundaries when they would
+    /// exceed `wrap_width` pixels. The current [`PixState::wrap`] setting is not changed.
+    ///
+    /// Returns the rendered `(width, height)` of the text, including any newlines or text
+    /// wrapping.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.text_wrapped("Lorem ipsum dolor sit amet, consetetur sadipscing elitr", 100)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn text_wrapped<S>(&mut self, text: S, wrap_width: u32) -> PixResult<(u32, u32)>
+    where
+        S: AsRef<str>,
+    {
+        let wrap = self.settings.wrap_width.replace(wrap_width);
+        let result = self.text_transformed(text, None, None, None);
+        self.settings.wrap_width = wrap;
+        result
+    }
+
     /// Draw heading text to the current canvas.
     ///
     /// Returns the rendered `(width, height)` of the text, including any newlines or text
@@ -111,7 +225,7 @@ impl PixState {
         S: AsRef<str>,
     {
         let s = self;
-        s.push();
+        s.push_screen_space();
         s.renderer.font_family(&s.theme.fonts.heading)?;
         s.renderer.font_size(s.theme.font_size + 6)?;
         s.renderer.font_style(s.theme.styles.heading);
@@ -146,7 +260,7 @@ impl PixState {
         S: AsRef<str>,
     {
         let s = self;
-        s.push();
+        s.push_screen_space();
         s.renderer.font_family(&s.theme.fonts.monospace)?;
         s.renderer.font_size(s.theme.font_size + 2)?;
         s.renderer.font_style(s.theme.styles.monospace);
@@ -156,7 +270,8 @@ impl PixState {
     }
 
     /// Draw transformed text to the current canvas, optionally rotated about a `center` by `angle`
-    /// or `flipped`. `angle` can be in radians or degrees depending on [`AngleMode`].
+    /// or `flipped`. `angle` can be in radians or degrees depending on [`AngleMode`]. Text is drawn
+    /// in world space while a [`Camera2D`] is set.
     ///
     /// Returns the rendered `(width, height)` of the text, including any newlines or text
     /// wrapping.
@@ -250,13 +365,15 @@ impl PixState {
 
         let r = font_size / 5;
 
-        s.push();
+        s.push_screen_space();
         s.ellipse_mode(EllipseMode::Corner);
         s.circle([pos.x() + ipad.x(), pos.y() + font_size / 2, r])?;
         s.pop();
 
         s.set_cursor_pos([pos.x() + ipad.x() + 2 * r + 2 * ipad.x(), pos.y()]);
+        s.push_screen_space();
         let (w, h) = s.text_transformed(text, 0.0, None, None)?;
+        s.pop();
 
         Ok((w + r as u32, h))
     }
@@ -279,7 +396,7 @@ impl PixState {
         let fpad = s.theme.spacing.frame_pad;
 
         // Calculate hover size
-        let (width, height) = s.text_size(text)?;
+        let (width, height) = s.padded_text_size(text)?;
         let width = s.ui.next_width.take().unwrap_or(width + 2 * fpad.x());
 
         let hover = rect![pos, width, height + 2 * fpad.y()];
@@ -287,7 +404,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let active = s.ui.is_active(id);
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Hover/Focused Rect
@@ -335,6 +452,48 @@ impl PixState {
     where
         S: AsRef<str>,
         F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let expanded = self.tree_node(text)?;
+        if expanded {
+            f(self)?;
+            self.tree_pop();
+        }
+        Ok(expanded)
+    }
+
+    /// Draw a collapsible tree node with an arrow and label to the current canvas which returns
+    /// `true` when the node is expanded. Expanded state is stored per label across frames.
+    ///
+    /// When `true` is returned, child content drawn afterwards is indented and its labels are
+    /// scoped to this node until [`PixState::tree_pop`] is called. [`PixState::tree_pop`] must only
+    /// be called when `true` is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.tree_node("Scene")? {
+    ///         if s.tree_node("Player")? {
+    ///             s.text("Health: 100")?;
+    ///             s.tree_pop();
+    ///         }
+    ///         s.text("Enemies: 3")?;
+    ///         s.tree_pop();
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn tree_node<S>(&mut self, text: S) -> PixResult<bool>
+    where
+        S: AsRef<str>,
     {
         let text = text.as_ref();
 
@@ -349,7 +508,7 @@ impl PixState {
         let arrow_width = font_size / 2;
 
         // Calculate hover size
-        let (width, height) = s.text_size(text)?;
+        let (width, height) = s.padded_text_size(text)?;
         let column_offset = s.ui.column_offset();
         let width =
             s.ui.next_width
@@ -361,7 +520,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let active = s.ui.is_active(id);
 
-        s.push();
+        s.push_screen_space();
 
         // Hover/Focused Rect
         let [stroke, bg, fg] = if hovered {
@@ -409,15 +568,38 @@ impl PixState {
         s.advance_cursor([hover.width(), ipad.y() / 2]);
 
         if expanded {
-            let (indent_width, _) = s.text_size("    ")?;
+            let (indent_width, _) = s.padded_text_size("    ")?;
             s.ui.set_column_offset(indent_width);
-            f(s)?;
-            s.ui.reset_column_offset();
+            s.push_id(id);
         }
 
         Ok(expanded)
     }
 
+    /// Close the scope of the last expanded [`PixState::tree_node`], restoring the indentation and
+    /// label scope from before it was drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.tree_node("Node")? {
+    ///         s.text("Child")?;
+    ///         s.tree_pop();
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn tree_pop(&mut self) {
+        self.pop_id();
+        self.ui.reset_column_offset();
+    }
+
     /// Draw a collapsing header to the current canvas which returns true when the tree is not
     /// collapsed.
     ///
@@ -433,16 +615,81 @@ impl PixState {
 
         let s = self;
         let id = s.ui.get_id(&text);
+        let expanded = s.ui.expanded(id);
+        if s.header(id, text, expanded)? {
+            s.ui.set_expanded(id, !expanded);
+        }
+
+        if expanded {
+            f(s)?;
+        }
+
+        Ok(expanded)
+    }
+
+    /// Draw a collapsible panel to the current canvas with a header that toggles `open` when
+    /// clicked, drawing the indented panel contents with `f` while open. Returns whether the panel
+    /// is open.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { show_log: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.collapsible_panel("Log", &mut self.show_log, |s: &mut PixState| {
+    ///         s.text("Started")?;
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn collapsible_panel<S, F>(&mut self, title: S, open: &mut bool, f: F) -> PixResult<bool>
+    where
+        S: AsRef<str>,
+        F: FnOnce(&mut PixState) -> PixResult<()>,
+    {
+        let title = title.as_ref();
+
+        let s = self;
+        let id = s.ui.get_id(&title);
+        let fpad = s.theme.spacing.frame_pad;
+        if s.header(id, title, *open)? {
+            *open = !*open;
+        }
+
+        if *open {
+            s.ui.set_column_offset(fpad.x());
+            s.push_id(id);
+            let result = f(s);
+            s.pop_id();
+            s.ui.reset_column_offset();
+            result?;
+        }
+
+        Ok(*open)
+    }
+}
+
+impl PixState {
+    /// Draw a collapsible header to the current canvas, returning whether it was toggled.
+    fn header(&mut self, id: ElementId, text: &str, expanded: bool) -> PixResult<bool> {
+        let s = self;
         let text = s.ui.get_label(text);
         let font_size = clamp_size(s.theme.font_size);
         let pos = s.cursor_pos();
         let fpad = s.theme.spacing.frame_pad;
         let ipad = s.theme.spacing.item_pad;
-        let expanded = s.ui.expanded(id);
         let arrow_width = font_size / 2;
 
         // Calculate hover size
-        let (width, height) = s.text_size(text)?;
+        let (width, height) = s.padded_text_size(text)?;
         let column_offset = s.ui.column_offset();
         let width =
             s.ui.next_width
@@ -454,7 +701,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let active = s.ui.is_active(id);
 
-        s.push();
+        s.push_screen_space();
 
         let [stroke, bg, fg] = s.widget_colors(id, ColorType::Secondary);
         if active || focused {
@@ -485,19 +732,13 @@ impl PixState {
         s.pop();
 
         // Process input
-        if (hovered && s.ui.was_clicked(id)) || (focused && s.ui.key_entered() == Some(Key::Return))
-        {
-            s.ui.set_expanded(id, !expanded);
-        }
+        let toggled = (hovered && s.ui.was_clicked(id))
+            || (focused && s.ui.key_entered() == Some(Key::Return));
         s.ui.handle_focus(id);
 
         s.advance_cursor([hover.width(), ipad.y() / 2]);
 
-        if expanded {
-            f(s)?;
-        }
-
-        Ok(expanded)
+        Ok(toggled)
     }
 }
 
@@ -517,6 +758,9 @@ impl PixState {
         let angle_mode = s.angle_mode;
         let colors = self.theme.colors;
         let ipad = self.theme.spacing.item_pad;
+        let camera = s.camera;
+        let font_size = s.font_size;
+        let rotated = matches!(angle, Some(angle) if angle != 0.0);
 
         let mut pos = self.cursor_pos();
         if s.rect_mode == RectMode::Center {
@@ -526,6 +770,20 @@ impl PixState {
         if outline == 0 && s.stroke_weight > 0 {
             pos += i32::from(s.stroke_weight);
         }
+        let layout = if s.text_align == Align::Left && s.text_baseline == Baseline::Top {
+            None
+        } else {
+            let layout = layout_text(text, wrap_width, s.text_align, s.text_baseline, |line| {
+                self.renderer.size_of(line, None)
+            })?;
+            if rotated {
+                // Rotated text is aligned as a single block
+                pos += layout.offset;
+                None
+            } else {
+                Some(layout)
+            }
+        };
 
         self.push();
 
@@ -545,7 +803,31 @@ impl PixState {
         } else {
             wrap_width
         };
-        let rect = if matches!(angle, Some(angle) if angle != 0.0) {
+        // Text follows the camera, scaled and rotated with the rest of the world
+        let zoom = camera.map_or(1.0, |camera| camera.zoom());
+        let scaled = |value: u32| (f64::from(value) * zoom).round() as u32;
+        let outline = u16::try_from(scaled(u32::from(outline))).unwrap_or(u16::MAX);
+        let rect = if let Some(layout) = layout {
+            if camera.is_some() {
+                self.renderer.font_size(scaled(font_size).max(1))?;
+            }
+            for (line, offset) in layout.lines {
+                let (pos, angle, center) =
+                    camera_text(camera, pos + offset, None, None, point![0.0, 0.0]);
+                self.renderer.text(
+                    pos,
+                    line,
+                    None,
+                    angle,
+                    center,
+                    flipped,
+                    Some(color),
+                    outline,
+                )?;
+            }
+            let (width, height) = layout.size;
+            rect![pos + layout.offset, clamp_size(width), clamp_size(height)]
+        } else if rotated || camera.is_some() {
             let angle = if angle_mode == AngleMode::Radians {
                 angle.map(f64::to_degrees)
             } else {
@@ -554,10 +836,19 @@ impl PixState {
             let (width, height) = self.renderer.size_of(text, wrap_width)?;
             let rect = rect![0, 0, clamp_size(width), clamp_size(height)];
             let rect = angle.map_or(rect, |angle| rect.rotated(angle.to_radians(), center));
+            let dst = pos - rect.top_left();
+            let pivot = center.map_or_else(
+                || point![f64::from(width) / 2.0, f64::from(height) / 2.0],
+                |center| point![f64::from(center.x()), f64::from(center.y())],
+            );
+            if camera.is_some() {
+                self.renderer.font_size(scaled(font_size).max(1))?;
+            }
+            let (dst, angle, center) = camera_text(camera, dst.into(), angle, center, pivot);
             self.renderer.text(
-                (pos - rect.top_left()).into(),
+                dst,
                 text,
-                wrap_width,
+                wrap_width.map(scaled),
                 angle,
                 center,
                 flipped,
@@ -583,3 +874,193 @@ impl PixState {
         Ok(rect)
     }
 }
+
+/// Lines of text positioned relative to an alignment anchor.
+#[derive(Debug, PartialEq)]
+struct TextLayout<'a> {
+    /// Each line and the offset of its top-left corner from the anchor.
+    lines: Vec<(&'a str, Vector<i32>)>,
+    /// Offset of the top-left corner of all lines from the anchor.
+    offset: Vector<i32>,
+    /// The `(width, height)` of all lines.
+    size: (u32, u32),
+}
+
+/// Split text into lines, then align each line using the `(width, height)` returned by `measure`.
+fn layout_text<F>(
+    text: &str,
+    wrap_width: Option<u32>,
+    align: Align,
+    baseline: Baseline,
+    mut measure: F,
+) -> PixResult<TextLayout<'_>>
+where
+    F: FnMut(&str) -> PixResult<(u32, u32)>,
+{
+    let lines = wrap_lines(text, wrap_width, |line| Ok(measure(line)?.0))?;
+
+    let mut sizes = Vec::with_capacity(lines.len());
+    for line in &lines {
+        let (width, height) = measure(line)?;
+        sizes.push((clamp_size(width), clamp_size(height)));
+    }
+    let width = sizes.iter().map(|&(width, _)| width).max().unwrap_or(0);
+    let height = sizes.iter().map(|&(_, height)| height).sum::<i32>();
+    let offset = vector![
+        match align {
+            Align::Left => 0,
+            Align::Center => -width / 2,
+            Align::Right => -width,
+        },
+        match baseline {
+            Baseline::Top => 0,
+            Baseline::Middle => -height / 2,
+            Baseline::Bottom => -height,
+        }
+    ];
+
+    let mut y = offset.y();
+    let lines = lines
+        .into_iter()
+        .zip(sizes)
+        .map(|(line, (line_width, line_height))| {
+            let x = match align {
+                Align::Left => offset.x(),
+                Align::Center => -line_width / 2,
+                Align::Right => -line_width,
+            };
+            let line = (line, vector![x, y]);
+            y += line_height;
+            line
+        })
+        .collect();
+    Ok(TextLayout {
+        lines,
+        offset,
+        size: (width as u32, height as u32),
+    })
+}
+
+/// Maps text drawn at the world position `pos` to screen space using the `camera`, rotating it by
+/// `angle` degrees about the `pivot` offset from `pos`. Returns the screen position, angle, and
+/// rotation center to draw with, or the unchanged values if there is no camera.
+fn camera_text(
+    camera: Option<Camera2D>,
+    pos: Point<i32>,
+    angle: Option<f64>,
+    center: Option<Point<i32>>,
+    pivot: Point<f64>,
+) -> (Point<i32>, Option<f64>, Option<Point<i32>>) {
+    let Some(camera) = camera else {
+        return (pos, angle, center);
+    };
+    let zoom = camera.zoom();
+    let world = point![
+        f64::from(pos.x()) + pivot.x(),
+        f64::from(pos.y()) + pivot.y()
+    ];
+    let screen = camera.world_to_screen(world);
+    let (pivot_x, pivot_y) = (pivot.x() * zoom, pivot.y() * zoom);
+    (
+        point![
+            (screen.x() - pivot_x).round() as i32,
+            (screen.y() - pivot_y).round() as i32
+        ],
+        Some(angle.unwrap_or(0.0) - camera.rotation().to_degrees()),
+        Some(point![pivot_x.round() as i32, pivot_y.round() as i32]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Measures text as a monospace font with 8x16 glyphs.
+    #[allow(clippy::unnecessary_wraps)]
+    fn monospace(text: &str) -> PixResult<(u32, u32)> {
+        Ok((8 * text.chars().count() as u32, 16))
+    }
+
+    #[test]
+    fn layout_default() -> PixResult<()> {
+        let layout = layout_text("Hello\nWorld!", None, Align::Left, Baseline::Top, monospace)?;
+        assert_eq!(layout.offset, vector![0, 0]);
+        assert_eq!(layout.size, (48, 32));
+        assert_eq!(
+            layout.lines,
+            vec![("Hello", vector![0, 0]), ("World!", vector![0, 16])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn layout_center_middle() -> PixResult<()> {
+        let layout = layout_text("Hello", None, Align::Center, Baseline::Middle, monospace)?;
+        assert_eq!(layout.offset, vector![-20, -8]);
+        assert_eq!(layout.size, (40, 16));
+        assert_eq!(layout.lines, vec![("Hello", vector![-20, -8])]);
+        Ok(())
+    }
+
+    #[test]
+    fn layout_multi_line() -> PixResult<()> {
+        let layout = layout_text("Hi\nThere", None, Align::Right, Baseline::Bottom, monospace)?;
+        assert_eq!(layout.offset, vector![-40, -32]);
+        assert_eq!(
+            layout.lines,
+            vec![("Hi", vector![-16, -32]), ("There", vector![-40, -16])]
+        );
+
+        let layout = layout_text("Hi\nThere", None, Align::Center, Baseline::Top, monospace)?;
+        assert_eq!(
+            layout.lines,
+            vec![("Hi", vector![-8, 0]), ("There", vector![-20, 16])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn layout_wrapped() -> PixResult<()> {
+        let text = "aa bb cc\nlongword x";
+        let layout = layout_text(text, Some(40), Align::Right, Baseline::Top, monospace)?;
+        assert_eq!(layout.size, (64, 64));
+        assert_eq!(
+            layout.lines,
+            vec![
+                ("aa bb", vector![-40, 0]),
+                ("cc", vector![-16, 16]),
+                ("longword", vector![-64, 32]),
+                ("x", vector![-8, 48]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn camera_text_transform() {
+        let pos = point![10, 5];
+        assert_eq!(
+            camera_text(None, pos, Some(45.0), None, point![0.0, 0.0]),
+            (pos, Some(45.0), None)
+        );
+
+        let mut camera = Camera2D::new();
+        camera.set_offset([100.0, 50.0]);
+        camera.set_zoom(2.0);
+        assert_eq!(
+            camera_text(Some(camera), pos, None, None, point![0.0, 0.0]),
+            (point![120, 60], Some(0.0), Some(point![0, 0]))
+        );
+
+        // Rotated text pivots about the camera transformed pivot
+        camera.set_rotation(std::f64::consts::FRAC_PI_2);
+        let (dst, angle, center) = camera_text(Some(camera), pos, None, None, point![4.0, 2.0]);
+        let pivot = camera.world_to_screen([14.0, 7.0]);
+        assert_eq!(center, Some(point![8, 4]));
+        assert_eq!(
+            dst + vector![8, 4],
+            point![pivot.x().round() as i32, pivot.y().round() as i32]
+        );
+        assert_eq!(angle, Some(-90.0));
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! - [`PixState::help_marker`]
 //! - [`PixState::tooltip`]
+//! - [`PixState::tooltip_delay`]
 //! - [`PixState::advanced_tooltip`]
 //!
 //! # Example
@@ -16,9 +17,7 @@
 //!     s.help_marker("Help marker icon w/ tooltip")?;
 //!
 //!     s.text("Hover me")?;
-//!     if s.hovered() {
-//!         s.tooltip("Basic tooltip")?;
-//!     }
+//!     s.tooltip("Basic tooltip")?;
 //!
 //!     s.text("Hover me too!")?;
 //!     if s.hovered() {
@@ -37,7 +36,9 @@
 //! # }
 //! ```
 
-use crate::{ops::clamp_dimensions, prelude::*};
+use crate::{gui::tooltip::tooltip_rect, ops::clamp_dimensions, prelude::*};
+use std::time::Duration;
+use web_time::Instant;
 
 impl PixState {
     /// Draw help marker text that, when hovered, displays a help box with text to the current
@@ -75,7 +76,7 @@ impl PixState {
 
         // Calculate hover area
         let marker = "?";
-        let (marker_width, marker_height) = s.text_size(marker)?;
+        let (marker_width, marker_height) = s.padded_text_size(marker)?;
         let hover = rect![
             pos,
             marker_width + 2 * ipad.x(),
@@ -87,7 +88,7 @@ impl PixState {
         let focused = s.focused() && s.ui.try_focus(id);
         let disabled = s.ui.disabled;
 
-        s.push();
+        s.push_screen_space();
         s.ui.push_cursor();
 
         // Marker outline
@@ -110,7 +111,7 @@ impl PixState {
 
         // Tooltip
         if focused {
-            let (text_width, text_height) = s.text_size(text)?;
+            let (text_width, text_height) = s.padded_text_size(text)?;
             let text_width = text_width + 2 * fpad.x();
             let text_height = text_height + 2 * fpad.y();
             s.push_id(id);
@@ -133,7 +134,7 @@ impl PixState {
             )?;
             s.pop_id();
         } else if hovered {
-            s.tooltip(text)?;
+            s.tooltip_box(text)?;
         }
 
         s.ui.pop_cursor();
@@ -146,7 +147,10 @@ impl PixState {
         Ok(())
     }
 
-    /// Draw tooltip box at the mouse cursor with text to the current canvas.
+    /// Draw a tooltip box with text next to the mouse cursor once the previously drawn element
+    /// has been hovered for the [tooltip delay](PixState::tooltip_delay). The tooltip is hidden as
+    /// soon as the mouse leaves the element, and long text is wrapped to half the window width
+    /// unless a wrap width is set with [`PixState::wrap`].
     ///
     /// # Errors
     ///
@@ -160,9 +164,7 @@ impl PixState {
     /// # impl PixEngine for App {
     /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
     ///     s.text("Hover me")?;
-    ///     if s.hovered() {
-    ///         s.tooltip("Basic tooltip")?;
-    ///     }
+    ///     s.tooltip("Basic tooltip")?;
     ///     Ok(())
     /// }
     /// # }
@@ -171,42 +173,39 @@ impl PixState {
     where
         S: AsRef<str>,
     {
-        let text = text.as_ref();
-
         let s = self;
-        let id = s.ui.get_id(&text);
-        let text = s.ui.get_label(text);
-        let spacing = s.theme.spacing;
-        let pad = spacing.frame_pad;
-
-        let (text_width, text_height) = s.text_size(text)?;
-        let text_width = text_width + 2 * pad.x();
-        let text_height = text_height + 2 * pad.y();
-
-        // Render
-        s.push_id(id);
-        s.advanced_tooltip(
-            text,
-            rect![s.mouse_pos(), text_width, text_height],
-            |s: &mut PixState| {
-                let [stroke, bg, fg] = s.widget_colors(id, ColorType::Surface);
-                s.background(bg);
-
-                s.stroke(stroke);
-                s.fill(None);
-                s.rect([0, 0, text_width - 1, text_height - 1])?;
-
-                s.stroke(None);
-                s.fill(fg);
-                s.text(text)?;
-                Ok(())
-            },
-        )?;
-        s.pop_id();
-
+        let Some(rect) = s.ui.last_size() else {
+            return Ok(());
+        };
+        let hovered = s.hovered();
+        if s.ui.tooltip.update(rect, hovered, Instant::now()) {
+            s.tooltip_box(text.as_ref())?;
+        }
         Ok(())
     }
 
+    /// Set how long the mouse must hover an element before its [`PixState::tooltip`] is shown.
+    /// Defaults to 500 milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// use std::time::Duration;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.tooltip_delay(Duration::from_millis(250));
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn tooltip_delay(&mut self, delay: Duration) {
+        self.ui.tooltip.delay = delay;
+    }
+
     /// Draw an advanced tooltip box at the mouse cursor to the current canvas. It accepts a
     /// closure that is passed [`&mut PixState`][`PixState`] which you can use to draw all the
     /// standard drawing primitives and change any drawing settings. Settings changed inside the
@@ -249,38 +248,79 @@ impl PixState {
 
         let s = self;
         let id = s.ui.get_id(&label);
-        let pad = s.theme.spacing.frame_pad;
 
         s.rect_mode(RectMode::Corner);
 
-        // Calculate rect
-        let mut rect = s.get_rect(rect).offset([15, 15]);
-
-        // Ensure rect stays inside window
+        // Calculate rect, ensuring it stays inside window
+        let rect = s.get_rect(rect);
         let (win_width, win_height) = s.window_dimensions()?;
         let (win_width, win_height) = clamp_dimensions(win_width, win_height);
-        if rect.right() > win_width {
-            let offset = (rect.right() - win_width) + pad.x();
-            rect = rect.offset([-offset, 0]);
-        }
-        if rect.bottom() > win_height {
-            let offset = (rect.bottom() - win_height) + pad.y();
-            rect = rect.offset([0, -offset]);
-            let mpos = s.mouse_pos();
-            if rect.contains(mpos) {
-                rect.set_bottom(mpos.y() - pad.y());
-            }
-        }
+        let rect = tooltip_rect(rect.top_left(), rect.size(), point![win_width, win_height]);
 
         let texture_id = s.get_or_create_texture(id, None, rect)?;
+        if let Some(texture) = s.ui.textures.iter_mut().find(|t| t.id == texture_id) {
+            texture.overlay = true;
+        }
         s.ui.offset_mouse(rect.top_left());
 
+        // Tooltip contents are always drawn in screen space
+        s.push_screen_space();
         s.set_texture_target(texture_id)?;
         f(s)?;
         s.clear_texture_target();
+        s.pop();
 
         s.ui.clear_mouse_offset();
 
         Ok(())
     }
 }
+
+impl PixState {
+    /// Draw tooltip box at the mouse cursor with text to the current canvas.
+    fn tooltip_box(&mut self, text: &str) -> PixResult<()> {
+        let s = self;
+        let id = s.ui.get_id(&text);
+        let text = s.ui.get_label(text);
+        let spacing = s.theme.spacing;
+        let pad = spacing.frame_pad;
+        let (win_width, _) = s.window_dimensions()?;
+
+        // Wrap long text to half the window width
+        s.push();
+        if s.settings.wrap_width.is_none() {
+            s.wrap(win_width / 2);
+        }
+        let result = s
+            .padded_text_size(text)
+            .and_then(|(text_width, text_height)| {
+                let text_width = text_width + 2 * pad.x();
+                let text_height = text_height + 2 * pad.y();
+
+                // Render
+                s.push_id(id);
+                let result = s.advanced_tooltip(
+                    text,
+                    rect![s.mouse_pos(), text_width, text_height],
+                    |s: &mut PixState| {
+                        let [stroke, bg, fg] = s.widget_colors(id, ColorType::Surface);
+                        s.background(bg);
+
+                        s.stroke(stroke);
+                        s.fill(None);
+                        s.rect([0, 0, text_width - 1, text_height - 1])?;
+
+                        s.stroke(None);
+                        s.fill(fg);
+                        s.text(text)?;
+                        Ok(())
+                    },
+                );
+                s.pop_id();
+                result
+            });
+        s.pop();
+
+        result
+    }
+}
@@ -4,7 +4,7 @@ use crate::{ops::clamp_dimensions, prelude::*, renderer::Rendering};
 #[cfg(not(target_arch = "wasm32"))]
 use anyhow::Context;
 #[cfg(not(target_arch = "wasm32"))]
-use png::{BitDepth, ColorType, Decoder};
+use png::{BitDepth, ColorType, Decoder, Transformations};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(not(target_arch = "wasm32"))]
@@ -16,6 +16,10 @@ use std::{
 };
 use std::{fmt, iter::Copied, slice};
 
+#[cfg(not(target_arch = "wasm32"))]
+mod bmp;
+pub(crate) mod gif;
+
 /// Format for interpreting image data.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -74,7 +78,7 @@ impl Default for PixelFormat {
 }
 
 /// An `Image` representing a buffer of pixel color values.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 #[must_use]
 pub struct Image {
     /// `Image` width.
@@ -183,23 +187,27 @@ impl Image {
         }
     }
 
-    /// Constructs an `Image` from a [png] file.
+    /// Constructs an `Image` from a [png] or `bmp` file.
     ///
     /// # Errors
     ///
-    /// If the file format is not supported or extension is not `.png`, then an error is returned.
+    /// If the file format is not supported or extension is not `.png` or `.bmp`, then an error is
+    /// returned.
     #[cfg(not(target_arch = "wasm32"))]
     pub fn from_file<P: AsRef<Path>>(path: P) -> PixResult<Self> {
         let path = path.as_ref();
-        let ext = path.extension();
-        if ext != Some(OsStr::new("png")) {
-            return Err(PixError::UnsupportedFileType(ext.map(OsStr::to_os_string)).into());
+        match FileType::from_path(path)? {
+            FileType::Png => Self::from_read(File::open(path)?),
+            FileType::Bmp => bmp::read(BufReader::new(File::open(path)?))
+                .with_context(|| format!("failed to read bmp file: {path:?}")),
         }
-        Self::from_read(File::open(path)?)
     }
 
     /// Constructs an `Image` from a [png] reader.
     ///
+    /// Paletted, grayscale, and 16-bit images are converted to 8-bit [`PixelFormat::Rgb`] or
+    /// [`PixelFormat::Rgba`].
+    ///
     /// # Errors
     ///
     /// If the file format is not supported or there is an [`io::Error`] reading the file then an
@@ -207,12 +215,12 @@ impl Image {
     #[cfg(not(target_arch = "wasm32"))]
     pub fn from_read<R: io::Read>(read: R) -> PixResult<Self> {
         let png_file = BufReader::new(read);
-        let png = Decoder::new(png_file);
+        let mut png = Decoder::new(png_file);
 
         // TODO: Make this machine-dependent to best match display capabilities for performance
         // EXPL: Switch RGBA32 (RGBA8888) format to ARGB8888 by swapping alpha
         // EXPL: Expand paletted to RGB and non-8-bit grayscale to 8-bits
-        // png.set_transformations(Transformations::SWAP_ALPHA | Transformations::EXPAND);
+        png.set_transformations(Transformations::normalize_to_color8());
 
         let mut reader = png.read_info().context("failed to read png data")?;
         let mut buf = vec![0x00; reader.output_buffer_size()];
@@ -221,7 +229,7 @@ impl Image {
             .context("failed to read png data frame")?;
         let bit_depth = info.bit_depth;
         let color_type = info.color_type;
-        if bit_depth != BitDepth::Eight || !matches!(color_type, ColorType::Rgb | ColorType::Rgba) {
+        if bit_depth != BitDepth::Eight {
             return Err(PixError::UnsupportedImageFormat {
                 bit_depth,
                 color_type,
@@ -230,14 +238,39 @@ impl Image {
         }
 
         let data = &buf[..info.buffer_size()];
-        let format = info
-            .color_type
-            .try_into()
-            .map_err(|_| PixError::UnsupportedImageFormat {
-                bit_depth,
-                color_type,
-            })?;
-        Self::from_bytes(info.width, info.height, data, format)
+        match color_type {
+            ColorType::Grayscale => {
+                let data = data.iter().flat_map(|&v| [v, v, v]).collect();
+                Ok(Self::from_vec(
+                    info.width,
+                    info.height,
+                    data,
+                    PixelFormat::Rgb,
+                ))
+            }
+            ColorType::GrayscaleAlpha => {
+                let data = data
+                    .chunks_exact(2)
+                    .flat_map(|v| [v[0], v[0], v[0], v[1]])
+                    .collect();
+                Ok(Self::from_vec(
+                    info.width,
+                    info.height,
+                    data,
+                    PixelFormat::Rgba,
+                ))
+            }
+            _ => {
+                let format =
+                    color_type
+                        .try_into()
+                        .map_err(|_| PixError::UnsupportedImageFormat {
+                            bit_depth,
+                            color_type,
+                        })?;
+                Self::from_bytes(info.width, info.height, data, format)
+            }
+        }
     }
 
     /// Returns the `Image` width.
@@ -378,6 +411,145 @@ impl Image {
         self.data[idx..(idx + channels)].clone_from_slice(&color.channels()[..channels]);
     }
 
+    /// Flip the `Image` pixels in the horizontal (left/right) direction.
+    pub fn flip_horizontal(&mut self) {
+        let channels = self.format.channels();
+        let pitch = self.pitch();
+        if pitch == 0 {
+            return;
+        }
+        for row in self.data.chunks_exact_mut(pitch) {
+            let width = row.len() / channels;
+            for x in 0..width / 2 {
+                let (left, right) = row.split_at_mut((width - 1 - x) * channels);
+                left[x * channels..(x + 1) * channels].swap_with_slice(&mut right[..channels]);
+            }
+        }
+    }
+
+    /// Flip the `Image` pixels in the vertical (up/down) direction.
+    pub fn flip_vertical(&mut self) {
+        let pitch = self.pitch();
+        let height = self.height as usize;
+        for y in 0..height / 2 {
+            let (top, bottom) = self.data.split_at_mut((height - 1 - y) * pitch);
+            top[y * pitch..(y + 1) * pitch].swap_with_slice(&mut bottom[..pitch]);
+        }
+    }
+
+    /// Draw another [Image] onto this `Image` at `position`, optionally rotated by an `angle` in
+    /// degrees about the `center` point, `flipped`, and tinted by multiplying each source pixel
+    /// by a `tint` color. `center` is relative to the top-left of `src` and defaults to the center
+    /// of `src`.
+    ///
+    /// Pixels are sampled using nearest-neighbor sampling and alpha blended onto this `Image`.
+    /// Rotations by multiples of `90` degrees result in exact pixel permutations. Pixels outside
+    /// the bounds of this `Image` are clipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let mut sprite = Image::new(16, 16);
+    /// sprite.set_pixel(0, 0, Color::RED);
+    /// let mut canvas = Image::new(64, 64);
+    /// canvas.blit_transformed(&sprite, [10, 10], 90.0, None, Flipped::Horizontal, None);
+    /// ```
+    pub fn blit_transformed<P, C, F, T>(
+        &mut self,
+        src: &Image,
+        position: P,
+        angle: f64,
+        center: C,
+        flipped: F,
+        tint: T,
+    ) where
+        P: Into<Point<i32>>,
+        C: Into<Option<Point<i32>>>,
+        F: Into<Option<Flipped>>,
+        T: Into<Option<Color>>,
+    {
+        let pos: Point<f64> = position.into().as_();
+        let (src_width, src_height) = (f64::from(src.width), f64::from(src.height));
+        let center: Point<f64> = center
+            .into()
+            .map_or_else(|| point![src_width / 2.0, src_height / 2.0], |c| c.as_());
+        let (flip_h, flip_v) = match flipped.into() {
+            Some(Flipped::Horizontal) => (true, false),
+            Some(Flipped::Vertical) => (false, true),
+            Some(Flipped::Both) => (true, true),
+            Some(Flipped::None) | None => (false, false),
+        };
+        let tint = tint.into();
+        let pivot = pos + center;
+        let (sin, cos) = angle.to_radians().sin_cos();
+
+        // Determine destination bounds by rotating the source corners about the pivot
+        let corners = [
+            point![0.0, 0.0],
+            point![src_width, 0.0],
+            point![0.0, src_height],
+            point![src_width, src_height],
+        ]
+        .map(|p| {
+            let (x, y) = (p.x() - center.x(), p.y() - center.y());
+            point![x * cos - y * sin, x * sin + y * cos] + pivot
+        });
+        let fold = |init: f64, f: fn(f64, f64) -> f64, coord: fn(&Point<f64>) -> f64| {
+            corners.iter().map(coord).fold(init, f)
+        };
+        let min_x = fold(f64::MAX, f64::min, Point::x).round().max(0.0) as u32;
+        let min_y = fold(f64::MAX, f64::min, Point::y).round().max(0.0) as u32;
+        let max_x = fold(f64::MIN, f64::max, Point::x)
+            .round()
+            .clamp(0.0, f64::from(self.width)) as u32;
+        let max_y = fold(f64::MIN, f64::max, Point::y)
+            .round()
+            .clamp(0.0, f64::from(self.height)) as u32;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                // Inverse rotate the destination pixel center back into source space
+                let dx = f64::from(x) + 0.5 - pivot.x();
+                let dy = f64::from(y) + 0.5 - pivot.y();
+                let sx = (dx * cos + dy * sin + center.x()).floor();
+                let sy = (-dx * sin + dy * cos + center.y()).floor();
+                if sx < 0.0 || sy < 0.0 || sx >= src_width || sy >= src_height {
+                    continue;
+                }
+                let (mut sx, mut sy) = (sx as u32, sy as u32);
+                if flip_h {
+                    sx = src.width - 1 - sx;
+                }
+                if flip_v {
+                    sy = src.height - 1 - sy;
+                }
+                let mut color = src.get_pixel(sx, sy);
+                if let Some(tint) = tint {
+                    let [r, g, b, a] = color.channels();
+                    let [tr, tg, tb, ta] = tint.channels();
+                    let mul = |c: u8, t: u8| ((u16::from(c) * u16::from(t) + 127) / 255) as u8;
+                    color = Color::rgba(mul(r, tr), mul(g, tg), mul(b, tb), mul(a, ta));
+                }
+                let color = match color.alpha() {
+                    255 => color,
+                    0 => continue,
+                    alpha => {
+                        let [r, g, b, a] = color.channels();
+                        let [dr, dg, db, da] = self.get_pixel(x, y).channels();
+                        let blend = |s: u8, d: u8| {
+                            let (s, d, alpha) = (u32::from(s), u32::from(d), u32::from(alpha));
+                            ((s * alpha + d * (255 - alpha) + 127) / 255) as u8
+                        };
+                        let a = u32::from(a) + u32::from(da) * (255 - u32::from(a)) / 255;
+                        Color::rgba(blend(r, dr), blend(g, dg), blend(b, db), a as u8)
+                    }
+                };
+                self.set_pixel(x, y, color);
+            }
+        }
+    }
+
     /// Update the `Image` with a  [u8] [prim@slice] representing RGB/A values.
     #[inline]
     pub fn update_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) {
@@ -390,12 +562,13 @@ impl Image {
         self.format
     }
 
-    /// Save the `Image` to a [png] file.
+    /// Save the `Image` to a [png] or `bmp` file, based on the file extension.
     ///
     /// # Errors
     ///
     /// Returns an error for any of the following:
-    ///     - An [`io::Error`] occurs attempting to create the [png] file.
+    ///     - The file extension is not `.png` or `.bmp`.
+    ///     - An [`io::Error`] occurs attempting to create the file.
     ///     - A [`png::EncodingError`] occurs attempting to write image bytes.
     ///
     /// # Example
@@ -419,16 +592,48 @@ impl Image {
         P: AsRef<Path>,
     {
         let path = path.as_ref();
-        let png_file = BufWriter::new(File::create(path)?);
-        let mut png = png::Encoder::new(png_file, self.width, self.height);
-        png.set_color(self.format.into());
-        png.set_depth(png::BitDepth::Eight);
-        let mut writer = png
-            .write_header()
-            .with_context(|| format!("failed to write png header: {path:?}"))?;
-        writer
-            .write_image_data(self.as_bytes())
-            .with_context(|| format!("failed to write png data: {path:?}"))
+        let file_type = FileType::from_path(path)?;
+        let file = BufWriter::new(File::create(path)?);
+        match file_type {
+            FileType::Png => {
+                let mut png = png::Encoder::new(file, self.width, self.height);
+                png.set_color(self.format.into());
+                png.set_depth(png::BitDepth::Eight);
+                let mut writer = png
+                    .write_header()
+                    .with_context(|| format!("failed to write png header: {path:?}"))?;
+                writer
+                    .write_image_data(self.as_bytes())
+                    .with_context(|| format!("failed to write png data: {path:?}"))
+            }
+            FileType::Bmp => bmp::write(self, file)
+                .with_context(|| format!("failed to write bmp data: {path:?}")),
+        }
+    }
+}
+
+/// Supported [Image] file types.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FileType {
+    Png,
+    Bmp,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileType {
+    /// Determine the file type from a case-insensitive file extension.
+    fn from_path(path: &Path) -> PixResult<Self> {
+        let ext = path.extension();
+        match ext
+            .and_then(OsStr::to_str)
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("png") => Ok(Self::Png),
+            Some("bmp") => Ok(Self::Bmp),
+            _ => Err(PixError::UnsupportedFileType(ext.map(OsStr::to_os_string)).into()),
+        }
     }
 }
 
@@ -521,12 +726,19 @@ impl PixState {
         if s.angle_mode == AngleMode::Radians {
             angle = angle.to_degrees();
         };
+        let mut center = center.into();
+        if let (Some(view), Some(world_dst)) = (self.view_transform(), dst) {
+            let (screen_dst, screen_angle) = view.dst(world_dst, angle, center);
+            dst = Some(screen_dst);
+            angle = screen_angle;
+            center = None;
+        }
         self.renderer.image(
             img,
             src.into(),
             dst,
             angle,
-            center.into(),
+            center,
             flipped.into(),
             s.image_tint,
         )
@@ -609,3 +821,134 @@ impl From<Image> for Icon {
         Self::Image(img)
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn test_image(format: PixelFormat) -> Image {
+        let (width, height) = (5, 3);
+        let data = (0..format.channels() * (width * height) as usize)
+            .map(|i| (i * 17 % 256) as u8)
+            .collect();
+        Image::from_vec(width, height, data, format)
+    }
+
+    fn round_trip(format: PixelFormat, ext: &str) -> PixResult<()> {
+        let img = test_image(format);
+        let path = env::temp_dir().join(format!("pix_engine_test_{format:?}.{ext}"));
+        img.save(&path)?;
+        let loaded = Image::from_file(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded.dimensions(), img.dimensions());
+        assert_eq!(loaded.format(), img.format());
+        assert_eq!(loaded.as_bytes(), img.as_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn png_round_trip() -> PixResult<()> {
+        round_trip(PixelFormat::Rgb, "png")?;
+        round_trip(PixelFormat::Rgba, "png")
+    }
+
+    #[test]
+    fn bmp_round_trip() -> PixResult<()> {
+        round_trip(PixelFormat::Rgb, "bmp")?;
+        round_trip(PixelFormat::Rgba, "BMP")
+    }
+
+    #[test]
+    fn bmp_oversized_header() {
+        let mut header = Vec::new();
+        header.extend_from_slice(b"BM");
+        header.extend_from_slice(&[0x00; 8]);
+        header.extend_from_slice(&54u32.to_le_bytes());
+        header.extend_from_slice(&40u32.to_le_bytes());
+        header.extend_from_slice(&i32::MAX.to_le_bytes());
+        header.extend_from_slice(&(-i32::MAX).to_le_bytes());
+        header.extend_from_slice(&1u16.to_le_bytes());
+        header.extend_from_slice(&32u16.to_le_bytes());
+        header.extend_from_slice(&[0x00; 24]);
+        assert!(bmp::read(header.as_slice()).is_err());
+    }
+
+    #[test]
+    fn flip_symmetry() {
+        for format in [PixelFormat::Rgb, PixelFormat::Rgba] {
+            let img = test_image(format);
+            let mut flipped = img.clone();
+            flipped.flip_horizontal();
+            assert_eq!(flipped.get_pixel(0, 1), img.get_pixel(4, 1));
+            assert_ne!(flipped.as_bytes(), img.as_bytes());
+            flipped.flip_horizontal();
+            assert_eq!(flipped.as_bytes(), img.as_bytes());
+
+            flipped.flip_vertical();
+            assert_eq!(flipped.get_pixel(3, 0), img.get_pixel(3, 2));
+            flipped.flip_vertical();
+            assert_eq!(flipped.as_bytes(), img.as_bytes());
+        }
+
+        let img = test_image(PixelFormat::Rgb);
+        let mut flipped = img.clone();
+        flipped.flip_horizontal();
+        flipped.flip_vertical();
+        let mut blitted = Image::rgb(5, 3);
+        blitted.blit_transformed(&img, [0, 0], 0.0, None, Flipped::Both, None);
+        assert_eq!(blitted.as_bytes(), flipped.as_bytes());
+    }
+
+    #[test]
+    fn blit_rotations() {
+        let img = test_image(PixelFormat::Rgb);
+        let (width, height) = img.dimensions();
+
+        // Rotated about the center of a 5x3 image, the 3x5 result is offset by (-1, 1)
+        let mut rotated = Image::rgb(3, 5);
+        rotated.blit_transformed(&img, [-1, 1], 90.0, None, None, None);
+        for y in 0..width {
+            for x in 0..height {
+                assert_eq!(rotated.get_pixel(x, y), img.get_pixel(y, height - 1 - x));
+            }
+        }
+
+        let mut rotated = Image::rgb(width, height);
+        rotated.blit_transformed(&img, [0, 0], 180.0, None, None, None);
+        for y in 0..height {
+            for x in 0..width {
+                assert_eq!(
+                    rotated.get_pixel(x, y),
+                    img.get_pixel(width - 1 - x, height - 1 - y)
+                );
+            }
+        }
+
+        let mut rotated = Image::rgb(3, 5);
+        rotated.blit_transformed(&img, [-1, 1], 270.0, None, None, None);
+        for y in 0..width {
+            for x in 0..height {
+                assert_eq!(rotated.get_pixel(x, y), img.get_pixel(width - 1 - y, x));
+            }
+        }
+    }
+
+    #[test]
+    fn blit_tint_and_clip() -> PixResult<()> {
+        let img = Image::from_pixels(2, 1, [Color::WHITE, Color::GRAY], PixelFormat::Rgba)?;
+        let mut canvas = Image::rgba(1, 1);
+        canvas.blit_transformed(&img, [0, 0], 0.0, None, None, Color::RED);
+        assert_eq!(canvas.get_pixel(0, 0), Color::RED);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_file_type() {
+        let img = test_image(PixelFormat::Rgba);
+        assert!(img
+            .save(env::temp_dir().join("pix_engine_test.gif"))
+            .is_err());
+        assert!(Image::from_file("pix_engine_test.jpg").is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Uncompressed [BMP](https://en.wikipedia.org/wiki/BMP_file_format) encoding and decoding.
+//!
+//! Supports reading 24-bit `BI_RGB` and 32-bit `BI_RGB`/`BI_BITFIELDS` images and writing 24-bit
+//! [`PixelFormat::Rgb`] or 32-bit [`PixelFormat::Rgba`] images.
+
+use crate::prelude::*;
+use anyhow::{bail, Context};
+use std::io::{self, Read, Write};
+
+/// Size of the `BITMAPFILEHEADER` in bytes.
+const FILE_HEADER_SIZE: u32 = 14;
+/// Size of the `BITMAPINFOHEADER` in bytes.
+const INFO_HEADER_SIZE: u32 = 40;
+/// Size of the `BITMAPV4HEADER` in bytes.
+const V4_HEADER_SIZE: u32 = 108;
+/// Uncompressed pixel data.
+const BI_RGB: u32 = 0;
+/// Uncompressed pixel data with explicit channel masks.
+const BI_BITFIELDS: u32 = 3;
+/// `LCS_sRGB` color space tag.
+const LCS_SRGB: u32 = 0x7352_4742;
+
+/// Read a `u16` from a little-endian byte slice at `offset`.
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    let bytes = bytes.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Read a `u32` from a little-endian byte slice at `offset`.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Extract an 8-bit channel value from a pixel given a channel bit `mask`.
+fn channel(pixel: u32, mask: u32) -> u8 {
+    if mask == 0 {
+        return 0xFF;
+    }
+    let shift = mask.trailing_zeros();
+    let max = mask >> shift;
+    let value = (pixel & mask) >> shift;
+    if max == 0xFF {
+        value as u8
+    } else {
+        ((value * 0xFF + max / 2) / max) as u8
+    }
+}
+
+/// Decode an [Image] from a BMP reader.
+pub(crate) fn read<R: Read>(mut read: R) -> PixResult<Image> {
+    let mut buf = Vec::new();
+    read.read_to_end(&mut buf)?;
+    let invalid = || anyhow::anyhow!("invalid bmp header");
+
+    if buf.get(0..2) != Some(b"BM") {
+        bail!("invalid bmp signature");
+    }
+    let data_offset = read_u32(&buf, 10).ok_or_else(invalid)? as usize;
+    let header_size = read_u32(&buf, 14).ok_or_else(invalid)?;
+    if header_size < INFO_HEADER_SIZE {
+        bail!("unsupported bmp header size: {header_size}");
+    }
+    let width = read_u32(&buf, 18).ok_or_else(invalid)? as i32;
+    let height = read_u32(&buf, 22).ok_or_else(invalid)? as i32;
+    let bit_count = read_u16(&buf, 28).ok_or_else(invalid)?;
+    let compression = read_u32(&buf, 30).ok_or_else(invalid)?;
+    if width <= 0 || height == 0 {
+        return Err(invalid());
+    }
+
+    // Masks are stored directly after the `BITMAPINFOHEADER`, which is also where they're located
+    // inside larger headers
+    let masks_offset = (FILE_HEADER_SIZE + INFO_HEADER_SIZE) as usize;
+    let (masks, format) = match (bit_count, compression) {
+        (24, BI_RGB) => ([0x00FF_0000, 0x0000_FF00, 0x0000_00FF, 0], PixelFormat::Rgb),
+        (32, BI_RGB) => ([0x00FF_0000, 0x0000_FF00, 0x0000_00FF, 0], PixelFormat::Rgb),
+        (32, BI_BITFIELDS) => {
+            let mask = |i: usize| read_u32(&buf, masks_offset + 4 * i).ok_or_else(invalid);
+            let alpha = if header_size >= 56 { mask(3)? } else { 0 };
+            let format = if alpha == 0 {
+                PixelFormat::Rgb
+            } else {
+                PixelFormat::Rgba
+            };
+            ([mask(0)?, mask(1)?, mask(2)?, alpha], format)
+        }
+        _ => {
+            return Err(PixError::UnsupportedBmpFormat {
+                bit_count,
+                compression,
+            }
+            .into())
+        }
+    };
+
+    let (width, top_down) = (width.unsigned_abs(), height < 0);
+    let height = height.unsigned_abs();
+    let bytes_per_pixel = usize::from(bit_count / 8);
+    // Dimensions come from the file, so guard against sizes that overflow
+    let row_size = bytes_per_pixel
+        .checked_mul(width as usize)
+        .ok_or_else(invalid)?;
+    let stride = row_size.checked_add(3).ok_or_else(invalid)? & !3;
+    let image_size = stride.checked_mul(height as usize).ok_or_else(invalid)?;
+    let pixel_data = buf
+        .get(data_offset..)
+        .filter(|data| data.len() >= image_size)
+        .context("bmp pixel data is truncated")?;
+
+    let channels = format.channels();
+    let mut data = Vec::with_capacity(channels * width as usize * height as usize);
+    for y in 0..height as usize {
+        let row = if top_down { y } else { height as usize - 1 - y };
+        let row = &pixel_data[row * stride..row * stride + row_size];
+        for pixel in row.chunks_exact(bytes_per_pixel) {
+            let pixel = match *pixel {
+                [b, g, r] => u32::from_le_bytes([b, g, r, 0x00]),
+                [b, g, r, a] => u32::from_le_bytes([b, g, r, a]),
+                _ => unreachable!("bytes per pixel is 3 or 4"),
+            };
+            let [r, g, b, a] = masks.map(|mask| channel(pixel, mask));
+            data.extend_from_slice(&[r, g, b, a][..channels]);
+        }
+    }
+    Ok(Image::from_vec(width, height, data, format))
+}
+
+/// Encode an [Image] to a BMP writer.
+pub(crate) fn write<W: Write>(img: &Image, mut write: W) -> io::Result<()> {
+    let format = img.format();
+    let (width, height) = img.dimensions();
+    let (header_size, bit_count, compression) = match format {
+        PixelFormat::Rgb => (INFO_HEADER_SIZE, 24u16, BI_RGB),
+        PixelFormat::Rgba => (V4_HEADER_SIZE, 32, BI_BITFIELDS),
+    };
+    let stride = (usize::from(bit_count / 8) * width as usize + 3) & !3;
+    let data_offset = FILE_HEADER_SIZE + header_size;
+    let image_size = (stride * height as usize) as u32;
+
+    // BITMAPFILEHEADER
+    write.write_all(b"BM")?;
+    write.write_all(&(data_offset + image_size).to_le_bytes())?;
+    write.write_all(&[0x00; 4])?;
+    write.write_all(&data_offset.to_le_bytes())?;
+
+    // BITMAPINFOHEADER
+    write.write_all(&header_size.to_le_bytes())?;
+    write.write_all(&width.to_le_bytes())?;
+    // Negative height indicates top-down row order
+    write.write_all(&(-(height as i32)).to_le_bytes())?;
+    write.write_all(&1u16.to_le_bytes())?;
+    write.write_all(&bit_count.to_le_bytes())?;
+    write.write_all(&compression.to_le_bytes())?;
+    write.write_all(&image_size.to_le_bytes())?;
+    // Pixels per meter, ~72 DPI
+    write.write_all(&2835u32.to_le_bytes())?;
+    write.write_all(&2835u32.to_le_bytes())?;
+    write.write_all(&[0x00; 8])?;
+
+    if format == PixelFormat::Rgba {
+        // BITMAPV4HEADER channel masks, color space and unused endpoints/gamma
+        for mask in [0x00FF_0000u32, 0x0000_FF00, 0x0000_00FF, 0xFF00_0000] {
+            write.write_all(&mask.to_le_bytes())?;
+        }
+        write.write_all(&LCS_SRGB.to_le_bytes())?;
+        write.write_all(&[0x00; 48])?;
+    }
+
+    let padding = stride - format.channels() * width as usize;
+    for row in img.as_bytes().chunks_exact(img.pitch().max(1)) {
+        for pixel in row.chunks_exact(format.channels()) {
+            match *pixel {
+                [r, g, b] => write.write_all(&[b, g, r])?,
+                [r, g, b, a] => write.write_all(&[b, g, r, a])?,
+                _ => unreachable!("channels is 3 or 4"),
+            }
+        }
+        write.write_all(&[0x00; 3][..padding])?;
+    }
+    write.flush()
+}
@@ -0,0 +1,418 @@
+//! Animated [GIF](https://en.wikipedia.org/wiki/GIF) encoding.
+//!
+//! Frames are quantized to a local 256-color palette using median cut and encoded incrementally
+//! on a background thread, so recording doesn't stall the render loop.
+
+use crate::prelude::*;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::mpsc::{self, Sender},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+use web_time::Instant;
+
+/// Maximum number of colors in a GIF palette.
+const MAX_COLORS: usize = 256;
+/// Maximum LZW code value.
+const MAX_CODE: u16 = 4096;
+
+/// An incremental animated GIF encoder.
+#[derive(Debug)]
+pub(crate) struct GifEncoder<W: Write> {
+    writer: W,
+    width: u16,
+    height: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    /// Create a new `GifEncoder`, writing the GIF header for an infinitely looping animation.
+    pub(crate) fn new(mut writer: W, width: u16, height: u16) -> io::Result<Self> {
+        writer.write_all(b"GIF89a")?;
+        // Logical screen descriptor without a global color table
+        writer.write_all(&width.to_le_bytes())?;
+        writer.write_all(&height.to_le_bytes())?;
+        writer.write_all(&[0x70, 0x00, 0x00])?;
+        // NETSCAPE2.0 application extension to loop forever
+        writer.write_all(&[0x21, 0xFF, 0x0B])?;
+        writer.write_all(b"NETSCAPE2.0")?;
+        writer.write_all(&[0x03, 0x01, 0x00, 0x00, 0x00])?;
+        Ok(Self {
+            writer,
+            width,
+            height,
+        })
+    }
+
+    /// Write a frame of RGBA pixel data, displayed for `delay` hundredths of a second.
+    pub(crate) fn write_frame(&mut self, rgba: &[u8], delay: u16) -> io::Result<()> {
+        let (palette, indices) = quantize(rgba);
+        let bits = palette_bits(palette.len());
+
+        // Graphic control extension
+        self.writer.write_all(&[0x21, 0xF9, 0x04, 0x00])?;
+        self.writer.write_all(&delay.to_le_bytes())?;
+        self.writer.write_all(&[0x00, 0x00])?;
+
+        // Image descriptor with a local color table
+        self.writer.write_all(&[0x2C, 0x00, 0x00, 0x00, 0x00])?;
+        self.writer.write_all(&self.width.to_le_bytes())?;
+        self.writer.write_all(&self.height.to_le_bytes())?;
+        self.writer.write_all(&[0x80 | (bits - 1)])?;
+        for i in 0..1 << bits {
+            self.writer
+                .write_all(palette.get(i).unwrap_or(&[0x00, 0x00, 0x00]))?;
+        }
+
+        // Image data
+        self.writer.write_all(&[bits])?;
+        for block in lzw_encode(&indices, bits).chunks(255) {
+            self.writer.write_all(&[block.len() as u8])?;
+            self.writer.write_all(block)?;
+        }
+        self.writer.write_all(&[0x00])
+    }
+
+    /// Write the GIF trailer and flush the writer.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.writer.write_all(&[0x3B])?;
+        self.writer.flush()
+    }
+}
+
+/// Number of bits required to index a palette, with a minimum of `2` as required by GIF.
+fn palette_bits(len: usize) -> u8 {
+    let mut bits = 2;
+    while (1 << bits) < len {
+        bits += 1;
+    }
+    bits
+}
+
+/// Quantize RGBA pixel data to a palette of at most `256` colors using median cut, returning the
+/// palette and palette indices for each pixel. Alpha is ignored.
+fn quantize(rgba: &[u8]) -> (Vec<[u8; 3]>, Vec<u8>) {
+    let pixels = rgba.chunks_exact(4).map(|p| [p[0], p[1], p[2]]);
+    let mut counts: HashMap<[u8; 3], u32> = HashMap::new();
+    for pixel in pixels.clone() {
+        *counts.entry(pixel).or_default() += 1;
+    }
+
+    let mut boxes = vec![counts.into_iter().collect::<Vec<_>>()];
+    while boxes.len() < MAX_COLORS {
+        // Split the box with the widest channel range
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, colors)| colors.len() > 1)
+            .map(|(i, colors)| {
+                let (channel, range) = (0..3)
+                    .map(|c| {
+                        let min = colors.iter().map(|(p, _)| p[c]).min().unwrap_or(0);
+                        let max = colors.iter().map(|(p, _)| p[c]).max().unwrap_or(0);
+                        (c, max - min)
+                    })
+                    .max_by_key(|&(_, range)| range)
+                    .unwrap_or((0, 0));
+                (i, channel, range)
+            })
+            .max_by_key(|&(_, _, range)| range);
+        let Some((i, channel, _)) = widest else {
+            break;
+        };
+        let mut colors = boxes.swap_remove(i);
+        colors.sort_unstable_by_key(|(p, _)| p[channel]);
+        let total: u32 = colors.iter().map(|(_, count)| count).sum();
+        let mut sum = 0;
+        let median = colors
+            .iter()
+            .position(|(_, count)| {
+                sum += count;
+                sum >= total / 2
+            })
+            .unwrap_or(0)
+            .clamp(0, colors.len() - 2);
+        let upper = colors.split_off(median + 1);
+        boxes.push(colors);
+        boxes.push(upper);
+    }
+
+    let mut palette = Vec::with_capacity(boxes.len());
+    let mut lookup = HashMap::new();
+    for (i, colors) in boxes.iter().enumerate() {
+        let total: u64 = colors.iter().map(|(_, count)| u64::from(*count)).sum();
+        let mut color = [0; 3];
+        for (c, value) in color.iter_mut().enumerate() {
+            let sum: u64 = colors
+                .iter()
+                .map(|(p, count)| u64::from(p[c]) * u64::from(*count))
+                .sum();
+            *value = ((sum + total / 2) / total.max(1)) as u8;
+        }
+        palette.push(color);
+        for (pixel, _) in colors {
+            lookup.insert(*pixel, i as u8);
+        }
+    }
+    let indices = pixels.map(|p| lookup[&p]).collect();
+    (palette, indices)
+}
+
+/// Packs variable-width codes into bytes, least significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compress palette indices using GIF variable-width LZW.
+fn lzw_encode(indices: &[u8], min_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_size;
+    let end = clear + 1;
+    let mut size = min_size + 1;
+    let mut next = end + 1;
+    let mut dictionary = HashMap::new();
+    let mut writer = BitWriter::default();
+
+    writer.write(clear, size);
+    let Some((&first, rest)) = indices.split_first() else {
+        writer.write(end, size);
+        return writer.finish();
+    };
+    let mut code = u16::from(first);
+    for &index in rest {
+        if let Some(&entry) = dictionary.get(&(code, index)) {
+            code = entry;
+            continue;
+        }
+        writer.write(code, size);
+        dictionary.insert((code, index), next);
+        next += 1;
+        if next > 1 << size && size < 12 {
+            size += 1;
+        }
+        if next == MAX_CODE {
+            writer.write(clear, size);
+            dictionary.clear();
+            size = min_size + 1;
+            next = end + 1;
+        }
+        code = u16::from(index);
+    }
+    writer.write(code, size);
+    if next == 1 << size && size < 12 {
+        size += 1;
+    }
+    writer.write(end, size);
+    writer.finish()
+}
+
+/// An in-progress recording of the window canvas to an animated GIF file.
+#[derive(Debug)]
+pub(crate) struct Recording {
+    frames: Sender<(Vec<u8>, u16)>,
+    encoder: JoinHandle<io::Result<()>>,
+    dimensions: (u32, u32),
+    max_frames: usize,
+    frame_skip: usize,
+    frames_skipped: usize,
+    frames_written: usize,
+    pending: Option<(Vec<u8>, Instant)>,
+}
+
+impl Recording {
+    /// Start recording to a GIF file at `path` with the given frame `dimensions`.
+    pub(crate) fn new<P: AsRef<Path>>(
+        path: P,
+        dimensions: (u32, u32),
+        max_frames: usize,
+        frame_skip: usize,
+    ) -> PixResult<Self> {
+        let (width, height) = dimensions;
+        let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(anyhow::anyhow!(
+                "{width}x{height} exceeds maximum GIF dimensions"
+            ));
+        };
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = GifEncoder::new(file, gif_width, gif_height)?;
+        let (frames, receiver) = mpsc::channel::<(Vec<u8>, u16)>();
+        let encoder = thread::Builder::new()
+            .name("gif-encoder".into())
+            .spawn(move || {
+                for (frame, delay) in receiver {
+                    encoder.write_frame(&frame, delay)?;
+                }
+                encoder.finish()
+            })?;
+        Ok(Self {
+            frames,
+            encoder,
+            dimensions,
+            max_frames,
+            frame_skip,
+            frames_skipped: 0,
+            frames_written: 0,
+            pending: None,
+        })
+    }
+
+    /// Returns the expected frame dimensions.
+    pub(crate) const fn dimensions(&self) -> (u32, u32) {
+        self.dimensions
+    }
+
+    /// Returns whether the next frame should be captured, based on the frame skip.
+    pub(crate) fn should_capture(&mut self) -> bool {
+        if self.pending.is_some() && self.frames_skipped < self.frame_skip {
+            self.frames_skipped += 1;
+            false
+        } else {
+            self.frames_skipped = 0;
+            true
+        }
+    }
+
+    /// Add a captured frame of pixel data in the given `format`. Returns `true` if the maximum
+    /// number of frames has been reached.
+    pub(crate) fn add_frame(&mut self, pixels: Vec<u8>, format: PixelFormat) -> PixResult<bool> {
+        let rgba = match format {
+            PixelFormat::Rgba => pixels,
+            PixelFormat::Rgb => pixels
+                .chunks_exact(3)
+                .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+                .collect(),
+        };
+        let now = Instant::now();
+        if let Some((frame, captured)) = self.pending.replace((rgba, now)) {
+            self.write_frame(frame, now - captured)?;
+        }
+        Ok(self.frames_written + 1 >= self.max_frames)
+    }
+
+    /// Write any pending frame and wait for the encoder to finish the GIF file.
+    pub(crate) fn finish(mut self) -> PixResult<()> {
+        if let Some((frame, captured)) = self.pending.take() {
+            // A send error means the encoder stopped early, which is reported by `join` below
+            let _ = self.write_frame(frame, captured.elapsed());
+        }
+        let Self {
+            frames, encoder, ..
+        } = self;
+        drop(frames);
+        match encoder.join() {
+            Ok(result) => Ok(result?),
+            Err(_) => Err(anyhow::anyhow!("gif encoder thread panicked")),
+        }
+    }
+
+    /// Send a frame to the encoder thread to be quantized and written.
+    fn write_frame(&mut self, frame: Vec<u8>, duration: Duration) -> PixResult<()> {
+        // Many decoders treat delays less than 2 hundredths of a second as 10
+        let delay = (duration.as_millis() / 10).clamp(2, u128::from(u16::MAX)) as u16;
+        self.frames
+            .send((frame, delay))
+            .map_err(|_| anyhow::anyhow!("gif encoder stopped unexpectedly"))?;
+        self.frames_written += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod decode;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use super::decode::{decode, lzw_decode};
+
+    #[test]
+    fn lzw_round_trip() {
+        let indices: Vec<u8> = (0..20_000u32).map(|i| (i * i / 7 % 251) as u8).collect();
+        assert_eq!(lzw_decode(&lzw_encode(&indices, 8), 8), indices);
+        let indices = vec![1; 10_000];
+        assert_eq!(lzw_decode(&lzw_encode(&indices, 2), 2), indices);
+    }
+
+    #[test]
+    fn quantize_exact() {
+        let rgba: Vec<u8> = (0..200u32)
+            .flat_map(|i| [i as u8, (i * 3) as u8, (i * 7) as u8, 255])
+            .collect();
+        let (palette, indices) = quantize(&rgba);
+        assert_eq!(palette.len(), 200);
+        for (pixel, &index) in rgba.chunks(4).zip(&indices) {
+            assert_eq!(palette[usize::from(index)], [pixel[0], pixel[1], pixel[2]]);
+        }
+    }
+
+    #[test]
+    fn quantize_many_colors() {
+        let rgba: Vec<u8> = (0..64 * 64u32)
+            .flat_map(|i| [(i % 64 * 4) as u8, (i / 64 * 4) as u8, 128, 255])
+            .collect();
+        let (palette, indices) = quantize(&rgba);
+        assert_eq!(palette.len(), MAX_COLORS);
+        let error: u32 = rgba
+            .chunks(4)
+            .zip(&indices)
+            .map(|(pixel, &index)| {
+                let color = palette[usize::from(index)];
+                (0..3)
+                    .map(|c| u32::from(pixel[c].abs_diff(color[c])))
+                    .sum::<u32>()
+            })
+            .sum();
+        assert!(
+            error / (64 * 64) <= 8,
+            "average error: {}",
+            error / (64 * 64)
+        );
+    }
+
+    #[test]
+    fn encode_frames() -> PixResult<()> {
+        let (width, height) = (8, 6);
+        let colors = [Color::RED, Color::GREEN, Color::BLUE];
+        let mut bytes = vec![];
+        let mut encoder = GifEncoder::new(&mut bytes, width, height)?;
+        for color in colors {
+            let frame: Vec<u8> = (0..width * height).flat_map(|_| color.channels()).collect();
+            encoder.write_frame(&frame, 10)?;
+        }
+        encoder.finish()?;
+
+        let frames = decode(&bytes);
+        assert_eq!(frames.len(), colors.len());
+        for (frame, color) in frames.iter().zip(colors) {
+            assert_eq!(frame.len(), usize::from(width * height));
+            let [r, g, b, _] = color.channels();
+            assert!(frame.iter().all(|&p| p == [r, g, b]));
+        }
+        Ok(())
+    }
+}
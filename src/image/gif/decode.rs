@@ -0,0 +1,85 @@
+//! Minimal GIF decoder for verifying encoded recordings in tests.
+
+/// Minimal GIF decoder returning the RGB pixels of each frame.
+pub(crate) fn decode(bytes: &[u8]) -> Vec<Vec<[u8; 3]>> {
+    assert_eq!(&bytes[..6], b"GIF89a");
+    let mut frames = vec![];
+    let mut i = 13;
+    loop {
+        match bytes[i] {
+            0x21 => {
+                i += 2;
+                while bytes[i] != 0 {
+                    i += usize::from(bytes[i]) + 1;
+                }
+                i += 1;
+            }
+            0x2C => {
+                let packed = bytes[i + 9];
+                let table_len = 1 << ((packed & 0x07) + 1);
+                let palette: Vec<_> = bytes[i + 10..i + 10 + 3 * table_len]
+                    .chunks(3)
+                    .map(|c| [c[0], c[1], c[2]])
+                    .collect();
+                i += 10 + 3 * table_len;
+                let min_size = bytes[i];
+                i += 1;
+                let mut data = vec![];
+                while bytes[i] != 0 {
+                    let len = usize::from(bytes[i]);
+                    data.extend_from_slice(&bytes[i + 1..=i + len]);
+                    i += len + 1;
+                }
+                i += 1;
+                let indices = lzw_decode(&data, min_size);
+                frames.push(indices.iter().map(|&i| palette[usize::from(i)]).collect());
+            }
+            0x3B => return frames,
+            byte => panic!("unexpected block {byte:#X}"),
+        }
+    }
+}
+
+pub(crate) fn lzw_decode(data: &[u8], min_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_size;
+    let end = clear + 1;
+    let reset = || -> Vec<Vec<u8>> { (0..=end).map(|i| vec![i as u8]).collect() };
+    let mut table = reset();
+    let mut size = min_size + 1;
+    let mut prev: Option<u16> = None;
+    let mut output = vec![];
+    let (mut buffer, mut bits, mut pos) = (0u32, 0u8, 0);
+    loop {
+        while bits < size {
+            buffer |= u32::from(data[pos]) << bits;
+            pos += 1;
+            bits += 8;
+        }
+        let code = (buffer & ((1 << size) - 1)) as u16;
+        buffer >>= size;
+        bits -= size;
+        if code == clear {
+            table = reset();
+            size = min_size + 1;
+            prev = None;
+            continue;
+        } else if code == end {
+            return output;
+        }
+        let entry = match prev {
+            None => table[usize::from(code)].clone(),
+            Some(prev) => {
+                let mut entry = table[usize::from(prev)].clone();
+                let first = table.get(usize::from(code)).unwrap_or(&entry)[0];
+                entry.push(first);
+                table.push(entry);
+                if table.len() == 1 << size && size < 12 {
+                    size += 1;
+                }
+                table[usize::from(code)].clone()
+            }
+        };
+        output.extend_from_slice(&entry);
+        prev = Some(code);
+    }
+}
@@ -49,52 +49,73 @@ pub mod error;
 #[macro_use]
 pub mod shape;
 pub mod audio;
+pub mod camera;
 pub mod event;
 pub mod image;
 pub mod lighting;
 pub mod ops;
 pub mod state;
 pub mod texture;
+#[cfg(feature = "tiled")]
+pub mod tilemap;
 pub mod window;
 #[macro_use]
 pub mod math;
 #[macro_use]
 pub mod vector;
 pub mod gui;
+pub mod noise;
 pub mod renderer;
 #[cfg(feature = "serde")]
 pub mod serialize;
+pub mod spatial;
+pub mod sprite;
 pub mod transform;
+pub mod tween;
 
 /// Exports most commonly used types, traits, and functions.
 pub mod prelude {
     pub use super::audio::{
-        AudioCallback, AudioDevice, AudioDeviceDriver, AudioFormat, AudioFormatNum, AudioSpec,
-        AudioSpecDesired, AudioStatus,
+        AudioCallback, AudioDevice, AudioDeviceDriver, AudioFn, AudioFormat, AudioFormatNum,
+        AudioSpec, AudioSpecDesired, AudioStatus, SoundId, SoundParams, VoiceId,
     };
+    pub use super::camera::Camera2D;
     pub use super::color::{Color, Mode as ColorMode};
     pub use super::draw::Draw;
     pub use super::engine::{Engine, PixEngine};
     pub use super::error::{Error as PixError, Result as PixResult};
     pub use super::event::{
         Axis, ControllerButton, ControllerEvent, ControllerId, ControllerUpdate, Event, HatState,
-        Key, KeyEvent, KeyMod, Mouse, Scan, WindowEvent,
+        Key, KeyEvent, KeyMod, Mouse, Scan, TouchPoint, WindowEvent,
     };
-    pub use super::gui::theme::{self, ColorType, Font, Theme};
+    pub use super::gui::theme::{self, ColorType, Font, FontId, Theme};
+    pub use super::gui::widgets::modal::{MessageBoxButtons, MessageBoxResult};
     pub use super::image::{Image, PixelFormat};
     pub use super::lighting::{Light, LightSource};
-    pub use super::math::{map, random_rng, Float, Num};
-    pub use super::shape::{Contains, Ellipse, Intersects, Line, Point, Quad, Rect, Sphere, Tri};
+    pub use super::math::{constrain, map, norm, random_rng, Float, Num};
+    pub use super::noise::Noise;
+    pub use super::renderer::RenderDriver;
+    pub use super::shape::{
+        Contains, Ellipse, Intersects, Line, Point, Polygon, Quad, Rect, Sphere, Tri,
+    };
+    pub use super::spatial::{ItemId, QuadTree};
+    pub use super::sprite::{Animation, PlayMode, SpriteSheet};
     pub use super::state::{
         settings::{
-            AngleMode, ArcMode, BlendMode, DrawMode, EllipseMode, FontStyle, ImageMode, RectMode,
+            Align, AngleMode, ArcMode, Baseline, BlendMode, DrawMode, EllipseMode, FontStyle,
+            ImageMode, RectMode,
         },
         PixState,
     };
     pub use super::texture::TextureId;
-    pub use super::transform::Flipped;
+    #[cfg(feature = "tiled")]
+    pub use super::tilemap::{TileLayer, TileMap, Tileset};
+    pub use super::transform::{Flipped, Transform};
+    pub use super::tween::{Easing, Lerp, Repeat, Tween};
     pub use super::vector::Vector;
-    pub use super::window::{Cursor, Position, SystemCursor, WindowId};
+    pub use super::window::{
+        Cursor, DisplayInfo, DisplayMode, HitTestResult, Position, SystemCursor, WindowId,
+    };
 
     // Shape macros
     pub use {circle, ellipse, line_, point, quad, rect, sphere, square, tri};
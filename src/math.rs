@@ -1,11 +1,17 @@
 //! Math functions and constants.
 
-use crate::prelude::Vector;
+use crate::{
+    noise::split_mix64,
+    prelude::{PixState, Vector},
+};
 use num_traits::{
     Float as FloatT, Num as NumT, NumAssignOps, NumAssignRef, NumCast, NumOps, NumRef,
 };
 use rand::{self, distributions::uniform::SampleUniform, Rng};
-use std::ops::{AddAssign, Range};
+use std::{
+    fmt,
+    ops::{AddAssign, Range},
+};
 
 use once_cell::sync::Lazy;
 /// Default math constants.
@@ -41,13 +47,15 @@ const PERLIN_ZWRAPB: usize = 8;
 const PERLIN_ZWRAP: usize = 1 << PERLIN_ZWRAPB;
 const PERLIN_SIZE: usize = 4095;
 
-static PERLIN: Lazy<Vec<f64>> = Lazy::new(|| {
-    let mut perlin = Vec::with_capacity(PERLIN_SIZE + 1);
-    for _ in 0..=PERLIN_SIZE {
-        perlin.push(random(1.0));
-    }
-    perlin
-});
+/// Default number of [`noise`] octaves.
+pub(crate) const DEFAULT_NOISE_OCTAVES: u32 = 4; // medium smooth
+/// Default [`noise`] amplitude falloff per octave.
+pub(crate) const DEFAULT_NOISE_FALLOFF: f64 = 0.5; // 50% reduction/octave
+/// Maximum number of noise octaves. Further octaves are too fine to change the result.
+pub(crate) const MAX_NOISE_OCTAVES: u32 = 32;
+
+/// Default [`NoiseTable`] sampled by [`noise`] and [`noise_detail`].
+static PERLIN: Lazy<NoiseTable> = Lazy::new(NoiseTable::default);
 
 /// Returns a random number within a range.
 ///
@@ -93,7 +101,12 @@ where
     }
 }
 
-/// Returns the [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) value at specified coordinates.
+/// Returns the [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) value at specified
+/// coordinates, ranging from `0.0..=1.0`. Noise values are deterministic, returning the same value
+/// for the same coordinates every run and on every platform, and match [`PixState::noise`],
+/// [`PixState::noise2d`] and [`PixState::noise3d`] with the default seed and noise detail.
+///
+/// Uses `4` octaves with an amplitude falloff of `0.5`. See [`noise_detail`] to customize them.
 ///
 /// # Examples
 ///
@@ -113,75 +126,290 @@ pub fn noise<V, const N: usize>(vector: V) -> f64
 where
     V: Into<Vector<f64, N>>,
 {
-    let v = vector.into();
-
-    let values = v.coords();
-    let x = values.first().unwrap_or(&0.0).abs();
-    let y = values.get(1).unwrap_or(&0.0).abs();
-    let z = values.get(2).unwrap_or(&0.0).abs();
-
-    let mut xi: usize = x.trunc() as usize;
-    let mut yi: usize = y.trunc() as usize;
-    let mut zi: usize = z.trunc() as usize;
-
-    let mut xf = x.fract();
-    let mut yf = y.fract();
-    let mut zf = z.fract();
-    let (mut rxf, mut ryf);
-
-    let mut noise_result = 0.0;
-    let mut ampl = 0.5;
-
-    let (mut n1, mut n2, mut n3);
-
-    let scaled_cosine = |i: f64| 0.5 * (1.0 - (i - PI).cos());
-
-    let perlin_octaves = 4; // default to medium smooth
-    let perlin_amp_falloff = 0.5; // 50% reduction/octave
-    for _ in 0..perlin_octaves {
-        let mut of = xi + (yi << PERLIN_YWRAPB) + (zi << PERLIN_ZWRAPB);
-
-        rxf = scaled_cosine(xf);
-        ryf = scaled_cosine(yf);
-
-        n1 = PERLIN[of & PERLIN_SIZE];
-        n1 += rxf * (PERLIN[(of + 1) & PERLIN_SIZE] - n1);
-        n2 = PERLIN[(of + PERLIN_YWRAP) & PERLIN_SIZE];
-        n2 += rxf * (PERLIN[(of + PERLIN_YWRAP + 1) & PERLIN_SIZE] - n2);
-        n1 += ryf * (n2 - n1);
-
-        of += PERLIN_ZWRAP;
-        n2 = PERLIN[of & PERLIN_SIZE];
-        n2 += rxf * (PERLIN[(of + 1) & PERLIN_SIZE] - n2);
-        n3 = PERLIN[(of + PERLIN_YWRAP) & PERLIN_SIZE];
-        n3 += rxf * (PERLIN[(of + PERLIN_YWRAP + 1) & PERLIN_SIZE] - n3);
-        n2 += ryf * (n3 - n2);
-
-        n1 += scaled_cosine(zf) * (n2 - n1);
-
-        noise_result += n1 * ampl;
-        ampl *= perlin_amp_falloff;
-        xi <<= 1;
-        xf *= 2.0;
-        yi <<= 1;
-        yf *= 2.0;
-        zi <<= 1;
-        zf *= 2.0;
-
-        if xf >= 1.0 {
-            xi += 1;
-            xf -= 1.0;
+    noise_detail(vector, DEFAULT_NOISE_OCTAVES, DEFAULT_NOISE_FALLOFF)
+}
+
+/// Returns the [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) value at specified
+/// coordinates, ranging from `0.0..=1.0`, summed over a number of `octaves`, clamped to `1..=32`.
+/// Each octave doubles the frequency of the previous one, with its amplitude scaled by `falloff`.
+/// Higher falloff values result in rougher noise.
+///
+/// # Examples
+///
+/// ```
+/// use pix_engine::math::noise_detail;
+///
+/// let smooth = noise_detail([2.0, 1.5], 2, 0.25);
+/// assert!(smooth >= 0.0 && smooth <= 1.0);
+///
+/// let rough = noise_detail([2.0, 1.5], 8, 0.75);
+/// assert!(rough >= 0.0 && rough <= 1.0);
+/// ```
+pub fn noise_detail<V, const N: usize>(vector: V, octaves: u32, falloff: f64) -> f64
+where
+    V: Into<Vector<f64, N>>,
+{
+    PERLIN.sample(vector, octaves, falloff)
+}
+
+/// Seeded lattice of random values sampled by [`noise_detail`] and [`PixState::noise`].
+#[derive(Clone)]
+pub(crate) struct NoiseTable {
+    seed: u64,
+    values: Box<[f64]>,
+}
+
+impl Default for NoiseTable {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl fmt::Debug for NoiseTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NoiseTable")
+            .field("seed", &self.seed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NoiseTable {
+    /// Constructs a `NoiseTable` with the given `seed`.
+    pub(crate) fn new(seed: u64) -> Self {
+        // Seeded with integer hashing so noise values are the same every run and on every platform
+        let mut state = seed;
+        let values = (0..=PERLIN_SIZE)
+            .map(|_| (split_mix64(&mut state) >> 11) as f64 / (1_u64 << 53) as f64)
+            .collect();
+        Self { seed, values }
+    }
+
+    /// Returns the seed used to generate the table.
+    #[inline]
+    pub(crate) const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the noise value at the given coordinates, ranging from `0.0..=1.0`, summed over a
+    /// number of `octaves`, clamped to `1..=32`, with each octave's amplitude scaled by `falloff`.
+    pub(crate) fn sample<V, const N: usize>(&self, vector: V, octaves: u32, falloff: f64) -> f64
+    where
+        V: Into<Vector<f64, N>>,
+    {
+        let v = vector.into();
+
+        let values = v.coords();
+        let x = values.first().unwrap_or(&0.0).abs();
+        let y = values.get(1).unwrap_or(&0.0).abs();
+        let z = values.get(2).unwrap_or(&0.0).abs();
+
+        let mut xi: usize = x.trunc() as usize;
+        let mut yi: usize = y.trunc() as usize;
+        let mut zi: usize = z.trunc() as usize;
+
+        let mut xf = x.fract();
+        let mut yf = y.fract();
+        let mut zf = z.fract();
+        let (mut rxf, mut ryf);
+
+        let mut noise_result = 0.0;
+        let mut ampl = 0.5;
+        let mut total_ampl = 0.0;
+
+        let (mut n1, mut n2, mut n3);
+
+        let scaled_cosine = |i: f64| 0.5 * (1.0 - (i - PI).cos());
+
+        let falloff = falloff.clamp(0.0, 1.0);
+        for _ in 0..octaves.clamp(1, MAX_NOISE_OCTAVES) {
+            let mut of = xi + (yi << PERLIN_YWRAPB) + (zi << PERLIN_ZWRAPB);
+
+            rxf = scaled_cosine(xf);
+            ryf = scaled_cosine(yf);
+
+            n1 = self.values[of & PERLIN_SIZE];
+            n1 += rxf * (self.values[(of + 1) & PERLIN_SIZE] - n1);
+            n2 = self.values[(of + PERLIN_YWRAP) & PERLIN_SIZE];
+            n2 += rxf * (self.values[(of + PERLIN_YWRAP + 1) & PERLIN_SIZE] - n2);
+            n1 += ryf * (n2 - n1);
+
+            of += PERLIN_ZWRAP;
+            n2 = self.values[of & PERLIN_SIZE];
+            n2 += rxf * (self.values[(of + 1) & PERLIN_SIZE] - n2);
+            n3 = self.values[(of + PERLIN_YWRAP) & PERLIN_SIZE];
+            n3 += rxf * (self.values[(of + PERLIN_YWRAP + 1) & PERLIN_SIZE] - n3);
+            n2 += ryf * (n3 - n2);
+
+            n1 += scaled_cosine(zf) * (n2 - n1);
+
+            noise_result += n1 * ampl;
+            total_ampl += ampl;
+            ampl *= falloff;
+            xi <<= 1;
+            xf *= 2.0;
+            yi <<= 1;
+            yf *= 2.0;
+            zi <<= 1;
+            zf *= 2.0;
+
+            if xf >= 1.0 {
+                xi += 1;
+                xf -= 1.0;
+            }
+            if yf >= 1.0 {
+                yi += 1;
+                yf -= 1.0;
+            }
+            if zf >= 1.0 {
+                zi += 1;
+                zf -= 1.0;
+            }
         }
-        if yf >= 1.0 {
-            yi += 1;
-            yf -= 1.0;
+        // Normalize so values range from `0.0..=1.0` regardless of octaves and falloff
+        if total_ampl > 0.0 {
+            noise_result / total_ampl
+        } else {
+            0.0
         }
-        if zf >= 1.0 {
-            zi += 1;
-            zf -= 1.0;
+    }
+}
+
+impl PixState {
+    /// Returns the 1D [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) value at `x`,
+    /// ranging from `0.0..=1.0`, using the octaves and falloff set by [`PixState::noise_detail`].
+    /// Noise values are deterministic, returning the same value for the same inputs and
+    /// [`PixState::noise_seed`] every run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { t: f64 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let x = s.noise(self.t) * f64::from(s.width()?);
+    ///     s.circle([x as i32, 50, 10])?;
+    ///     self.t += 0.01;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn noise(&self, x: f64) -> f64 {
+        self.noise_fbm([x])
+    }
+
+    /// Returns the 2D [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) value at `(x,
+    /// y)`, ranging from `0.0..=1.0`, using the octaves and falloff set by
+    /// [`PixState::noise_detail`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     for y in 0..20 {
+    ///         for x in 0..20 {
+    ///             let n = s.noise2d(f64::from(x) * 0.1, f64::from(y) * 0.1);
+    ///             s.fill(rgb!((n * 255.0) as u8));
+    ///             s.square([x * 10, y * 10, 10])?;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn noise2d(&self, x: f64, y: f64) -> f64 {
+        self.noise_fbm([x, y])
+    }
+
+    /// Returns the 3D [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) value at `(x, y,
+    /// z)`, ranging from `0.0..=1.0`, using the octaves and falloff set by
+    /// [`PixState::noise_detail`]. Using time as `z` animates 2D noise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { t: f64 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let n = s.noise3d(0.5, 0.5, self.t);
+    ///     s.background(rgb!((n * 255.0) as u8));
+    ///     self.t += 0.01;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn noise3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.noise_fbm([x, y, z])
+    }
+
+    /// Set the number of `octaves` and amplitude `falloff` per octave used by [`PixState::noise`],
+    /// [`PixState::noise2d`], and [`PixState::noise3d`]. More octaves add finer detail, and higher
+    /// falloff values, ranging from `0.0..=1.0`, make that detail more pronounced. Octaves are
+    /// clamped to `1..=32`. The defaults are `4` octaves with a falloff of `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.noise_detail(8, 0.65);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn noise_detail(&mut self, octaves: u32, falloff: f64) {
+        self.settings.noise_octaves = octaves.clamp(1, MAX_NOISE_OCTAVES);
+        self.settings.noise_falloff = falloff.clamp(0.0, 1.0);
+    }
+
+    /// Set the `seed` used by [`PixState::noise`], [`PixState::noise2d`], and
+    /// [`PixState::noise3d`]. The default seed is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { seed: u64 };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Generate a different world each run
+    ///     self.seed = random!(u64::MAX);
+    ///     s.noise_seed(self.seed);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn noise_seed(&mut self, seed: u64) {
+        if seed != self.noise.seed() {
+            self.noise = NoiseTable::new(seed);
         }
     }
-    noise_result
+
+    /// Returns the noise value at the given coordinates using the current noise detail.
+    #[inline]
+    fn noise_fbm<const N: usize>(&self, coords: [f64; N]) -> f64 {
+        self.noise.sample(
+            coords,
+            self.settings.noise_octaves,
+            self.settings.noise_falloff,
+        )
+    }
 }
 
 /// Returns a random number within a range.
@@ -273,6 +501,10 @@ macro_rules! noise {
 /// let value = f64::NEG_INFINITY;
 /// let m = map(value, 0.0, 100.0, 0.0, 1.0);
 /// assert_eq!(m, 0.0);
+///
+/// let value = 25.0;
+/// let m = map(value, 0.0, 100.0, 1.0, 0.0);
+/// assert_eq!(m, 0.75);
 /// ```
 pub fn map<T>(value: T, start1: T, end1: T, start2: T, end2: T) -> T
 where
@@ -285,7 +517,53 @@ where
     let end2 = end2.into();
     let value = value.into();
     let new_val = ((value - start1) / (end1 - start1)).mul_add(end2 - start2, start2);
-    NumCast::from(new_val.clamp(start2, end2)).unwrap_or(default)
+    let (min, max) = if start2 > end2 {
+        (end2, start2)
+    } else {
+        (start2, end2)
+    };
+    NumCast::from(new_val.clamp(min, max)).unwrap_or(default)
+}
+
+/// Constrains a value between a `low` and `high` value.
+///
+/// # Example
+///
+/// ```
+/// # use pix_engine::prelude::*;
+/// assert_eq!(constrain(150, 0, 100), 100);
+/// assert_eq!(constrain(-5.0, 0.0, 1.0), 0.0);
+/// assert_eq!(constrain(0.5, 0.0, 1.0), 0.5);
+/// ```
+pub fn constrain<T>(value: T, low: T, high: T) -> T
+where
+    T: PartialOrd,
+{
+    if value < low {
+        low
+    } else if value > high {
+        high
+    } else {
+        value
+    }
+}
+
+/// Normalizes a value from the range `start..=end` to `0.0..=1.0`. Values outside of the range are
+/// constrained to `0.0..=1.0`.
+///
+/// # Example
+///
+/// ```
+/// # use pix_engine::prelude::*;
+/// assert_eq!(norm(25.0, 0.0, 100.0), 0.25);
+/// assert_eq!(norm(25.0, 100.0, 0.0), 0.75);
+/// assert_eq!(norm(150.0, 0.0, 100.0), 1.0);
+/// ```
+pub fn norm<T>(value: T, start: T, end: T) -> T
+where
+    T: Float,
+{
+    constrain((value - start) / (end - start), T::zero(), T::one())
 }
 
 /// Linear interpolates between two values by a given amount.
@@ -349,3 +627,63 @@ where
         values
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noise_is_deterministic() {
+        let n = noise([2.5, 1.5, 0.5]);
+        assert_eq!(n, noise([2.5, 1.5, 0.5]));
+        assert_ne!(n, noise([2.6, 1.5, 0.5]));
+        assert_eq!(noise([3.0]), noise([3.0, 0.0, 0.0]));
+
+        // Values vary between whole number coordinates
+        let n = noise!(1.0);
+        assert!([2.0, 3.0, 4.0, 5.0].iter().any(|&k| noise!(k) != n));
+    }
+
+    #[test]
+    fn noise_detail_range() {
+        for &(octaves, falloff) in &[(0, 0.5), (1, 0.0), (4, 0.5), (8, 0.9), (16, 1.0), (2, 5.0)] {
+            let mut x = 0.0;
+            for _ in 0..100 {
+                let n = noise_detail([x, x * 0.5, -x], octaves, falloff);
+                assert!((0.0..=1.0).contains(&n), "{n} out of range");
+                x += 0.37;
+            }
+        }
+        assert_eq!(
+            noise([4.2, 1.3]),
+            noise_detail([4.2, 1.3], DEFAULT_NOISE_OCTAVES, DEFAULT_NOISE_FALLOFF)
+        );
+    }
+
+    #[test]
+    fn noise_detail_clamps_octaves() {
+        let p = [4.2, 1.3];
+        assert_eq!(
+            noise_detail(p, u32::MAX, 1.0),
+            noise_detail(p, MAX_NOISE_OCTAVES, 1.0)
+        );
+    }
+
+    #[test]
+    fn noise_table_seeds() {
+        let p = [2.5, 1.5, 0.5];
+        let table = NoiseTable::default();
+        assert_eq!(table.seed(), 0);
+        assert_eq!(
+            table.sample(p, DEFAULT_NOISE_OCTAVES, DEFAULT_NOISE_FALLOFF),
+            noise(p)
+        );
+
+        let seeded = NoiseTable::new(42);
+        let sample = |table: &NoiseTable, x: f64| table.sample([x], 1, 0.5);
+        assert_eq!(sample(&seeded, 1.5), sample(&NoiseTable::new(42), 1.5));
+        assert!([0.5, 1.5, 2.5, 3.5]
+            .iter()
+            .any(|&x| sample(&seeded, x) != sample(&table, x)));
+    }
+}
@@ -0,0 +1,392 @@
+//! Seeded [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) generation.
+//!
+//! [`Noise`] generates smooth, continuous gradient noise in 1, 2 or 3 dimensions, ranging from
+//! `-1.0..=1.0`. Noise values only depend on the seed and coordinates, using integer hashing so
+//! that the same seed produces the same values on every platform.
+//!
+//! [`Noise`] is separate from [`math::noise`] and [`PixState::noise`], which generate value noise
+//! ranging from `0.0..=1.0` that, unlike gradient noise, also varies between whole number
+//! coordinates.
+//!
+//! [`math::noise`]: crate::math::noise
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! let noise = Noise::new(42);
+//! let n = noise.get([1.5, 2.25]);
+//! assert!((-1.0..=1.0).contains(&n));
+//!
+//! // Same seed, same values
+//! assert_eq!(n, Noise::new(42).get([1.5, 2.25]));
+//!
+//! // Fractal noise, summing 6 octaves that each double in frequency and halve in amplitude
+//! let height = noise.fbm([1.5, 2.25], 6, 2.0, 0.5);
+//! assert!((-1.0..=1.0).contains(&height));
+//! ```
+
+use crate::{math::MAX_NOISE_OCTAVES, prelude::*};
+
+/// Seeded [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) generator.
+#[derive(Clone)]
+#[must_use]
+pub struct Noise {
+    seed: u64,
+    /// Permutation of `0..=255`, repeated twice to avoid wrapping lookups.
+    perm: [u8; 512],
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl std::fmt::Debug for Noise {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Noise")
+            .field("seed", &self.seed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Noise {
+    /// Constructs a `Noise` generator with the given `seed`.
+    pub fn new(seed: u64) -> Self {
+        let mut perm = [0; 512];
+        for (i, p) in perm.iter_mut().take(256).enumerate() {
+            *p = i as u8;
+        }
+        // Fisher-Yates shuffle using SplitMix64 so the permutation is identical on every platform
+        let mut state = seed;
+        for i in (1..256).rev() {
+            let z = split_mix64(&mut state);
+            perm.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+        perm.copy_within(0..256, 256);
+        Self { seed, perm }
+    }
+
+    /// Returns the seed used to generate noise values.
+    #[inline]
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the noise value at the given 1D, 2D or 3D coordinates, ranging from `-1.0..=1.0`.
+    /// Noise is `0.0` at integer coordinates and varies smoothly in between. Coordinates beyond
+    /// the third are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let noise = Noise::new(7);
+    /// assert_eq!(noise.get([3.0, -2.0]), 0.0);
+    /// let n = noise.get([3.5, -2.5, 0.25]);
+    /// assert!((-1.0..=1.0).contains(&n));
+    /// ```
+    #[must_use]
+    pub fn get<V, const N: usize>(&self, vector: V) -> f64
+    where
+        V: Into<Vector<f64, N>>,
+    {
+        let v = vector.into();
+        let coords = v.coords();
+        let n = match *coords.as_slice() {
+            [] => 0.0,
+            [x] => self.noise1d(x),
+            [x, y] => self.noise2d(x, y),
+            [x, y, z, ..] => self.noise3d(x, y, z),
+        };
+        n.clamp(-1.0, 1.0)
+    }
+
+    /// Returns fractal Brownian motion noise at the given coordinates, ranging from `-1.0..=1.0`,
+    /// by summing `octaves` layers of noise, clamped to `1..=32`. Each octave multiplies the
+    /// frequency of the previous one by `lacunarity` and its amplitude by `persistence`. The result
+    /// is normalized by the total amplitude.
+    ///
+    /// Common values are a `lacunarity` of `2.0` and a `persistence` of `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let noise = Noise::new(7);
+    /// let smooth = noise.fbm([3.5, 2.5], 2, 2.0, 0.25);
+    /// let rough = noise.fbm([3.5, 2.5], 8, 2.0, 0.75);
+    /// assert!((-1.0..=1.0).contains(&smooth));
+    /// assert!((-1.0..=1.0).contains(&rough));
+    /// ```
+    #[must_use]
+    pub fn fbm<V, const N: usize>(
+        &self,
+        vector: V,
+        octaves: u32,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> f64
+    where
+        V: Into<Vector<f64, N>>,
+    {
+        let v = vector.into();
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut total_amplitude = 0.0;
+        for _ in 0..octaves.clamp(1, MAX_NOISE_OCTAVES) {
+            total += self.get(v * frequency) * amplitude;
+            total_amplitude += amplitude;
+            frequency *= lacunarity;
+            amplitude *= persistence;
+        }
+        if total_amplitude > 0.0 {
+            (total / total_amplitude).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// 1D gradient noise.
+    fn noise1d(&self, x: f64) -> f64 {
+        let (xi, x) = lattice(x);
+        let u = fade(x);
+        let a = grad1d(self.hash(xi), x);
+        let b = grad1d(self.hash(xi + 1), x - 1.0);
+        // Scaled so the largest possible value is 1.0
+        2.0 * lerp(u, a, b)
+    }
+
+    /// 2D gradient noise.
+    fn noise2d(&self, x: f64, y: f64) -> f64 {
+        let (xi, x) = lattice(x);
+        let (yi, y) = lattice(y);
+        let (u, v) = (fade(x), fade(y));
+        let (a, b) = (self.hash(xi) + yi, self.hash(xi + 1) + yi);
+        let x1 = lerp(
+            u,
+            grad2d(self.hash(a), x, y),
+            grad2d(self.hash(b), x - 1.0, y),
+        );
+        let x2 = lerp(
+            u,
+            grad2d(self.hash(a + 1), x, y - 1.0),
+            grad2d(self.hash(b + 1), x - 1.0, y - 1.0),
+        );
+        // Scaled so the largest possible value is 1.0
+        std::f64::consts::SQRT_2 * lerp(v, x1, x2)
+    }
+
+    /// 3D gradient noise, using Ken Perlin's improved noise.
+    fn noise3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (xi, x) = lattice(x);
+        let (yi, y) = lattice(y);
+        let (zi, z) = lattice(z);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+        let a = self.hash(xi) + yi;
+        let (aa, ab) = (self.hash(a) + zi, self.hash(a + 1) + zi);
+        let b = self.hash(xi + 1) + yi;
+        let (ba, bb) = (self.hash(b) + zi, self.hash(b + 1) + zi);
+        let y1 = lerp(
+            v,
+            lerp(
+                u,
+                grad3d(self.hash(aa), x, y, z),
+                grad3d(self.hash(ba), x - 1.0, y, z),
+            ),
+            lerp(
+                u,
+                grad3d(self.hash(ab), x, y - 1.0, z),
+                grad3d(self.hash(bb), x - 1.0, y - 1.0, z),
+            ),
+        );
+        let y2 = lerp(
+            v,
+            lerp(
+                u,
+                grad3d(self.hash(aa + 1), x, y, z - 1.0),
+                grad3d(self.hash(ba + 1), x - 1.0, y, z - 1.0),
+            ),
+            lerp(
+                u,
+                grad3d(self.hash(ab + 1), x, y - 1.0, z - 1.0),
+                grad3d(self.hash(bb + 1), x - 1.0, y - 1.0, z - 1.0),
+            ),
+        );
+        lerp(w, y1, y2)
+    }
+
+    /// Looks up `index` in the permutation table.
+    #[inline]
+    fn hash(&self, index: usize) -> usize {
+        usize::from(self.perm[index & 511])
+    }
+}
+
+/// Advances a [SplitMix64](https://prng.di.unimi.it/splitmix64.c) `state`, returning the next
+/// pseudo-random value. Only uses integer operations, so the sequence is identical on every
+/// platform.
+#[inline]
+pub(crate) fn split_mix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Splits `value` into its lattice cell, wrapped to `0..=255`, and its offset within the cell.
+#[inline]
+fn lattice(value: f64) -> (usize, f64) {
+    let floor = value.floor();
+    ((floor as i64 & 255) as usize, value - floor)
+}
+
+/// Quintic fade curve `6t^5 - 15t^4 + 10t^3`, which has zero first and second derivatives at
+/// `0.0` and `1.0`.
+#[inline]
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline]
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Dot product of `x` with one of 16 gradients evenly spaced within `-1.0..=1.0`.
+#[inline]
+fn grad1d(hash: usize, x: f64) -> f64 {
+    let gradient = (hash & 15) as f64 / 7.5 - 1.0;
+    gradient * x
+}
+
+/// Dot product of `(x, y)` with one of 8 unit gradients.
+#[inline]
+fn grad2d(hash: usize, x: f64, y: f64) -> f64 {
+    const DIAGONAL: f64 = std::f64::consts::FRAC_1_SQRT_2;
+    match hash & 7 {
+        0 => x,
+        1 => -x,
+        2 => y,
+        3 => -y,
+        4 => (x + y) * DIAGONAL,
+        5 => (-x + y) * DIAGONAL,
+        6 => (x - y) * DIAGONAL,
+        _ => (-x - y) * DIAGONAL,
+    }
+}
+
+/// Dot product of `(x, y, z)` with one of the 12 cube edge gradients.
+#[inline]
+fn grad3d(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let hash = hash & 15;
+    let u = if hash < 8 { x } else { y };
+    let v = match hash {
+        0..=3 => y,
+        12 | 14 => x,
+        _ => z,
+    };
+    let u = if hash & 1 == 0 { u } else { -u };
+    let v = if hash & 2 == 0 { v } else { -v };
+    u + v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::assert_approx_eq;
+
+    #[test]
+    fn fixed_seed_values() {
+        let noise = Noise::new(42);
+        assert_approx_eq([noise.get([0.3])], [0.188_675_2], 1e-12);
+        assert_approx_eq([noise.get([1.7, 2.4])], [-0.221_467_761_830_032_9], 1e-12);
+        assert_approx_eq(
+            [noise.get([0.5, 1.25, 2.75])],
+            [0.091_460_704_803_466_8],
+            1e-12,
+        );
+        assert_approx_eq([noise.get([-3.3, 4.1])], [-0.211_595_385_245_665_5], 1e-12);
+        assert_approx_eq(
+            [noise.fbm([1.7, 2.4], 4, 2.0, 0.5)],
+            [-0.201_285_473_948_455_8],
+            1e-12,
+        );
+
+        let noise = Noise::new(7);
+        assert_approx_eq([noise.get([0.3])], [0.486_931_2], 1e-12);
+        assert_approx_eq([noise.get([1.7, 2.4])], [-0.540_805_469_347_615], 1e-12);
+        assert_approx_eq(
+            [noise.get([0.5, 1.25, 2.75])],
+            [0.084_333_896_636_962_9],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn lattice_points_and_range() {
+        let noise = Noise::new(1);
+        for i in -10..10 {
+            let i = f64::from(i);
+            assert_approx_eq([noise.get([i])], [0.0], 1e-12);
+            assert_approx_eq([noise.get([i, i * 2.0])], [0.0], 1e-12);
+            assert_approx_eq([noise.get([i, 3.0, -i])], [0.0], 1e-12);
+        }
+        for i in 0..2000 {
+            let t = f64::from(i) * 0.137;
+            for n in [
+                noise.get([t]),
+                noise.get([t, t * 0.7]),
+                noise.get([t, t * 0.7, t * 1.3]),
+                noise.fbm([t, t * 0.7], 8, 2.0, 0.7),
+            ] {
+                assert!((-1.0..=1.0).contains(&n), "{n} out of range at {t}");
+            }
+        }
+    }
+
+    #[test]
+    fn continuity() {
+        let noise = Noise::new(42);
+        let step = 1e-3;
+        // Noise gradients are bounded, so neighboring samples can only differ by a few steps
+        let max_delta = step * 4.0;
+        for i in 0..5000 {
+            let x = f64::from(i) * step - 2.5;
+            let y = x * 0.5 + 0.25;
+            assert!((noise.get([x + step]) - noise.get([x])).abs() < max_delta);
+            assert!((noise.get([x + step, y]) - noise.get([x, y])).abs() < max_delta);
+            assert!((noise.get([x, y + step]) - noise.get([x, y])).abs() < max_delta);
+            assert!((noise.get([x, y, x + step]) - noise.get([x, y, x])).abs() < max_delta);
+            let fbm = |x| noise.fbm([x, y], 4, 2.0, 0.5);
+            assert!((fbm(x + step) - fbm(x)).abs() < max_delta * 4.0);
+        }
+    }
+
+    #[test]
+    fn seeds() {
+        let (a, b) = (Noise::new(1), Noise::new(2));
+        assert_eq!(a.seed(), 1);
+        assert_approx_eq([a.get([0.5, 0.5])], [Noise::new(1).get([0.5, 0.5])], 1e-12);
+        let differs = (0..10).any(|i| {
+            let p = [f64::from(i) + 0.5, 0.25];
+            (a.get(p) - b.get(p)).abs() > 1e-6
+        });
+        assert!(differs);
+    }
+
+    #[test]
+    fn fbm_clamps_octaves() {
+        let noise = Noise::new(3);
+        let p = [1.3, 2.7];
+        assert_eq!(noise.fbm(p, 0, 2.0, 0.5), noise.fbm(p, 1, 2.0, 0.5));
+        assert_eq!(
+            noise.fbm(p, u32::MAX, 2.0, 1.0),
+            noise.fbm(p, MAX_NOISE_OCTAVES, 2.0, 1.0)
+        );
+    }
+}
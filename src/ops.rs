@@ -21,6 +21,50 @@ pub(crate) fn clamp_dimensions(width: u32, height: u32) -> (i32, i32) {
     (clamp_size(width), clamp_size(height))
 }
 
+/// Asserts that every coordinate of `a` is within `epsilon` of `b`.
+#[cfg(test)]
+#[track_caller]
+pub(crate) fn assert_approx_eq<A, B, const N: usize>(a: A, b: B, epsilon: f64)
+where
+    A: Into<Point<f64, N>>,
+    B: Into<Point<f64, N>>,
+{
+    let (a, b) = (a.into(), b.into());
+    assert!(a.approx_eq(b, epsilon), "{a:?} != {b:?}");
+}
+
+/// Split text into lines at newlines and, if `wrap_width` is set, at word boundaries where the
+/// width returned by `measure` would exceed `wrap_width`.
+pub(crate) fn wrap_lines<F>(
+    text: &str,
+    wrap_width: Option<u32>,
+    mut measure: F,
+) -> PixResult<Vec<&str>>
+where
+    F: FnMut(&str) -> PixResult<u32>,
+{
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        match wrap_width {
+            Some(wrap_width) => {
+                let mut start = 0;
+                let mut end = 0;
+                let breaks = line.match_indices(' ').map(|(idx, _)| idx);
+                for idx in breaks.chain(std::iter::once(line.len())) {
+                    if end > start && measure(&line[start..idx])? > wrap_width {
+                        lines.push(&line[start..end]);
+                        start = end + 1;
+                    }
+                    end = idx;
+                }
+                lines.push(&line[start..]);
+            }
+            None => lines.push(line),
+        }
+    }
+    Ok(lines)
+}
+
 /// Helper macro to create From conversion traits for arrays into generic shape types.
 macro_rules! impl_from_array {
     ($($Type:ident<T$(, $N:ident)?>),* => [$T:ty; $M:expr]) => {$(
@@ -1,7 +1,9 @@
 //! Graphics renderer functions.
 
 use crate::{error::Result, image::Icon, prelude::*};
-use std::num::NonZeroUsize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{num::NonZeroUsize, time::Duration};
 
 pub(crate) use crate::{texture::TextureRenderer, window::WindowRenderer};
 
@@ -18,6 +20,29 @@ pub(crate) use wasm::Renderer;
 const TEXTURE_CACHE_SIZE: usize = 256;
 const TEXT_CACHE_SIZE: usize = 512;
 
+/// Graphics backend used to render to a window.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RenderDriver {
+    /// `OpenGL`.
+    OpenGL,
+    /// `OpenGL ES`.
+    OpenGLES,
+    /// `Direct3D`, only available on Windows.
+    Direct3D,
+    /// `Metal`, only available on macOS and iOS.
+    Metal,
+    /// `Vulkan`. Not currently supported by SDL2, so the platform default driver is used instead.
+    Vulkan,
+    /// Software rendering on the CPU.
+    Software,
+    /// HTML canvas 2D context, used when targeting the web.
+    Canvas,
+    /// A driver not listed here.
+    Unknown,
+}
+
 /// Settings used to set up the renderer.
 #[derive(Debug, Clone)]
 pub(crate) struct RendererSettings {
@@ -29,6 +54,10 @@ pub(crate) struct RendererSettings {
     pub(crate) x: Position,
     /// Starting window Y coordinate.
     pub(crate) y: Position,
+    /// Display to open the window on. `None` uses the primary display.
+    pub(crate) display: Option<u32>,
+    /// Graphics backend to request. `None` uses the platform default.
+    pub(crate) render_driver: Option<RenderDriver>,
     /// Starting window width.
     pub(crate) width: u32,
     /// Starting window height.
@@ -55,6 +84,14 @@ pub(crate) struct RendererSettings {
     pub(crate) allow_highdpi: bool,
     /// Hide window.
     pub(crate) hidden: bool,
+    /// Minimum window dimensions as `(width, height)`.
+    pub(crate) min_size: Option<(u32, u32)>,
+    /// Maximum window dimensions as `(width, height)`.
+    pub(crate) max_size: Option<(u32, u32)>,
+    /// Window opacity, from `0.0` (transparent) to `1.0` (opaque).
+    pub(crate) opacity: f32,
+    /// Keep window above all other windows.
+    pub(crate) always_on_top: bool,
     /// Show frame rate per second in title bar.
     pub(crate) show_frame_rate: bool,
     /// Limit [`Engine::on_update`] to target frame frate per second.
@@ -73,6 +110,8 @@ impl Default for RendererSettings {
             icon: None,
             x: Position::default(),
             y: Position::default(),
+            display: None,
+            render_driver: None,
             width: 640,
             height: 480,
             scale_x: 1.0,
@@ -86,6 +125,10 @@ impl Default for RendererSettings {
             borderless: false,
             allow_highdpi: false,
             hidden: false,
+            min_size: None,
+            max_size: None,
+            opacity: 1.0,
+            always_on_top: false,
             show_frame_rate: false,
             target_frame_rate: None,
             texture_cache_size: TEXTURE_CACHE_SIZE.try_into().expect("valid cache size"),
@@ -99,6 +142,9 @@ pub(crate) trait Rendering: Sized {
     /// Creates a new Renderer instance.
     fn new(settings: RendererSettings) -> Result<Self>;
 
+    /// Returns the graphics backend selected when the renderer was created.
+    fn render_driver(&self) -> RenderDriver;
+
     /// Clears the current canvas to the given clear color
     fn clear(&mut self) -> Result<()>;
 
@@ -111,6 +157,9 @@ pub(crate) trait Rendering: Sized {
     /// Sets the blend mode used by the renderer to drawing.
     fn blend_mode(&mut self, mode: BlendMode);
 
+    /// Sets the line width used by the renderer to draw shape outlines.
+    fn stroke_weight(&mut self, weight: u16);
+
     /// Updates the canvas from the current back buffer.
     fn present(&mut self);
 
@@ -124,9 +173,16 @@ pub(crate) trait Rendering: Sized {
     /// Set the font style for drawing text to the current canvas.
     fn font_style(&mut self, style: FontStyle);
 
-    /// Set the font family for drawing text to the current canvas.
+    /// Set the font family for drawing text to the current canvas. If the font fails to load, the
+    /// current font family is left unchanged.
     fn font_family(&mut self, font: &Font) -> Result<()>;
 
+    /// Set the fonts used to draw glyphs missing from the current font.
+    fn font_fallbacks(&mut self, fonts: &[Font]) -> Result<()>;
+
+    /// Clear the cache of rendered text for every window.
+    fn clear_text_cache(&mut self);
+
     /// Get clipboard text from the system clipboard.
     fn clipboard_text(&self) -> String;
 
@@ -136,6 +192,19 @@ pub(crate) trait Rendering: Sized {
     /// Open a URL in the default system browser.
     fn open_url(&self, url: &str) -> Result<()>;
 
+    /// Start accepting Unicode text input events, showing an on-screen keyboard or IME if
+    /// supported.
+    fn start_text_input(&self);
+
+    /// Stop accepting Unicode text input events, hiding any on-screen keyboard or IME.
+    fn stop_text_input(&self);
+
+    /// Returns whether Unicode text input events are enabled.
+    fn text_input_active(&self) -> bool;
+
+    /// Set the rectangle used to position an on-screen keyboard or IME candidate list.
+    fn set_text_input_rect(&self, rect: Rect<i32>);
+
     /// Draw text to the current canvas. `angle` must be in degrees.
     #[allow(clippy::too_many_arguments)]
     fn text(
@@ -157,6 +226,9 @@ pub(crate) trait Rendering: Sized {
     /// Draw a pixel to the current canvas.
     fn point(&mut self, p: Point<i32>, color: Color) -> Result<()>;
 
+    /// Draw a pixel to the current canvas, compositing `color` using the current blend mode.
+    fn pixel(&mut self, p: Point<i32>, color: Color) -> Result<()>;
+
     /// Draw a line to the current canvas.
     fn line(&mut self, line: Line<i32>, smooth: bool, width: u8, color: Color) -> Result<()>;
 
@@ -239,12 +311,22 @@ pub(crate) trait Rendering: Sized {
         tint: Option<Color>,
     ) -> Result<()>;
 
-    /// Return the current rendered target pixels as an array of bytes.
-    fn to_bytes(&mut self) -> Result<Vec<u8>>;
+    /// Return the current rendered target pixels as an array of RGBA bytes, optionally limited
+    /// to a `src` rectangle.
+    fn to_bytes(&mut self, src: Option<Rect<i32>>) -> Result<Vec<u8>>;
 
     /// Connect a controller with the given joystick index to start receiving events.
     fn open_controller(&mut self, controller_id: ControllerId) -> Result<()>;
 
     /// Disconnect a controller with the given joystick index to stop receiving events.
     fn close_controller(&mut self, controller_id: ControllerId);
+
+    /// Start a rumble effect on a controller with the given joystick index.
+    fn rumble_controller(
+        &mut self,
+        controller_id: ControllerId,
+        low: u16,
+        high: u16,
+        duration: Duration,
+    ) -> Result<()>;
 }
@@ -2,7 +2,7 @@
 
 use crate::{
     error::{Error, Result},
-    gui::theme::{FontId, FontSrc},
+    gui::theme::FontId,
     prelude::*,
     renderer::{RendererSettings, Rendering},
 };
@@ -13,17 +13,21 @@ use once_cell::sync::Lazy;
 use sdl2::{
     audio::{AudioQueue, AudioSpecDesired},
     controller::GameController,
+    event::EventType,
     gfx::primitives::{DrawRenderer, ToColor},
     mouse::{Cursor, SystemCursor},
     pixels::{Color as SdlColor, PixelFormatEnum as SdlPixelFormat},
     rect::{Point as SdlPoint, Rect as SdlRect},
     render::{BlendMode as SdlBlendMode, Canvas, TextureQuery},
-    rwops::RWops,
     ttf::{Font as SdlFont, FontStyle as SdlFontStyle, Sdl2TtfContext},
     video::Window,
     EventPump, GameControllerSubsystem, Sdl,
 };
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    time::Duration,
+};
 use texture::RendererTexture;
 use window::{TextCacheKey, WindowCanvas};
 
@@ -34,6 +38,7 @@ pub use audio::{AudioDevice, AudioFormatNum};
 
 pub mod audio;
 mod event;
+mod font;
 mod texture;
 mod window;
 
@@ -48,15 +53,23 @@ pub(crate) struct Renderer {
     settings: RendererSettings,
     cursor: Option<Cursor>,
     blend_mode: SdlBlendMode,
+    stroke_weight: u8,
     current_font: FontId,
     font_size: u16,
     font_style: SdlFontStyle,
     primary_window_id: WindowId,
+    render_driver: RenderDriver,
     window_target: WindowId,
     texture_target: Option<TextureId>,
+    texture_target_stack: Vec<Option<TextureId>>,
     windows: HashMap<WindowId, WindowCanvas>,
+    /// Maps SDL window IDs of recreated windows to the `WindowId` they replaced.
+    window_ids: HashMap<u32, WindowId>,
+    /// Events generated by the renderer, returned before polling SDL.
+    pending_events: VecDeque<Event>,
     next_texture_id: usize,
     font_data: LruCache<FontId, Font>,
+    fallback_fonts: Vec<Font>,
     loaded_fonts: LruCache<(FontId, u16), SdlFont<'static, 'static>>,
 }
 
@@ -98,6 +111,7 @@ impl Renderer {
     fn load_font(&mut self) -> Result<bool> {
         let key = (self.current_font, self.font_size);
         if self.loaded_fonts.contains(&key) {
+            self.load_fallback_fonts();
             return Ok(false);
         }
 
@@ -105,18 +119,9 @@ impl Renderer {
             .font_data
             .get(&self.current_font)
             .ok_or_else(|| anyhow!("invalid current font"))?;
-        let loaded_font = match font_data.source() {
-            FontSrc::None => return Err(anyhow!("Must provide a font data source")),
-            FontSrc::Bytes(bytes) => {
-                let rwops = RWops::from_bytes(bytes).map_err(Error::Renderer)?;
-                TTF.load_font_from_rwops(rwops, self.font_size)
-                    .map_err(Error::Renderer)?
-            }
-            FontSrc::Path(ref path) => TTF
-                .load_font(path, self.font_size)
-                .map_err(Error::Renderer)?,
-        };
+        let loaded_font = font::open_font(font_data, self.font_size)?;
         self.loaded_fonts.put(key, loaded_font);
+        self.load_fallback_fonts();
         Ok(true)
     }
 
@@ -139,6 +144,55 @@ impl Renderer {
     }
 }
 
+/// Draw lines `width` pixels wide between consecutive points, joining them with filled circles.
+/// If `closed` is set, the last point is joined back to the first.
+fn thick_polyline(
+    canvas: &mut Canvas<Window>,
+    vx: &[i16],
+    vy: &[i16],
+    closed: bool,
+    width: u8,
+    color: Color,
+) -> Result<()> {
+    let len = vx.len().min(vy.len());
+    let segments = if closed { len } else { len.saturating_sub(1) };
+    for i in 0..segments {
+        let j = (i + 1) % len;
+        canvas
+            .thick_line(vx[i], vy[i], vx[j], vy[j], width, color)
+            .map_err(Error::Renderer)?;
+    }
+    if width > 2 {
+        let radius = i16::from(width / 2);
+        for (&x, &y) in vx.iter().zip(vy) {
+            canvas
+                .filled_circle(x, y, radius, color)
+                .map_err(Error::Renderer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Outline points of an ellipse centered at `(x, y)`, clockwise from `start` to `end` degrees.
+fn ellipse_points(x: i16, y: i16, rw: i16, rh: i16, start: i16, end: i16) -> (Vec<i16>, Vec<i16>) {
+    let start = f64::from(start);
+    let mut end = f64::from(end);
+    if end <= start {
+        end += 360.0;
+    }
+    // Roughly one segment every 4 pixels along the outline
+    let length = f64::from(rw.max(rh)) * (end - start).to_radians();
+    let segments = ((length / 4.0).ceil() as usize).max(8);
+    (0..=segments)
+        .map(|i| {
+            let angle = (start + (end - start) * i as f64 / segments as f64).to_radians();
+            let px = f64::from(rw).mul_add(angle.cos(), f64::from(x));
+            let py = f64::from(rh).mul_add(angle.sin(), f64::from(y));
+            (px.round() as i16, py.round() as i16)
+        })
+        .unzip()
+}
+
 impl Rendering for Renderer {
     /// Initializes the `Sdl2Renderer` using the given settings and opens a new window.
     #[inline]
@@ -146,10 +200,19 @@ impl Rendering for Renderer {
         debug!("Initializing SDLRenderer");
 
         let context = sdl2::init().map_err(Error::Renderer)?;
-        let event_pump = context.event_pump().map_err(Error::Renderer)?;
+        let mut event_pump = context.event_pump().map_err(Error::Renderer)?;
+        for event_type in [
+            EventType::DropBegin,
+            EventType::DropFile,
+            EventType::DropText,
+            EventType::DropComplete,
+        ] {
+            event_pump.enable_event(event_type);
+        }
 
         let title = s.title.clone();
         let primary_window = WindowCanvas::new(&context, &mut s)?;
+        let render_driver = window::render_driver_from_name(primary_window.canvas.info().name);
         let cursor_result = Cursor::from_system(SystemCursor::Arrow).map_err(Error::Renderer);
         let cursor = match cursor_result {
             Ok(c) => Some(c),
@@ -191,15 +254,21 @@ impl Rendering for Renderer {
             title,
             cursor,
             blend_mode: SdlBlendMode::None,
+            stroke_weight: 1,
             current_font,
             font_size: 14,
             font_style: SdlFontStyle::NORMAL,
             primary_window_id: window_target,
+            render_driver,
             window_target,
             texture_target: None,
+            texture_target_stack: Vec::new(),
             windows,
+            window_ids: HashMap::new(),
+            pending_events: VecDeque::new(),
             next_texture_id: 0,
             font_data,
+            fallback_fonts: Vec::new(),
             loaded_fonts: LruCache::new(texture_cache_size),
         };
         renderer.load_font()?;
@@ -207,6 +276,12 @@ impl Rendering for Renderer {
         Ok(renderer)
     }
 
+    /// Returns the graphics backend selected when the renderer was created.
+    #[inline]
+    fn render_driver(&self) -> RenderDriver {
+        self.render_driver
+    }
+
     /// Clears the canvas to the current clear color.
     #[inline]
     fn clear(&mut self) -> Result<()> {
@@ -240,6 +315,12 @@ impl Rendering for Renderer {
         self.blend_mode = mode.into();
     }
 
+    /// Sets the line width used by the renderer to draw shape outlines.
+    #[inline]
+    fn stroke_weight(&mut self, weight: u16) {
+        self.stroke_weight = weight.clamp(1, u16::from(u8::MAX)) as u8;
+    }
+
     /// Updates the canvas from the current back buffer.
     #[inline]
     fn present(&mut self) {
@@ -275,14 +356,46 @@ impl Rendering for Renderer {
         }
     }
 
+    /// Set the fonts used to draw glyphs missing from the current font.
+    #[inline]
+    fn font_fallbacks(&mut self, fonts: &[Font]) -> Result<()> {
+        self.fallback_fonts = fonts.to_vec();
+        for font in fonts {
+            let key = (font.id(), self.font_size);
+            if !self.loaded_fonts.contains(&key) {
+                let loaded_font = font::open_font(font, self.font_size)?;
+                self.loaded_fonts.put(key, loaded_font);
+            }
+        }
+        self.clear_text_cache();
+        Ok(())
+    }
+
+    /// Clear the cache of rendered text for every window.
+    #[inline]
+    fn clear_text_cache(&mut self) {
+        for window_canvas in self.windows.values_mut() {
+            window_canvas.text_cache.clear();
+        }
+    }
+
     /// Set the font family for drawing to the current canvas.
     #[inline]
     fn font_family(&mut self, font: &Font) -> Result<()> {
+        let previous_font = self.current_font;
         self.current_font = font.id();
-        if !self.font_data.contains(&self.current_font) {
+        let added = !self.font_data.contains(&self.current_font);
+        if added {
             self.font_data.put(self.current_font, font.clone());
         }
-        self.load_font()?;
+        if let Err(err) = self.load_font() {
+            // Keep the previous font and don't cache the font that failed to load
+            if added {
+                self.font_data.pop(&self.current_font);
+            }
+            self.current_font = previous_font;
+            return Err(err);
+        }
         Ok(())
     }
 
@@ -303,29 +416,35 @@ impl Rendering for Renderer {
             return self.size_of(text, wrap_width);
         }
         if let Some(fill) = fill {
+            let font_ids = self.font_chain_ids();
             let window = self
                 .windows
                 .get_mut(&self.window_target)
                 .ok_or(Error::InvalidWindow(self.window_target))?;
 
             let texture = {
-                // FIXME: Use default or return error
-                let font = self
-                    .loaded_fonts
-                    .get_mut(&(self.current_font, self.font_size))
-                    .ok_or_else(|| anyhow!("invalid current font"))?;
-                if font.get_outline_width() != outline {
-                    font.set_outline_width(outline);
+                for id in &font_ids {
+                    if let Some(font) = self.loaded_fonts.get_mut(&(*id, self.font_size)) {
+                        if font.get_outline_width() != outline {
+                            font.set_outline_width(outline);
+                        }
+                    }
                 }
+                let fonts = font::font_chain(&self.loaded_fonts, &font_ids, self.font_size)?;
+                let font = fonts[0];
 
                 let key = TextCacheKey::new(text, self.current_font, fill, self.font_size);
                 if !window.text_cache.contains(&key) {
-                    let surface = wrap_width
-                        .map_or_else(
-                            || font.render(text).blended(fill),
-                            |width| font.render(text).blended_wrapped(fill, width),
-                        )
-                        .context("invalid text")?;
+                    let surface = if font::needs_fallback(font, text) {
+                        font::render_fallback(&fonts, text, wrap_width, fill)?
+                    } else {
+                        wrap_width
+                            .map_or_else(
+                                || font.render(text).blended(fill),
+                                |width| font.render(text).blended_wrapped(fill, width),
+                            )
+                            .context("invalid text")?
+                    };
                     window.text_cache.put(
                         key,
                         RendererTexture::new(
@@ -417,6 +536,40 @@ impl Rendering for Renderer {
         sdl2::url::open_url(url).context("invalid url")
     }
 
+    /// Start accepting Unicode text input events, showing an on-screen keyboard or IME if
+    /// supported.
+    #[inline]
+    fn start_text_input(&self) {
+        if let Ok(video) = self.context.video() {
+            video.text_input().start();
+        }
+    }
+
+    /// Stop accepting Unicode text input events, hiding any on-screen keyboard or IME.
+    #[inline]
+    fn stop_text_input(&self) {
+        if let Ok(video) = self.context.video() {
+            video.text_input().stop();
+        }
+    }
+
+    /// Returns whether Unicode text input events are enabled.
+    #[inline]
+    fn text_input_active(&self) -> bool {
+        match self.context.video() {
+            Ok(video) => video.text_input().is_active(),
+            Err(_) => false,
+        }
+    }
+
+    /// Set the rectangle used to position an on-screen keyboard or IME candidate list.
+    #[inline]
+    fn set_text_input_rect(&self, rect: Rect<i32>) {
+        if let Ok(video) = self.context.video() {
+            video.text_input().set_rect(rect.into());
+        }
+    }
+
     /// Returns the rendered dimensions of the given text using the current font
     /// as `(width, height)`.
     #[inline]
@@ -425,7 +578,11 @@ impl Rendering for Renderer {
         if text.is_empty() {
             return Ok((0, font.height() as u32));
         }
-        let (width, mut height) = if let Some(width) = wrap_width {
+        let (width, mut height) = if font::needs_fallback(font, text) {
+            let fonts =
+                font::font_chain(&self.loaded_fonts, &self.font_chain_ids(), self.font_size)?;
+            font::size_of_fallback(&fonts, text, wrap_width)?
+        } else if let Some(width) = wrap_width {
             let (width, height) = font
                 .render(text)
                 .blended_wrapped(Color::BLACK, width)
@@ -450,6 +607,20 @@ impl Rendering for Renderer {
         })
     }
 
+    /// Draw a pixel to the current canvas, compositing `color` using the current blend mode.
+    #[inline]
+    fn pixel(&mut self, p: Point<i32>, color: Color) -> Result<()> {
+        let blend_mode = self.blend_mode;
+        self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
+            // SDL_gfx primitives set their own draw blend mode, so set it on every call
+            canvas.set_blend_mode(blend_mode);
+            canvas.set_draw_color(color);
+            Ok(canvas
+                .draw_point(SdlPoint::from((p.x(), p.y())))
+                .map_err(Error::Renderer)?)
+        })
+    }
+
     /// Draw a line to the current canvas.
     #[inline]
     fn line(&mut self, line: Line<i32>, smooth: bool, width: u8, color: Color) -> Result<()> {
@@ -505,6 +676,7 @@ impl Rendering for Renderer {
         fill: Option<Color>,
         stroke: Option<Color>,
     ) -> Result<()> {
+        let weight = self.stroke_weight;
         self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
             let [x1, y1] = tri.p1().map(|v| v as i16);
             let [x2, y2] = tri.p2().map(|v| v as i16);
@@ -515,12 +687,17 @@ impl Rendering for Renderer {
                     .map_err(Error::Renderer)?;
             }
             if let Some(stroke) = stroke {
-                if smooth {
-                    canvas.aa_trigon(x1, y1, x2, y2, x3, y3, stroke)
+                if weight > 1 {
+                    thick_polyline(canvas, &[x1, x2, x3], &[y1, y2, y3], true, weight, stroke)?;
+                } else if smooth {
+                    canvas
+                        .aa_trigon(x1, y1, x2, y2, x3, y3, stroke)
+                        .map_err(Error::Renderer)?;
                 } else {
-                    canvas.trigon(x1, y1, x2, y2, x3, y3, stroke)
+                    canvas
+                        .trigon(x1, y1, x2, y2, x3, y3, stroke)
+                        .map_err(Error::Renderer)?;
                 }
-                .map_err(Error::Renderer)?;
             }
             Ok(())
         })
@@ -535,6 +712,7 @@ impl Rendering for Renderer {
         fill: Option<Color>,
         stroke: Option<Color>,
     ) -> Result<()> {
+        let weight = self.stroke_weight;
         self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
             let [x, y, width, height] = rect.map(|v| v as i16);
             if let Some(fill) = fill {
@@ -549,16 +727,27 @@ impl Rendering for Renderer {
                     .map_err(Error::Renderer)?;
             }
             if let Some(stroke) = stroke {
-                radius
-                    .map_or_else(
-                        // EXPL: SDL2_gfx renders this 1px smaller than it should.
-                        || canvas.rectangle(x, y, x + width + 1, y + height + 1, stroke),
-                        |radius| {
-                            let radius = radius as i16;
-                            canvas.rounded_rectangle(x, y, x + width, y + height, radius, stroke)
-                        },
-                    )
-                    .map_err(Error::Renderer)?;
+                // Thicker strokes are drawn inside the rect bounds
+                for i in 0..i16::from(weight).min(width / 2 + 1).min(height / 2 + 1) {
+                    let [x, y, width, height] = [x + i, y + i, width - 2 * i, height - 2 * i];
+                    radius
+                        .map_or_else(
+                            // EXPL: SDL2_gfx renders this 1px smaller than it should.
+                            || canvas.rectangle(x, y, x + width + 1, y + height + 1, stroke),
+                            |radius| {
+                                let radius = (radius as i16 - i).max(0);
+                                canvas.rounded_rectangle(
+                                    x,
+                                    y,
+                                    x + width,
+                                    y + height,
+                                    radius,
+                                    stroke,
+                                )
+                            },
+                        )
+                        .map_err(Error::Renderer)?;
+                }
             }
             Ok(())
         })
@@ -573,6 +762,7 @@ impl Rendering for Renderer {
         fill: Option<Color>,
         stroke: Option<Color>,
     ) -> Result<()> {
+        let weight = self.stroke_weight;
         self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
             let [x1, y1] = quad.p1().map(|v| v as i16);
             let [x2, y2] = quad.p2().map(|v| v as i16);
@@ -586,12 +776,15 @@ impl Rendering for Renderer {
                     .map_err(Error::Renderer)?;
             }
             if let Some(stroke) = stroke {
-                if smooth {
-                    canvas.aa_polygon(&vx, &vy, stroke)
+                if weight > 1 {
+                    thick_polyline(canvas, &vx, &vy, true, weight, stroke)?;
+                } else if smooth {
+                    canvas
+                        .aa_polygon(&vx, &vy, stroke)
+                        .map_err(Error::Renderer)?;
                 } else {
-                    canvas.polygon(&vx, &vy, stroke)
+                    canvas.polygon(&vx, &vy, stroke).map_err(Error::Renderer)?;
                 }
-                .map_err(Error::Renderer)?;
             }
             Ok(())
         })
@@ -609,6 +802,7 @@ impl Rendering for Renderer {
     where
         I: Iterator<Item = Point<i32>>,
     {
+        let weight = self.stroke_weight;
         self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
             let (vx, vy): (Vec<i16>, Vec<i16>) = ps
                 .map(|p| -> (i16, i16) {
@@ -622,12 +816,15 @@ impl Rendering for Renderer {
                     .map_err(Error::Renderer)?;
             }
             if let Some(stroke) = stroke {
-                if smooth {
-                    canvas.aa_polygon(&vx, &vy, stroke)
+                if weight > 1 {
+                    thick_polyline(canvas, &vx, &vy, true, weight, stroke)?;
+                } else if smooth {
+                    canvas
+                        .aa_polygon(&vx, &vy, stroke)
+                        .map_err(Error::Renderer)?;
                 } else {
-                    canvas.polygon(&vx, &vy, stroke)
+                    canvas.polygon(&vx, &vy, stroke).map_err(Error::Renderer)?;
                 }
-                .map_err(Error::Renderer)?;
             }
             Ok(())
         })
@@ -642,6 +839,7 @@ impl Rendering for Renderer {
         fill: Option<Color>,
         stroke: Option<Color>,
     ) -> Result<()> {
+        let weight = self.stroke_weight;
         self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
             let [x, y, width, height] = ellipse.map(|v| v as i16);
             let rw = width / 2;
@@ -655,6 +853,10 @@ impl Rendering for Renderer {
                 .map_err(Error::Renderer)?;
             }
             if let Some(stroke) = stroke {
+                if weight > 1 {
+                    let (vx, vy) = ellipse_points(x, y, rw, rh, 0, 360);
+                    return thick_polyline(canvas, &vx, &vy, false, weight, stroke);
+                }
                 if width == height {
                     if smooth {
                         canvas.aa_circle(x, y, rw, stroke)
@@ -684,6 +886,7 @@ impl Rendering for Renderer {
         fill: Option<Color>,
         stroke: Option<Color>,
     ) -> Result<()> {
+        let weight = self.stroke_weight;
         self.update_canvas(|canvas: &mut Canvas<_>| -> Result<()> {
             let [x, y] = p.map(|v| v as i16);
             let radius = radius as i16;
@@ -692,9 +895,14 @@ impl Rendering for Renderer {
             match mode {
                 ArcMode::Default => {
                     if let Some(stroke) = stroke {
-                        canvas
-                            .arc(x, y, radius, start, end, stroke)
-                            .map_err(Error::Renderer)?;
+                        if weight > 1 {
+                            let (vx, vy) = ellipse_points(x, y, radius, radius, start, end);
+                            thick_polyline(canvas, &vx, &vy, false, weight, stroke)?;
+                        } else {
+                            canvas
+                                .arc(x, y, radius, start, end, stroke)
+                                .map_err(Error::Renderer)?;
+                        }
                     }
                 }
                 ArcMode::Pie => {
@@ -704,9 +912,16 @@ impl Rendering for Renderer {
                             .map_err(Error::Renderer)?;
                     }
                     if let Some(stroke) = stroke {
-                        canvas
-                            .pie(x, y, radius, start, end, stroke)
-                            .map_err(Error::Renderer)?;
+                        if weight > 1 {
+                            let (mut vx, mut vy) = ellipse_points(x, y, radius, radius, start, end);
+                            vx.push(x);
+                            vy.push(y);
+                            thick_polyline(canvas, &vx, &vy, true, weight, stroke)?;
+                        } else {
+                            canvas
+                                .pie(x, y, radius, start, end, stroke)
+                                .map_err(Error::Renderer)?;
+                        }
                     }
                 }
             }
@@ -766,7 +981,7 @@ impl Rendering for Renderer {
         let update = |canvas: &mut Canvas<_>| -> Result<()> {
             let src = src.map(Into::into);
             let dst = dst.map(Into::into);
-            if angle > 0.0 || center.is_some() || flipped.is_some() {
+            if angle != 0.0 || center.is_some() || flipped.is_some() {
                 let center = center.map(Into::into);
                 let horizontal = matches!(flipped, Some(Flipped::Horizontal | Flipped::Both));
                 let vertical = matches!(flipped, Some(Flipped::Vertical | Flipped::Both));
@@ -800,7 +1015,8 @@ impl Rendering for Renderer {
 
     /// Return the current rendered target pixels as an array of bytes.
     #[inline]
-    fn to_bytes(&mut self) -> Result<Vec<u8>> {
+    fn to_bytes(&mut self, src: Option<Rect<i32>>) -> Result<Vec<u8>> {
+        let src = src.map(Into::into);
         if let Some(texture_id) = self.texture_target {
             let window = self
                 .windows
@@ -816,7 +1032,7 @@ impl Rendering for Renderer {
                 window
                     .canvas
                     .with_texture_canvas(&mut texture.borrow_mut(), |canvas| {
-                        result = canvas.read_pixels(None, SdlPixelFormat::RGBA32);
+                        result = canvas.read_pixels(src, SdlPixelFormat::RGBA32);
                     })
                     .with_context(|| format!("failed to read texture target {texture_id}"))?;
                 Ok(result.map_err(Error::Renderer)?)
@@ -826,7 +1042,7 @@ impl Rendering for Renderer {
         } else {
             Ok(self
                 .canvas()?
-                .read_pixels(None, SdlPixelFormat::RGBA32)
+                .read_pixels(src, SdlPixelFormat::RGBA32)
                 .map_err(Error::Renderer)?)
         }
     }
@@ -847,6 +1063,22 @@ impl Rendering for Renderer {
     fn close_controller(&mut self, controller_id: ControllerId) {
         self.controllers.remove(&controller_id);
     }
+
+    /// Start a rumble effect on a controller with the given joystick index.
+    fn rumble_controller(
+        &mut self,
+        controller_id: ControllerId,
+        low: u16,
+        high: u16,
+        duration: Duration,
+    ) -> Result<()> {
+        let controller = self
+            .controllers
+            .get_mut(&controller_id)
+            .ok_or_else(|| anyhow!("controller {} is not connected", *controller_id))?;
+        let duration_ms = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+        Ok(controller.set_rumble(low, high, duration_ms)?)
+    }
 }
 
 impl fmt::Debug for Renderer {
@@ -863,6 +1095,7 @@ impl fmt::Debug for Renderer {
             )
             .field("title", &self.title)
             .field("settings", &self.settings)
+            .field("render_driver", &self.render_driver)
             .field("blend_mode", &self.blend_mode)
             .field(
                 "current_font",
@@ -2,11 +2,10 @@
 
 use super::Renderer;
 use crate::{
-    audio::{AudioDeviceDriver, AudioDriver},
+    audio::{queueable_samples, AudioDeviceDriver, AudioDriver, WARN_QUEUE_SIZE},
     error::{Error, Result},
     prelude::*,
 };
-use anyhow::anyhow;
 use log::warn;
 use sdl2::audio::{
     AudioCallback as SdlAudioCallback, AudioDevice as SdlAudioDevice,
@@ -17,11 +16,6 @@ use std::fmt;
 
 pub use sdl2::audio::AudioFormatNum;
 
-// ~1.5 minutes of audio @ 48,000 HZ.
-const WARN_QUEUE_SIZE: u32 = 1 << 22;
-// ~11.5  minutes of audio @ 48,000 HZ.
-const MAX_QUEUE_SIZE: u32 = 1 << 25;
-
 /// Audio callback or playback device that can be paused and resumed.
 pub struct AudioDevice<CB: AudioCallback>(SdlAudioDevice<UserCallback<CB>>);
 
@@ -99,21 +93,20 @@ impl<CB: AudioCallback> From<SdlAudioDevice<UserCallback<CB>>> for AudioDevice<C
 }
 
 impl AudioDriver for Renderer {
-    /// Add audio samples to the audio buffer queue.
+    /// Add audio samples to the audio buffer queue, returning the number of samples queued.
     #[inline]
-    fn enqueue_audio(&mut self, samples: &[f32]) -> Result<()> {
+    fn enqueue_audio(&mut self, samples: &[f32]) -> Result<usize> {
         let size = self.audio_device.size();
-        if size <= MAX_QUEUE_SIZE {
-            if size >= WARN_QUEUE_SIZE {
-                warn!("Audio queue size is increasing: {}. Did you forget to call `PixState::resume_audio`? Audio Device Status: {:?}", size, self.audio_device.status());
-            }
+        if size >= WARN_QUEUE_SIZE {
+            warn!("Audio queue size is increasing: {}. Did you forget to call `PixState::resume_audio`? Audio Device Status: {:?}", size, self.audio_device.status());
+        }
+        let len = queueable_samples(size, self.audio_device.spec().channels, samples.len());
+        if len > 0 {
             self.audio_device
-                .queue_audio(samples)
+                .queue_audio(&samples[..len])
                 .map_err(Error::Renderer)?;
-            Ok(())
-        } else {
-            Err(anyhow!("Reached max audio queue size: {}. Did you forget to call `PixState::resume_audio`? Audio Device Status: {:?}", MAX_QUEUE_SIZE, self.audio_device.status()))
         }
+        Ok(len)
     }
 
     /// Clear audio samples from the audio buffer queue.
@@ -138,6 +131,11 @@ impl AudioDriver for Renderer {
         self.audio_device.spec().freq
     }
 
+    /// Return the number of channels of the current audio queue device.
+    fn audio_channels(&self) -> u8 {
+        self.audio_device.spec().channels
+    }
+
     /// Returns the queued buffer size (in bytes) of the current audio queue device.
     fn audio_queued_size(&self) -> u32 {
         self.audio_device.size()
@@ -23,48 +23,89 @@ impl From<SdlEvent> for Event {
                 win_event: win_event.into(),
             },
             SdlEvent::KeyDown {
+                window_id,
                 keycode,
                 keymod,
                 repeat,
                 scancode,
                 ..
             } => Self::KeyDown {
+                window_id,
                 key: keycode.map(Into::into),
                 keymod: keymod.into(),
                 repeat,
                 scan: scancode.map(Into::into),
             },
             SdlEvent::KeyUp {
+                window_id,
                 keycode,
                 keymod,
                 repeat,
                 scancode,
                 ..
             } => Self::KeyUp {
+                window_id,
                 key: keycode.map(Into::into),
                 keymod: keymod.into(),
                 repeat,
                 scan: scancode.map(Into::into),
             },
-            SdlEvent::TextInput { text, .. } => Self::TextInput { text },
+            SdlEvent::TextInput {
+                window_id, text, ..
+            } => Self::TextInput { window_id, text },
+            SdlEvent::TextEditing {
+                window_id,
+                text,
+                start,
+                length,
+                ..
+            } => Self::TextEditing {
+                window_id,
+                text,
+                start,
+                length,
+            },
             SdlEvent::MouseMotion {
-                x, y, xrel, yrel, ..
-            } => Self::MouseMotion { x, y, xrel, yrel },
+                window_id,
+                x,
+                y,
+                xrel,
+                yrel,
+                ..
+            } => Self::MouseMotion {
+                window_id,
+                x,
+                y,
+                xrel,
+                yrel,
+            },
             SdlEvent::MouseButtonDown {
-                mouse_btn, x, y, ..
+                window_id,
+                mouse_btn,
+                x,
+                y,
+                ..
             } => Self::MouseDown {
+                window_id,
                 button: mouse_btn.into(),
                 x,
                 y,
             },
             SdlEvent::MouseButtonUp {
-                mouse_btn, x, y, ..
+                window_id,
+                mouse_btn,
+                x,
+                y,
+                ..
             } => Self::MouseUp {
+                window_id,
                 button: mouse_btn.into(),
                 x,
                 y,
             },
-            SdlEvent::MouseWheel { x, y, .. } => Self::MouseWheel { x, y },
+            SdlEvent::MouseWheel {
+                window_id, x, y, ..
+            } => Self::MouseWheel { window_id, x, y },
             SdlEvent::JoyAxisMotion {
                 which,
                 axis_idx,
@@ -201,6 +242,25 @@ impl From<SdlEvent> for Event {
                 device_id: which,
                 iscapture,
             },
+            SdlEvent::DropBegin { window_id, .. } => Self::DropBegin { window_id },
+            SdlEvent::DropFile {
+                window_id,
+                filename,
+                ..
+            } => Self::DropFile {
+                window_id,
+                path: filename.into(),
+            },
+            SdlEvent::DropText {
+                window_id,
+                filename,
+                ..
+            } => Self::DropText {
+                window_id,
+                text: filename,
+            },
+            SdlEvent::DropComplete { window_id, .. } => Self::DropComplete { window_id },
+            SdlEvent::ClipboardUpdate { .. } => Self::ClipboardUpdate,
             evt => {
                 warn!("Unhandled SDL `Event`: {:?}", evt);
                 Self::Unhandled
@@ -715,3 +775,42 @@ impl From<SdlHatState> for HatState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn drop_events() {
+        let event = SdlEvent::DropFile {
+            timestamp: 0,
+            window_id: 2,
+            filename: "/tmp/my images/ferris.png".to_string(),
+        };
+        assert_eq!(
+            Event::from(event),
+            Event::DropFile {
+                window_id: 2,
+                path: PathBuf::from("/tmp/my images/ferris.png"),
+            }
+        );
+
+        let event = SdlEvent::DropText {
+            timestamp: 0,
+            window_id: 2,
+            filename: "hello".to_string(),
+        };
+        assert_eq!(
+            Event::from(event),
+            Event::DropText {
+                window_id: 2,
+                text: "hello".to_string(),
+            }
+        );
+        assert_eq!(
+            Event::from(SdlEvent::ClipboardUpdate { timestamp: 0 }),
+            Event::ClipboardUpdate
+        );
+    }
+}
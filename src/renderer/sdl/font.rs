@@ -0,0 +1,251 @@
+//! SDL font loading and fallback text rendering.
+
+use super::{Renderer, TTF};
+use crate::{
+    error::{Error, Result},
+    gui::theme::{FontId, FontSrc},
+    ops::wrap_lines,
+    prelude::*,
+};
+use anyhow::{anyhow, Context};
+use log::warn;
+use lru::LruCache;
+use sdl2::{
+    pixels::PixelFormatEnum as SdlPixelFormat, rect::Rect as SdlRect,
+    render::BlendMode as SdlBlendMode, rwops::RWops, surface::Surface, ttf::Font as SdlFont,
+};
+
+/// Character substituted for glyphs missing from every font in the fallback chain.
+const REPLACEMENT_CHAR: char = char::REPLACEMENT_CHARACTER;
+
+/// A run of text drawn with a single font, indexed into the font fallback chain.
+type Run = (usize, String);
+
+impl Renderer {
+    /// Load fallback fonts at the current font size, skipping any that fail to load.
+    pub(super) fn load_fallback_fonts(&mut self) {
+        for font in &self.fallback_fonts {
+            let key = (font.id(), self.font_size);
+            if self.loaded_fonts.contains(&key) {
+                continue;
+            }
+            match open_font(font, self.font_size) {
+                Ok(loaded_font) => {
+                    self.loaded_fonts.put(key, loaded_font);
+                }
+                Err(err) => warn!("Failed to load fallback font `{}`: {}", font.name(), err),
+            }
+        }
+    }
+
+    /// Returns the IDs of the current font followed by each fallback font.
+    pub(super) fn font_chain_ids(&self) -> Vec<FontId> {
+        std::iter::once(self.current_font)
+            .chain(self.fallback_fonts.iter().map(Font::id))
+            .collect()
+    }
+}
+
+/// Open a font from its data source with a given point size.
+pub(super) fn open_font(font: &Font, size: u16) -> Result<SdlFont<'static, 'static>> {
+    let loaded_font = match font.source() {
+        FontSrc::None => return Err(anyhow!("Must provide a font data source")),
+        FontSrc::Bytes(bytes) => {
+            let rwops = RWops::from_bytes(bytes).map_err(Error::Renderer)?;
+            TTF.load_font_from_rwops(rwops, size)
+                .map_err(Error::Renderer)?
+        }
+        FontSrc::Path(ref path) => TTF.load_font(path, size).map_err(Error::Renderer)?,
+    };
+    Ok(loaded_font)
+}
+
+/// Returns the loaded fonts in the fallback chain for a given font size. The first font is the
+/// current font, and fallback fonts that failed to load are skipped.
+pub(super) fn font_chain<'a>(
+    loaded_fonts: &'a LruCache<(FontId, u16), SdlFont<'static, 'static>>,
+    ids: &[FontId],
+    size: u16,
+) -> Result<Vec<&'a SdlFont<'static, 'static>>> {
+    let mut fonts = Vec::with_capacity(ids.len());
+    for (i, &id) in ids.iter().enumerate() {
+        match loaded_fonts.peek(&(id, size)) {
+            Some(font) => fonts.push(font),
+            None if i == 0 => return Err(anyhow!("invalid current font")),
+            None => (),
+        }
+    }
+    Ok(fonts)
+}
+
+/// Returns whether text contains glyphs missing from the current font.
+pub(super) fn needs_fallback(font: &SdlFont<'_, '_>, text: &str) -> bool {
+    text.chars()
+        .any(|ch| !ch.is_control() && !has_glyph(&[font], 0, ch))
+}
+
+/// Returns whether the font at index `font` in the fallback chain has a glyph for `ch`.
+///
+/// `SDL_ttf` can only check glyphs in the Basic Multilingual Plane, so characters outside of it,
+/// such as most emoji, are assumed to only be provided by the first fallback font.
+fn has_glyph(fonts: &[&SdlFont<'_, '_>], font: usize, ch: char) -> bool {
+    if u16::try_from(u32::from(ch)).is_ok() {
+        fonts[font].find_glyph(ch).is_some()
+    } else {
+        font == 1
+    }
+}
+
+/// Returns the `(width, height)` of text drawn using the font fallback chain.
+pub(super) fn size_of_fallback(
+    fonts: &[&SdlFont<'_, '_>],
+    text: &str,
+    wrap_width: Option<u32>,
+) -> Result<(u32, u32)> {
+    let lines = layout_runs(fonts, text, wrap_width)?;
+    let mut size = (0, 0);
+    for runs in &lines {
+        let (width, _, height) = line_metrics(fonts, runs)?;
+        size = (size.0.max(width), size.1 + height);
+    }
+    Ok(size)
+}
+
+/// Render text to a surface using the font fallback chain, compositing runs drawn with different
+/// fonts along a shared baseline.
+pub(super) fn render_fallback(
+    fonts: &[&SdlFont<'_, '_>],
+    text: &str,
+    wrap_width: Option<u32>,
+    color: Color,
+) -> Result<Surface<'static>> {
+    let lines = layout_runs(fonts, text, wrap_width)?;
+    let mut metrics = Vec::with_capacity(lines.len());
+    for runs in &lines {
+        metrics.push(line_metrics(fonts, runs)?);
+    }
+    let width = metrics.iter().map(|&(width, ..)| width).max().unwrap_or(0);
+    let height = metrics.iter().map(|&(.., height)| height).sum::<u32>();
+
+    let mut surface = Surface::new(width.max(1), height.max(1), SdlPixelFormat::RGBA32)
+        .map_err(Error::Renderer)?;
+    let mut y = 0;
+    for (runs, (_, ascent, height)) in lines.iter().zip(metrics) {
+        let mut x = 0;
+        for (font, run) in runs {
+            let font = fonts[*font];
+            let mut run_surface = font.render(run).blended(color).context("invalid text")?;
+            // Runs never overlap, so copy alpha values directly instead of blending
+            run_surface
+                .set_blend_mode(SdlBlendMode::None)
+                .map_err(Error::Renderer)?;
+            let (run_width, run_height) = run_surface.size();
+            let dst = SdlRect::new(x, y + ascent - font.ascent(), run_width, run_height);
+            run_surface
+                .blit(None, &mut surface, dst)
+                .map_err(Error::Renderer)?;
+            x += run_width as i32;
+        }
+        y += height as i32;
+    }
+    Ok(surface)
+}
+
+/// Split text into lines of [Run]s, wrapping lines at word boundaries that would exceed
+/// `wrap_width`.
+fn layout_runs(
+    fonts: &[&SdlFont<'_, '_>],
+    text: &str,
+    wrap_width: Option<u32>,
+) -> Result<Vec<Vec<Run>>> {
+    let has_glyph = |font: usize, ch: char| has_glyph(fonts, font, ch);
+    let lines = wrap_lines(text, wrap_width, |line| {
+        let runs = split_runs(line, fonts.len(), has_glyph);
+        Ok(line_metrics(fonts, &runs)?.0)
+    })?;
+    Ok(lines
+        .into_iter()
+        .map(|line| split_runs(line, fonts.len(), has_glyph))
+        .collect())
+}
+
+/// Returns the `(width, ascent, height)` of a line of [Run]s.
+fn line_metrics(fonts: &[&SdlFont<'_, '_>], runs: &[Run]) -> Result<(u32, i32, u32)> {
+    let mut width = 0;
+    let mut ascent = fonts[0].ascent();
+    let mut descent = fonts[0].height() - ascent;
+    for (font, run) in runs {
+        let font = fonts[*font];
+        width += font.size_of(run).context("invalid text")?.0;
+        ascent = ascent.max(font.ascent());
+        descent = descent.max(font.height() - font.ascent());
+    }
+    Ok((width, ascent, (ascent + descent) as u32))
+}
+
+/// Split a line of text into [Run]s using the first font in a chain of `fonts` that has a glyph
+/// for each character. Characters missing from every font are replaced with
+/// [`char::REPLACEMENT_CHARACTER`], or `?` if no font has a replacement glyph.
+fn split_runs<F>(line: &str, fonts: usize, has_glyph: F) -> Vec<Run>
+where
+    F: Fn(usize, char) -> bool,
+{
+    let find_font = |ch: char| (0..fonts).find(|&font| has_glyph(font, ch));
+    let replacement = find_font(REPLACEMENT_CHAR).map_or((0, '?'), |font| (font, REPLACEMENT_CHAR));
+    let mut runs: Vec<Run> = Vec::new();
+    for ch in line.chars() {
+        let (font, ch) = if ch.is_control() {
+            (0, ch)
+        } else {
+            find_font(ch).map_or(replacement, |font| (font, ch))
+        };
+        match runs.last_mut() {
+            Some((last, run)) if *last == font => run.push(ch),
+            _ => runs.push((font, ch.to_string())),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_runs_fallback() {
+        // Primary font only has ASCII glyphs, the fallback has everything
+        let has_glyph = |font: usize, ch: char| font == 1 || ch.is_ascii();
+        assert_eq!(
+            split_runs("Hi ☃!", 2, has_glyph),
+            vec![
+                (0, "Hi ".to_string()),
+                (1, "☃".to_string()),
+                (0, "!".to_string())
+            ]
+        );
+        assert_eq!(split_runs("", 2, has_glyph), vec![]);
+    }
+
+    #[test]
+    fn split_runs_replacement() {
+        let has_glyph = |font: usize, ch: char| match font {
+            0 => ch.is_ascii(),
+            _ => ch == REPLACEMENT_CHAR,
+        };
+        assert_eq!(
+            split_runs("a😀b", 2, has_glyph),
+            vec![
+                (0, "a".to_string()),
+                (1, REPLACEMENT_CHAR.to_string()),
+                (0, "b".to_string())
+            ]
+        );
+
+        // Without a replacement glyph, missing characters are replaced with `?` in the primary font
+        let has_glyph = |_, ch: char| ch.is_ascii();
+        assert_eq!(
+            split_runs("a😀😀b", 1, has_glyph),
+            vec![(0, "a??b".to_string())]
+        );
+    }
+}
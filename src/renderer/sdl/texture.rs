@@ -154,7 +154,7 @@ impl TextureRenderer for Renderer {
             let src = src.map(Into::into);
             let dst = dst.map(Into::into);
             let update = |canvas: &mut Canvas<_>| -> Result<()> {
-                let result = if angle > 0.0 || center.is_some() || flipped.is_some() {
+                let result = if angle != 0.0 || center.is_some() || flipped.is_some() {
                     canvas.copy_ex(
                         &texture.borrow(),
                         src,
@@ -218,6 +218,32 @@ impl TextureRenderer for Renderer {
         self.texture_target = None;
     }
 
+    /// Push the current target to a stack and set a `Texture` as the target for drawing
+    /// operations.
+    ///
+    /// # Errors
+    ///
+    /// If the texture has been dropped or is invalid, then an error is returned.
+    #[inline]
+    fn push_texture_target(&mut self, id: TextureId) -> Result<()> {
+        let target = self.texture_target;
+        self.set_texture_target(id)?;
+        self.texture_target_stack.push(target);
+        Ok(())
+    }
+
+    /// Restore the previously pushed target for drawing operations. Returns `false` if the stack
+    /// is empty.
+    #[inline]
+    fn pop_texture_target(&mut self) -> bool {
+        if let Some(target) = self.texture_target_stack.pop() {
+            self.texture_target = target;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Returns whether a texture is set as the target for drawing operations.
     #[inline]
     fn has_texture_target(&self) -> bool {
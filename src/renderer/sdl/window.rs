@@ -4,23 +4,32 @@ use crate::{
     image::Icon,
     prelude::*,
     renderer::{RendererSettings, WindowRenderer},
-    window::{Position, WindowId},
+    window::{constrain_dimensions, HitTestCallback, Position, WindowId},
 };
 use anyhow::Context;
+use log::{error, warn};
 use lru::LruCache;
 use sdl2::{
     image::LoadSurface,
     mouse::{Cursor as SdlCursor, SystemCursor as SdlSystemCursor},
     render::{Canvas, TextureQuery},
     surface::Surface,
-    video::{FullscreenType, Window},
+    sys::{
+        SDL_HitTest, SDL_HitTestResult, SDL_Point, SDL_Window, SDL_WindowFlags,
+        SDL_WINDOWPOS_CENTERED_MASK,
+    },
+    video::{FullscreenType, Window, WindowPos},
     Sdl,
 };
 use std::{
     cell::RefCell,
     collections::{hash_map::DefaultHasher, HashMap},
+    ffi::c_void,
     fmt::{self, Write},
     hash::{Hash, Hasher},
+    mem,
+    panic::{self, AssertUnwindSafe},
+    ptr,
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -95,6 +104,9 @@ pub(super) struct WindowCanvas {
     pub(super) textures: HashMap<TextureId, RefCell<RendererTexture>>,
     pub(super) text_cache: LruCache<TextCacheKey, RendererTexture>,
     pub(super) image_cache: LruCache<*const Image, RendererTexture>,
+    /// Hit test callback passed to SDL. Declared after `canvas` so the window is destroyed before
+    /// the callback is dropped.
+    pub(super) hit_test: Option<Box<HitTestCallback>>,
 }
 
 impl WindowCanvas {
@@ -103,28 +115,48 @@ impl WindowCanvas {
 
         // TODO: more testing - macOS performance seems low with default "metal" renderer
         // However: https://github.com/libsdl-org/SDL/issues/4001
-        #[cfg(feature = "opengl")]
-        {
-            sdl2::hint::set_with_priority(
-                "SDL_RENDER_DRIVER",
-                "opengl",
-                &sdl2::hint::Hint::Override,
-            );
+        let render_driver = s
+            .render_driver
+            .or_else(|| cfg!(feature = "opengl").then_some(RenderDriver::OpenGL));
+        if let Some(driver) = render_driver {
+            match render_driver_hint(driver) {
+                Some(hint) => {
+                    sdl2::hint::set_with_priority(
+                        "SDL_RENDER_DRIVER",
+                        hint,
+                        &sdl2::hint::Hint::Override,
+                    );
+                }
+                None => warn!("Render driver {:?} is not supported", driver),
+            }
         }
 
         // Set up window with options
         let mut window_builder = video_subsys.window(&s.title, s.width, s.height);
-        #[cfg(feature = "opengl")]
-        {
+        if matches!(
+            render_driver,
+            Some(RenderDriver::OpenGL | RenderDriver::OpenGLES)
+        ) {
             window_builder.opengl();
         }
-        match (s.x, s.y) {
-            (Position::Centered, Position::Centered) => {
+        let display = s.display.map(display_index).transpose()?;
+        match (s.x, s.y, display) {
+            (Position::Centered, Position::Centered, None) => {
                 window_builder.position_centered();
             }
-            (Position::Positioned(x), Position::Positioned(y)) => {
+            (Position::Centered, Position::Centered, Some(display)) => {
+                let centered = (SDL_WINDOWPOS_CENTERED_MASK as i32) | display;
+                window_builder.position(centered, centered);
+            }
+            (Position::Positioned(x), Position::Positioned(y), None) => {
                 window_builder.position(x, y);
             }
+            (Position::Positioned(x), Position::Positioned(y), Some(display)) => {
+                let bounds = video_subsys
+                    .display_bounds(display)
+                    .map_err(Error::Renderer)?;
+                window_builder.position(bounds.x() + x, bounds.y() + y);
+            }
             _ => unreachable!("invalid window position combination"),
         };
         if s.fullscreen {
@@ -142,8 +174,33 @@ impl WindowCanvas {
         if s.hidden {
             window_builder.hidden();
         }
+        if s.always_on_top {
+            let flags = window_builder.window_flags();
+            window_builder
+                .set_window_flags(flags | SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32);
+        }
 
-        let window = window_builder.build().context("failed to build window")?;
+        let mut window = window_builder.build().context("failed to build window")?;
+        if let Some((width, height)) = s.min_size {
+            window
+                .set_minimum_size(width, height)
+                .context("invalid minimum window size")?;
+        }
+        if let Some((width, height)) = s.max_size {
+            window
+                .set_maximum_size(width, height)
+                .context("invalid maximum window size")?;
+        }
+        if s.opacity < 1.0 {
+            if let Err(err) = window.set_opacity(s.opacity) {
+                warn!("Window opacity is not supported: {}", err);
+            }
+        }
+        if s.always_on_top
+            && window.window_flags() & SDL_WindowFlags::SDL_WINDOW_ALWAYS_ON_TOP as u32 == 0
+        {
+            warn!("Always on top windows are not supported");
+        }
 
         let window_id = WindowId(window.id());
         let mut canvas_builder = window.into_canvas().accelerated().target_texture();
@@ -182,8 +239,105 @@ impl WindowCanvas {
             textures: HashMap::new(),
             text_cache: LruCache::new(s.text_cache_size),
             image_cache: LruCache::new(s.texture_cache_size),
+            hit_test: None,
         })
     }
+
+    /// Set or clear the hit test callback for this window, returning the previous callback.
+    pub(super) fn set_hit_test(
+        &mut self,
+        callback: Option<HitTestCallback>,
+    ) -> Result<Option<Box<HitTestCallback>>> {
+        let callback = callback.map(Box::new);
+        let (hit_test, data): (SDL_HitTest, *mut c_void) = match &callback {
+            Some(callback) => (
+                Some(hit_test_callback),
+                ptr::addr_of!(**callback) as *mut c_void,
+            ),
+            None => (None, ptr::null_mut()),
+        };
+        // SAFETY: `data` points to the boxed callback, which is stored below and only dropped
+        // after SDL stops referencing it, either once replaced here or the window is destroyed.
+        let result =
+            unsafe { sdl2::sys::SDL_SetWindowHitTest(self.canvas.window().raw(), hit_test, data) };
+        if result != 0 {
+            return Err(Error::Renderer(sdl2::get_error()).into());
+        }
+        Ok(mem::replace(&mut self.hit_test, callback))
+    }
+}
+
+/// Bridge SDL hit testing to a [`HitTestCallback`], treating panics as normal regions so they
+/// don't unwind across the C boundary.
+unsafe extern "C" fn hit_test_callback(
+    window: *mut SDL_Window,
+    area: *const SDL_Point,
+    data: *mut c_void,
+) -> SDL_HitTestResult {
+    let (mut width, mut height) = (0, 0);
+    // SAFETY: SDL passes the window being tested, a valid point and the `data` pointer set in
+    // `WindowCanvas::set_hit_test`, which points to a live `HitTestCallback`.
+    let (area, callback) = unsafe {
+        sdl2::sys::SDL_GetWindowSize(window, &mut width, &mut height);
+        (&*area, &*(data as *const HitTestCallback))
+    };
+    let pos = point![area.x, area.y];
+    let size = (width.max(0) as u32, height.max(0) as u32);
+    match panic::catch_unwind(AssertUnwindSafe(|| callback(pos, size))) {
+        Ok(result) => result.into(),
+        Err(_) => {
+            error!("Window hit test callback panicked");
+            SDL_HitTestResult::SDL_HITTEST_NORMAL
+        }
+    }
+}
+
+/// Returns the `SDL_RENDER_DRIVER` hint for a [`RenderDriver`], if SDL supports it.
+const fn render_driver_hint(driver: RenderDriver) -> Option<&'static str> {
+    match driver {
+        RenderDriver::OpenGL => Some("opengl"),
+        RenderDriver::OpenGLES => Some("opengles2"),
+        RenderDriver::Direct3D => Some("direct3d"),
+        RenderDriver::Metal => Some("metal"),
+        RenderDriver::Software => Some("software"),
+        RenderDriver::Vulkan | RenderDriver::Canvas | RenderDriver::Unknown => None,
+    }
+}
+
+/// Returns the [`RenderDriver`] for an SDL renderer name.
+pub(super) fn render_driver_from_name(name: &str) -> RenderDriver {
+    match name {
+        "opengl" => RenderDriver::OpenGL,
+        "opengles" | "opengles2" => RenderDriver::OpenGLES,
+        "direct3d" | "direct3d11" | "direct3d12" => RenderDriver::Direct3D,
+        "metal" => RenderDriver::Metal,
+        "vulkan" => RenderDriver::Vulkan,
+        "software" => RenderDriver::Software,
+        _ => RenderDriver::Unknown,
+    }
+}
+
+/// Convert a display index into the signed index SDL expects.
+fn display_index(index: u32) -> Result<i32> {
+    i32::try_from(index).map_err(|_| Error::InvalidDisplay(index).into())
+}
+
+#[doc(hidden)]
+impl From<HitTestResult> for SDL_HitTestResult {
+    fn from(result: HitTestResult) -> Self {
+        match result {
+            HitTestResult::Normal => Self::SDL_HITTEST_NORMAL,
+            HitTestResult::Draggable => Self::SDL_HITTEST_DRAGGABLE,
+            HitTestResult::ResizeTopLeft => Self::SDL_HITTEST_RESIZE_TOPLEFT,
+            HitTestResult::ResizeTop => Self::SDL_HITTEST_RESIZE_TOP,
+            HitTestResult::ResizeTopRight => Self::SDL_HITTEST_RESIZE_TOPRIGHT,
+            HitTestResult::ResizeRight => Self::SDL_HITTEST_RESIZE_RIGHT,
+            HitTestResult::ResizeBottomRight => Self::SDL_HITTEST_RESIZE_BOTTOMRIGHT,
+            HitTestResult::ResizeBottom => Self::SDL_HITTEST_RESIZE_BOTTOM,
+            HitTestResult::ResizeBottomLeft => Self::SDL_HITTEST_RESIZE_BOTTOMLEFT,
+            HitTestResult::ResizeLeft => Self::SDL_HITTEST_RESIZE_LEFT,
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -235,6 +389,7 @@ impl WindowRenderer for Renderer {
         if self.windows.remove(&id).is_none() {
             return Err(Error::InvalidWindow(id).into());
         }
+        self.window_ids.retain(|_, window_id| *window_id != id);
         if id == self.window_target {
             self.reset_window_target();
         }
@@ -253,6 +408,19 @@ impl WindowRenderer for Renderer {
                         let surface = Surface::from_file(path).map_err(Error::Renderer)?;
                         Some(SdlCursor::from_surface(surface, *x, *y).map_err(Error::Renderer)?)
                     }
+                    Cursor::Pixels(img, (x, y)) => {
+                        // SDL copies the surface pixels when creating the cursor
+                        let mut bytes = img.as_bytes().to_vec();
+                        let surface = Surface::from_data(
+                            &mut bytes,
+                            img.width(),
+                            img.height(),
+                            img.pitch() as u32,
+                            img.format().into(),
+                        )
+                        .map_err(Error::Renderer)?;
+                        Some(SdlCursor::from_surface(surface, *x, *y).map_err(Error::Renderer)?)
+                    }
                 };
                 if let Some(cursor) = &self.cursor {
                     cursor.set();
@@ -266,10 +434,41 @@ impl WindowRenderer for Renderer {
         Ok(())
     }
 
+    /// Returns whether the mouse cursor is confined to the current window target.
+    #[inline]
+    fn cursor_confined(&self) -> bool {
+        matches!(self.window(), Ok(window) if window.grab())
+    }
+
+    /// Confine the mouse cursor to the current window target or release it.
+    #[inline]
+    fn confine_cursor(&mut self, confine: bool) -> Result<()> {
+        self.window_mut()?.set_grab(confine);
+        Ok(())
+    }
+
+    /// Move the mouse cursor to a position within the current window target.
+    #[inline]
+    fn set_mouse_pos(&mut self, pos: Point<i32>) -> Result<()> {
+        self.context
+            .mouse()
+            .warp_mouse_in_window(self.window()?, pos.x(), pos.y());
+        Ok(())
+    }
+
     /// Returns a single event or None if the event pump is empty.
     #[inline]
     fn poll_event(&mut self) -> Option<Event> {
-        self.event_pump.poll_event().map(Into::into)
+        if let Some(event) = self.pending_events.pop_front() {
+            return Some(event);
+        }
+        let mut event: Event = self.event_pump.poll_event()?.into();
+        if let Some(window_id) = event.window_id_mut() {
+            if let Some(id) = self.window_ids.get(window_id) {
+                *window_id = id.0;
+            }
+        }
+        Some(event)
     }
 
     /// Get the current window title.
@@ -332,9 +531,33 @@ impl WindowRenderer for Renderer {
         Ok(self.window()?.position())
     }
 
-    /// Set dimensions of the current window target as `(width, height)`.
+    /// Set position of the current window target as `(x, y)`.
+    #[inline]
+    fn set_window_position(&mut self, (x, y): (i32, i32)) -> Result<()> {
+        self.window_mut()?
+            .set_position(WindowPos::Positioned(x), WindowPos::Positioned(y));
+        Ok(())
+    }
+
+    /// Set or clear the hit test callback of the current window target.
     #[inline]
-    fn set_window_dimensions(&mut self, (width, height): (u32, u32)) -> Result<()> {
+    fn set_window_hit_test(&mut self, callback: Option<HitTestCallback>) -> Result<()> {
+        self.window_canvas_mut()?.set_hit_test(callback)?;
+        Ok(())
+    }
+
+    /// Set dimensions of the current window target as `(width, height)`, constrained by its
+    /// minimum and maximum dimensions.
+    #[inline]
+    fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> Result<(u32, u32)> {
+        let window = self.window()?;
+        // SDL reports `(0, 0)` when no minimum or maximum dimensions are set
+        let limit = |size: (u32, u32)| (size != (0, 0)).then_some(size);
+        let (width, height) = constrain_dimensions(
+            dimensions,
+            limit(window.minimum_size()),
+            limit(window.maximum_size()),
+        );
         self.settings.width = width;
         self.settings.height = height;
         let canvas = self.canvas_mut()?;
@@ -345,6 +568,67 @@ impl WindowRenderer for Renderer {
         canvas
             .set_logical_size(width, height)
             .context("invalid logical window size")?;
+        Ok((width, height))
+    }
+
+    /// Set minimum dimensions of the current window target as `(width, height)`.
+    #[inline]
+    fn set_window_min_size(&mut self, (width, height): (u32, u32)) -> Result<()> {
+        self.window_mut()?
+            .set_minimum_size(width, height)
+            .context("invalid minimum window size")?;
+        Ok(())
+    }
+
+    /// Set maximum dimensions of the current window target as `(width, height)`.
+    #[inline]
+    fn set_window_max_size(&mut self, (width, height): (u32, u32)) -> Result<()> {
+        self.window_mut()?
+            .set_maximum_size(width, height)
+            .context("invalid maximum window size")?;
+        Ok(())
+    }
+
+    /// Opacity of the current window target.
+    #[inline]
+    fn window_opacity(&self) -> Result<f32> {
+        Ok(self.window()?.opacity().map_err(Error::Renderer)?)
+    }
+
+    /// Set the opacity of the current window target, logging a warning if unsupported.
+    #[inline]
+    fn set_window_opacity(&mut self, opacity: f32) -> Result<()> {
+        if let Err(err) = self.window_mut()?.set_opacity(opacity) {
+            warn!("Window opacity is not supported: {}", err);
+        }
+        Ok(())
+    }
+
+    /// Raise the current window target above other windows and focus it.
+    #[inline]
+    fn raise_window(&mut self) -> Result<()> {
+        self.window_mut()?.raise();
+        Ok(())
+    }
+
+    /// Minimize the current window target.
+    #[inline]
+    fn minimize_window(&mut self) -> Result<()> {
+        self.window_mut()?.minimize();
+        Ok(())
+    }
+
+    /// Maximize the current window target.
+    #[inline]
+    fn maximize_window(&mut self) -> Result<()> {
+        self.window_mut()?.maximize();
+        Ok(())
+    }
+
+    /// Restore the size and position of a minimized or maximized current window target.
+    #[inline]
+    fn restore_window(&mut self) -> Result<()> {
+        self.window_mut()?.restore();
         Ok(())
     }
 
@@ -373,6 +657,57 @@ impl WindowRenderer for Renderer {
         Ok((bounds.width(), bounds.height()))
     }
 
+    /// Number of connected displays.
+    #[inline]
+    fn display_count(&self) -> Result<u32> {
+        let video_subsys = self.context.video().map_err(Error::Renderer)?;
+        let count = video_subsys.num_video_displays().map_err(Error::Renderer)?;
+        Ok(u32::try_from(count).unwrap_or(0))
+    }
+
+    /// Information about the connected display at `index`.
+    fn display_info(&self, index: u32) -> Result<DisplayInfo> {
+        if index >= self.display_count()? {
+            return Err(Error::InvalidDisplay(index).into());
+        }
+        let display = display_index(index)?;
+        let video_subsys = self.context.video().map_err(Error::Renderer)?;
+        let name = video_subsys
+            .display_name(display)
+            .map_err(Error::Renderer)?;
+        let bounds = video_subsys
+            .display_bounds(display)
+            .map_err(Error::Renderer)?;
+        let mut modes: Vec<DisplayMode> = vec![];
+        let mode_count = video_subsys
+            .num_display_modes(display)
+            .map_err(Error::Renderer)?;
+        for mode_index in 0..mode_count {
+            let mode = video_subsys
+                .display_mode(display, mode_index)
+                .map_err(Error::Renderer)?;
+            // Modes are listed once per pixel format
+            let mode = DisplayMode::new(
+                u32::try_from(mode.w).unwrap_or(0),
+                u32::try_from(mode.h).unwrap_or(0),
+                u32::try_from(mode.refresh_rate).unwrap_or(0),
+            );
+            if !modes.contains(&mode) {
+                modes.push(mode);
+            }
+        }
+        Ok(DisplayInfo {
+            name,
+            bounds: rect![
+                bounds.x(),
+                bounds.y(),
+                bounds.width() as i32,
+                bounds.height() as i32
+            ],
+            modes,
+        })
+    }
+
     /// Returns whether the application is fullscreen or not.
     #[inline]
     fn fullscreen(&self) -> Result<bool> {
@@ -401,11 +736,9 @@ impl WindowRenderer for Renderer {
     ///
     /// # Note
     ///
-    /// Due to the current limitation with changing `VSync` at runtime, this method creates a new
-    /// window using the properties of the current window and returns the new `WindowId`.
-    ///
-    /// If you are storing and interacting with this window using the `WindowId`, make sure to
-    /// use the newly returned `WindowId`.
+    /// Due to the current limitation with changing `VSync` at runtime, this method recreates the
+    /// window using the properties of the current window. The `WindowId` and texture contents are
+    /// preserved, and a [`WindowEvent::Recreated`] event is sent once the window is recreated.
     fn set_vsync(&mut self, val: bool) -> Result<WindowId> {
         log::debug!("Set VSync: {}", val);
         let window_canvas = self
@@ -426,29 +759,58 @@ impl WindowRenderer for Renderer {
         );
 
         let mut new_window = WindowCanvas::new(&self.context, &mut self.settings)?;
+        new_window.text_cache.resize(window_canvas.text_cache.cap());
+        new_window
+            .image_cache
+            .resize(window_canvas.image_cache.cap());
 
-        let previous_window_id = self.window_target;
         for (texture_id, texture) in &window_canvas.textures {
+            let mut texture = texture.borrow_mut();
             let TextureQuery {
                 width,
                 height,
                 format,
                 ..
-            } = texture.borrow().query();
-            new_window.textures.insert(
-                *texture_id,
-                RefCell::new(RendererTexture::new(
-                    new_window
-                        .canvas
-                        .create_texture_target(format, width, height)?,
-                )),
-            );
+            } = texture.query();
+            let mut pixels = Ok(Vec::new());
+            window_canvas
+                .canvas
+                .with_texture_canvas(&mut texture, |canvas| {
+                    pixels = canvas.read_pixels(None, format);
+                })
+                .with_context(|| format!("failed to read texture {texture_id}"))?;
+            let pixels = pixels.map_err(Error::Renderer)?;
+
+            let mut new_texture = new_window
+                .canvas
+                .create_texture_target(format, width, height)?;
+            new_texture
+                .update(None, &pixels, width as usize * format.byte_size_per_pixel())
+                .with_context(|| format!("failed to restore texture {texture_id}"))?;
+            new_texture.set_blend_mode(texture.blend_mode());
+            let (r, g, b) = texture.color_mod();
+            new_texture.set_color_mod(r, g, b);
+            new_texture.set_alpha_mod(texture.alpha_mod());
+            new_window
+                .textures
+                .insert(*texture_id, RefCell::new(RendererTexture::new(new_texture)));
         }
 
-        self.windows.remove(&previous_window_id);
-        self.window_target = new_window.id;
-        self.windows.insert(new_window.id, new_window);
-        Ok(self.window_target)
+        if let Some(callback) = window_canvas.set_hit_test(None)? {
+            new_window.set_hit_test(Some(*callback))?;
+        }
+
+        // SDL assigns the recreated window a new ID, so keep the previous one and map events
+        // from the new window back to it.
+        let window_id = self.window_target;
+        self.window_ids.insert(new_window.id.0, window_id);
+        new_window.id = window_id;
+        self.windows.insert(window_id, new_window);
+        self.pending_events.push_back(Event::Window {
+            window_id: window_id.0,
+            win_event: WindowEvent::Recreated,
+        });
+        Ok(window_id)
     }
 
     /// Set window as the target for drawing operations.
@@ -1,208 +1,997 @@
-//! WASM Renderer
-
-use crate::renderer::{RendererSettings, Rendering};
+//! Web renderer drawing to an HTML5 `<canvas>` element.
+//!
+//! Functionality that browsers don't provide, such as game controllers, multiple windows, or
+//! moving the mouse cursor, returns an [`Error::Renderer`] describing what is unsupported.
+
+use crate::{
+    error::{Error, Result},
+    prelude::*,
+    renderer::{RendererSettings, Rendering},
+};
+use anyhow::anyhow;
+use log::{error, warn};
+use std::{
+    cell::{Cell, RefCell},
+    f64::consts::TAU,
+    mem,
+    rc::Rc,
+    time::Duration,
+};
+use wasm_bindgen::{closure::Closure, Clamped, JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, Document, HtmlCanvasElement, ImageData};
 
 mod audio;
+mod event;
 mod texture;
 mod window;
 
 pub use audio::{AudioDevice, AudioFormatNum};
 
+/// Id of the `<canvas>` element to render to. If no element with this id exists, a canvas is
+/// created and appended to the document body.
+pub const CANVAS_ID: &str = "pix-engine";
+
+/// Line height as a multiple of the font size.
+const LINE_HEIGHT: f64 = 1.2;
+
+/// Identifier of the canvas window.
+const WINDOW_ID: WindowId = WindowId(1);
+
+/// A canvas and its 2D rendering context, used for both the window and textures.
+#[derive(Debug, Clone)]
+pub(crate) struct Surface {
+    canvas: HtmlCanvasElement,
+    context: CanvasRenderingContext2d,
+}
+
+impl Surface {
+    /// Wrap an existing canvas element.
+    fn new(canvas: HtmlCanvasElement) -> Result<Self> {
+        let context = canvas
+            .get_context("2d")
+            .map_err(js_err)?
+            .ok_or_else(|| anyhow!(Error::Renderer("canvas 2d context is unsupported".into())))?
+            .dyn_into::<CanvasRenderingContext2d>()
+            .map_err(|_| anyhow!(Error::Renderer("invalid canvas 2d context".into())))?;
+        // Restoring this state resets the transform and clip rect, see `Renderer::reset_context`
+        context.save();
+        Ok(Self { canvas, context })
+    }
+
+    /// Create a new offscreen canvas with the given dimensions.
+    fn create(width: u32, height: u32) -> Result<Self> {
+        let canvas = document()?
+            .create_element("canvas")
+            .map_err(js_err)?
+            .dyn_into::<HtmlCanvasElement>()
+            .map_err(|_| anyhow!(Error::Renderer("invalid canvas element".into())))?;
+        canvas.set_width(width);
+        canvas.set_height(height);
+        Self::new(canvas)
+    }
+
+    /// Create a new offscreen canvas containing the pixels of an [Image].
+    fn from_image(img: &Image) -> Result<Self> {
+        let surface = Self::create(img.width(), img.height())?;
+        surface.put_pixels(
+            img.as_bytes(),
+            img.format(),
+            0,
+            0,
+            img.width(),
+            img.height(),
+        )?;
+        Ok(surface)
+    }
+
+    /// Dimensions of the canvas as `(width, height)`.
+    fn dimensions(&self) -> (u32, u32) {
+        (self.canvas.width(), self.canvas.height())
+    }
+
+    /// Bounds of the canvas.
+    fn bounds(&self) -> Rect<i32> {
+        let (width, height) = self.dimensions();
+        rect![0, 0, width as i32, height as i32]
+    }
+
+    /// Replace a region of the canvas with `pixels`.
+    fn put_pixels(
+        &self,
+        pixels: &[u8],
+        format: PixelFormat,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) -> Result<()> {
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        // `ImageData` is always RGBA
+        let rgba = match format {
+            PixelFormat::Rgba => pixels.to_vec(),
+            PixelFormat::Rgb => pixels
+                .chunks_exact(3)
+                .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+                .collect(),
+        };
+        let data = ImageData::new_with_u8_clamped_array_and_sh(Clamped(&rgba), width, height)
+            .map_err(js_err)?;
+        self.context
+            .put_image_data(&data, f64::from(x), f64::from(y))
+            .map_err(js_err)
+    }
+}
+
+/// A web renderer drawing to an HTML5 canvas.
 #[derive(Debug)]
-pub(crate) struct Renderer {}
+pub(crate) struct Renderer {
+    settings: RendererSettings,
+    title: String,
+    surface: Surface,
+    events: event::EventQueue,
+    _listeners: event::EventListeners,
+    text_input: Rc<Cell<bool>>,
+    cursor_confined: bool,
+    draw_color: Color,
+    clip: Option<Rect<i32>>,
+    blend_mode: BlendMode,
+    stroke_weight: u16,
+    scale: (f32, f32),
+    viewport: Option<Rect<i32>>,
+    font_size: u32,
+    font_style: FontStyle,
+    font_family: String,
+    font_fallbacks: Vec<String>,
+    textures: Vec<Option<Surface>>,
+    texture_target: Option<TextureId>,
+    texture_target_stack: Vec<Option<TextureId>>,
+    audio: audio::AudioQueue,
+}
+
+impl Renderer {
+    /// The surface of the current render target.
+    fn surface(&self) -> Result<&Surface> {
+        match self.texture_target {
+            Some(id) => self.texture_surface(id),
+            None => Ok(&self.surface),
+        }
+    }
+
+    /// The surface of a texture.
+    fn texture_surface(&self, id: TextureId) -> Result<&Surface> {
+        self.textures
+            .get(*id)
+            .and_then(Option::as_ref)
+            .ok_or_else(|| anyhow!(Error::InvalidTexture(id)))
+    }
+
+    /// The 2D rendering context of the current render target.
+    fn context(&self) -> Result<&CanvasRenderingContext2d> {
+        Ok(&self.surface()?.context)
+    }
+
+    /// CSS font shorthand for the current font settings.
+    fn font(&self) -> String {
+        let mut font = String::new();
+        if self.font_style.contains(FontStyle::ITALIC) {
+            font.push_str("italic ");
+        }
+        if self.font_style.contains(FontStyle::BOLD) {
+            font.push_str("bold ");
+        }
+        font.push_str(&format!("{}px \"{}\"", self.font_size, self.font_family));
+        for fallback in &self.font_fallbacks {
+            font.push_str(&format!(", \"{fallback}\""));
+        }
+        font.push_str(", sans-serif");
+        font
+    }
+
+    /// Height of a line of text in pixels.
+    fn line_height(&self) -> f64 {
+        (f64::from(self.font_size) * LINE_HEIGHT).round()
+    }
+
+    /// Reset the current render target to the current scale, viewport, clip rect, blend mode, and
+    /// font settings.
+    fn reset_context(&self) -> Result<()> {
+        let ctx = self.context()?;
+        ctx.restore();
+        ctx.save();
+        let (x, y) = match (self.texture_target, self.viewport) {
+            (None, Some(viewport)) => (f64::from(viewport.x()), f64::from(viewport.y())),
+            _ => (0.0, 0.0),
+        };
+        ctx.set_transform(
+            f64::from(self.scale.0),
+            0.0,
+            0.0,
+            f64::from(self.scale.1),
+            x,
+            y,
+        )
+        .map_err(js_err)?;
+        if let Some(rect) = self.clip {
+            let [x, y, width, height] = rect.coords().map(f64::from);
+            ctx.begin_path();
+            ctx.rect(x, y, width, height);
+            ctx.clip();
+        }
+        ctx.set_global_composite_operation(match self.blend_mode {
+            // The `copy` operation clears everything outside of what's drawn, so alpha blending
+            // can't be disabled
+            BlendMode::None | BlendMode::Blend => "source-over",
+            BlendMode::Add => "lighter",
+            BlendMode::Mod => "multiply",
+        })
+        .map_err(js_err)?;
+        ctx.set_font(&self.font());
+        ctx.set_text_baseline("top");
+        Ok(())
+    }
+
+    /// Fill and/or stroke a path. `path` is called with the offset to align strokes to pixel
+    /// centers, which is `0.0` when filling and half the stroke weight when stroking.
+    fn draw_path<F>(&self, fill: Option<Color>, stroke: Option<Color>, path: F) -> Result<()>
+    where
+        F: Fn(&CanvasRenderingContext2d, f64) -> Result<()>,
+    {
+        let ctx = self.context()?;
+        if let Some(fill) = fill {
+            ctx.begin_path();
+            path(ctx, 0.0)?;
+            ctx.set_fill_style_str(&css_color(fill));
+            ctx.fill();
+        }
+        if let Some(stroke) = stroke {
+            let width = f64::from(self.stroke_weight);
+            ctx.begin_path();
+            path(ctx, width / 2.0)?;
+            ctx.set_line_width(width);
+            ctx.set_stroke_style_str(&css_color(stroke));
+            ctx.stroke();
+        }
+        Ok(())
+    }
+
+    /// Split `text` into lines, wrapping words that would exceed `wrap_width`.
+    fn wrap_lines(&self, text: &str, wrap_width: Option<u32>) -> Result<Vec<String>> {
+        let ctx = self.context()?;
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let Some(wrap_width) = wrap_width else {
+                lines.push(paragraph.to_owned());
+                continue;
+            };
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() {
+                    word.to_owned()
+                } else {
+                    format!("{line} {word}")
+                };
+                let width = ctx.measure_text(&candidate).map_err(js_err)?.width();
+                if !line.is_empty() && width > f64::from(wrap_width) {
+                    lines.push(mem::replace(&mut line, word.to_owned()));
+                } else {
+                    line = candidate;
+                }
+            }
+            lines.push(line);
+        }
+        Ok(lines)
+    }
+
+    /// Draw a region of `surface` to the current render target.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_surface(
+        &self,
+        surface: &Surface,
+        src: Option<Rect<i32>>,
+        dst: Option<Rect<i32>>,
+        angle: f64,
+        center: Option<Point<i32>>,
+        flipped: Option<Flipped>,
+        tint: Option<Color>,
+    ) -> Result<()> {
+        let src = src.unwrap_or_else(|| surface.bounds());
+        let dst = match dst {
+            Some(dst) => dst,
+            None => self.surface()?.bounds(),
+        };
+        let tinted;
+        let (surface, src) = match tint {
+            Some(tint) => {
+                tinted = tinted_surface(surface, src, tint)?;
+                (&tinted, tinted.bounds())
+            }
+            None => (surface, src),
+        };
+        let ctx = self.context()?;
+        ctx.save();
+        let result = transform(ctx, dst, angle, center, flipped).and_then(|_| {
+            let [sx, sy, sw, sh] = src.coords().map(f64::from);
+            let [_, _, dw, dh] = dst.coords().map(f64::from);
+            ctx.draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                &surface.canvas,
+                sx,
+                sy,
+                sw,
+                sh,
+                0.0,
+                0.0,
+                dw,
+                dh,
+            )
+            .map_err(js_err)
+        });
+        ctx.restore();
+        result
+    }
+}
 
 impl Rendering for Renderer {
-    fn new(settings: RendererSettings) -> crate::prelude::Result<Self> {
-        todo!()
+    /// Initializes the canvas renderer.
+    fn new(settings: RendererSettings) -> Result<Self> {
+        let document = document()?;
+        let canvas = match document.get_element_by_id(CANVAS_ID) {
+            Some(element) => element.dyn_into::<HtmlCanvasElement>().map_err(|_| {
+                anyhow!(Error::Renderer(format!(
+                    "element `#{CANVAS_ID}` is not a canvas"
+                )))
+            })?,
+            None => {
+                let canvas = Surface::create(settings.width, settings.height)?.canvas;
+                canvas.set_id(CANVAS_ID);
+                document
+                    .body()
+                    .ok_or_else(|| anyhow!(Error::Renderer("document has no body".into())))?
+                    .append_child(&canvas)
+                    .map_err(js_err)?;
+                canvas
+            }
+        };
+        canvas.set_width(settings.width);
+        canvas.set_height(settings.height);
+        // Allow the canvas to receive keyboard focus, and keep touches from scrolling the page
+        canvas.set_tab_index(0);
+        let style = canvas.style();
+        style.set_property("outline", "none").map_err(js_err)?;
+        style.set_property("touch-action", "none").map_err(js_err)?;
+        if settings.opacity < 1.0 {
+            style
+                .set_property("opacity", &settings.opacity.to_string())
+                .map_err(js_err)?;
+        }
+        if settings.always_on_top {
+            warn!("Always on top windows are not supported on the web");
+        }
+        document.set_title(&settings.title);
+
+        let events = event::EventQueue::default();
+        let text_input = Rc::new(Cell::new(false));
+        let listeners = event::EventListeners::new(&canvas, &events, &text_input)?;
+        let audio = audio::AudioQueue::new(&settings)?;
+        let surface = Surface::new(canvas)?;
+
+        let mut renderer = Self {
+            title: settings.title.clone(),
+            scale: (settings.scale_x, settings.scale_y),
+            settings,
+            surface,
+            events,
+            _listeners: listeners,
+            text_input,
+            cursor_confined: false,
+            draw_color: Color::BLACK,
+            clip: None,
+            blend_mode: BlendMode::None,
+            stroke_weight: 1,
+            viewport: None,
+            font_size: 12,
+            font_style: FontStyle::NORMAL,
+            font_family: Font::default().name().to_owned(),
+            font_fallbacks: Vec::new(),
+            textures: Vec::new(),
+            texture_target: None,
+            texture_target_stack: Vec::new(),
+            audio,
+        };
+        renderer.reset_context()?;
+        if renderer.settings.hidden {
+            crate::renderer::WindowRenderer::hide(&mut renderer)?;
+        } else if let Err(err) = renderer.surface.canvas.focus() {
+            error!("Failed to focus canvas: {:?}", err);
+        }
+        Ok(renderer)
     }
 
-    fn clear(&mut self) -> crate::prelude::Result<()> {
-        todo!()
+    /// Returns the graphics backend. The web always renders with a canvas 2D context and ignores
+    /// the requested driver.
+    #[inline]
+    fn render_driver(&self) -> RenderDriver {
+        RenderDriver::Canvas
     }
 
-    fn set_draw_color(&mut self, color: crate::prelude::Color) -> crate::prelude::Result<()> {
-        todo!()
+    /// Clears the current render target with the current draw color, ignoring the clip rect.
+    #[inline]
+    fn clear(&mut self) -> Result<()> {
+        let surface = self.surface()?;
+        let ctx = &surface.context;
+        let (width, height) = surface.dimensions();
+        ctx.save();
+        ctx.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+            .map_err(js_err)?;
+        ctx.set_global_composite_operation("copy").map_err(js_err)?;
+        ctx.set_fill_style_str(&css_color(self.draw_color));
+        ctx.fill_rect(0.0, 0.0, f64::from(width), f64::from(height));
+        ctx.restore();
+        Ok(())
     }
 
-    fn clip(&mut self, rect: Option<crate::prelude::Rect<i32>>) -> crate::prelude::Result<()> {
-        todo!()
+    /// Sets the color used by the renderer to draw to the current canvas.
+    #[inline]
+    fn set_draw_color(&mut self, color: Color) -> Result<()> {
+        self.draw_color = color;
+        Ok(())
     }
 
-    fn blend_mode(&mut self, mode: crate::prelude::BlendMode) {
-        todo!()
+    /// Sets the clip rect used by the renderer to draw to the current canvas.
+    #[inline]
+    fn clip(&mut self, rect: Option<Rect<i32>>) -> Result<()> {
+        self.clip = rect;
+        self.reset_context()
     }
 
-    fn present(&mut self) {
-        todo!()
+    /// Sets the blend mode used by the renderer to draw.
+    #[inline]
+    fn blend_mode(&mut self, mode: BlendMode) {
+        self.blend_mode = mode;
+        if let Err(err) = self.reset_context() {
+            error!("Failed to set blend mode: {}", err);
+        }
     }
 
-    fn scale(&mut self, x: f32, y: f32) -> crate::prelude::Result<()> {
-        todo!()
+    /// Sets the line width used by the renderer to draw shape outlines.
+    #[inline]
+    fn stroke_weight(&mut self, weight: u16) {
+        self.stroke_weight = weight.max(1);
     }
 
-    fn font_size(&mut self, size: u32) -> crate::prelude::Result<()> {
-        todo!()
+    /// Updates the canvas. Browsers present the canvas automatically after each animation frame.
+    #[inline]
+    fn present(&mut self) {}
+
+    /// Set the rendering scale of the current canvas. Drawing coordinates are scaled by x/y
+    /// factors before being drawn to the canvas.
+    #[inline]
+    fn scale(&mut self, x: f32, y: f32) -> Result<()> {
+        self.scale = (x, y);
+        self.reset_context()
+    }
+
+    /// Set the font size for drawing text to the current canvas.
+    #[inline]
+    fn font_size(&mut self, size: u32) -> Result<()> {
+        self.font_size = size;
+        self.context()?.set_font(&self.font());
+        Ok(())
     }
 
-    fn font_style(&mut self, style: crate::prelude::FontStyle) {
-        todo!()
+    /// Set the font style for drawing text to the current canvas. Underline and strikethrough
+    /// styles are not supported by the canvas and are ignored.
+    #[inline]
+    fn font_style(&mut self, style: FontStyle) {
+        self.font_style = style;
+        if let Ok(ctx) = self.context() {
+            ctx.set_font(&self.font());
+        }
     }
 
-    fn font_family(&mut self, font: &crate::prelude::theme::Font) -> crate::prelude::Result<()> {
-        todo!()
+    /// Set the font family for drawing text to the current canvas. The font must be available
+    /// to the page, e.g. as a system font or loaded with a CSS `@font-face` rule.
+    #[inline]
+    fn font_family(&mut self, font: &Font) -> Result<()> {
+        self.font_family = font.name().to_owned();
+        self.context()?.set_font(&self.font());
+        Ok(())
     }
 
+    /// Set the fallback font families for glyphs missing from the current font.
+    #[inline]
+    fn font_fallbacks(&mut self, fonts: &[Font]) -> Result<()> {
+        self.font_fallbacks = fonts.iter().map(|font| font.name().to_owned()).collect();
+        self.context()?.set_font(&self.font());
+        Ok(())
+    }
+
+    /// Text is drawn directly to the canvas, so there is no cache to clear.
+    #[inline]
+    fn clear_text_cache(&mut self) {}
+
+    /// Browsers only allow reading the clipboard asynchronously with user permission, so this
+    /// always returns an empty string.
+    #[inline]
     fn clipboard_text(&self) -> String {
-        todo!()
+        String::new()
     }
 
-    fn set_clipboard_text(&self, value: &str) -> crate::prelude::Result<()> {
-        todo!()
+    /// Setting the clipboard is not supported on the web.
+    #[inline]
+    fn set_clipboard_text(&self, _value: &str) -> Result<()> {
+        Err(unsupported("setting clipboard text"))
     }
 
-    fn open_url(&self, url: &str) -> crate::prelude::Result<()> {
-        todo!()
+    /// Open a URL in a new browser tab.
+    #[inline]
+    fn open_url(&self, url: &str) -> Result<()> {
+        window()?
+            .open_with_url_and_target(url, "_blank")
+            .map_err(js_err)?;
+        Ok(())
     }
 
+    /// Start sending text input events.
+    #[inline]
+    fn start_text_input(&self) {
+        self.text_input.set(true);
+    }
+
+    /// Stop sending text input events.
+    #[inline]
+    fn stop_text_input(&self) {
+        self.text_input.set(false);
+    }
+
+    /// Returns whether text input events are being sent.
+    #[inline]
+    fn text_input_active(&self) -> bool {
+        self.text_input.get()
+    }
+
+    /// Browsers position candidate windows relative to the focused canvas, so this is ignored.
+    #[inline]
+    fn set_text_input_rect(&self, _rect: Rect<i32>) {}
+
+    /// Draw text to the current canvas. `angle` must be in degrees.
+    #[allow(clippy::too_many_arguments)]
     fn text(
         &mut self,
-        position: crate::prelude::Point<i32>,
+        position: Point<i32>,
         text: &str,
         wrap_width: Option<u32>,
         angle: Option<f64>,
-        center: Option<crate::prelude::Point<i32>>,
-        flipped: Option<crate::prelude::Flipped>,
-        fill: Option<crate::prelude::Color>,
+        center: Option<Point<i32>>,
+        flipped: Option<Flipped>,
+        fill: Option<Color>,
         outline: u16,
-    ) -> crate::prelude::Result<(u32, u32)> {
-        todo!()
+    ) -> Result<(u32, u32)> {
+        let (width, height) = self.size_of(text, wrap_width)?;
+        let Some(fill) = fill else {
+            return Ok((width, height));
+        };
+        if text.is_empty() {
+            return Ok((width, height));
+        }
+
+        let lines = self.wrap_lines(text, wrap_width)?;
+        let line_height = self.line_height();
+        let dst = rect![position, width as i32, height as i32];
+        let ctx = self.context()?;
+        ctx.save();
+        let result = transform(ctx, dst, angle.unwrap_or(0.0), center, flipped).and_then(|_| {
+            let color = css_color(fill);
+            ctx.set_fill_style_str(&color);
+            if outline > 0 {
+                ctx.set_stroke_style_str(&color);
+                ctx.set_line_width(2.0 * f64::from(outline));
+            }
+            for (i, line) in lines.iter().enumerate() {
+                let y = i as f64 * line_height;
+                if outline > 0 {
+                    ctx.stroke_text(line, 0.0, y).map_err(js_err)?;
+                }
+                ctx.fill_text(line, 0.0, y).map_err(js_err)?;
+            }
+            Ok(())
+        });
+        ctx.restore();
+        result.map(|_| (width, height))
     }
 
-    fn size_of(&self, text: &str, wrap_width: Option<u32>) -> crate::prelude::Result<(u32, u32)> {
-        todo!()
+    /// Returns the rendered dimensions of the given text using the current font as
+    /// `(width, height)`.
+    #[inline]
+    fn size_of(&self, text: &str, wrap_width: Option<u32>) -> Result<(u32, u32)> {
+        let line_height = self.line_height();
+        if text.is_empty() {
+            return Ok((0, line_height as u32));
+        }
+        let ctx = self.context()?;
+        let lines = self.wrap_lines(text, wrap_width)?;
+        let mut width = 0.0_f64;
+        for line in &lines {
+            width = width.max(ctx.measure_text(line).map_err(js_err)?.width());
+        }
+        Ok((
+            width.ceil() as u32,
+            (lines.len() as f64 * line_height) as u32,
+        ))
     }
 
-    fn point(
-        &mut self,
-        p: crate::prelude::Point<i32>,
-        color: crate::prelude::Color,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+    /// Draw a pixel to the current canvas.
+    #[inline]
+    fn point(&mut self, p: Point<i32>, color: Color) -> Result<()> {
+        let ctx = self.context()?;
+        let [x, y] = p.coords().map(f64::from);
+        ctx.set_fill_style_str(&css_color(color));
+        ctx.fill_rect(x, y, 1.0, 1.0);
+        Ok(())
     }
 
-    fn line(
-        &mut self,
-        line: crate::prelude::Line<i32>,
-        smooth: bool,
-        width: u8,
-        color: crate::prelude::Color,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+    /// Draw a pixel to the current canvas, compositing `color` using the current blend mode.
+    #[inline]
+    fn pixel(&mut self, p: Point<i32>, color: Color) -> Result<()> {
+        let ctx = self.context()?;
+        let [x, y] = p.coords().map(f64::from);
+        if self.blend_mode == BlendMode::None {
+            // The canvas always alpha blends, so clear the pixel first to overwrite it
+            ctx.clear_rect(x, y, 1.0, 1.0);
+        }
+        ctx.set_fill_style_str(&css_color(color));
+        ctx.fill_rect(x, y, 1.0, 1.0);
+        Ok(())
     }
 
-    fn bezier<I>(
-        &mut self,
-        ps: I,
-        detail: i32,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()>
+    /// Draw a line to the current canvas. Lines are always anti-aliased by the browser.
+    #[inline]
+    fn line(&mut self, line: Line<i32>, _smooth: bool, width: u8, color: Color) -> Result<()> {
+        let ctx = self.context()?;
+        let [x1, y1, x2, y2] = line.coords().map(f64::from);
+        // Odd widths are centered on pixels
+        let offset = if width % 2 == 1 { 0.5 } else { 0.0 };
+        ctx.begin_path();
+        ctx.move_to(x1 + offset, y1 + offset);
+        ctx.line_to(x2 + offset, y2 + offset);
+        ctx.set_line_width(f64::from(width));
+        ctx.set_stroke_style_str(&css_color(color));
+        ctx.stroke();
+        Ok(())
+    }
+
+    /// Draw a series of cubic bezier curves to the current canvas. The first point is the start
+    /// of the curve, followed by two control points and an end point for each curve segment.
+    #[inline]
+    fn bezier<I>(&mut self, ps: I, _detail: i32, stroke: Option<Color>) -> Result<()>
     where
-        I: Iterator<Item = crate::prelude::Point<i32>>,
+        I: Iterator<Item = Point<i32>>,
     {
-        todo!()
+        let Some(stroke) = stroke else {
+            return Ok(());
+        };
+        let ps: Vec<_> = ps.map(|p| p.coords().map(f64::from)).collect();
+        let Some(([x, y], rest)) = ps.split_first() else {
+            return Ok(());
+        };
+        let ctx = self.context()?;
+        ctx.begin_path();
+        ctx.move_to(*x, *y);
+        for curve in rest.chunks_exact(3) {
+            let [[cx1, cy1], [cx2, cy2], [x, y]] = [curve[0], curve[1], curve[2]];
+            ctx.bezier_curve_to(cx1, cy1, cx2, cy2, x, y);
+        }
+        ctx.set_line_width(1.0);
+        ctx.set_stroke_style_str(&css_color(stroke));
+        ctx.stroke();
+        Ok(())
     }
 
+    /// Draw a triangle to the current canvas.
+    #[inline]
     fn triangle(
         &mut self,
-        tri: crate::prelude::Tri<i32>,
+        tri: Tri<i32>,
         smooth: bool,
-        fill: Option<crate::prelude::Color>,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        fill: Option<Color>,
+        stroke: Option<Color>,
+    ) -> Result<()> {
+        self.polygon(tri.points().into_iter(), smooth, fill, stroke)
     }
 
+    /// Draw a rectangle to the current canvas.
+    #[inline]
     fn rect(
         &mut self,
-        rect: crate::prelude::Rect<i32>,
+        rect: Rect<i32>,
         radius: Option<i32>,
-        fill: Option<crate::prelude::Color>,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        fill: Option<Color>,
+        stroke: Option<Color>,
+    ) -> Result<()> {
+        let [x, y, width, height] = rect.coords().map(f64::from);
+        let radius = radius.map_or(0.0, f64::from);
+        self.draw_path(fill, stroke, |ctx, offset| {
+            // Strokes are drawn inside the rect bounds
+            let (x, y) = (x + offset, y + offset);
+            let (width, height) = (width - 2.0 * offset, height - 2.0 * offset);
+            let radius = radius.min(width / 2.0).min(height / 2.0).max(0.0);
+            if radius > 0.0 {
+                ctx.move_to(x + radius, y);
+                ctx.arc_to(x + width, y, x + width, y + height, radius)
+                    .map_err(js_err)?;
+                ctx.arc_to(x + width, y + height, x, y + height, radius)
+                    .map_err(js_err)?;
+                ctx.arc_to(x, y + height, x, y, radius).map_err(js_err)?;
+                ctx.arc_to(x, y, x + width, y, radius).map_err(js_err)?;
+                ctx.close_path();
+            } else {
+                ctx.rect(x, y, width, height);
+            }
+            Ok(())
+        })
     }
 
+    /// Draw a quadrilateral to the current canvas.
+    #[inline]
     fn quad(
         &mut self,
-        quad: crate::prelude::Quad<i32>,
+        quad: Quad<i32>,
         smooth: bool,
-        fill: Option<crate::prelude::Color>,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        fill: Option<Color>,
+        stroke: Option<Color>,
+    ) -> Result<()> {
+        self.polygon(quad.points().into_iter(), smooth, fill, stroke)
     }
 
+    /// Draw a polygon to the current canvas.
+    #[inline]
     fn polygon<I>(
         &mut self,
         ps: I,
-        smooth: bool,
-        fill: Option<crate::prelude::Color>,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()>
+        _smooth: bool,
+        fill: Option<Color>,
+        stroke: Option<Color>,
+    ) -> Result<()>
     where
-        I: Iterator<Item = crate::prelude::Point<i32>>,
+        I: Iterator<Item = Point<i32>>,
     {
-        todo!()
+        let ps: Vec<_> = ps.map(|p| p.coords().map(f64::from)).collect();
+        let Some(([x, y], rest)) = ps.split_first() else {
+            return Ok(());
+        };
+        self.draw_path(fill, stroke, |ctx, offset| {
+            // Only offset odd stroke widths by half a pixel to keep edges crisp
+            let offset = offset.fract();
+            ctx.move_to(x + offset, y + offset);
+            for [x, y] in rest {
+                ctx.line_to(x + offset, y + offset);
+            }
+            ctx.close_path();
+            Ok(())
+        })
     }
 
+    /// Draw a ellipse to the current canvas.
+    #[inline]
     fn ellipse(
         &mut self,
-        ellipse: crate::prelude::Ellipse<i32>,
-        smooth: bool,
-        fill: Option<crate::prelude::Color>,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        ellipse: Ellipse<i32>,
+        _smooth: bool,
+        fill: Option<Color>,
+        stroke: Option<Color>,
+    ) -> Result<()> {
+        let [x, y, width, height] = ellipse.coords().map(f64::from);
+        self.draw_path(fill, stroke, |ctx, _| {
+            ctx.ellipse(x, y, width / 2.0, height / 2.0, 0.0, 0.0, TAU)
+                .map_err(js_err)
+        })
     }
 
+    /// Draw an arc to the current canvas. `start` and `end` must be in degrees.
+    #[inline]
     fn arc(
         &mut self,
-        p: crate::prelude::Point<i32>,
+        p: Point<i32>,
         radius: i32,
         start: i32,
         end: i32,
-        mode: crate::prelude::ArcMode,
-        fill: Option<crate::prelude::Color>,
-        stroke: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        mode: ArcMode,
+        fill: Option<Color>,
+        stroke: Option<Color>,
+    ) -> Result<()> {
+        let [x, y] = p.coords().map(f64::from);
+        let radius = f64::from(radius);
+        let start = f64::from(start).to_radians();
+        let end = f64::from(end).to_radians();
+        match mode {
+            ArcMode::Default => self.draw_path(None, stroke, |ctx, _| {
+                ctx.arc(x, y, radius, start, end).map_err(js_err)
+            }),
+            ArcMode::Pie => self.draw_path(fill, stroke, |ctx, _| {
+                ctx.move_to(x, y);
+                ctx.arc(x, y, radius, start, end).map_err(js_err)?;
+                ctx.close_path();
+                Ok(())
+            }),
+        }
     }
 
+    /// Draw an image to the current canvas, optionally rotated about a `center`, flipped or
+    /// tinted. `angle` must be in degrees.
+    #[inline]
     fn image(
         &mut self,
-        img: &crate::prelude::Image,
-        src: Option<crate::prelude::Rect<i32>>,
-        dst: Option<crate::prelude::Rect<i32>>,
+        img: &Image,
+        src: Option<Rect<i32>>,
+        dst: Option<Rect<i32>>,
         angle: f64,
-        center: Option<crate::prelude::Point<i32>>,
-        flipped: Option<crate::prelude::Flipped>,
-        tint: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        center: Option<Point<i32>>,
+        flipped: Option<Flipped>,
+        tint: Option<Color>,
+    ) -> Result<()> {
+        let surface = Surface::from_image(img)?;
+        self.draw_surface(&surface, src, dst, angle, center, flipped, tint)
+    }
+
+    /// Return the current rendered target pixels as an array of RGBA bytes.
+    #[inline]
+    fn to_bytes(&mut self, src: Option<Rect<i32>>) -> Result<Vec<u8>> {
+        let surface = self.surface()?;
+        let [x, y, width, height] = src
+            .unwrap_or_else(|| surface.bounds())
+            .coords()
+            .map(f64::from);
+        let data = surface
+            .context
+            .get_image_data(x, y, width, height)
+            .map_err(js_err)?;
+        Ok(data.data().0)
     }
 
-    fn to_bytes(&mut self) -> crate::prelude::Result<Vec<u8>> {
-        todo!()
+    /// Game controllers are not supported on the web.
+    #[inline]
+    fn open_controller(&mut self, _controller_id: ControllerId) -> Result<()> {
+        Err(unsupported("game controllers"))
     }
 
-    fn open_controller(
+    /// Game controllers are not supported on the web.
+    #[inline]
+    fn close_controller(&mut self, _controller_id: ControllerId) {}
+
+    /// Game controllers are not supported on the web.
+    #[inline]
+    fn rumble_controller(
         &mut self,
-        controller_id: crate::event::ControllerId,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        _controller_id: ControllerId,
+        _low: u16,
+        _high: u16,
+        _duration: Duration,
+    ) -> Result<()> {
+        Err(unsupported("game controllers"))
     }
+}
 
-    fn close_controller(&mut self, controller_id: crate::event::ControllerId) {
-        todo!()
+/// Call `f` once per browser animation frame until it returns `false` or an error, which is
+/// logged.
+pub(crate) fn request_frames<F>(mut f: F) -> Result<()>
+where
+    F: FnMut() -> Result<bool> + 'static,
+{
+    let callback = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+    let next_frame = Rc::clone(&callback);
+    *callback.borrow_mut() = Some(Closure::new(move || {
+        let running = f().unwrap_or_else(|err| {
+            error!("Error: {}", err);
+            false
+        });
+        if running {
+            if let Some(callback) = next_frame.borrow().as_ref() {
+                if let Err(err) = request_animation_frame(callback) {
+                    error!("Error: {}", err);
+                }
+            }
+        } else {
+            // Dropping the callback ends the loop
+            drop(next_frame.borrow_mut().take());
+        }
+    }));
+    let callback = callback.borrow();
+    callback.as_ref().map_or(Ok(()), request_animation_frame)
+}
+
+/// Schedule `callback` for the next browser animation frame.
+fn request_animation_frame(callback: &Closure<dyn FnMut()>) -> Result<()> {
+    window()?
+        .request_animation_frame(callback.as_ref().unchecked_ref())
+        .map_err(js_err)?;
+    Ok(())
+}
+
+/// Apply a rotation of `angle` degrees about `center` and flip to `ctx`, relative to the top-left
+/// of `dst`, which is moved to the origin.
+fn transform(
+    ctx: &CanvasRenderingContext2d,
+    dst: Rect<i32>,
+    angle: f64,
+    center: Option<Point<i32>>,
+    flipped: Option<Flipped>,
+) -> Result<()> {
+    let [x, y, width, height] = dst.coords().map(f64::from);
+    let [cx, cy] = center.map_or([width / 2.0, height / 2.0], |c| c.coords().map(f64::from));
+    ctx.translate(x + cx, y + cy).map_err(js_err)?;
+    if angle != 0.0 {
+        ctx.rotate(angle.to_radians()).map_err(js_err)?;
     }
+    let (sx, sy) = match flipped {
+        Some(Flipped::Horizontal) => (-1.0, 1.0),
+        Some(Flipped::Vertical) => (1.0, -1.0),
+        Some(Flipped::Both) => (-1.0, -1.0),
+        Some(Flipped::None) | None => (1.0, 1.0),
+    };
+    ctx.scale(sx, sy).map_err(js_err)?;
+    ctx.translate(-cx, -cy).map_err(js_err)
+}
+
+/// Copy a region of `surface` to a new surface, multiplied by `tint`.
+fn tinted_surface(surface: &Surface, src: Rect<i32>, tint: Color) -> Result<Surface> {
+    let [sx, sy, sw, sh] = src.coords().map(f64::from);
+    let tinted = Surface::create(src.width() as u32, src.height() as u32)?;
+    let ctx = &tinted.context;
+    let copy = |ctx: &CanvasRenderingContext2d| {
+        ctx.draw_image_with_html_canvas_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+            &surface.canvas,
+            sx,
+            sy,
+            sw,
+            sh,
+            0.0,
+            0.0,
+            sw,
+            sh,
+        )
+        .map_err(js_err)
+    };
+    copy(ctx)?;
+    ctx.set_global_composite_operation("multiply")
+        .map_err(js_err)?;
+    ctx.set_fill_style_str(&css_color(tint));
+    ctx.fill_rect(0.0, 0.0, sw, sh);
+    // Restore the original alpha channel
+    ctx.set_global_composite_operation("destination-in")
+        .map_err(js_err)?;
+    copy(ctx)?;
+    Ok(tinted)
+}
+
+/// Convert a [Color] into a CSS color string.
+fn css_color(color: Color) -> String {
+    let [r, g, b, a] = color.channels();
+    format!("rgba({r}, {g}, {b}, {})", f64::from(a) / 255.0)
+}
+
+/// The global browser `window`.
+fn window() -> Result<web_sys::Window> {
+    web_sys::window().ok_or_else(|| anyhow!(Error::Renderer("no global `window` exists".into())))
+}
+
+/// The `document` of the global browser `window`.
+fn document() -> Result<Document> {
+    window()?
+        .document()
+        .ok_or_else(|| anyhow!(Error::Renderer("window has no `document`".into())))
+}
+
+/// Convert a JavaScript exception into an [`Error::Renderer`].
+fn js_err(err: JsValue) -> anyhow::Error {
+    anyhow!(Error::Renderer(
+        err.as_string().unwrap_or_else(|| format!("{err:?}"))
+    ))
+}
+
+/// Returns an [`Error::Renderer`] for functionality browsers don't provide.
+fn unsupported(feature: &str) -> anyhow::Error {
+    anyhow!(Error::Renderer(format!(
+        "{feature} is not supported on the web"
+    )))
 }
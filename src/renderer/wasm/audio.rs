@@ -1,34 +1,53 @@
-use super::Renderer;
+//! Web Audio
+
+use super::{js_err, unsupported, Renderer};
 use crate::{
-    audio::{AudioDeviceDriver, AudioDriver},
+    audio::{queueable_samples, AudioDeviceDriver, AudioDriver, WARN_QUEUE_SIZE},
+    error::Result,
     prelude::*,
+    renderer::RendererSettings,
+};
+use log::warn;
+use std::{collections::VecDeque, marker::PhantomData, mem};
+use web_sys::{
+    AudioBufferSourceNode, AudioContext, AudioContextOptions, AudioContextState,
+    AudioScheduledSourceNode,
 };
-use std::marker::PhantomData;
 
-/// Audio callback or playback device that can be paused and resumed.
+const DEFAULT_CHANNELS: u8 = 1;
+const DEFAULT_BUFFER_SIZE: u16 = 4096;
+
+/// Audio callback or playback device that can be paused and resumed. Audio callback devices are
+/// not supported on the web, so this can't be constructed.
 #[derive(Debug)]
 pub struct AudioDevice<CB: AudioCallback>(PhantomData<CB>);
 
 impl<CB: AudioCallback> AudioDeviceDriver for AudioDevice<CB> {
+    /// Return the status of this audio callback device.
+    #[inline]
     fn status(&self) -> AudioStatus {
-        todo!()
+        AudioStatus::Stopped
     }
 
+    /// Return the current driver of this audio callback device.
+    #[inline]
     fn driver(&self) -> &'static str {
-        todo!()
+        "webaudio"
     }
 
+    /// Returns the [`AudioSpec`] for this audio callback device.
+    #[inline]
     fn spec(&self) -> AudioSpec {
-        todo!()
+        AudioSpec::default()
     }
 
-    fn resume(&self) {
-        todo!()
-    }
+    /// Resumes playback of this audio callback device.
+    #[inline]
+    fn resume(&self) {}
 
-    fn pause(&self) {
-        todo!()
-    }
+    /// Pause playback of this audio callback device.
+    #[inline]
+    fn pause(&self) {}
 }
 
 /// Represents a valid Channel format for generic audio sample types.
@@ -41,70 +60,208 @@ impl AudioFormatNum for u16 {}
 impl AudioFormatNum for i32 {}
 impl AudioFormatNum for f32 {}
 
+/// Audio queue scheduling buffers of samples on a Web Audio context.
+#[derive(Debug)]
+pub(crate) struct AudioQueue {
+    context: AudioContext,
+    channels: u8,
+    buffer_size: u16,
+    /// Scheduled buffer sources and the times they finish, in order of playback.
+    sources: VecDeque<(AudioBufferSourceNode, f64)>,
+    /// Time in seconds on the audio context clock when the last scheduled buffer finishes.
+    end_time: f64,
+}
+
+impl AudioQueue {
+    /// Create an audio queue with the desired audio settings. Browsers start audio suspended
+    /// until the page receives user input.
+    pub(crate) fn new(settings: &RendererSettings) -> Result<Self> {
+        let context = match settings.audio_sample_rate {
+            Some(sample_rate) => {
+                let options = AudioContextOptions::new();
+                options.set_sample_rate(sample_rate as f32);
+                AudioContext::new_with_context_options(&options)
+            }
+            None => AudioContext::new(),
+        }
+        .map_err(js_err)?;
+        Ok(Self {
+            context,
+            channels: settings.audio_channels.unwrap_or(DEFAULT_CHANNELS).max(1),
+            buffer_size: settings.audio_buffer_size.unwrap_or(DEFAULT_BUFFER_SIZE),
+            sources: VecDeque::new(),
+            end_time: 0.0,
+        })
+    }
+
+    /// Audio sample rate in Hz.
+    fn sample_rate(&self) -> i32 {
+        self.context.sample_rate() as i32
+    }
+
+    /// Size in bytes of the audio queued but not yet played.
+    fn queued_size(&self) -> u32 {
+        let remaining = (self.end_time - self.context.current_time()).max(0.0);
+        let samples = remaining * f64::from(self.sample_rate()) * f64::from(self.channels);
+        (samples as u32).saturating_mul(mem::size_of::<f32>() as u32)
+    }
+
+    /// Schedule interleaved `samples` to play after all previously queued samples.
+    fn enqueue(&mut self, samples: &[f32]) -> Result<()> {
+        let now = self.context.current_time();
+        while matches!(self.sources.front(), Some((_, end_time)) if *end_time <= now) {
+            self.sources.pop_front();
+        }
+
+        let channels = usize::from(self.channels);
+        let frames = samples.len() / channels;
+        if frames == 0 {
+            return Ok(());
+        }
+        let buffer = self
+            .context
+            .create_buffer(
+                u32::from(self.channels),
+                frames as u32,
+                self.sample_rate() as f32,
+            )
+            .map_err(js_err)?;
+        let mut channel = Vec::with_capacity(frames);
+        for c in 0..channels {
+            channel.clear();
+            channel.extend(samples.iter().skip(c).step_by(channels).take(frames));
+            buffer.copy_to_channel(&channel, c as i32).map_err(js_err)?;
+        }
+
+        let source = self.context.create_buffer_source().map_err(js_err)?;
+        source.set_buffer(Some(&buffer));
+        source
+            .connect_with_audio_node(&self.context.destination())
+            .map_err(js_err)?;
+        let start = self.end_time.max(now);
+        source.start_with_when(start).map_err(js_err)?;
+        self.end_time = start + buffer.duration();
+        self.sources.push_back((source, self.end_time));
+        Ok(())
+    }
+
+    /// Stop all scheduled buffers.
+    fn clear(&mut self) {
+        for (source, _) in self.sources.drain(..) {
+            if let Err(err) = AudioScheduledSourceNode::stop(&source) {
+                warn!("Failed to stop audio: {:?}", err);
+            }
+        }
+        self.end_time = 0.0;
+    }
+}
+
 impl AudioDriver for Renderer {
-    fn enqueue_audio(&mut self, samples: &[f32]) -> crate::prelude::Result<()> {
-        todo!()
+    /// Add audio samples to the audio buffer queue, returning the number of samples queued.
+    #[inline]
+    fn enqueue_audio(&mut self, samples: &[f32]) -> Result<usize> {
+        let size = self.audio.queued_size();
+        if size >= WARN_QUEUE_SIZE {
+            warn!("Audio queue size is increasing: {}. Did you forget to call `PixState::resume_audio`? Audio Device Status: {:?}", size, self.audio_status());
+        }
+        let len = queueable_samples(size, self.audio.channels, samples.len());
+        if len > 0 {
+            self.audio.enqueue(&samples[..len])?;
+        }
+        Ok(len)
     }
 
+    /// Clear audio samples from the audio buffer queue.
+    #[inline]
     fn clear_audio(&mut self) {
-        todo!()
+        self.audio.clear();
     }
 
-    fn audio_status(&self) -> crate::prelude::AudioStatus {
-        todo!()
+    /// Return the status of the current audio device.
+    #[inline]
+    fn audio_status(&self) -> AudioStatus {
+        match self.audio.context.state() {
+            AudioContextState::Running => AudioStatus::Playing,
+            AudioContextState::Suspended => AudioStatus::Paused,
+            _ => AudioStatus::Stopped,
+        }
     }
 
+    /// Return the driver of current audio queue device.
     fn audio_driver(&self) -> &'static str {
-        todo!()
+        "webaudio"
     }
 
+    /// Return the sample rate of the current audio device.
     fn audio_sample_rate(&self) -> i32 {
-        todo!()
+        self.audio.sample_rate()
+    }
+
+    /// Return the number of channels of the current audio queue device.
+    fn audio_channels(&self) -> u8 {
+        self.audio.channels
     }
 
+    /// Returns the queued buffer size (in bytes) of the current audio queue device.
     fn audio_queued_size(&self) -> u32 {
-        todo!()
+        self.audio.queued_size()
     }
 
+    /// Returns the buffer size (in bytes) of the current audio queue device.
     fn audio_size(&self) -> u32 {
-        todo!()
+        u32::from(self.audio.buffer_size)
+            * u32::from(self.audio.channels)
+            * mem::size_of::<f32>() as u32
     }
 
+    /// Resume playback of the current audio device. Browsers only allow resuming audio after the
+    /// page receives user input.
+    #[inline]
     fn resume_audio(&mut self) {
-        todo!()
+        if let Err(err) = self.audio.context.resume() {
+            warn!("Failed to resume audio: {:?}", err);
+        }
     }
 
+    /// Pause playback of the current audio device.
+    #[inline]
     fn pause_audio(&mut self) {
-        todo!()
+        if let Err(err) = self.audio.context.suspend() {
+            warn!("Failed to pause audio: {:?}", err);
+        }
     }
 
+    /// Audio callback devices are not supported on the web.
     #[allow(single_use_lifetimes)]
+    #[inline]
     fn open_playback<'a, CB, F, D>(
         &self,
-        device: D,
-        desired_spec: &crate::prelude::AudioSpecDesired,
-        get_callback: F,
-    ) -> crate::prelude::Result<crate::prelude::AudioDevice<CB>>
+        _device: D,
+        _desired_spec: &AudioSpecDesired,
+        _get_callback: F,
+    ) -> Result<AudioDevice<CB>>
     where
-        CB: crate::prelude::AudioCallback,
-        F: FnOnce(crate::prelude::AudioSpec) -> CB,
+        CB: AudioCallback,
+        F: FnOnce(AudioSpec) -> CB,
         D: Into<Option<&'a str>>,
     {
-        todo!()
+        Err(unsupported("audio playback callbacks"))
     }
 
+    /// Audio capture devices are not supported on the web.
     #[allow(single_use_lifetimes)]
+    #[inline]
     fn open_capture<'a, CB, F, D>(
         &self,
-        device: D,
-        desired_spec: &crate::prelude::AudioSpecDesired,
-        get_callback: F,
-    ) -> crate::prelude::Result<crate::prelude::AudioDevice<CB>>
+        _device: D,
+        _desired_spec: &AudioSpecDesired,
+        _get_callback: F,
+    ) -> Result<AudioDevice<CB>>
     where
-        CB: crate::prelude::AudioCallback,
-        F: FnOnce(crate::prelude::AudioSpec) -> CB,
+        CB: AudioCallback,
+        F: FnOnce(AudioSpec) -> CB,
         D: Into<Option<&'a str>>,
     {
-        todo!()
+        Err(unsupported("audio capture"))
     }
 }
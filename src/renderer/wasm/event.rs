@@ -0,0 +1,599 @@
+//! Web DOM event conversion.
+
+use super::{js_err, WINDOW_ID};
+use crate::{error::Result, prelude::*};
+use log::warn;
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{
+    CompositionEvent, EventTarget, FocusEvent, HtmlCanvasElement, KeyboardEvent, MouseEvent,
+    TouchEvent, WheelEvent,
+};
+
+/// Queue of converted DOM events waiting to be polled.
+pub(crate) type EventQueue = Rc<RefCell<VecDeque<Event>>>;
+
+/// A DOM event listener, which is removed from its event target when dropped.
+#[derive(Debug)]
+struct Listener {
+    target: EventTarget,
+    kind: &'static str,
+    callback: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl Listener {
+    /// Add a listener for `kind` events of type `E` to `target`.
+    fn new<E, F>(target: &EventTarget, kind: &'static str, mut f: F) -> Result<Self>
+    where
+        E: JsCast,
+        F: FnMut(E) + 'static,
+    {
+        let callback = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+            if let Ok(event) = event.dyn_into::<E>() {
+                f(event);
+            }
+        });
+        target
+            .add_event_listener_with_callback(kind, callback.as_ref().unchecked_ref())
+            .map_err(js_err)?;
+        Ok(Self {
+            target: target.clone(),
+            kind,
+            callback,
+        })
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        if let Err(err) = self
+            .target
+            .remove_event_listener_with_callback(self.kind, self.callback.as_ref().unchecked_ref())
+        {
+            warn!("Failed to remove `{}` listener: {:?}", self.kind, err);
+        }
+    }
+}
+
+/// DOM event listeners on the canvas, converting browser input into [Event]s. Listeners are
+/// removed when dropped.
+#[derive(Debug)]
+pub(crate) struct EventListeners {
+    _listeners: Vec<Listener>,
+}
+
+impl EventListeners {
+    /// Add listeners to `canvas`, pushing converted events to `events`. Text input events are
+    /// only sent while `text_input` is `true`.
+    pub(crate) fn new(
+        canvas: &HtmlCanvasElement,
+        events: &EventQueue,
+        text_input: &Rc<Cell<bool>>,
+    ) -> Result<Self> {
+        let target: &EventTarget = canvas.as_ref();
+        let mut listeners = Vec::new();
+
+        // Keyboard
+        let queue = Rc::clone(events);
+        let typing = Rc::clone(text_input);
+        listeners.push(Listener::new(
+            target,
+            "keydown",
+            move |e: KeyboardEvent| {
+                if e.is_composing() {
+                    return;
+                }
+                let keymod = keymod(e.shift_key(), e.ctrl_key(), e.alt_key(), e.meta_key());
+                let mut queue = queue.borrow_mut();
+                queue.push_back(Event::KeyDown {
+                    window_id: *WINDOW_ID,
+                    key: Some(key(&e.key(), &e.code())),
+                    keymod,
+                    repeat: e.repeat(),
+                    scan: Some(scan(&e.code())),
+                });
+                // Single characters are typed text, unless used for a shortcut
+                let text = e.key();
+                let shortcut = keymod.intersects(KeyMod::CTRL | KeyMod::GUI);
+                if typing.get() && !shortcut && text.chars().count() == 1 {
+                    queue.push_back(Event::TextInput {
+                        window_id: *WINDOW_ID,
+                        text,
+                    });
+                }
+                // Keep browser shortcuts, but otherwise don't scroll or navigate the page
+                if !shortcut {
+                    e.prevent_default();
+                }
+            },
+        )?);
+        let queue = Rc::clone(events);
+        listeners.push(Listener::new(target, "keyup", move |e: KeyboardEvent| {
+            if e.is_composing() {
+                return;
+            }
+            queue.borrow_mut().push_back(Event::KeyUp {
+                window_id: *WINDOW_ID,
+                key: Some(key(&e.key(), &e.code())),
+                keymod: keymod(e.shift_key(), e.ctrl_key(), e.alt_key(), e.meta_key()),
+                repeat: e.repeat(),
+                scan: Some(scan(&e.code())),
+            });
+        })?);
+
+        // Input method composition
+        let queue = Rc::clone(events);
+        let typing = Rc::clone(text_input);
+        listeners.push(Listener::new(
+            target,
+            "compositionupdate",
+            move |e: CompositionEvent| {
+                if let (true, Some(text)) = (typing.get(), e.data()) {
+                    let start = text.chars().count() as i32;
+                    queue.borrow_mut().push_back(Event::TextEditing {
+                        window_id: *WINDOW_ID,
+                        text,
+                        start,
+                        length: 0,
+                    });
+                }
+            },
+        )?);
+        let queue = Rc::clone(events);
+        let typing = Rc::clone(text_input);
+        listeners.push(Listener::new(
+            target,
+            "compositionend",
+            move |e: CompositionEvent| {
+                if let (true, Some(text)) = (typing.get(), e.data()) {
+                    queue.borrow_mut().push_back(Event::TextInput {
+                        window_id: *WINDOW_ID,
+                        text,
+                    });
+                }
+            },
+        )?);
+
+        // Mouse
+        let queue = Rc::clone(events);
+        listeners.push(Listener::new(target, "mousemove", move |e: MouseEvent| {
+            queue.borrow_mut().push_back(Event::MouseMotion {
+                window_id: *WINDOW_ID,
+                x: e.offset_x(),
+                y: e.offset_y(),
+                xrel: e.movement_x(),
+                yrel: e.movement_y(),
+            });
+        })?);
+        let queue = Rc::clone(events);
+        let canvas_focus = canvas.clone();
+        listeners.push(Listener::new(target, "mousedown", move |e: MouseEvent| {
+            // Focus the canvas for keyboard input without scrolling the page
+            e.prevent_default();
+            if let Err(err) = canvas_focus.focus() {
+                warn!("Failed to focus canvas: {:?}", err);
+            }
+            queue.borrow_mut().push_back(Event::MouseDown {
+                window_id: *WINDOW_ID,
+                button: mouse_button(e.button()),
+                x: e.offset_x(),
+                y: e.offset_y(),
+            });
+        })?);
+        let queue = Rc::clone(events);
+        listeners.push(Listener::new(target, "mouseup", move |e: MouseEvent| {
+            queue.borrow_mut().push_back(Event::MouseUp {
+                window_id: *WINDOW_ID,
+                button: mouse_button(e.button()),
+                x: e.offset_x(),
+                y: e.offset_y(),
+            });
+        })?);
+        let queue = Rc::clone(events);
+        listeners.push(Listener::new(target, "wheel", move |e: WheelEvent| {
+            e.prevent_default();
+            // DOM wheel deltas are positive when scrolling down, the opposite of `MouseWheel`
+            queue.borrow_mut().push_back(Event::MouseWheel {
+                window_id: *WINDOW_ID,
+                x: wheel_step(e.delta_x()),
+                y: -wheel_step(e.delta_y()),
+            });
+        })?);
+        listeners.push(Listener::new(target, "contextmenu", |e: MouseEvent| {
+            // Allow right-clicking the canvas
+            e.prevent_default();
+        })?);
+
+        // Touch
+        for (kind, touch_kind) in [
+            ("touchstart", TouchKind::Down),
+            ("touchmove", TouchKind::Motion),
+            ("touchend", TouchKind::Up),
+            ("touchcancel", TouchKind::Up),
+        ] {
+            let queue = Rc::clone(events);
+            let touch_canvas = canvas.clone();
+            listeners.push(Listener::new(target, kind, move |e: TouchEvent| {
+                let bounds = touch_canvas.get_bounding_client_rect();
+                let touches = e.changed_touches();
+                let mut queue = queue.borrow_mut();
+                for touch in (0..touches.length()).filter_map(|i| touches.get(i)) {
+                    // Finger positions are normalized to the canvas size
+                    let x = ((f64::from(touch.client_x()) - bounds.left()) / bounds.width()) as f32;
+                    let y = ((f64::from(touch.client_y()) - bounds.top()) / bounds.height()) as f32;
+                    let (touch_id, finger_id) = (0, i64::from(touch.identifier()));
+                    let (dx, dy, pressure) = (0.0, 0.0, touch.force());
+                    queue.push_back(match touch_kind {
+                        TouchKind::Down => Event::FingerDown {
+                            touch_id,
+                            finger_id,
+                            x,
+                            y,
+                            dx,
+                            dy,
+                            pressure,
+                        },
+                        TouchKind::Motion => Event::FingerMotion {
+                            touch_id,
+                            finger_id,
+                            x,
+                            y,
+                            dx,
+                            dy,
+                            pressure,
+                        },
+                        TouchKind::Up => Event::FingerUp {
+                            touch_id,
+                            finger_id,
+                            x,
+                            y,
+                            dx,
+                            dy,
+                            pressure,
+                        },
+                    });
+                }
+            })?);
+        }
+
+        // Window
+        for (kind, win_event) in [
+            ("focus", WindowEvent::FocusGained),
+            ("blur", WindowEvent::FocusLost),
+        ] {
+            let queue = Rc::clone(events);
+            listeners.push(Listener::new(target, kind, move |_: FocusEvent| {
+                queue.borrow_mut().push_back(Event::Window {
+                    window_id: *WINDOW_ID,
+                    win_event,
+                });
+            })?);
+        }
+        for (kind, win_event) in [
+            ("mouseenter", WindowEvent::Enter),
+            ("mouseleave", WindowEvent::Leave),
+        ] {
+            let queue = Rc::clone(events);
+            listeners.push(Listener::new(target, kind, move |_: MouseEvent| {
+                queue.borrow_mut().push_back(Event::Window {
+                    window_id: *WINDOW_ID,
+                    win_event,
+                });
+            })?);
+        }
+
+        Ok(Self {
+            _listeners: listeners,
+        })
+    }
+}
+
+/// The kind of finger event a DOM touch event is converted into.
+#[derive(Debug, Copy, Clone)]
+enum TouchKind {
+    Down,
+    Motion,
+    Up,
+}
+
+/// Convert DOM modifier key states into a [`KeyMod`].
+fn keymod(shift: bool, ctrl: bool, alt: bool, meta: bool) -> KeyMod {
+    let mut keymod = KeyMod::NONE;
+    if shift {
+        keymod |= KeyMod::SHIFT;
+    }
+    if ctrl {
+        keymod |= KeyMod::CTRL;
+    }
+    if alt {
+        keymod |= KeyMod::ALT;
+    }
+    if meta {
+        keymod |= KeyMod::GUI;
+    }
+    keymod
+}
+
+/// Convert a DOM wheel delta into a single scroll step.
+fn wheel_step(delta: f64) -> i32 {
+    if delta > 0.0 {
+        1
+    } else if delta < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Convert a DOM `MouseEvent.button` into a [Mouse] button.
+const fn mouse_button(button: i16) -> Mouse {
+    match button {
+        0 => Mouse::Left,
+        1 => Mouse::Middle,
+        2 => Mouse::Right,
+        _ => Mouse::Unhandled,
+    }
+}
+
+/// Convert a DOM `KeyboardEvent.key` value into a [Key]. Keypad and modifier keys are identified
+/// by their physical `KeyboardEvent.code` instead.
+#[allow(clippy::too_many_lines)]
+fn key(key: &str, code: &str) -> Key {
+    let keypad = match code {
+        "Numpad0" => Some(Key::Kp0),
+        "Numpad1" => Some(Key::Kp1),
+        "Numpad2" => Some(Key::Kp2),
+        "Numpad3" => Some(Key::Kp3),
+        "Numpad4" => Some(Key::Kp4),
+        "Numpad5" => Some(Key::Kp5),
+        "Numpad6" => Some(Key::Kp6),
+        "Numpad7" => Some(Key::Kp7),
+        "Numpad8" => Some(Key::Kp8),
+        "Numpad9" => Some(Key::Kp9),
+        "NumpadDecimal" => Some(Key::KpPeriod),
+        "NumpadEqual" => Some(Key::KpEquals),
+        "NumpadComma" => Some(Key::KpComma),
+        "NumpadDivide" => Some(Key::KpDivide),
+        "NumpadMultiply" => Some(Key::KpMultiply),
+        "NumpadSubtract" => Some(Key::KpMinus),
+        "NumpadAdd" => Some(Key::KpPlus),
+        "NumpadEnter" => Some(Key::KpEnter),
+        "ControlLeft" => Some(Key::LCtrl),
+        "ShiftLeft" => Some(Key::LShift),
+        "AltLeft" => Some(Key::LAlt),
+        "MetaLeft" => Some(Key::LGui),
+        "ControlRight" => Some(Key::RCtrl),
+        "ShiftRight" => Some(Key::RShift),
+        "AltRight" => Some(Key::RAlt),
+        "MetaRight" => Some(Key::RGui),
+        _ => None,
+    };
+    if let Some(key) = keypad {
+        return key;
+    }
+
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return match c.to_ascii_lowercase() {
+            'a' => Key::A,
+            'b' => Key::B,
+            'c' => Key::C,
+            'd' => Key::D,
+            'e' => Key::E,
+            'f' => Key::F,
+            'g' => Key::G,
+            'h' => Key::H,
+            'i' => Key::I,
+            'j' => Key::J,
+            'k' => Key::K,
+            'l' => Key::L,
+            'm' => Key::M,
+            'n' => Key::N,
+            'o' => Key::O,
+            'p' => Key::P,
+            'q' => Key::Q,
+            'r' => Key::R,
+            's' => Key::S,
+            't' => Key::T,
+            'u' => Key::U,
+            'v' => Key::V,
+            'w' => Key::W,
+            'x' => Key::X,
+            'y' => Key::Y,
+            'z' => Key::Z,
+            '0' => Key::Num0,
+            '1' => Key::Num1,
+            '2' => Key::Num2,
+            '3' => Key::Num3,
+            '4' => Key::Num4,
+            '5' => Key::Num5,
+            '6' => Key::Num6,
+            '7' => Key::Num7,
+            '8' => Key::Num8,
+            '9' => Key::Num9,
+            ' ' => Key::Space,
+            '!' => Key::Exclaim,
+            '"' => Key::Quotedbl,
+            '#' => Key::Hash,
+            '$' => Key::Dollar,
+            '%' => Key::Percent,
+            '&' => Key::Ampersand,
+            '\'' => Key::Quote,
+            '(' => Key::LeftParen,
+            ')' => Key::RightParen,
+            '*' => Key::Asterisk,
+            '+' => Key::Plus,
+            ',' => Key::Comma,
+            '-' => Key::Minus,
+            '.' => Key::Period,
+            '/' => Key::Slash,
+            ':' => Key::Colon,
+            ';' => Key::Semicolon,
+            '<' => Key::Less,
+            '=' => Key::Equals,
+            '>' => Key::Greater,
+            '?' => Key::Question,
+            '@' => Key::At,
+            '[' => Key::LeftBracket,
+            '\\' => Key::Backslash,
+            ']' => Key::RightBracket,
+            '^' => Key::Caret,
+            '_' => Key::Underscore,
+            '`' => Key::Backquote,
+            _ => Key::Unhandled,
+        };
+    }
+
+    match key {
+        "Backspace" => Key::Backspace,
+        "Tab" => Key::Tab,
+        "Enter" => Key::Return,
+        "Escape" => Key::Escape,
+        "Delete" => Key::Delete,
+        "CapsLock" => Key::CapsLock,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
+        "PrintScreen" => Key::PrintScreen,
+        "ScrollLock" => Key::ScrollLock,
+        "Pause" => Key::Pause,
+        "Insert" => Key::Insert,
+        "Home" => Key::Home,
+        "PageUp" => Key::PageUp,
+        "End" => Key::End,
+        "PageDown" => Key::PageDown,
+        "ArrowRight" => Key::Right,
+        "ArrowLeft" => Key::Left,
+        "ArrowDown" => Key::Down,
+        "ArrowUp" => Key::Up,
+        "NumLock" => Key::NumLock,
+        _ => Key::Unhandled,
+    }
+}
+
+/// Convert a DOM `KeyboardEvent.code` value into a [Scan] code.
+#[allow(clippy::too_many_lines)]
+fn scan(code: &str) -> Scan {
+    match code {
+        "KeyA" => Scan::A,
+        "KeyB" => Scan::B,
+        "KeyC" => Scan::C,
+        "KeyD" => Scan::D,
+        "KeyE" => Scan::E,
+        "KeyF" => Scan::F,
+        "KeyG" => Scan::G,
+        "KeyH" => Scan::H,
+        "KeyI" => Scan::I,
+        "KeyJ" => Scan::J,
+        "KeyK" => Scan::K,
+        "KeyL" => Scan::L,
+        "KeyM" => Scan::M,
+        "KeyN" => Scan::N,
+        "KeyO" => Scan::O,
+        "KeyP" => Scan::P,
+        "KeyQ" => Scan::Q,
+        "KeyR" => Scan::R,
+        "KeyS" => Scan::S,
+        "KeyT" => Scan::T,
+        "KeyU" => Scan::U,
+        "KeyV" => Scan::V,
+        "KeyW" => Scan::W,
+        "KeyX" => Scan::X,
+        "KeyY" => Scan::Y,
+        "KeyZ" => Scan::Z,
+        "Digit1" => Scan::Num1,
+        "Digit2" => Scan::Num2,
+        "Digit3" => Scan::Num3,
+        "Digit4" => Scan::Num4,
+        "Digit5" => Scan::Num5,
+        "Digit6" => Scan::Num6,
+        "Digit7" => Scan::Num7,
+        "Digit8" => Scan::Num8,
+        "Digit9" => Scan::Num9,
+        "Digit0" => Scan::Num0,
+        "Enter" => Scan::Return,
+        "Escape" => Scan::Escape,
+        "Backspace" => Scan::Backspace,
+        "Tab" => Scan::Tab,
+        "Space" => Scan::Space,
+        "Minus" => Scan::Minus,
+        "Equal" => Scan::Equals,
+        "BracketLeft" => Scan::LeftBracket,
+        "BracketRight" => Scan::RightBracket,
+        "Backslash" => Scan::Backslash,
+        "Semicolon" => Scan::Semicolon,
+        "Quote" => Scan::Apostrophe,
+        "Backquote" => Scan::Grave,
+        "Comma" => Scan::Comma,
+        "Period" => Scan::Period,
+        "Slash" => Scan::Slash,
+        "CapsLock" => Scan::CapsLock,
+        "F1" => Scan::F1,
+        "F2" => Scan::F2,
+        "F3" => Scan::F3,
+        "F4" => Scan::F4,
+        "F5" => Scan::F5,
+        "F6" => Scan::F6,
+        "F7" => Scan::F7,
+        "F8" => Scan::F8,
+        "F9" => Scan::F9,
+        "F10" => Scan::F10,
+        "F11" => Scan::F11,
+        "F12" => Scan::F12,
+        "PrintScreen" => Scan::PrintScreen,
+        "ScrollLock" => Scan::ScrollLock,
+        "Pause" => Scan::Pause,
+        "Insert" => Scan::Insert,
+        "Home" => Scan::Home,
+        "PageUp" => Scan::PageUp,
+        "Delete" => Scan::Delete,
+        "End" => Scan::End,
+        "PageDown" => Scan::PageDown,
+        "ArrowRight" => Scan::Right,
+        "ArrowLeft" => Scan::Left,
+        "ArrowDown" => Scan::Down,
+        "ArrowUp" => Scan::Up,
+        "NumLock" => Scan::NumLockClear,
+        "NumpadDivide" => Scan::KpDivide,
+        "NumpadMultiply" => Scan::KpMultiply,
+        "NumpadSubtract" => Scan::KpMinus,
+        "NumpadAdd" => Scan::KpPlus,
+        "NumpadEnter" => Scan::KpEnter,
+        "Numpad1" => Scan::Kp1,
+        "Numpad2" => Scan::Kp2,
+        "Numpad3" => Scan::Kp3,
+        "Numpad4" => Scan::Kp4,
+        "Numpad5" => Scan::Kp5,
+        "Numpad6" => Scan::Kp6,
+        "Numpad7" => Scan::Kp7,
+        "Numpad8" => Scan::Kp8,
+        "Numpad9" => Scan::Kp9,
+        "Numpad0" => Scan::Kp0,
+        "NumpadDecimal" => Scan::KpPeriod,
+        "IntlBackslash" => Scan::NonUsBackslash,
+        "ContextMenu" => Scan::Application,
+        "NumpadEqual" => Scan::KpEquals,
+        "ControlLeft" => Scan::LCtrl,
+        "ShiftLeft" => Scan::LShift,
+        "AltLeft" => Scan::LAlt,
+        "MetaLeft" => Scan::LGui,
+        "ControlRight" => Scan::RCtrl,
+        "ShiftRight" => Scan::RShift,
+        "AltRight" => Scan::RAlt,
+        "MetaRight" => Scan::RGui,
+        _ => Scan::Unhandled,
+    }
+}
@@ -1,63 +1,165 @@
-use super::Renderer;
-use crate::renderer::TextureRenderer;
+//! Web texture functions.
+
+use super::{Renderer, Surface};
+use crate::{
+    error::{Error, Result},
+    prelude::*,
+    renderer::TextureRenderer,
+};
+use anyhow::anyhow;
+use log::error;
 
 impl TextureRenderer for Renderer {
+    /// Create an offscreen canvas texture to render to. Textures are always stored as RGBA.
+    #[inline]
     fn create_texture(
         &mut self,
         width: u32,
         height: u32,
-        format: Option<crate::prelude::PixelFormat>,
-    ) -> crate::prelude::Result<crate::prelude::TextureId> {
-        todo!()
+        _format: Option<PixelFormat>,
+    ) -> Result<TextureId> {
+        let surface = Surface::create(width, height)?;
+        let texture_id = TextureId(self.textures.len());
+        self.textures.push(Some(surface));
+        Ok(texture_id)
     }
 
-    fn delete_texture(
-        &mut self,
-        texture_id: crate::prelude::TextureId,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+    /// Delete texture.
+    #[inline]
+    fn delete_texture(&mut self, texture_id: TextureId) -> Result<()> {
+        self.textures
+            .get_mut(*texture_id)
+            .and_then(Option::take)
+            .map_or(Err(Error::InvalidTexture(texture_id).into()), |_| Ok(()))
     }
 
+    /// Update texture with RGB or RGBA pixel data, based on `pitch`.
+    #[inline]
     fn update_texture<P: AsRef<[u8]>>(
         &mut self,
-        texture_id: crate::prelude::TextureId,
-        rect: Option<crate::prelude::Rect<i32>>,
+        texture_id: TextureId,
+        rect: Option<Rect<i32>>,
         pixels: P,
         pitch: usize,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+    ) -> Result<()> {
+        let surface = self.texture_surface(texture_id)?;
+        let rect = rect.unwrap_or_else(|| surface.bounds());
+        let (width, height) = (rect.width() as usize, rect.height() as usize);
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let format = if pitch / width == 3 {
+            PixelFormat::Rgb
+        } else {
+            PixelFormat::Rgba
+        };
+        let row_len = width * format.channels();
+        let pixels = pixels.as_ref();
+        if pitch < row_len || pixels.len() < pitch * (height - 1) + row_len {
+            return Err(anyhow!(Error::Renderer(format!(
+                "invalid texture pixel data for {width}x{height} with pitch {pitch}"
+            ))));
+        }
+        // Remove any row padding
+        let data: Vec<u8> = (0..height)
+            .flat_map(|row| &pixels[row * pitch..row * pitch + row_len])
+            .copied()
+            .collect();
+        surface.put_pixels(
+            &data,
+            format,
+            rect.x(),
+            rect.y(),
+            width as u32,
+            height as u32,
+        )
     }
 
+    /// Draw texture to the current render target.
+    #[inline]
     fn texture(
         &mut self,
-        texture_id: crate::prelude::TextureId,
-        src: Option<crate::prelude::Rect<i32>>,
-        dst: Option<crate::prelude::Rect<i32>>,
+        texture_id: TextureId,
+        src: Option<Rect<i32>>,
+        dst: Option<Rect<i32>>,
         angle: f64,
-        center: Option<crate::prelude::Point<i32>>,
-        flipped: Option<crate::prelude::Flipped>,
-        tint: Option<crate::prelude::Color>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+        center: Option<Point<i32>>,
+        flipped: Option<Flipped>,
+        tint: Option<Color>,
+    ) -> Result<()> {
+        if self.texture_target == Some(texture_id) {
+            return Err(anyhow!(Error::Renderer(format!(
+                "can't draw texture {texture_id} to itself"
+            ))));
+        }
+        let surface = self.texture_surface(texture_id)?;
+        self.draw_surface(surface, src, dst, angle, center, flipped, tint)
     }
 
-    fn texture_target(&self) -> Option<crate::prelude::TextureId> {
-        todo!()
+    /// Returns texture used as the target for drawing operations, if set.
+    #[inline]
+    fn texture_target(&self) -> Option<TextureId> {
+        self.texture_target
     }
 
-    fn set_texture_target(&mut self, texture_id: crate::prelude::TextureId) {
-        todo!()
+    /// Set a `Texture` as the primary target for drawing operations instead of the window
+    /// target canvas.
+    ///
+    /// # Errors
+    ///
+    /// If the texture has been dropped or is invalid, then an error is returned.
+    #[inline]
+    fn set_texture_target(&mut self, id: TextureId) -> Result<()> {
+        self.texture_surface(id)?;
+        self.texture_target = Some(id);
+        self.reset_context()
     }
 
+    /// Clear `Texture` target back to the window target canvas for drawing operations.
+    #[inline]
     fn clear_texture_target(&mut self) {
-        todo!()
+        self.texture_target = None;
+        if let Err(err) = self.reset_context() {
+            error!("Failed to reset window target: {}", err);
+        }
     }
 
-    fn has_texture_target(&self) -> bool {
-        todo!()
+    /// Push the current target to a stack and set a `Texture` as the target for drawing
+    /// operations.
+    ///
+    /// # Errors
+    ///
+    /// If the texture has been dropped or is invalid, then an error is returned.
+    #[inline]
+    fn push_texture_target(&mut self, id: TextureId) -> Result<()> {
+        let target = self.texture_target;
+        self.set_texture_target(id)?;
+        self.texture_target_stack.push(target);
+        Ok(())
     }
 
-    fn clear_texture_cache(&mut self) {
-        todo!()
+    /// Restore the previously pushed target for drawing operations. Returns `false` if the stack
+    /// is empty.
+    #[inline]
+    fn pop_texture_target(&mut self) -> bool {
+        if let Some(target) = self.texture_target_stack.pop() {
+            self.texture_target = target;
+            if let Err(err) = self.reset_context() {
+                error!("Failed to reset texture target: {}", err);
+            }
+            true
+        } else {
+            false
+        }
     }
+
+    /// Returns whether a texture is set as the target for drawing operations.
+    #[inline]
+    fn has_texture_target(&self) -> bool {
+        self.texture_target.is_some()
+    }
+
+    /// Images and text are drawn directly to the canvas, so there is no cache to clear.
+    #[inline]
+    fn clear_texture_cache(&mut self) {}
 }
@@ -1,102 +1,373 @@
-use super::Renderer;
-use crate::renderer::WindowRenderer;
+//! Web window functions.
+
+use super::{document, js_err, unsupported, window, Renderer, Surface, WINDOW_ID};
+use crate::{
+    error::{Error, Result},
+    prelude::*,
+    renderer::{RendererSettings, WindowRenderer},
+    window::{constrain_dimensions, HitTestCallback},
+};
+use anyhow::anyhow;
 
 impl WindowRenderer for Renderer {
+    /// Get the count of open windows. The canvas is the only window.
+    #[inline]
     fn window_count(&self) -> usize {
-        todo!()
+        1
+    }
+
+    /// Get the primary window ID.
+    #[inline]
+    fn primary_window_id(&self) -> WindowId {
+        WINDOW_ID
+    }
+
+    /// Get the current window target ID.
+    #[inline]
+    fn window_id(&self) -> WindowId {
+        WINDOW_ID
+    }
+
+    /// Browsers only support rendering to a single canvas.
+    #[inline]
+    fn create_window(&mut self, _s: &mut RendererSettings) -> Result<WindowId> {
+        Err(unsupported("opening multiple windows"))
+    }
+
+    /// Close a window. Closing the canvas window quits the application.
+    #[inline]
+    fn close_window(&mut self, id: WindowId) -> Result<()> {
+        if id != WINDOW_ID {
+            return Err(Error::InvalidWindow(id).into());
+        }
+        self.events.borrow_mut().push_back(Event::Quit);
+        Ok(())
     }
 
-    fn window_id(&self) -> crate::prelude::WindowId {
-        todo!()
+    /// Set the mouse cursor to a predefined symbol, or hides cursor if `None`.
+    #[inline]
+    fn cursor(&mut self, cursor: Option<&Cursor>) -> Result<()> {
+        let image_css;
+        let css = match cursor {
+            Some(Cursor::System(cursor)) => match cursor {
+                SystemCursor::Arrow => "default",
+                SystemCursor::IBeam => "text",
+                SystemCursor::Wait => "wait",
+                SystemCursor::Crosshair => "crosshair",
+                SystemCursor::WaitArrow => "progress",
+                SystemCursor::SizeNWSE => "nwse-resize",
+                SystemCursor::SizeNESW => "nesw-resize",
+                SystemCursor::SizeWE => "ew-resize",
+                SystemCursor::SizeNS => "ns-resize",
+                SystemCursor::SizeAll => "move",
+                SystemCursor::No => "not-allowed",
+                SystemCursor::Hand => "pointer",
+            },
+            Some(Cursor::Pixels(img, (x, y))) => {
+                let url = Surface::from_image(img)?
+                    .canvas
+                    .to_data_url()
+                    .map_err(js_err)?;
+                image_css = format!("url({url}) {x} {y}, auto");
+                &image_css
+            }
+            None => "none",
+        };
+        self.surface
+            .canvas
+            .style()
+            .set_property("cursor", css)
+            .map_err(js_err)
     }
 
-    fn create_window(
-        &mut self,
-        s: &mut crate::renderer::RendererSettings,
-    ) -> crate::prelude::Result<crate::prelude::WindowId> {
-        todo!()
+    /// Returns whether the mouse cursor is locked to the canvas.
+    #[inline]
+    fn cursor_confined(&self) -> bool {
+        self.cursor_confined
     }
 
-    fn close_window(&mut self, id: crate::prelude::WindowId) -> crate::prelude::Result<()> {
-        todo!()
+    /// Lock the mouse cursor to the canvas using the pointer lock API. Browsers only grant
+    /// pointer lock in response to user input, such as a mouse click.
+    #[inline]
+    fn confine_cursor(&mut self, confine: bool) -> Result<()> {
+        if confine {
+            self.surface.canvas.request_pointer_lock();
+        } else {
+            document()?.exit_pointer_lock();
+        }
+        self.cursor_confined = confine;
+        Ok(())
     }
 
-    fn cursor(&mut self, cursor: Option<&crate::prelude::Cursor>) -> crate::prelude::Result<()> {
-        todo!()
+    /// Browsers don't allow moving the mouse cursor.
+    #[inline]
+    fn set_mouse_pos(&mut self, _pos: Point<i32>) -> Result<()> {
+        Err(unsupported("setting the mouse position"))
     }
 
-    fn poll_event(&mut self) -> Option<crate::event::Event> {
-        todo!()
+    /// Returns a single event or None if the event pump is empty. Mouse positions are converted
+    /// to the scaled drawing coordinates, matching SDL.
+    #[inline]
+    fn poll_event(&mut self) -> Option<Event> {
+        let mut event = self.events.borrow_mut().pop_front()?;
+        let (sx, sy) = self.scale;
+        let unscale = |v: &mut i32, scale: f32| *v = (*v as f32 / scale) as i32;
+        match &mut event {
+            Event::MouseMotion {
+                x, y, xrel, yrel, ..
+            } => {
+                unscale(x, sx);
+                unscale(y, sy);
+                unscale(xrel, sx);
+                unscale(yrel, sy);
+            }
+            Event::MouseDown { x, y, .. } | Event::MouseUp { x, y, .. } => {
+                unscale(x, sx);
+                unscale(y, sy);
+            }
+            _ => (),
+        }
+        Some(event)
     }
 
+    /// Get the current window title.
+    #[inline]
     fn title(&self) -> &str {
-        todo!()
+        &self.settings.title
     }
 
-    fn set_title(&mut self, title: &str) -> crate::prelude::Result<()> {
-        todo!()
+    /// Set the current document title.
+    #[inline]
+    fn set_title(&mut self, title: &str) -> Result<()> {
+        self.settings.title.replace_range(.., title);
+        document()?.set_title(title);
+        Ok(())
     }
 
-    fn set_fps(&mut self, fps: f32) -> crate::prelude::Result<()> {
-        todo!()
+    #[inline]
+    fn set_fps(&mut self, fps: f32) -> Result<()> {
+        self.title = format!("{} - FPS: {:.02}", &self.settings.title, fps);
+        document()?.set_title(&self.title);
+        Ok(())
     }
 
-    fn dimensions(&self) -> crate::prelude::Result<(u32, u32)> {
-        todo!()
+    /// Dimensions of the current render target as `(width, height)`.
+    #[inline]
+    fn dimensions(&self) -> Result<(u32, u32)> {
+        Ok(self.surface()?.dimensions())
     }
 
-    fn window_dimensions(&self) -> crate::prelude::Result<(u32, u32)> {
-        todo!()
+    /// Dimensions of the canvas as `(width, height)`.
+    #[inline]
+    fn window_dimensions(&self) -> Result<(u32, u32)> {
+        Ok(self.surface.dimensions())
     }
 
-    fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> crate::prelude::Result<()> {
-        todo!()
+    /// Position of the canvas relative to the page viewport as `(x, y)`.
+    #[inline]
+    fn window_position(&self) -> Result<(i32, i32)> {
+        let rect = self.surface.canvas.get_bounding_client_rect();
+        Ok((rect.left() as i32, rect.top() as i32))
     }
 
-    fn viewport(&self) -> crate::prelude::Result<crate::prelude::Rect<i32>> {
-        todo!()
+    /// The canvas is positioned by the page layout, so it can't be moved.
+    #[inline]
+    fn set_window_position(&mut self, _position: (i32, i32)) -> Result<()> {
+        Err(unsupported("moving windows"))
     }
 
-    fn set_viewport(
-        &mut self,
-        rect: Option<crate::prelude::Rect<i32>>,
-    ) -> crate::prelude::Result<()> {
-        todo!()
+    /// The canvas can't be moved or resized by dragging.
+    #[inline]
+    fn set_window_hit_test(&mut self, _callback: Option<HitTestCallback>) -> Result<()> {
+        Err(unsupported("window hit testing"))
     }
 
-    fn display_dimensions(&self) -> crate::prelude::Result<(u32, u32)> {
-        todo!()
+    /// Resize the canvas, constrained by its minimum and maximum dimensions.
+    #[inline]
+    fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> Result<(u32, u32)> {
+        let (width, height) =
+            constrain_dimensions(dimensions, self.settings.min_size, self.settings.max_size);
+        self.settings.width = width;
+        self.settings.height = height;
+        // Resizing a canvas resets its context, including the saved state
+        self.surface.canvas.set_width(width);
+        self.surface.canvas.set_height(height);
+        self.surface.context.save();
+        self.reset_context()?;
+        Ok((width, height))
     }
 
-    fn fullscreen(&self) -> crate::prelude::Result<bool> {
-        todo!()
+    /// Set minimum dimensions of the canvas as `(width, height)`.
+    #[inline]
+    fn set_window_min_size(&mut self, size: (u32, u32)) -> Result<()> {
+        self.settings.min_size = Some(size);
+        let (width, height) = self.surface.dimensions();
+        self.set_window_dimensions((width, height))?;
+        Ok(())
     }
 
-    fn set_fullscreen(&mut self, val: bool) -> crate::prelude::Result<()> {
-        todo!()
+    /// Set maximum dimensions of the canvas as `(width, height)`.
+    #[inline]
+    fn set_window_max_size(&mut self, size: (u32, u32)) -> Result<()> {
+        self.settings.max_size = Some(size);
+        let (width, height) = self.surface.dimensions();
+        self.set_window_dimensions((width, height))?;
+        Ok(())
     }
 
-    fn vsync(&self) -> bool {
-        todo!()
+    /// Opacity of the canvas.
+    #[inline]
+    fn window_opacity(&self) -> Result<f32> {
+        Ok(self.settings.opacity)
+    }
+
+    /// Set the opacity of the canvas.
+    #[inline]
+    fn set_window_opacity(&mut self, opacity: f32) -> Result<()> {
+        self.settings.opacity = opacity;
+        self.surface
+            .canvas
+            .style()
+            .set_property("opacity", &opacity.to_string())
+            .map_err(js_err)
+    }
+
+    /// Focus the canvas.
+    #[inline]
+    fn raise_window(&mut self) -> Result<()> {
+        self.surface.canvas.focus().map_err(js_err)
+    }
+
+    /// Browsers don't allow minimizing the page.
+    #[inline]
+    fn minimize_window(&mut self) -> Result<()> {
+        Err(unsupported("minimizing windows"))
+    }
+
+    /// Browsers don't allow maximizing the page.
+    #[inline]
+    fn maximize_window(&mut self) -> Result<()> {
+        Err(unsupported("maximizing windows"))
     }
 
-    fn set_vsync(&mut self, val: bool) -> crate::prelude::Result<()> {
-        todo!()
+    /// Browsers don't allow restoring the page.
+    #[inline]
+    fn restore_window(&mut self) -> Result<()> {
+        Err(unsupported("restoring windows"))
     }
 
-    fn set_window_target(&mut self, id: crate::prelude::WindowId) -> crate::prelude::Result<()> {
-        todo!()
+    /// Returns the rendering viewport of the canvas.
+    #[inline]
+    fn viewport(&self) -> Result<Rect<i32>> {
+        Ok(self.viewport.unwrap_or_else(|| self.surface.bounds()))
     }
 
-    fn reset_window_target(&mut self) {
-        todo!()
+    /// Set the rendering viewport of the canvas.
+    #[inline]
+    fn set_viewport(&mut self, rect: Option<Rect<i32>>) -> Result<()> {
+        self.viewport = rect;
+        self.reset_context()
     }
 
-    fn show(&mut self) -> crate::prelude::Result<()> {
-        todo!()
+    /// Dimensions of the screen as `(width, height)`.
+    #[inline]
+    fn display_dimensions(&self) -> Result<(u32, u32)> {
+        let screen = window()?.screen().map_err(js_err)?;
+        let width = screen.avail_width().map_err(js_err)?;
+        let height = screen.avail_height().map_err(js_err)?;
+        Ok((width as u32, height as u32))
+    }
+
+    /// Number of connected displays. Browsers only expose the current screen.
+    #[inline]
+    fn display_count(&self) -> Result<u32> {
+        Ok(1)
+    }
+
+    /// Information about the current screen.
+    fn display_info(&self, index: u32) -> Result<DisplayInfo> {
+        if index != 0 {
+            return Err(Error::InvalidDisplay(index).into());
+        }
+        let screen = window()?.screen().map_err(js_err)?;
+        let width = screen.width().map_err(js_err)?;
+        let height = screen.height().map_err(js_err)?;
+        Ok(DisplayInfo {
+            name: "screen".into(),
+            bounds: rect![0, 0, width, height],
+            modes: vec![DisplayMode::new(width as u32, height as u32, 0)],
+        })
+    }
+
+    /// Returns whether the canvas is fullscreen or not.
+    #[inline]
+    fn fullscreen(&self) -> Result<bool> {
+        Ok(document()?.fullscreen_element().is_some())
+    }
+
+    /// Set the canvas to fullscreen or not. Browsers only allow entering fullscreen in response
+    /// to user input, such as a key press.
+    #[inline]
+    fn set_fullscreen(&mut self, val: bool) -> Result<()> {
+        self.settings.fullscreen = val;
+        if val {
+            self.surface.canvas.request_fullscreen().map_err(js_err)
+        } else {
+            document()?.exit_fullscreen();
+            Ok(())
+        }
+    }
+
+    /// Browsers synchronize animation frames with the display, so vsync is always enabled.
+    #[inline]
+    fn vsync(&self) -> bool {
+        true
+    }
+
+    /// Browsers synchronize animation frames with the display, so vsync can't be disabled.
+    #[inline]
+    fn set_vsync(&mut self, val: bool) -> Result<WindowId> {
+        if val {
+            Ok(WINDOW_ID)
+        } else {
+            Err(unsupported("disabling vsync"))
+        }
+    }
+
+    /// Set the canvas as the target for drawing operations.
+    #[inline]
+    fn set_window_target(&mut self, id: WindowId) -> Result<()> {
+        if id == WINDOW_ID {
+            Ok(())
+        } else {
+            Err(anyhow!(Error::InvalidWindow(id)))
+        }
+    }
+
+    /// The canvas is always the window target.
+    #[inline]
+    fn reset_window_target(&mut self) {}
+
+    /// Show the canvas.
+    #[inline]
+    fn show(&mut self) -> Result<()> {
+        self.settings.hidden = false;
+        self.surface
+            .canvas
+            .style()
+            .remove_property("display")
+            .map_err(js_err)?;
+        Ok(())
     }
 
-    fn hide(&mut self) -> crate::prelude::Result<()> {
-        todo!()
+    /// Hide the canvas.
+    #[inline]
+    fn hide(&mut self) -> Result<()> {
+        self.settings.hidden = true;
+        self.surface
+            .canvas
+            .style()
+            .set_property("display", "none")
+            .map_err(js_err)
     }
 }
@@ -11,13 +11,19 @@
 //!
 //! - [`PixState::point`]: Draw a [Point] to the current canvas.
 //! - [`PixState::line`]: Draw a [Line] to the current canvas.
+//! - [`PixState::draw_thick_line`]: Draw a [Line] of a given thickness to the current canvas.
+//! - [`PixState::draw_arrow`]: Draw an arrow from one [Point] to another to the current canvas.
+//! - [`PixState::draw_grid`]: Draw a grid of evenly-spaced lines within a [Rect] to the current
+//!   canvas.
+//! - [`PixState::draw_grid_world`]: Draw a grid of world space cells visible within a [Rect] to
+//!   the current canvas.
 //! - [`PixState::triangle`]: Draw a [Triangle][Tri] to the current canvas.
 //! - [`PixState::square`]: Draw a square [Rect] to the current canvas.
 //! - [`PixState::rounded_square`]: Draw a square [Rect] with rounded corners to the current canvas.
 //! - [`PixState::rect`]: Draw a [Rect] to the current canvas.
 //! - [`PixState::rounded_rect`]: Draw a [Rect] with rounded corners to the current canvas.
 //! - [`PixState::quad`]: Draw a [Quad] to the current canvas.
-//! - [`PixState::polygon`]: Draw a polygon defined by a set of [Point]s to the current canvas.
+//! - [`PixState::polygon`]: Draw a [Polygon] defined by a set of [Point]s to the current canvas.
 //! - [`PixState::wireframe`]: Draw a wireframe defined by a set vertexes to the current canvas.
 //! - [`PixState::circle`]: Draw a circle [Ellipse] to the current canvas.
 //! - [`PixState::ellipse`]: Draw an [Ellipse] to the current canvas.
@@ -27,7 +33,8 @@
 //! [`intersects`]: Intersects::intersects
 
 use crate::{prelude::*, renderer::Rendering};
-use std::iter::Iterator;
+use anyhow::anyhow;
+use std::{iter::Iterator, ops::RangeInclusive};
 
 #[macro_use]
 pub mod ellipse;
@@ -37,6 +44,7 @@ pub mod line;
 pub mod point;
 #[macro_use]
 pub mod rect;
+pub mod polygon;
 #[macro_use]
 pub mod quad;
 #[macro_use]
@@ -51,6 +59,8 @@ pub use line::*;
 #[doc(inline)]
 pub use point::*;
 #[doc(inline)]
+pub use polygon::*;
+#[doc(inline)]
 pub use quad::*;
 #[doc(inline)]
 pub use rect::*;
@@ -100,11 +110,43 @@ impl PixState {
         P: Into<Point<i32>>,
     {
         if let Some(stroke) = self.settings.stroke {
-            self.renderer.point(p.into(), stroke)?;
+            let p = transform(self.view_transform(), p);
+            self.renderer.point(p, stroke)?;
         }
         Ok(())
     }
 
+    /// Set a single pixel on the current canvas to `color`, composited with the existing pixel
+    /// using the current [`PixState::blend_mode`]. With [`BlendMode::Blend`], a partially
+    /// transparent `color` blends with what's already drawn instead of overwriting it.
+    /// [`PixState::stroke`] and [`PixState::fill`] have no effect.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.blend_mode(BlendMode::Blend);
+    ///     // Soft brush blending with the background
+    ///     s.set_pixel(s.mouse_pos(), Color::rgba(255, 0, 0, 128))?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn set_pixel<P>(&mut self, pos: P, color: Color) -> PixResult<()>
+    where
+        P: Into<Point<i32>>,
+    {
+        let pos = transform(self.view_transform(), pos);
+        self.renderer.pixel(pos, color)
+    }
+
     /// Draw a [Line] to the current canvas. [`PixState::stroke`] controls whether the line is drawn
     /// or not. [`PixState::stroke_weight`] controls the line thickness. [`PixState::fill`] has no
     /// effect.
@@ -132,8 +174,185 @@ impl PixState {
     {
         let s = &self.settings;
         if let Some(stroke) = s.stroke {
+            let view = self.view_transform();
+            let [start, end] = line.into().points().map(|p| transform(view, p));
+            self.renderer.line(
+                Line::new(start, end),
+                s.smooth,
+                s.stroke_weight as u8,
+                stroke,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draw a line from `p1` to `p2` that is `thickness` pixels wide to the current canvas,
+    /// regardless of [`PixState::stroke_weight`]. [`PixState::stroke`] controls whether the line
+    /// is drawn or not. A `thickness` of `0` draws nothing and thicknesses are capped at `255`.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.stroke(Color::RED);
+    ///     s.draw_thick_line(s.pmouse_pos(), s.mouse_pos(), 8)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn draw_thick_line<P>(&mut self, p1: P, p2: P, thickness: u32) -> PixResult<()>
+    where
+        P: Into<Point<i32>>,
+    {
+        let s = &self.settings;
+        let width = thickness.min(u32::from(u8::MAX)) as u8;
+        if let (Some(stroke), 1..) = (s.stroke, width) {
+            let view = self.view_transform();
+            let [start, end] = [p1, p2].map(|p| transform(view, p));
             self.renderer
-                .line(line.into(), s.smooth, s.stroke_weight as u8, stroke)?;
+                .line(Line::new(start, end), s.smooth, width, stroke)?;
+        }
+        Ok(())
+    }
+
+    /// Draw an arrow from `from` to `to` to the current canvas. [`PixState::stroke`] controls
+    /// whether the shaft is drawn and [`PixState::fill`] controls whether the head is drawn. The
+    /// head is a triangle `head_size` pixels long and wide, pointing at `to`.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.stroke(Color::WHITE);
+    ///     s.fill(Color::RED);
+    ///     s.draw_arrow(point![100, 100], s.mouse_pos(), 12)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn draw_arrow<P>(&mut self, from: P, to: P, head_size: u32) -> PixResult<()>
+    where
+        P: Into<Point<i32>>,
+    {
+        let (from, to) = (from.into(), to.into());
+        let [base, left, right] = arrow_head(from, to, head_size);
+        let s = &self.settings;
+        let view = self.view_transform();
+        if let Some(stroke) = s.stroke {
+            self.renderer.line(
+                Line::new(transform(view, from), transform(view, base)),
+                s.smooth,
+                s.stroke_weight as u8,
+                stroke,
+            )?;
+        }
+        if s.fill.is_some() && from != to {
+            let [p1, p2, p3] = [to, left, right].map(|p| transform(view, p));
+            self.renderer
+                .triangle(Tri::new(p1, p2, p3), s.smooth, s.fill, None)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a grid of `cols` by `rows` evenly-spaced cells within `rect` to the current canvas,
+    /// including the outer edges. [`PixState::stroke`] and [`PixState::stroke_weight`] control how
+    /// the lines are drawn. [`RectMode`] controls how the `(x, y)` position is interpreted.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.stroke(Color::DARK_GRAY);
+    ///     s.draw_grid([0, 0, 400, 300], 8, 6)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn draw_grid<R>(&mut self, rect: R, cols: u32, rows: u32) -> PixResult<()>
+    where
+        R: Into<Rect<i32>>,
+    {
+        let rect = self.get_rect(rect);
+        let (left, top) = (i64::from(rect.left()), i64::from(rect.top()));
+        let (width, height) = (i64::from(rect.width()), i64::from(rect.height()));
+        for col in 0..=i64::from(cols) {
+            let x = (left + width * col / i64::from(cols.max(1))) as i32;
+            self.line([x, rect.top(), x, rect.bottom()])?;
+        }
+        for row in 0..=i64::from(rows) {
+            let y = (top + height * row / i64::from(rows.max(1))) as i32;
+            self.line([rect.left(), y, rect.right(), y])?;
+        }
+        Ok(())
+    }
+
+    /// Draw a grid of square cells `cell_size` wide, aligned to `origin`, in world space to the
+    /// current canvas. Only lines within `view` are drawn, so `view` is typically the visible
+    /// area of the current [`Camera2D`]. [`PixState::stroke`] and [`PixState::stroke_weight`]
+    /// control how the lines are drawn.
+    ///
+    /// # Errors
+    ///
+    /// If `cell_size` is not positive, or the renderer fails to draw to the current render target,
+    /// then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { camera: Camera2D };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.set_camera(self.camera);
+    ///     let top_left = self.camera.screen_to_world([0.0, 0.0]);
+    ///     let (width, height) = s.dimensions()?;
+    ///     let bottom_right = self.camera.screen_to_world([width as f64, height as f64]);
+    ///     let view = Rect::with_points(top_left, bottom_right);
+    ///     s.stroke(Color::DARK_GRAY);
+    ///     s.draw_grid_world([0.0, 0.0], 32.0, view)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn draw_grid_world<P, R>(&mut self, origin: P, cell_size: f64, view: R) -> PixResult<()>
+    where
+        P: Into<Point<f64>>,
+        R: Into<Rect<f64>>,
+    {
+        if !(cell_size.is_finite() && cell_size > 0.0) {
+            return Err(anyhow!("invalid grid cell size: {cell_size}"));
+        }
+        let (origin, view) = (origin.into(), view.into());
+        let (top, bottom) = (view.top().round() as i32, view.bottom().round() as i32);
+        for col in grid_cells(origin.x(), cell_size, view.left(), view.right()) {
+            let x = cell_size.mul_add(col as f64, origin.x()).round() as i32;
+            self.line([x, top, x, bottom])?;
+        }
+        let (left, right) = (view.left().round() as i32, view.right().round() as i32);
+        for row in grid_cells(origin.y(), cell_size, view.top(), view.bottom()) {
+            let y = cell_size.mul_add(row as f64, origin.y()).round() as i32;
+            self.line([left, y, right, y])?;
         }
         Ok(())
     }
@@ -168,8 +387,9 @@ impl PixState {
         I: IntoIterator<Item = P>,
     {
         let s = &self.settings;
+        let view = self.view_transform();
         self.renderer.bezier(
-            points.into_iter().map(Into::into),
+            points.into_iter().map(|p| transform(view, p)),
             s.bezier_detail,
             s.stroke,
         )
@@ -201,8 +421,10 @@ impl PixState {
         T: Into<Tri<i32>>,
     {
         let s = &self.settings;
+        let view = self.view_transform();
+        let [p1, p2, p3] = tri.into().points().map(|p| transform(view, p));
         self.renderer
-            .triangle(tri.into(), s.smooth, s.fill, s.stroke)
+            .triangle(Tri::new(p1, p2, p3), s.smooth, s.fill, s.stroke)
     }
 
     /// Draw a square [Rect] to the current canvas. [`PixState::fill`] and [`PixState::stroke`] control
@@ -297,7 +519,17 @@ impl PixState {
     {
         let s = &self.settings;
         let rect = self.get_rect(rect);
-        self.renderer.rect(rect, None, s.fill, s.stroke)
+        match self.view_transform() {
+            Some(view) if !view.is_axis_aligned() => {
+                let quad = view.quad(rect);
+                self.renderer.quad(quad, s.smooth, s.fill, s.stroke)
+            }
+            Some(view) => {
+                let rect = view.rect(rect);
+                self.renderer.rect(rect, None, s.fill, s.stroke)
+            }
+            None => self.renderer.rect(rect, None, s.fill, s.stroke),
+        }
     }
 
     /// Draw a rounded [Rectangle](Rect) to the current canvas. [`PixState::fill`] and
@@ -328,7 +560,18 @@ impl PixState {
     {
         let s = &self.settings;
         let rect = self.get_rect(rect);
-        self.renderer.rect(rect, Some(radius), s.fill, s.stroke)
+        match self.view_transform() {
+            Some(view) if !view.is_axis_aligned() => {
+                let quad = view.quad(rect);
+                self.renderer.quad(quad, s.smooth, s.fill, s.stroke)
+            }
+            Some(view) => {
+                let rect = view.rect(rect);
+                let radius = view.length(radius);
+                self.renderer.rect(rect, Some(radius), s.fill, s.stroke)
+            }
+            None => self.renderer.rect(rect, Some(radius), s.fill, s.stroke),
+        }
     }
 
     /// Draw a [Quadrilateral](Quad) to the current canvas. [`PixState::fill`] and
@@ -358,11 +601,15 @@ impl PixState {
         Q: Into<Quad<i32>>,
     {
         let s = &self.settings;
-        self.renderer.quad(quad.into(), s.smooth, s.fill, s.stroke)
+        let view = self.view_transform();
+        let [p1, p2, p3, p4] = quad.into().points().map(|p| transform(view, p));
+        self.renderer
+            .quad(Quad::new(p1, p2, p3, p4), s.smooth, s.fill, s.stroke)
     }
 
     /// Draw a polygon to the current canvas. [`PixState::fill`] and [`PixState::stroke`] control
-    /// whether the polygon is filled or outlined. [`RectMode`] has no effect.
+    /// whether the polygon is filled or outlined. [`RectMode`] has no effect. Accepts a [Polygon]
+    /// or any list of [Point]s.
     ///
     /// # Errors
     ///
@@ -388,8 +635,9 @@ impl PixState {
         I: IntoIterator<Item = P>,
     {
         let s = &self.settings;
+        let view = self.view_transform();
         self.renderer.polygon(
-            points.into_iter().map(Into::into),
+            points.into_iter().map(|p| transform(view, p)),
             s.smooth,
             s.fill,
             s.stroke,
@@ -517,7 +765,22 @@ impl PixState {
         E: Into<Ellipse<i32>>,
     {
         let s = &self.settings;
-        let ellipse = self.get_ellipse(ellipse);
+        let mut ellipse = self.get_ellipse(ellipse);
+        if let Some(view) = self.view_transform() {
+            let (sx, sy) = view.scale_factors();
+            let is_circle = ellipse.width() == ellipse.height() && (sx - sy).abs() < f64::EPSILON;
+            if !view.is_axis_aligned() && !is_circle {
+                let points = ellipse_points(ellipse, view);
+                return self.renderer.polygon(points, s.smooth, s.fill, s.stroke);
+            }
+            let center = view.point(ellipse.center());
+            ellipse = Ellipse::new(
+                center.x(),
+                center.y(),
+                (f64::from(ellipse.width()) * sx).round() as i32,
+                (f64::from(ellipse.height()) * sy).round() as i32,
+            );
+        }
         self.renderer.ellipse(ellipse, s.smooth, s.fill, s.stroke)
     }
 
@@ -549,8 +812,94 @@ impl PixState {
         P: Into<Point<i32>>,
     {
         let s = &self.settings;
-        let p = p.into();
+        let (p, radius, start, end) = match self.view_transform() {
+            Some(view) => {
+                let rotation = view.angle().to_degrees().round() as i32;
+                let p = view.point(p.into());
+                (p, view.length(radius), start + rotation, end + rotation)
+            }
+            None => (p.into(), radius, start, end),
+        };
         self.renderer
             .arc(p, radius, start, end, s.arc_mode, s.fill, s.stroke)
     }
 }
+
+/// Transform a position using an optional view [`Transform`].
+#[inline]
+fn transform<P: Into<Point<i32>>>(view: Option<Transform>, p: P) -> Point<i32> {
+    let p = p.into();
+    view.map_or(p, |view| view.point(p))
+}
+
+/// Returns the range of grid line indexes, spaced `cell_size` apart from `origin`, that lie
+/// within `min..=max`.
+fn grid_cells(origin: f64, cell_size: f64, min: f64, max: f64) -> RangeInclusive<i64> {
+    let first = ((min - origin) / cell_size).ceil() as i64;
+    let last = ((max - origin) / cell_size).floor() as i64;
+    first..=last
+}
+
+/// Returns the base center and two back corners of an arrow head `size` pixels long and wide,
+/// pointing from `from` to `to`. The head is clamped to the length of the arrow.
+fn arrow_head(from: Point<i32>, to: Point<i32>, size: u32) -> [Point<i32>; 3] {
+    let (dx, dy) = (f64::from(to.x() - from.x()), f64::from(to.y() - from.y()));
+    let len = dx.hypot(dy);
+    if len == 0.0 {
+        return [to; 3];
+    }
+    let size = f64::from(size).min(len);
+    let (ux, uy) = (dx / len, dy / len);
+    let (bx, by) = (f64::from(to.x()) - ux * size, f64::from(to.y()) - uy * size);
+    let (px, py) = (-uy * size / 2.0, ux * size / 2.0);
+    let round = |x: f64, y: f64| point![x.round() as i32, y.round() as i32];
+    [
+        round(bx, by),
+        round(bx + px, by + py),
+        round(bx - px, by - py),
+    ]
+}
+
+/// Returns transformed points along the outline of an [Ellipse] for drawing it as a polygon.
+fn ellipse_points(ellipse: Ellipse<i32>, view: Transform) -> impl Iterator<Item = Point<i32>> {
+    const SEGMENTS: u16 = 64;
+    let center = ellipse.center();
+    let (cx, cy) = (f64::from(center.x()), f64::from(center.y()));
+    let rx = f64::from(ellipse.width()) / 2.0;
+    let ry = f64::from(ellipse.height()) / 2.0;
+    (0..SEGMENTS).map(move |i| {
+        let (sin, cos) = (f64::from(i) * std::f64::consts::TAU / f64::from(SEGMENTS)).sin_cos();
+        let p = view.apply([rx.mul_add(cos, cx), ry.mul_add(sin, cy)]);
+        point![p.x().round() as i32, p.y().round() as i32]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrow_head_points() {
+        let [base, left, right] = arrow_head(point![0, 0], point![20, 0], 10);
+        assert_eq!(base, point![10, 0]);
+        assert_eq!(left, point![10, 5]);
+        assert_eq!(right, point![10, -5]);
+
+        // Head is clamped to the arrow length
+        let [base, ..] = arrow_head(point![0, 0], point![0, 4], 10);
+        assert_eq!(base, point![0, 0]);
+
+        assert_eq!(
+            arrow_head(point![3, 3], point![3, 3], 10),
+            [point![3, 3]; 3]
+        );
+    }
+
+    #[test]
+    fn grid_cells_culled() {
+        assert_eq!(grid_cells(0.0, 10.0, 0.0, 30.0), 0..=3);
+        assert_eq!(grid_cells(5.0, 10.0, 0.0, 30.0), 0..=2);
+        assert_eq!(grid_cells(0.0, 10.0, -25.0, -5.0), -2..=-1);
+        assert!(grid_cells(0.0, 10.0, 1.0, 9.0).is_empty());
+    }
+}
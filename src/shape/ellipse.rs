@@ -26,7 +26,8 @@
 //! let c = circle!(point![10, 20], 100);
 //! ```
 
-use crate::{error::Result, prelude::*};
+use crate::{error::Result, math::PI, prelude::*};
+use num_traits::AsPrimitive;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -322,6 +323,25 @@ impl<T: Num> Ellipse<T> {
         rect![self.left(), self.top(), self.width(), self.height()]
     }
 
+    /// Returns the area of the ellipse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let c = circle!(0, 0, 10);
+    /// assert!((c.area() - 314.159).abs() < 0.001);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> f64
+    where
+        T: AsPrimitive<f64>,
+    {
+        let width: f64 = self.width().as_();
+        let height: f64 = self.height().as_();
+        PI * width * height / 4.0
+    }
+
     /// Returns `Ellipse` as a [Vec].
     ///
     /// # Example
@@ -419,12 +439,13 @@ impl<T: Num> Ellipse<T> {
 impl<T: Num> Contains<Point<T>> for Ellipse<T> {
     /// Returns whether this ellipse contains a given [Point].
     fn contains(&self, p: Point<T>) -> bool {
-        let px = p.x() - self.x();
-        let py = p.y() - self.y();
+        // Compare against the diameters to avoid truncating integer division
         let two = T::one() + T::one();
-        let rx = self.width() / two;
-        let ry = self.height() / two;
-        (px * px) / (rx * rx) + (py * py) / (ry * ry) <= T::one()
+        let dx = two * (p.x() - self.x());
+        let dy = two * (p.y() - self.y());
+        let w = self.width();
+        let h = self.height();
+        dx * dx * h * h + dy * dy * w * w <= w * w * h * h
     }
 }
 
@@ -439,6 +460,46 @@ impl<T: Num> Contains<Ellipse<T>> for Ellipse<T> {
     }
 }
 
+impl<T: Num> Intersects<Ellipse<T>> for Ellipse<T> {
+    type Result = ();
+
+    /// Returns whether this ellipse intersects with another ellipse.
+    ///
+    /// The result is exact for circles and for ellipses aligned along an axis, otherwise it's
+    /// approximated by the sum of both radii along each axis.
+    fn intersects(&self, ellipse: Ellipse<T>) -> Option<Self::Result> {
+        let two = T::one() + T::one();
+        let dx = two * (self.x() - ellipse.x());
+        let dy = two * (self.y() - ellipse.y());
+        let w = self.width() + ellipse.width();
+        let h = self.height() + ellipse.height();
+        if dx * dx * h * h + dy * dy * w * w <= w * w * h * h {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Num> Intersects<Rect<T>> for Ellipse<T> {
+    type Result = ();
+
+    /// Returns whether this ellipse intersects with a rectangle.
+    fn intersects(&self, rect: Rect<T>) -> Option<Self::Result> {
+        // Closest point on the rectangle to the center of the ellipse
+        let two = T::one() + T::one();
+        let dx = two * (constrain(self.x(), rect.left(), rect.right()) - self.x());
+        let dy = two * (constrain(self.y(), rect.top(), rect.bottom()) - self.y());
+        let w = self.width();
+        let h = self.height();
+        if dx * dx * h * h + dy * dy * w * w <= w * w * h * h {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
 impl Draw for Ellipse<i32> {
     /// Draw `Ellipse` to the current [`PixState`] canvas.
     fn draw(&self, s: &mut PixState) -> Result<()> {
@@ -461,3 +522,62 @@ impl<T: Num> From<&[T; 3]> for Ellipse<T> {
         Self::circle(x, y, r)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_contains_point() {
+        for ellipse in [
+            circle!(50, 50, 20),
+            ellipse!(50, 50, 41, 20),
+            ellipse!(-5, 10, 7, 30),
+        ] {
+            let rx = f64::from(ellipse.width()) / 2.0;
+            let ry = f64::from(ellipse.height()) / 2.0;
+            let bounds = ellipse.bounding_rect();
+            for x in bounds.left() - 2..=bounds.right() + 2 {
+                for y in bounds.top() - 2..=bounds.bottom() + 2 {
+                    let px = f64::from(x - ellipse.x());
+                    let py = f64::from(y - ellipse.y());
+                    let inside = (px * px) / (rx * rx) + (py * py) / (ry * ry) <= 1.0;
+                    assert_eq!(
+                        ellipse.contains(point!(x, y)),
+                        inside,
+                        "{ellipse:?} ({x}, {y})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_intersects_ellipse() {
+        let circle = circle!(50, 50, 20);
+
+        assert!(circle.intersects(circle!(80, 50, 10)).is_some());
+        assert!(circle.intersects(circle!(50, 50, 5)).is_some());
+        assert!(circle.intersects(circle!(80, 80, 10)).is_none());
+        assert!(circle.intersects(circle!(100, 50, 10)).is_none());
+
+        let ellipse = ellipse!(50, 50, 100, 20);
+        assert!(ellipse.intersects(ellipse!(110, 50, 30, 30)).is_some());
+        assert!(ellipse.intersects(ellipse!(50, 80, 30, 30)).is_none());
+    }
+
+    #[test]
+    fn test_intersects_rect() {
+        let circle = circle!(50, 50, 20);
+
+        assert!(circle.intersects(rect!(60, 40, 50, 20)).is_some());
+        assert!(circle.intersects(rect!(0, 0, 100, 100)).is_some());
+        assert!(circle.intersects(rect!(45, 45, 10, 10)).is_some());
+        assert!(rect!(60, 40, 50, 20).intersects(circle).is_some());
+
+        // Near the corner, but outside the radius
+        assert!(circle.intersects(rect!(66, 66, 20, 20)).is_none());
+        assert!(circle.intersects(rect!(80, 40, 20, 20)).is_none());
+        assert!(rect!(80, 40, 20, 20).intersects(circle).is_none());
+    }
+}
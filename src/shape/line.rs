@@ -118,6 +118,24 @@ impl<T: Copy> Line<T> {
     }
 }
 
+impl<T: Num> Line<T> {
+    /// Returns the bounding [Rect] of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let l = Line::from_xy(100, 10, 5, 50);
+    /// assert_eq!(l.bounding_rect(), rect!(5, 10, 95, 40));
+    /// ```
+    pub fn bounding_rect(&self) -> Rect<T> {
+        let [x1, y1, x2, y2] = self.coords();
+        let (left, right) = if x1 < x2 { (x1, x2) } else { (x2, x1) };
+        let (top, bottom) = if y1 < y2 { (y1, y2) } else { (y2, y1) };
+        rect![left, top, right - left, bottom - top]
+    }
+}
+
 impl<T> Line<T, 3> {
     /// Constructs a `Line` from individual x/y/z coordinates.
     #[inline]
@@ -225,6 +243,60 @@ impl<T: Copy, const N: usize> Line<T, N> {
     }
 }
 
+impl<T: Float, const N: usize> Line<T, N> {
+    /// Returns the Euclidean length of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let l: Line<f64> = Line::new([10.0, 10.0], [13.0, 14.0]);
+    /// assert_eq!(l.length(), 5.0);
+    /// ```
+    pub fn length(&self) -> T {
+        self.start().dist(self.end())
+    }
+
+    /// Returns the point halfway between the start and end of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let l: Line<f64> = Line::new([10.0, 10.0], [20.0, 30.0]);
+    /// assert_eq!(l.midpoint(), point!(15.0, 20.0));
+    /// ```
+    pub fn midpoint(&self) -> Point<T, N> {
+        let two = T::one() + T::one();
+        self.start().lerp(self.end(), T::one() / two)
+    }
+
+    /// Returns the point on the line nearest to a given point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let l: Line<f64> = Line::new([0.0, 0.0], [10.0, 0.0]);
+    /// assert_eq!(l.closest_point([4.0, 5.0]), point!(4.0, 0.0));
+    /// assert_eq!(l.closest_point([-3.0, 2.0]), point!(0.0, 0.0));
+    /// assert_eq!(l.closest_point([15.0, -2.0]), point!(10.0, 0.0));
+    /// ```
+    pub fn closest_point<P>(&self, p: P) -> Point<T, N>
+    where
+        P: Into<Point<T, N>>,
+    {
+        let start = self.start();
+        let line = self.end() - start;
+        let len_sq = line.mag_sq();
+        if len_sq == T::zero() {
+            return start;
+        }
+        let t = (p.into() - start).dot(line) / len_sq;
+        start.lerp(self.end(), t)
+    }
+}
+
 impl<T: Float> Intersects<Line<T>> for Line<T> {
     type Result = (Point<T>, T);
 
@@ -0,0 +1,499 @@
+//! A shape type representing polygons used for drawing.
+//!
+//! # Examples
+//!
+//! You can create a [Polygon] using [`Polygon::new`]:
+//!
+//! ```
+//! use pix_engine::prelude::*;
+//!
+//! let polygon = Polygon::new([[10, 10], [50, 20], [70, 30], [60, 50], [10, 50]]);
+//!
+//! let p1 = point!(10, 10);
+//! let p2 = point!(50, 20);
+//! let p3 = point!(10, 50);
+//! let polygon = Polygon::new([p1, p2, p3]);
+//! ```
+
+use crate::{error::Result, prelude::*};
+use num_traits::AsPrimitive;
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::vec;
+
+/// A `Polygon` with any number of [Point]s, connected in order with the last point connected to
+/// the first.
+///
+/// Please see the [module-level documentation] for examples.
+///
+/// [module-level documentation]: crate::shape::polygon
+#[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "T: Serialize + DeserializeOwned"))]
+pub struct Polygon<T = i32>(pub(crate) Vec<Point<T>>);
+
+impl<T> Polygon<T> {
+    /// Constructs a `Polygon` from a list of [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let polygon = Polygon::new([[10, 10], [50, 20], [10, 50]]);
+    /// assert_eq!(polygon.points(), &[point!(10, 10), point!(50, 20), point!(10, 50)]);
+    /// ```
+    pub fn new<P, I>(points: I) -> Self
+    where
+        P: Into<Point<T>>,
+        I: IntoIterator<Item = P>,
+    {
+        Self(points.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the [Point]s of the polygon.
+    #[inline]
+    pub fn points(&self) -> &[Point<T>] {
+        &self.0
+    }
+
+    /// Returns the [Point]s of the polygon as a mutable [Vec].
+    #[inline]
+    pub fn points_mut(&mut self) -> &mut Vec<Point<T>> {
+        &mut self.0
+    }
+
+    /// Returns the number of [Point]s in the polygon.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the polygon has no [Point]s.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `Polygon` as a [Vec].
+    #[inline]
+    #[must_use]
+    pub fn to_vec(self) -> Vec<Point<T>> {
+        self.0
+    }
+}
+
+impl<T: Num> Polygon<T> {
+    /// Returns the bounding [Rect] of the polygon, or `None` if the polygon has no [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let polygon = Polygon::new([[10, 20], [30, 10], [20, 25], [15, 15]]);
+    /// assert_eq!(polygon.bounding_rect(), Some(rect!(10, 10, 20, 15)));
+    /// ```
+    #[must_use]
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        let (first, rest) = self.0.split_first()?;
+        let [mut left, mut top] = first.coords();
+        let [mut right, mut bottom] = first.coords();
+        for p in rest {
+            let [x, y] = p.coords();
+            if x < left {
+                left = x;
+            } else if x > right {
+                right = x;
+            }
+            if y < top {
+                top = y;
+            } else if y > bottom {
+                bottom = y;
+            }
+        }
+        Some(rect![left, top, right - left, bottom - top])
+    }
+
+    /// Returns whether the polygon is convex. Polygons with fewer than 3 distinct [Point]s, with
+    /// all points collinear, or with intersecting edges are not convex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let square = Polygon::new([[0, 0], [10, 0], [10, 10], [0, 10]]);
+    /// assert!(square.is_convex());
+    ///
+    /// let l_shape = Polygon::new([[0, 0], [10, 0], [10, 5], [5, 5], [5, 10], [0, 10]]);
+    /// assert!(!l_shape.is_convex());
+    /// ```
+    #[must_use]
+    pub fn is_convex(&self) -> bool {
+        let points = self.simplified();
+        let len = points.len();
+        if len < 3 {
+            return false;
+        }
+        let zero = T::zero();
+        let mut turn = zero;
+        // A simple convex polygon changes horizontal direction exactly twice, which rules out
+        // polygons with intersecting edges that always turn in the same direction
+        let mut x_flips = 0;
+        let mut x_dir = (0..len)
+            .map(|i| points[(i + 1) % len].x() - points[i].x())
+            .rfind(|&dx| dx != zero)
+            .unwrap_or(zero);
+        for i in 0..len {
+            let [p1, p2, p3] = [points[i], points[(i + 1) % len], points[(i + 2) % len]];
+            let cross = cross(p1, p2, p3);
+            if (cross > zero && turn < zero) || (cross < zero && turn > zero) {
+                return false;
+            }
+            turn = cross;
+            let dx = p2.x() - p1.x();
+            if dx != zero {
+                if (dx > zero && x_dir < zero) || (dx < zero && x_dir > zero) {
+                    x_flips += 1;
+                }
+                x_dir = dx;
+            }
+        }
+        x_flips <= 2
+    }
+
+    /// Returns the [Point]s with duplicate consecutive points and collinear points removed.
+    fn simplified(&self) -> Vec<Point<T>> {
+        let mut points: Vec<Point<T>> = Vec::with_capacity(self.0.len());
+        for &p in &self.0 {
+            if points.last() != Some(&p) {
+                points.push(p);
+            }
+        }
+        while points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        let zero = T::zero();
+        let mut i = 0;
+        while points.len() >= 3 && i < points.len() {
+            let len = points.len();
+            let prev = points[(i + len - 1) % len];
+            let next = points[(i + 1) % len];
+            if cross(prev, points[i], next) == zero {
+                let _ = points.remove(i);
+                i = i.saturating_sub(1);
+            } else {
+                i += 1;
+            }
+        }
+        points
+    }
+
+    /// Splits the polygon into [Triangle][Tri]s using ear clipping, which can be used to fill
+    /// concave polygons. Duplicate consecutive [Point]s and collinear points are skipped, and
+    /// degenerate polygons return no triangles. Polygons with intersecting edges are only
+    /// partially triangulated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let l_shape = Polygon::new([[0, 0], [10, 0], [10, 5], [5, 5], [5, 10], [0, 10]]);
+    /// let triangles = l_shape.triangulate();
+    /// assert_eq!(triangles.len(), 4);
+    /// let area: f64 = triangles.iter().map(Tri::area).sum();
+    /// assert_eq!(area, l_shape.area().abs());
+    /// ```
+    #[must_use]
+    pub fn triangulate(&self) -> Vec<Tri<T>> {
+        let mut points = self.simplified();
+        let mut triangles = Vec::with_capacity(points.len().saturating_sub(2));
+        if points.len() < 3 {
+            return triangles;
+        }
+
+        let zero = T::zero();
+        let orientation = (0..points.len()).fold(zero, |area, i| {
+            let p1 = points[i];
+            let p2 = points[(i + 1) % points.len()];
+            area + p1.x() * p2.y() - p2.x() * p1.y()
+        });
+        let is_ear = |points: &[Point<T>], i: usize| {
+            let len = points.len();
+            let [p1, p2, p3] = [
+                points[(i + len - 1) % len],
+                points[i],
+                points[(i + 1) % len],
+            ];
+            let turn = cross(p1, p2, p3);
+            // Ears turn in the same direction as the polygon winding
+            if (turn > zero) != (orientation > zero) || turn == zero {
+                return false;
+            }
+            let ear = Tri::new(p1, p2, p3);
+            !points
+                .iter()
+                .any(|&p| p != p1 && p != p2 && p != p3 && ear.contains(p))
+        };
+        while points.len() > 3 {
+            match (0..points.len()).find(|&i| is_ear(&points, i)) {
+                Some(i) => {
+                    let len = points.len();
+                    triangles.push(Tri::new(
+                        points[(i + len - 1) % len],
+                        points[i],
+                        points[(i + 1) % len],
+                    ));
+                    let _ = points.remove(i);
+                }
+                // Intersecting edges leave no ears to clip
+                None => return triangles,
+            }
+        }
+        triangles.push(Tri::new(points[0], points[1], points[2]));
+        triangles
+    }
+}
+
+impl<T: Num + AsPrimitive<f64>> Polygon<T> {
+    /// Returns the signed area of the polygon. The area is positive when the [Point]s are ordered
+    /// clockwise on screen, where `y` increases downwards, and negative when ordered
+    /// counter-clockwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let polygon = Polygon::new([[0, 0], [10, 0], [10, 10], [0, 10]]);
+    /// assert_eq!(polygon.area(), 100.0);
+    ///
+    /// let polygon = Polygon::new([[0, 0], [0, 10], [10, 10], [10, 0]]);
+    /// assert_eq!(polygon.area(), -100.0);
+    /// ```
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        let len = self.0.len();
+        (0..len)
+            .map(|i| {
+                let [x1, y1]: [f64; 2] = self.0[i].as_().coords();
+                let [x2, y2]: [f64; 2] = self.0[(i + 1) % len].as_().coords();
+                x1 * y2 - x2 * y1
+            })
+            .sum::<f64>()
+            / 2.0
+    }
+
+    /// Returns the centroid of the polygon, or `None` if the polygon has no [Point]s. Degenerate
+    /// polygons with no area return the average of their [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let polygon = Polygon::new([[0, 0], [10, 0], [10, 10], [0, 10]]);
+    /// assert_eq!(polygon.centroid(), Some(point!(5.0, 5.0)));
+    /// ```
+    #[must_use]
+    pub fn centroid(&self) -> Option<Point<f64>> {
+        let len = self.0.len();
+        if len == 0 {
+            return None;
+        }
+        let area = self.area();
+        if area == 0.0 {
+            let (x, y) = self.0.iter().fold((0.0, 0.0), |(x, y), p| {
+                let [px, py]: [f64; 2] = p.as_().coords();
+                (x + px, y + py)
+            });
+            return Some(point![x / len as f64, y / len as f64]);
+        }
+        let (x, y) = (0..len).fold((0.0, 0.0), |(x, y), i| {
+            let [x1, y1]: [f64; 2] = self.0[i].as_().coords();
+            let [x2, y2]: [f64; 2] = self.0[(i + 1) % len].as_().coords();
+            let cross = x1 * y2 - x2 * y1;
+            (x + (x1 + x2) * cross, y + (y1 + y2) * cross)
+        });
+        Some(point![x / (6.0 * area), y / (6.0 * area)])
+    }
+}
+
+/// Returns the cross product of the edges `p1 -> p2` and `p2 -> p3`.
+fn cross<T: Num>(p1: Point<T>, p2: Point<T>, p3: Point<T>) -> T {
+    (p2.x() - p1.x()) * (p3.y() - p2.y()) - (p2.y() - p1.y()) * (p3.x() - p2.x())
+}
+
+impl<T: Num> Contains<Point<T>> for Polygon<T> {
+    /// Returns whether this polygon contains a given [Point] using the even-odd rule. Points on
+    /// the edges or vertices of the polygon are contained.
+    fn contains(&self, p: Point<T>) -> bool {
+        let len = self.0.len();
+        let zero = T::zero();
+        let mut inside = false;
+        for i in 0..len {
+            let p1 = self.0[i];
+            let p2 = self.0[(i + 1) % len];
+            let cross = (p2.x() - p1.x()) * (p.y() - p1.y()) - (p.x() - p1.x()) * (p2.y() - p1.y());
+            let within = |a: T, b: T, v: T| (a <= v && v <= b) || (b <= v && v <= a);
+            if cross == zero && within(p1.x(), p2.x(), p.x()) && within(p1.y(), p2.y(), p.y()) {
+                return true;
+            }
+            // Cast a ray towards positive x, counting edges crossed
+            if (p1.y() > p.y()) != (p2.y() > p.y())
+                && ((p2.y() > p1.y() && cross > zero) || (p2.y() < p1.y() && cross < zero))
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+impl Draw for Polygon<i32> {
+    /// Draw `Polygon` to the current [`PixState`] canvas.
+    fn draw(&self, s: &mut PixState) -> Result<()> {
+        s.polygon(self.0.iter().copied())
+    }
+}
+
+impl<T> IntoIterator for Polygon<T> {
+    type Item = Point<T>;
+    type IntoIter = vec::IntoIter<Point<T>>;
+
+    /// Owned `Polygon<T>` iterator over `[Point<T>]`.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> FromIterator<Point<T>> for Polygon<T> {
+    /// Converts an iterator of [Point]s into `Polygon<T>`.
+    fn from_iter<I: IntoIterator<Item = Point<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> From<Vec<Point<T>>> for Polygon<T> {
+    /// Converts `Vec<Point<T>>` into `Polygon<T>`.
+    #[inline]
+    fn from(points: Vec<Point<T>>) -> Self {
+        Self(points)
+    }
+}
+
+impl<T: Copy> From<Tri<T>> for Polygon<T> {
+    /// Converts [`Tri<T>`] into `Polygon<T>`.
+    #[inline]
+    fn from(tri: Tri<T>) -> Self {
+        Self(tri.points().to_vec())
+    }
+}
+
+impl<T: Copy> From<Quad<T>> for Polygon<T> {
+    /// Converts [`Quad<T>`] into `Polygon<T>`.
+    #[inline]
+    fn from(quad: Quad<T>) -> Self {
+        Self(quad.points().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn l_shape() -> Polygon {
+        Polygon::new([[0, 0], [10, 0], [10, 5], [5, 5], [5, 10], [0, 10]])
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let polygon = l_shape();
+        assert!(polygon.contains(point!(2, 2)));
+        assert!(polygon.contains(point!(8, 2)));
+        assert!(polygon.contains(point!(2, 8)));
+        assert!(!polygon.contains(point!(8, 8)));
+        assert!(!polygon.contains(point!(-1, 5)));
+        assert!(!polygon.contains(point!(11, 2)));
+
+        // Vertices and edges are contained regardless of winding order
+        let mut reversed = polygon.clone();
+        reversed.points_mut().reverse();
+        for p in polygon.points() {
+            assert!(polygon.contains(*p), "{p:?}");
+            assert!(reversed.contains(*p), "{p:?}");
+        }
+        for p in [
+            point!(5, 0),
+            point!(10, 2),
+            point!(7, 5),
+            point!(5, 7),
+            point!(0, 5),
+        ] {
+            assert!(polygon.contains(p), "{p:?}");
+            assert!(reversed.contains(p), "{p:?}");
+        }
+    }
+
+    #[test]
+    fn test_triangulate() {
+        let polygon = l_shape();
+        let triangles = polygon.triangulate();
+        assert_eq!(triangles.len(), 4);
+        let area: f64 = triangles.iter().map(Tri::area).sum();
+        assert_eq!(area, polygon.area().abs());
+
+        // Every point inside the polygon is inside a triangle, and vice versa
+        for x in -1..=11 {
+            for y in -1..=11 {
+                let p = point!(x, y);
+                let in_triangles = triangles.iter().any(|t| t.contains(p));
+                assert_eq!(polygon.contains(p), in_triangles, "{p:?}");
+            }
+        }
+
+        let mut reversed = polygon;
+        reversed.points_mut().reverse();
+        let area: f64 = reversed.triangulate().iter().map(Tri::area).sum();
+        assert_eq!(area, reversed.area().abs());
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let empty = Polygon::<i32>::default();
+        assert!(empty.triangulate().is_empty());
+        assert!(!empty.contains(point!(0, 0)));
+        assert!(!empty.is_convex());
+        assert_eq!(empty.area(), 0.0);
+        assert_eq!(empty.centroid(), None);
+        assert_eq!(empty.bounding_rect(), None);
+
+        let line = Polygon::new([[0, 0], [10, 0]]);
+        assert!(line.triangulate().is_empty());
+        assert!(line.contains(point!(5, 0)));
+        assert!(!line.is_convex());
+
+        let collinear = Polygon::new([[0, 0], [5, 5], [10, 10]]);
+        assert!(collinear.triangulate().is_empty());
+        assert!(!collinear.is_convex());
+        assert_eq!(collinear.centroid(), Some(point!(5.0, 5.0)));
+
+        let duplicates = Polygon::new([[0, 0], [0, 0], [10, 0], [10, 0], [10, 10], [0, 0]]);
+        assert_eq!(
+            duplicates.triangulate(),
+            vec![tri!([0, 0], [10, 0], [10, 10])]
+        );
+        assert!(duplicates.is_convex());
+    }
+
+    #[test]
+    fn test_is_convex() {
+        assert!(Polygon::new([[0, 0], [10, 0], [10, 10], [0, 10]]).is_convex());
+        // Collinear points along an edge don't affect convexity
+        assert!(Polygon::new([[0, 0], [5, 0], [10, 0], [10, 10], [0, 10]]).is_convex());
+        assert!(!l_shape().is_convex());
+        // Each turn of a star is in the same direction, but its edges intersect
+        let star = Polygon::new([[50, 0], [79, 90], [2, 35], [98, 35], [21, 90]]);
+        assert!(!star.is_convex());
+    }
+}
@@ -342,6 +342,46 @@ impl<T: Num> Rect<T> {
         rect
     }
 
+    /// Returns the smallest rectangle enclosing both this rectangle and another rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let r = rect!(10, 10, 50, 50).union(rect!(40, 30, 100, 20));
+    /// assert_eq!(r, rect!(10, 10, 130, 50));
+    /// ```
+    pub fn union(&self, rect: Rect<T>) -> Self {
+        let min = |a: T, b: T| if a < b { a } else { b };
+        let max = |a: T, b: T| if a > b { a } else { b };
+        let left = min(self.left(), rect.left());
+        let top = min(self.top(), rect.top());
+        let right = max(self.right(), rect.right());
+        let bottom = max(self.bottom(), rect.bottom());
+        Self::new(left, top, right - left, bottom - top)
+    }
+
+    /// Returns the overlapping area of this rectangle and another rectangle, or `None` if they
+    /// don't overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let r = rect!(10, 10, 50, 50);
+    /// assert_eq!(r.intersection(rect!(40, 30, 100, 20)), Some(rect!(40, 30, 20, 20)));
+    /// assert_eq!(r.intersection(rect!(60, 10, 50, 50)), None);
+    /// ```
+    pub fn intersection(&self, rect: Rect<T>) -> Option<Self> {
+        let min = |a: T, b: T| if a < b { a } else { b };
+        let max = |a: T, b: T| if a > b { a } else { b };
+        let left = max(self.left(), rect.left());
+        let top = max(self.top(), rect.top());
+        let right = min(self.right(), rect.right());
+        let bottom = min(self.bottom(), rect.bottom());
+        (left < right && top < bottom).then(|| Self::new(left, top, right - left, bottom - top))
+    }
+
     /// Returns `Rect` as a [Vec].
     ///
     /// # Example
@@ -508,9 +548,9 @@ impl<T: Num> Contains<Rect<T>> for Rect<T> {
     /// Returns whether this rectangle completely contains another rectangle.
     fn contains(&self, rect: Rect<T>) -> bool {
         rect.left() >= self.left()
-            && rect.right() < self.right()
+            && rect.right() <= self.right()
             && rect.top() >= self.top()
-            && rect.bottom() < self.bottom()
+            && rect.bottom() <= self.bottom()
     }
 }
 
@@ -540,21 +580,21 @@ impl<T: Float> Intersects<Line<T>> for Rect<T> {
 }
 
 impl<T: Num> Intersects<Rect<T>> for Rect<T> {
-    // FIXME: Provide a better intersection result
-    type Result = ();
+    type Result = Rect<T>;
 
-    /// Returns whether this rectangle intersects with another rectangle.
+    /// Returns the overlapping area with another rectangle or `None` if there is no
+    /// intersection.
     fn intersects(&self, rect: Rect<T>) -> Option<Self::Result> {
-        let tl = self.top_left();
-        let br = self.bottom_right();
-        let otl = rect.top_left();
-        let obr = rect.bottom_right();
-        // Both rectangle corner x and y values overlap ranges
-        if tl.x() < obr.x() && br.x() > otl.x() && tl.y() < otl.y() && br.y() > obr.y() {
-            Some(())
-        } else {
-            None
-        }
+        self.intersection(rect)
+    }
+}
+
+impl<T: Num> Intersects<Ellipse<T>> for Rect<T> {
+    type Result = ();
+
+    /// Returns whether this rectangle intersects with an ellipse.
+    fn intersects(&self, ellipse: Ellipse<T>) -> Option<Self::Result> {
+        ellipse.intersects(*self)
     }
 }
 
@@ -594,3 +634,48 @@ impl Sub<Point<i32>> for Rect {
         self.offset(-p)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_intersects_rect() {
+        let rect = rect!(10, 10, 100, 100);
+
+        assert!(rect.intersects(rect!(50, 50, 100, 100)).is_some());
+        assert!(rect.intersects(rect!(0, 50, 20, 20)).is_some());
+        assert!(rect.intersects(rect!(50, 0, 20, 20)).is_some());
+        assert!(rect.intersects(rect!(30, 30, 10, 10)).is_some());
+        assert!(rect.intersects(rect!(0, 0, 200, 200)).is_some());
+
+        // Touching edges don't intersect
+        assert!(rect.intersects(rect!(110, 10, 20, 20)).is_none());
+        assert!(rect.intersects(rect!(10, 110, 20, 20)).is_none());
+        assert!(rect.intersects(rect!(150, 150, 20, 20)).is_none());
+    }
+
+    #[test]
+    fn test_union_intersection() {
+        let rect = rect!(10, 10, 100, 100);
+
+        assert_eq!(rect.union(rect!(50, 50, 100, 100)), rect!(10, 10, 140, 140));
+        assert_eq!(rect.union(rect!(30, 30, 10, 10)), rect);
+        assert_eq!(rect.union(rect!(-10, 200, 5, 5)), rect!(-10, 10, 120, 195));
+
+        assert_eq!(
+            rect.intersection(rect!(50, 50, 100, 100)),
+            Some(rect!(50, 50, 60, 60))
+        );
+        assert_eq!(
+            rect.intersection(rect!(30, 30, 10, 10)),
+            Some(rect!(30, 30, 10, 10))
+        );
+        assert_eq!(rect.intersection(rect!(110, 10, 20, 20)), None);
+
+        assert!(rect.contains(rect));
+        assert!(rect.contains(rect!(30, 30, 80, 80)));
+        assert!(!rect.contains(rect!(30, 30, 81, 80)));
+        assert!(rect.union(rect!(50, 50, 100, 100)).contains(rect));
+    }
+}
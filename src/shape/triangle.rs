@@ -17,6 +17,7 @@
 //! ```
 
 use crate::{error::Result, prelude::*};
+use num_traits::AsPrimitive;
 #[cfg(feature = "serde")]
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
@@ -60,7 +61,7 @@ macro_rules! tri {
         $crate::prelude::Tri::from_xy($x1, $y1, $x2, $y2, $x3, $y3)
     };
     ($x1:expr, $y1:expr, $z1:expr, $x2:expr, $y2:expr, $z2:expr, $x3:expr, $y3:expr, $z3:expr$(,)?) => {
-        $crate::prelude::Tri::from_xyz($x1, $y1, $z2, $x2, $y2, $z2, $x3, $y3, $z3)
+        $crate::prelude::Tri::from_xyz($x1, $y1, $z1, $x2, $y2, $z2, $x3, $y3, $z3)
     };
 }
 
@@ -114,6 +115,105 @@ impl<T: Copy> Tri<T> {
     }
 }
 
+impl<T: Num> Tri<T> {
+    /// Returns the bounding [Rect] of the triangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let tri = Tri::new([10, 20], [30, 10], [20, 25]);
+    /// assert_eq!(tri.bounding_rect(), rect!(10, 10, 20, 15));
+    /// ```
+    pub fn bounding_rect(&self) -> Rect<T> {
+        let [p1, p2, p3] = self.points();
+        let [mut left, mut top] = p1.coords();
+        let [mut right, mut bottom] = p1.coords();
+        for p in [p2, p3] {
+            let [x, y] = p.coords();
+            if x < left {
+                left = x;
+            } else if x > right {
+                right = x;
+            }
+            if y < top {
+                top = y;
+            } else if y > bottom {
+                bottom = y;
+            }
+        }
+        rect![left, top, right - left, bottom - top]
+    }
+
+    /// Returns the area of the triangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let tri = Tri::new([0, 0], [10, 0], [0, 10]);
+    /// assert_eq!(tri.area(), 50.0);
+    /// ```
+    pub fn area(&self) -> f64
+    where
+        T: AsPrimitive<f64>,
+    {
+        let [p1, p2, p3]: [Point<f64>; 3] = self.points().map(|p| p.as_());
+        ((p2.x() - p1.x()) * (p3.y() - p1.y()) - (p3.x() - p1.x()) * (p2.y() - p1.y())).abs() / 2.0
+    }
+
+    /// Returns the centroid of the triangle, the average of its three points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let tri = Tri::new([0, 0], [9, 0], [0, 6]);
+    /// assert_eq!(tri.centroid(), point!(3.0, 2.0));
+    /// ```
+    pub fn centroid(&self) -> Point<f64>
+    where
+        T: AsPrimitive<f64>,
+    {
+        let [p1, p2, p3]: [Point<f64>; 3] = self.points().map(|p| p.as_());
+        point![
+            (p1.x() + p2.x() + p3.x()) / 3.0,
+            (p1.y() + p2.y() + p3.y()) / 3.0,
+        ]
+    }
+
+    /// Returns the barycentric coordinates of a [Point] relative to the triangle, which are the
+    /// weights of each triangle point that sum to `1.0`. The point is inside the triangle when all
+    /// weights are between `0.0` and `1.0`. Returns `None` if the triangle is degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let tri = Tri::new([0, 0], [10, 0], [0, 10]);
+    /// assert_eq!(tri.barycentric([0, 0]), Some([1.0, 0.0, 0.0]));
+    /// assert_eq!(tri.barycentric([5, 5]), Some([0.0, 0.5, 0.5]));
+    /// assert_eq!(Tri::new([0, 0], [5, 5], [10, 10]).barycentric([5, 5]), None);
+    /// ```
+    pub fn barycentric<P>(&self, p: P) -> Option<[f64; 3]>
+    where
+        T: AsPrimitive<f64>,
+        P: Into<Point<T>>,
+    {
+        let [p1, p2, p3]: [Point<f64>; 3] = self.points().map(|p| p.as_());
+        let p: Point<f64> = p.into().as_();
+        let det = (p2.y() - p3.y()) * (p1.x() - p3.x()) + (p3.x() - p2.x()) * (p1.y() - p3.y());
+        if det == 0.0 {
+            return None;
+        }
+        let w1 =
+            ((p2.y() - p3.y()) * (p.x() - p3.x()) + (p3.x() - p2.x()) * (p.y() - p3.y())) / det;
+        let w2 =
+            ((p3.y() - p1.y()) * (p.x() - p3.x()) + (p1.x() - p3.x()) * (p.y() - p3.y())) / det;
+        Some([w1, w2, 1.0 - w1 - w2])
+    }
+}
+
 impl<T> Tri<T, 3> {
     /// Constructs a `Triangle` from individual x/y/z coordinates.
     #[allow(clippy::too_many_arguments)]
@@ -250,16 +350,17 @@ impl<T: Copy, const N: usize> Tri<T, N> {
 }
 
 impl<T: Num> Contains<Point<T>> for Tri<T> {
-    /// Returns whether this rectangle contains a given [Point].
+    /// Returns whether this triangle contains a given [Point].
     fn contains(&self, p: Point<T>) -> bool {
         let [p1, p2, p3] = self.points();
-        let b1 = ((p.x() - p2.x()) * (p1.y() - p2.y()) - (p.y() - p2.y()) * (p1.x() - p2.x()))
-            < T::zero();
-        let b2 = ((p.x() - p3.x()) * (p2.y() - p3.y()) - (p.y() - p3.y()) * (p2.x() - p3.x()))
-            < T::zero();
-        let b3 = ((p.x() - p1.x()) * (p3.y() - p1.y()) - (p.y() - p1.y()) * (p3.x() - p1.x()))
-            < T::zero();
-        (b1 == b2) && (b2 == b3)
+        let d1 = (p.x() - p2.x()) * (p1.y() - p2.y()) - (p.y() - p2.y()) * (p1.x() - p2.x());
+        let d2 = (p.x() - p3.x()) * (p2.y() - p3.y()) - (p.y() - p3.y()) * (p2.x() - p3.x());
+        let d3 = (p.x() - p1.x()) * (p3.y() - p1.y()) - (p.y() - p1.y()) * (p3.x() - p1.x());
+        // Points on an edge are contained regardless of winding order
+        let zero = T::zero();
+        let has_neg = d1 < zero || d2 < zero || d3 < zero;
+        let has_pos = d1 > zero || d2 > zero || d3 > zero;
+        !(has_neg && has_pos)
     }
 }
 
@@ -301,3 +402,61 @@ impl<T: Copy> From<[[T; 3]; 3]> for Tri<T, 3> {
         Self::from_xyz(x1, y1, z1, x2, y2, z2, x3, y3, z3)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_contains_point() {
+        let tris = [
+            tri!([10, 20], [30, 10], [20, 25]),
+            tri!([0, 0], [0, 40], [25, 40]),
+            tri!([-10, 5], [15, -20], [5, 30]),
+        ];
+        for tri in tris {
+            let bounds = tri.bounding_rect();
+            for x in bounds.left() - 2..=bounds.right() + 2 {
+                for y in bounds.top() - 2..=bounds.bottom() + 2 {
+                    let inside = matches!(
+                        tri.barycentric([x, y]),
+                        Some(weights) if weights.iter().all(|&w| (-1e-9..=1.0 + 1e-9).contains(&w))
+                    );
+                    assert_eq!(tri.contains(point!(x, y)), inside, "{tri:?} ({x}, {y})");
+                }
+            }
+            let centroid = tri.centroid();
+            let centroid = point!(centroid.x().round() as i32, centroid.y().round() as i32);
+            assert!(tri.contains(centroid));
+        }
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        let tris = [
+            tri!([10, 20], [30, 10], [20, 25]),
+            tri!([20, 25], [10, 20], [30, 10]),
+            tri!([-10, 5], [15, -20], [5, 30]),
+        ];
+        for tri in tris {
+            let bounds = tri.bounding_rect();
+            for p in tri.points() {
+                assert!(
+                    p.x() >= bounds.left() && p.x() <= bounds.right(),
+                    "{tri:?} {p:?}"
+                );
+                assert!(
+                    p.y() >= bounds.top() && p.y() <= bounds.bottom(),
+                    "{tri:?} {p:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(tri!([0, 0], [10, 0], [0, 10]).area(), 50.0);
+        assert_eq!(tri!([0, 0], [0, 10], [10, 0]).area(), 50.0);
+        assert_eq!(tri!([0, 0], [5, 5], [10, 10]).area(), 0.0);
+    }
+}
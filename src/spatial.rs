@@ -0,0 +1,650 @@
+//! Spatial indexing for fast [`Rect`] and [`Point`] queries.
+//!
+//! [`QuadTree`] stores items alongside their bounding [`Rect`] or [`Point`] and recursively
+//! subdivides its area into quadrants as items are inserted, so that area, radius and nearest
+//! neighbor queries only visit the nodes that can contain a match instead of scanning every item.
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { tree: QuadTree<&'static str> };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     self.tree.clear();
+//!     self.tree.insert(rect![10.0, 10.0, 20.0, 20.0], "box");
+//!     self.tree.insert_point([50.0, 40.0], "dot");
+//!
+//!     let mouse: Point<f64> = s.mouse_pos().as_();
+//!     for (_, name) in self.tree.query_circle(mouse, 25.0) {
+//!         s.text(name)?;
+//!     }
+//!
+//!     // Visualize node boundaries
+//!     s.fill(None);
+//!     s.stroke(Color::GRAY);
+//!     self.tree.draw(s)?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// Unique identifier for an item inserted into a [`QuadTree`].
+///
+/// Identifiers of removed items may be reused by later insertions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ItemId(usize);
+
+/// An item stored in a [`QuadTree`] along with its bounds.
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    bounds: Rect<f64>,
+    item: T,
+}
+
+/// A node covering a quadrant of the [`QuadTree`] area.
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Rect<f64>,
+    depth: usize,
+    /// Number of items stored in this node and all of its descendants.
+    len: usize,
+    /// Items that don't fit entirely inside a single child quadrant.
+    items: Vec<usize>,
+    children: Option<Box<[Node; 4]>>,
+}
+
+/// A quadtree spatial index storing items with an associated [`Rect`] or [`Point`].
+///
+/// Each node holds up to `node_capacity` items before subdividing into four quadrants, up to
+/// `max_depth` levels deep. Items that span a quadrant boundary remain in the parent node, and
+/// items that lie partly or fully outside of the tree bounds are kept in the root node, so
+/// queries always return every matching item. Nodes are merged back together as items are
+/// removed.
+///
+/// Bounds are inclusive, so a [`Point`] lying exactly on the edge of a query region matches it.
+///
+/// # Example
+///
+/// ```
+/// # use pix_engine::prelude::*;
+/// let mut tree = QuadTree::new(rect![0.0, 0.0, 100.0, 100.0]);
+/// let a = tree.insert_point([10.0, 10.0], 'a');
+/// let b = tree.insert(rect![40.0, 40.0, 20.0, 20.0], 'b');
+/// tree.insert_point([90.0, 90.0], 'c');
+///
+/// let found: Vec<_> = tree.query(rect![0.0, 0.0, 50.0, 50.0]).collect();
+/// assert_eq!(found.len(), 2);
+///
+/// let nearest: Vec<_> = tree.nearest([0.0, 0.0], 1).map(|(id, _)| id).collect();
+/// assert_eq!(nearest, [a]);
+///
+/// assert_eq!(tree.remove(b), Some('b'));
+/// assert_eq!(tree.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuadTree<T> {
+    root: Node,
+    entries: Vec<Option<Entry<T>>>,
+    free: Vec<usize>,
+    node_capacity: usize,
+    max_depth: usize,
+}
+
+impl<T> QuadTree<T> {
+    /// Default number of items a node holds before subdividing.
+    pub const DEFAULT_NODE_CAPACITY: usize = 8;
+    /// Default maximum depth of subdivision.
+    pub const DEFAULT_MAX_DEPTH: usize = 8;
+
+    /// Constructs an empty `QuadTree` covering `bounds`, using the default node capacity and
+    /// maximum depth.
+    pub fn new<R>(bounds: R) -> Self
+    where
+        R: Into<Rect<f64>>,
+    {
+        Self::with_limits(bounds, Self::DEFAULT_NODE_CAPACITY, Self::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Constructs an empty `QuadTree` covering `bounds`, where each node holds up to
+    /// `node_capacity` items before subdividing, up to `max_depth` levels deep.
+    ///
+    /// A `node_capacity` of `0` is treated as `1`.
+    pub fn with_limits<R>(bounds: R, node_capacity: usize, max_depth: usize) -> Self
+    where
+        R: Into<Rect<f64>>,
+    {
+        Self {
+            root: Node::new(bounds.into(), 0),
+            entries: vec![],
+            free: vec![],
+            node_capacity: node_capacity.max(1),
+            max_depth,
+        }
+    }
+
+    /// Returns the area covered by the `QuadTree`.
+    #[inline]
+    pub const fn bounds(&self) -> Rect<f64> {
+        self.root.bounds
+    }
+
+    /// Returns the number of items a node holds before subdividing.
+    #[inline]
+    #[must_use]
+    pub const fn node_capacity(&self) -> usize {
+        self.node_capacity
+    }
+
+    /// Returns the maximum depth of subdivision.
+    #[inline]
+    #[must_use]
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the number of items in the `QuadTree`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.root.len
+    }
+
+    /// Returns whether the `QuadTree` contains no items.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.root.len == 0
+    }
+
+    /// Removes all items from the `QuadTree`.
+    pub fn clear(&mut self) {
+        self.root = Node::new(self.root.bounds, 0);
+        self.entries.clear();
+        self.free.clear();
+    }
+
+    /// Inserts an `item` bounded by `rect`, returning its [`ItemId`].
+    pub fn insert<R>(&mut self, rect: R, item: T) -> ItemId
+    where
+        R: Into<Rect<f64>>,
+    {
+        let bounds = rect.into();
+        let entry = Some(Entry { bounds, item });
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.entries[index] = entry;
+                index
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        self.root.insert(
+            index,
+            bounds,
+            &self.entries,
+            self.node_capacity,
+            self.max_depth,
+        );
+        ItemId(index)
+    }
+
+    /// Inserts an `item` located at `point`, returning its [`ItemId`].
+    pub fn insert_point<P>(&mut self, point: P, item: T) -> ItemId
+    where
+        P: Into<Point<f64>>,
+    {
+        let point = point.into();
+        self.insert(rect![point.x(), point.y(), 0.0, 0.0], item)
+    }
+
+    /// Removes the item with the given [`ItemId`], returning it if it was present.
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let bounds = self.entries.get(id.0)?.as_ref()?.bounds;
+        if !self.root.remove(id.0, bounds, self.node_capacity) {
+            return None;
+        }
+        self.free.push(id.0);
+        self.entries[id.0].take().map(|entry| entry.item)
+    }
+
+    /// Returns a reference to the item with the given [`ItemId`].
+    #[inline]
+    #[must_use]
+    pub fn get(&self, id: ItemId) -> Option<&T> {
+        self.entry(id.0).map(|entry| &entry.item)
+    }
+
+    /// Returns a mutable reference to the item with the given [`ItemId`].
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, id: ItemId) -> Option<&mut T> {
+        self.entries
+            .get_mut(id.0)
+            .and_then(Option::as_mut)
+            .map(|entry| &mut entry.item)
+    }
+
+    /// Returns the bounds of the item with the given [`ItemId`]. Items inserted with
+    /// [`QuadTree::insert_point`] have a width and height of `0.0`.
+    #[inline]
+    #[must_use]
+    pub fn item_bounds(&self, id: ItemId) -> Option<Rect<f64>> {
+        self.entry(id.0).map(|entry| entry.bounds)
+    }
+
+    /// Returns an iterator over all items in the `QuadTree` in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, &T)> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((ItemId(index), &entry.as_ref()?.item)))
+    }
+
+    /// Returns an iterator over all items whose bounds intersect `rect`, in no particular order.
+    pub fn query<R>(&self, rect: R) -> impl Iterator<Item = (ItemId, &T)> + '_
+    where
+        R: Into<Rect<f64>>,
+    {
+        let rect = rect.into();
+        let mut found = vec![];
+        self.root
+            .visit(&|bounds| overlaps(bounds, rect), &mut |index| {
+                if matches!(self.entry(index), Some(e) if overlaps(e.bounds, rect)) {
+                    found.push(index);
+                }
+            });
+        self.items(found)
+    }
+
+    /// Returns an iterator over all items whose bounds intersect the circle at `center` with the
+    /// given `radius`, in no particular order.
+    pub fn query_circle<P>(&self, center: P, radius: f64) -> impl Iterator<Item = (ItemId, &T)> + '_
+    where
+        P: Into<Point<f64>>,
+    {
+        let center = center.into();
+        let radius_sq = radius * radius;
+        let mut found = vec![];
+        self.root.visit(
+            &|bounds| distance_sq(bounds, center) <= radius_sq,
+            &mut |index| {
+                if matches!(self.entry(index), Some(e) if distance_sq(e.bounds, center) <= radius_sq) {
+                    found.push(index);
+                }
+            },
+        );
+        self.items(found)
+    }
+
+    /// Returns an iterator over the `k` items nearest to `point`, closest first. Distance is
+    /// measured to the closest edge of each item's bounds, so items containing `point` have a
+    /// distance of `0.0`.
+    pub fn nearest<P>(&self, point: P, k: usize) -> impl Iterator<Item = (ItemId, &T)> + '_
+    where
+        P: Into<Point<f64>>,
+    {
+        let point = point.into();
+        let mut nearest = Vec::with_capacity(k);
+        if k > 0 {
+            self.root.nearest(point, k, &self.entries, &mut nearest);
+        }
+        self.items(nearest.into_iter().map(|(_, index)| index).collect())
+    }
+
+    /// Returns the entry at `index`, if present.
+    #[inline]
+    fn entry(&self, index: usize) -> Option<&Entry<T>> {
+        self.entries.get(index).and_then(Option::as_ref)
+    }
+
+    /// Maps entry indexes to their [`ItemId`] and item.
+    fn items(&self, indexes: Vec<usize>) -> impl Iterator<Item = (ItemId, &T)> + '_ {
+        indexes
+            .into_iter()
+            .filter_map(|index| Some((ItemId(index), &self.entry(index)?.item)))
+    }
+}
+
+impl<T> Draw for QuadTree<T> {
+    /// Draw the boundaries of each `QuadTree` node to the current [`PixState`] canvas using the
+    /// current stroke and fill settings.
+    fn draw(&self, s: &mut PixState) -> PixResult<()> {
+        self.root.draw(s)
+    }
+}
+
+impl Node {
+    /// Constructs an empty leaf `Node`.
+    fn new(bounds: Rect<f64>, depth: usize) -> Self {
+        Self {
+            bounds,
+            depth,
+            len: 0,
+            items: vec![],
+            children: None,
+        }
+    }
+
+    /// Returns the index of the child quadrant that entirely contains `bounds`, if any.
+    fn child_index(&self, bounds: Rect<f64>) -> Option<usize> {
+        self.children
+            .as_ref()?
+            .iter()
+            .position(|child| encloses(child.bounds, bounds))
+    }
+
+    /// Inserts the entry at `index` into this node or the child quadrant that contains it,
+    /// subdividing if this node exceeds `capacity`.
+    fn insert<T>(
+        &mut self,
+        index: usize,
+        bounds: Rect<f64>,
+        entries: &[Option<Entry<T>>],
+        capacity: usize,
+        max_depth: usize,
+    ) {
+        self.len += 1;
+        if let Some(child) = self.child_index(bounds) {
+            if let Some(children) = self.children.as_mut() {
+                children[child].insert(index, bounds, entries, capacity, max_depth);
+                return;
+            }
+        }
+        self.items.push(index);
+        if self.children.is_none() && self.items.len() > capacity && self.depth < max_depth {
+            self.subdivide(entries, capacity, max_depth);
+        }
+    }
+
+    /// Splits this node into four quadrants and moves down any items that fit inside one.
+    fn subdivide<T>(&mut self, entries: &[Option<Entry<T>>], capacity: usize, max_depth: usize) {
+        let Rect([x, y, width, height]) = self.bounds;
+        let (half_width, half_height) = (width / 2.0, height / 2.0);
+        let depth = self.depth + 1;
+        self.children = Some(Box::new([
+            Node::new(rect![x, y, half_width, half_height], depth),
+            Node::new(rect![x + half_width, y, half_width, half_height], depth),
+            Node::new(rect![x, y + half_height, half_width, half_height], depth),
+            Node::new(
+                rect![x + half_width, y + half_height, half_width, half_height],
+                depth,
+            ),
+        ]));
+        for index in std::mem::take(&mut self.items) {
+            let Some(Some(entry)) = entries.get(index) else {
+                continue;
+            };
+            match (self.child_index(entry.bounds), self.children.as_mut()) {
+                (Some(child), Some(children)) => {
+                    children[child].insert(index, entry.bounds, entries, capacity, max_depth);
+                }
+                _ => self.items.push(index),
+            }
+        }
+    }
+
+    /// Removes the entry at `index` from this node or its descendants, merging child quadrants
+    /// back together once they hold `capacity` items or fewer. Returns whether it was found.
+    fn remove(&mut self, index: usize, bounds: Rect<f64>, capacity: usize) -> bool {
+        let removed = match (self.child_index(bounds), self.children.as_mut()) {
+            (Some(child), Some(children)) => children[child].remove(index, bounds, capacity),
+            _ => match self.items.iter().position(|&i| i == index) {
+                Some(position) => {
+                    self.items.swap_remove(position);
+                    true
+                }
+                None => false,
+            },
+        };
+        if removed {
+            self.len -= 1;
+            if self.len <= capacity {
+                if let Some(children) = self.children.take() {
+                    for child in *children {
+                        child.collect_into(&mut self.items);
+                    }
+                }
+            }
+        }
+        removed
+    }
+
+    /// Moves all items in this node and its descendants into `items`.
+    fn collect_into(self, items: &mut Vec<usize>) {
+        items.extend(self.items);
+        if let Some(children) = self.children {
+            for child in *children {
+                child.collect_into(items);
+            }
+        }
+    }
+
+    /// Draw the boundaries of this node and its descendants.
+    fn draw(&self, s: &mut PixState) -> PixResult<()> {
+        s.rect(self.bounds.round().as_())?;
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.draw(s)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Visits each node whose bounds pass `filter` and each item stored in those nodes.
+    fn visit(&self, filter: &impl Fn(Rect<f64>) -> bool, f: &mut impl FnMut(usize)) {
+        // Items outside the root bounds are stored in the root, so always visit it
+        if self.depth > 0 && !filter(self.bounds) {
+            return;
+        }
+        self.items.iter().for_each(|&index| f(index));
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.visit(filter, f);
+            }
+        }
+    }
+
+    /// Finds the `k` entries nearest to `point`, keeping `nearest` sorted by squared distance.
+    fn nearest<T>(
+        &self,
+        point: Point<f64>,
+        k: usize,
+        entries: &[Option<Entry<T>>],
+        nearest: &mut Vec<(f64, usize)>,
+    ) {
+        // Ties are broken by index so results are deterministic
+        let closer = |nearest: &Vec<(f64, usize)>, dist: f64, index: usize| match nearest.last() {
+            Some(&(d, i)) if nearest.len() >= k => compare(dist, index, d, i).is_lt(),
+            _ => true,
+        };
+        for &index in &self.items {
+            let Some(Some(entry)) = entries.get(index) else {
+                continue;
+            };
+            let dist = distance_sq(entry.bounds, point);
+            if closer(nearest, dist, index) {
+                let position =
+                    nearest.partition_point(|&(d, i)| compare(d, i, dist, index).is_lt());
+                nearest.insert(position, (dist, index));
+                nearest.truncate(k);
+            }
+        }
+        if let Some(children) = &self.children {
+            let mut children: Vec<_> = children
+                .iter()
+                .map(|child| (distance_sq(child.bounds, point), child))
+                .collect();
+            children.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            for (dist, child) in children {
+                if closer(nearest, dist, 0) {
+                    child.nearest(point, k, entries, nearest);
+                }
+            }
+        }
+    }
+}
+
+/// Orders `(distance, index)` pairs by distance, then by index.
+#[inline]
+fn compare(dist_a: f64, index_a: usize, dist_b: f64, index_b: usize) -> Ordering {
+    dist_a
+        .partial_cmp(&dist_b)
+        .unwrap_or(Ordering::Equal)
+        .then(index_a.cmp(&index_b))
+}
+
+/// Returns whether `a` and `b` overlap, including touching edges.
+#[inline]
+fn overlaps(a: Rect<f64>, b: Rect<f64>) -> bool {
+    a.left() <= b.right() && b.left() <= a.right() && a.top() <= b.bottom() && b.top() <= a.bottom()
+}
+
+/// Returns whether `outer` entirely contains `inner`, including touching edges.
+#[inline]
+fn encloses(outer: Rect<f64>, inner: Rect<f64>) -> bool {
+    outer.left() <= inner.left()
+        && inner.right() <= outer.right()
+        && outer.top() <= inner.top()
+        && inner.bottom() <= outer.bottom()
+}
+
+/// Returns the squared distance from `point` to the closest point within `rect`.
+#[inline]
+fn distance_sq(rect: Rect<f64>, point: Point<f64>) -> f64 {
+    let dx = (rect.left() - point.x())
+        .max(point.x() - rect.right())
+        .max(0.0);
+    let dy = (rect.top() - point.y())
+        .max(point.y() - rect.bottom())
+        .max(0.0);
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn sorted<T>(iter: impl Iterator<Item = (ItemId, T)>) -> Vec<usize> {
+        // Items are inserted as their own index, so ids double as items
+        let mut ids: Vec<_> = iter.map(|(id, _)| id.0).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    fn random_tree(rng: &mut StdRng, count: usize) -> (QuadTree<usize>, Vec<Rect<f64>>) {
+        let mut tree = QuadTree::with_limits(rect![0.0, 0.0, 1000.0, 1000.0], 4, 6);
+        let mut rects = vec![];
+        for item in 0..count {
+            // Mix points, small rects and some items extending past the tree bounds
+            let (x, y) = (rng.gen_range(-50.0..1050.0), rng.gen_range(-50.0..1050.0));
+            let rect = match item % 3 {
+                0 => rect![x, y, 0.0, 0.0],
+                1 => rect![x, y, rng.gen_range(0.0..30.0), rng.gen_range(0.0..30.0)],
+                _ => rect![x, y, rng.gen_range(0.0..300.0), rng.gen_range(0.0..300.0)],
+            };
+            tree.insert(rect, item);
+            rects.push(rect);
+        }
+        (tree, rects)
+    }
+
+    #[test]
+    fn query_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let (tree, rects) = random_tree(&mut rng, 500);
+        assert_eq!(tree.len(), 500);
+        assert!(tree.root.children.is_some());
+        for _ in 0..100 {
+            let (x, y) = (rng.gen_range(-100.0..1000.0), rng.gen_range(-100.0..1000.0));
+            let area = rect![x, y, rng.gen_range(0.0..200.0), rng.gen_range(0.0..200.0)];
+            let expected: Vec<_> = (0..rects.len())
+                .filter(|&i| overlaps(rects[i], area))
+                .collect();
+            assert_eq!(sorted(tree.query(area)), expected);
+
+            let center = area.center();
+            let radius = rng.gen_range(0.0..150.0);
+            let expected: Vec<_> = (0..rects.len())
+                .filter(|&i| distance_sq(rects[i], center) <= radius * radius)
+                .collect();
+            assert_eq!(sorted(tree.query_circle(center, radius)), expected);
+
+            let k = rng.gen_range(1..10);
+            let mut expected: Vec<_> = (0..rects.len())
+                .map(|i| (distance_sq(rects[i], center), i))
+                .collect();
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            let expected: Vec<_> = expected.into_iter().take(k).map(|(_, i)| i).collect();
+            let nearest: Vec<_> = tree.nearest(center, k).map(|(_, &i)| i).collect();
+            assert_eq!(nearest, expected);
+        }
+    }
+
+    #[test]
+    fn remove_and_merge() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (mut tree, rects) = random_tree(&mut rng, 200);
+        let ids: Vec<_> = tree.iter().map(|(id, _)| id).collect();
+        for &id in ids.iter().step_by(2) {
+            assert!(tree.remove(id).is_some());
+            assert!(tree.remove(id).is_none());
+        }
+        assert_eq!(tree.len(), 100);
+        let area = rect![200.0, 200.0, 400.0, 400.0];
+        let expected: Vec<_> = (0..rects.len())
+            .filter(|&i| i % 2 == 1 && overlaps(rects[i], area))
+            .collect();
+        assert_eq!(sorted(tree.query(area)), expected);
+
+        for (id, _) in ids.iter().zip(0..).filter(|(_, i)| i % 2 == 1) {
+            assert!(tree.remove(*id).is_some());
+        }
+        assert!(tree.is_empty());
+        assert!(tree.root.children.is_none());
+        assert!(tree.root.items.is_empty());
+    }
+
+    #[test]
+    fn boundary_items() {
+        let mut tree = QuadTree::with_limits(rect![0.0, 0.0, 100.0, 100.0], 1, 4);
+        let corners = [[0.0, 0.0], [100.0, 0.0], [0.0, 100.0], [100.0, 100.0]];
+        for (item, corner) in corners.into_iter().enumerate() {
+            tree.insert_point(corner, item);
+        }
+        // Spans all four quadrants, so stays in the root
+        let spanning = tree.insert(rect![40.0, 40.0, 20.0, 20.0], 4);
+        // Exactly on the midlines
+        tree.insert_point([50.0, 50.0], 5);
+        tree.insert_point([50.0, 0.0], 6);
+        // Outside the tree bounds
+        tree.insert_point([150.0, -10.0], 7);
+
+        assert_eq!(sorted(tree.query(tree.bounds())), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(sorted(tree.query(rect![100.0, 100.0, 0.0, 0.0])), [3]);
+        assert_eq!(
+            sorted(tree.query(rect![50.0, 0.0, 50.0, 50.0])),
+            [1, 4, 5, 6]
+        );
+        assert_eq!(sorted(tree.query(rect![140.0, -20.0, 20.0, 20.0])), [7]);
+        assert_eq!(sorted(tree.query_circle([100.0, 50.0], 50.0)), [1, 3, 4, 5]);
+        assert_eq!(
+            tree.item_bounds(spanning),
+            Some(rect![40.0, 40.0, 20.0, 20.0])
+        );
+        assert_eq!(tree.remove(spanning), Some(4));
+        assert_eq!(sorted(tree.query(rect![45.0, 45.0, 1.0, 1.0])), []);
+        assert_eq!(
+            tree.nearest([49.0, 1.0], 2)
+                .map(|(_, &i)| i)
+                .collect::<Vec<_>>(),
+            [6, 0]
+        );
+    }
+}
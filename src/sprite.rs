@@ -0,0 +1,638 @@
+//! [`SpriteSheet`] texture atlas and [`Animation`] functions.
+//!
+//! A `SpriteSheet` is a single [Image] containing many sprites, each identified by a named region.
+//! Drawing sprites from one sheet re-uses a single cached texture instead of one texture per
+//! [Image]. An `Animation` plays a sequence of regions from an atlas [Image] with per-frame
+//! durations.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::sprite`]: Draw a named region of a [`SpriteSheet`] to the current canvas.
+//! - [`PixState::animation`]: Advance and draw the current frame of an [`Animation`] to the
+//!   current canvas.
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { sheet: SpriteSheet };
+//! # impl PixEngine for App {
+//! fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     self.sheet = SpriteSheet::from_file("./characters.png")?;
+//!     self.sheet.add_region("player", [0, 0, 16, 16]);
+//!     self.sheet.add_region("enemy", [16, 0, 16, 16]);
+//!     Ok(())
+//! }
+//!
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     s.sprite(&self.sheet, "player", [10, 10, 32, 32])?;
+//!     s.sprite(&self.sheet, "enemy", [50, 10, 32, 32])?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::{collections::HashMap, ops::Range, time::Duration};
+
+/// A texture atlas [Image] with named sprite regions.
+#[derive(Default, Debug, Clone)]
+#[must_use]
+pub struct SpriteSheet {
+    image: Image,
+    regions: HashMap<String, Rect<i32>>,
+}
+
+impl SpriteSheet {
+    /// Constructs a `SpriteSheet` from an [Image] with no regions.
+    pub fn new(image: Image) -> Self {
+        Self {
+            image,
+            regions: HashMap::new(),
+        }
+    }
+
+    /// Constructs a `SpriteSheet` from a [png] or `bmp` image file with no regions.
+    ///
+    /// # Errors
+    ///
+    /// If the file fails to load as an [Image], then an error is returned.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> PixResult<Self> {
+        Ok(Self::new(Image::from_file(path)?))
+    }
+
+    /// Returns the atlas [Image].
+    #[inline]
+    pub const fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Add a named region of the atlas [Image], replacing any existing region with the same name.
+    pub fn add_region<S, R>(&mut self, name: S, rect: R) -> &mut Self
+    where
+        S: Into<String>,
+        R: Into<Rect<i32>>,
+    {
+        self.regions.insert(name.into(), rect.into());
+        self
+    }
+
+    /// Returns the source rectangle for a named region.
+    ///
+    /// # Errors
+    ///
+    /// If no region with the given `name` exists, then an error is returned listing the available
+    /// region names.
+    pub fn region<S: AsRef<str>>(&self, name: S) -> PixResult<Rect<i32>> {
+        let name = name.as_ref();
+        self.regions.get(name).copied().ok_or_else(|| {
+            let mut available: Vec<_> = self.regions.keys().cloned().collect();
+            available.sort_unstable();
+            PixError::InvalidSpriteRegion {
+                name: name.to_owned(),
+                available,
+            }
+            .into()
+        })
+    }
+
+    /// Returns the source rectangles for a sequence of numbered regions named `{prefix}{index}`,
+    /// such as `walk_0` through `walk_7`, useful for animation frames.
+    ///
+    /// # Errors
+    ///
+    /// If any region in the sequence doesn't exist, then an error is returned listing the
+    /// available region names.
+    pub fn region_sequence<S: AsRef<str>>(
+        &self,
+        prefix: S,
+        range: Range<usize>,
+    ) -> PixResult<Vec<Rect<i32>>> {
+        let prefix = prefix.as_ref();
+        range.map(|i| self.region(format!("{prefix}{i}"))).collect()
+    }
+
+    /// Returns an iterator over the region names and source rectangles in arbitrary order.
+    pub fn regions(&self) -> impl Iterator<Item = (&str, Rect<i32>)> {
+        self.regions
+            .iter()
+            .map(|(name, &rect)| (name.as_str(), rect))
+    }
+}
+
+/// [`Animation`] playback mode.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayMode {
+    /// Play through the frames once, stopping on the last frame.
+    Once,
+    /// Play through the frames repeatedly, starting over after the last frame.
+    #[default]
+    Loop,
+    /// Play through the frames forwards then backwards repeatedly.
+    PingPong,
+}
+
+/// A single [`Animation`] frame.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Frame {
+    src: Rect<i32>,
+    duration: Duration,
+}
+
+/// A sprite animation made up of regions of an atlas [Image] displayed for per-frame durations.
+///
+/// Animations are advanced by elapsed time, making playback independent of frame rate.
+/// Zero-duration frames are skipped during playback.
+///
+/// # Example
+///
+/// ```
+/// # use pix_engine::prelude::*;
+/// # use std::time::Duration;
+/// # struct App { walk: Animation };
+/// # impl PixEngine for App {
+/// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+///     let mut sheet = SpriteSheet::from_file("./player.png")?;
+///     for i in 0..4 {
+///         sheet.add_region(format!("walk_{i}"), [i * 16, 0, 16, 16]);
+///     }
+///     self.walk = Animation::from_sheet(sheet, "walk_", 0..4, Duration::from_millis(100))?;
+///     self.walk.set_play_mode(PlayMode::PingPong);
+///     Ok(())
+/// }
+///
+/// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+///     s.animation(&mut self.walk, [10, 10])?;
+///     Ok(())
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Animation {
+    image: Image,
+    frames: Vec<Frame>,
+    mode: PlayMode,
+    index: usize,
+    elapsed: Duration,
+    reverse: bool,
+    speed: f64,
+    paused: bool,
+    finished: bool,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::new(Image::default())
+    }
+}
+
+impl Animation {
+    /// Constructs an `Animation` from an atlas [Image] with no frames.
+    pub fn new(image: Image) -> Self {
+        Self {
+            image,
+            frames: Vec::new(),
+            mode: PlayMode::default(),
+            index: 0,
+            elapsed: Duration::ZERO,
+            reverse: false,
+            speed: 1.0,
+            paused: false,
+            finished: false,
+        }
+    }
+
+    /// Constructs an `Animation` from a sequence of numbered [`SpriteSheet`] regions named
+    /// `{prefix}{index}`, each displayed for `duration`.
+    ///
+    /// # Errors
+    ///
+    /// If any region in the sequence doesn't exist, then an error is returned listing the
+    /// available region names.
+    pub fn from_sheet<S: AsRef<str>>(
+        sheet: SpriteSheet,
+        prefix: S,
+        range: Range<usize>,
+        duration: Duration,
+    ) -> PixResult<Self> {
+        let regions = sheet.region_sequence(prefix, range)?;
+        let mut animation = Self::new(sheet.image);
+        for src in regions {
+            animation.add_frame(src, duration);
+        }
+        Ok(animation)
+    }
+
+    /// Add a frame displaying the `src` region of the atlas [Image] for `duration`.
+    pub fn add_frame<R: Into<Rect<i32>>>(&mut self, src: R, duration: Duration) -> &mut Self {
+        self.frames.push(Frame {
+            src: src.into(),
+            duration,
+        });
+        self
+    }
+
+    /// Returns the atlas [Image].
+    #[inline]
+    pub const fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the number of frames.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns whether the animation has no frames.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the current [`PlayMode`].
+    #[inline]
+    #[must_use]
+    pub const fn play_mode(&self) -> PlayMode {
+        self.mode
+    }
+
+    /// Set the [`PlayMode`]. Defaults to [`PlayMode::Loop`].
+    #[inline]
+    pub fn set_play_mode(&mut self, mode: PlayMode) {
+        self.mode = mode;
+        self.finished = false;
+    }
+
+    /// Returns the playback speed multiplier.
+    #[inline]
+    #[must_use]
+    pub const fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Set the playback speed multiplier. Defaults to `1.0`. Negative values are treated as `0.0`
+    /// and non-finite values are ignored.
+    #[inline]
+    pub fn set_speed(&mut self, multiplier: f64) {
+        if multiplier.is_finite() {
+            self.speed = multiplier.max(0.0);
+        }
+    }
+
+    /// Pause playback.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resume playback.
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns whether playback is paused.
+    #[inline]
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns whether a [`PlayMode::Once`] animation has finished playing. Looping animations
+    /// never finish.
+    #[inline]
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Restart playback from the first frame.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.elapsed = Duration::ZERO;
+        self.reverse = false;
+        self.finished = false;
+    }
+
+    /// Returns the current frame index.
+    #[inline]
+    #[must_use]
+    pub const fn current_frame(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the atlas region of the current frame, or `None` if the animation is empty.
+    #[inline]
+    #[must_use]
+    pub fn current_src(&self) -> Option<Rect<i32>> {
+        self.frames.get(self.index).map(|frame| frame.src)
+    }
+
+    /// Advance playback by `delta` time, scaled by the playback speed.
+    pub fn update(&mut self, delta: Duration) {
+        let total = self.frames.iter().fold(Duration::ZERO, |total, frame| {
+            total.saturating_add(frame.duration)
+        });
+        if self.paused || self.finished || total.is_zero() {
+            return;
+        }
+        let delta =
+            Duration::try_from_secs_f64(delta.as_secs_f64() * self.speed).unwrap_or(Duration::MAX);
+        self.elapsed = self.elapsed.saturating_add(delta);
+        // Skip whole cycles so long stalls or large speeds don't step through every frame
+        match self.mode {
+            PlayMode::Once => self.elapsed = self.elapsed.min(total),
+            PlayMode::Loop => self.elapsed = duration_rem(self.elapsed, total),
+            PlayMode::PingPong => {
+                // Frames between the ends are played twice per cycle
+                let last = self.frames.len() - 1;
+                let cycle = if last == 0 {
+                    total
+                } else {
+                    let ends = self.frames[0]
+                        .duration
+                        .saturating_add(self.frames[last].duration);
+                    total.saturating_mul(2).saturating_sub(ends)
+                };
+                self.elapsed = duration_rem(self.elapsed, cycle);
+            }
+        }
+        while !self.finished {
+            let duration = self.frames[self.index].duration;
+            if self.elapsed < duration {
+                break;
+            }
+            self.elapsed -= duration;
+            self.advance();
+        }
+    }
+
+    /// Move to the next frame based on the [`PlayMode`].
+    fn advance(&mut self) {
+        let last = self.frames.len() - 1;
+        match self.mode {
+            PlayMode::Once if self.index == last => {
+                self.finished = true;
+                self.elapsed = Duration::ZERO;
+            }
+            PlayMode::Once => self.index += 1,
+            PlayMode::Loop => {
+                self.index = if self.index == last {
+                    0
+                } else {
+                    self.index + 1
+                }
+            }
+            PlayMode::PingPong if last == 0 => (),
+            PlayMode::PingPong => {
+                if self.index == last {
+                    self.reverse = true;
+                } else if self.index == 0 {
+                    self.reverse = false;
+                }
+                if self.reverse {
+                    self.index -= 1;
+                } else {
+                    self.index += 1;
+                }
+            }
+        }
+    }
+}
+
+impl PixState {
+    /// Draw a named region of a [`SpriteSheet`] to the current canvas resized to the target `dst`.
+    /// [`PixState::image_tint`] can optionally add a tint color to the rendered sprite.
+    ///
+    /// # Errors
+    ///
+    /// If the region doesn't exist or the renderer fails to draw to the current render target,
+    /// then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { sheet: SpriteSheet };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.sprite(&self.sheet, "player", [10, 10, 32, 32])?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn sprite<S, R>(&mut self, sheet: &SpriteSheet, name: S, dst: R) -> PixResult<()>
+    where
+        S: AsRef<str>,
+        R: Into<Rect<i32>>,
+    {
+        let src = sheet.region(name)?;
+        self.image_transformed(&sheet.image, src, dst.into(), None, None, None)
+    }
+
+    /// Advance an [`Animation`] by [`PixState::delta_time`] and draw the current frame to the
+    /// current canvas at `position`. Drawing an empty animation does nothing.
+    /// [`PixState::image_tint`] can optionally add a tint color to the rendered frame.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to draw to the current render target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { walk: Animation };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.animation(&mut self.walk, [10, 10])?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn animation<P>(&mut self, animation: &mut Animation, position: P) -> PixResult<()>
+    where
+        P: Into<Point<i32>>,
+    {
+        animation.update(self.delta_time());
+        match animation.current_src() {
+            Some(src) => {
+                let dst = rect![position.into(), src.width(), src.height()];
+                self.image_transformed(&animation.image, src, dst, None, None, None)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the remainder of `duration` divided by a non-zero `divisor`.
+fn duration_rem(duration: Duration, divisor: Duration) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    let rem = duration.as_nanos() % divisor.as_nanos();
+    Duration::new((rem / NANOS_PER_SEC) as u64, (rem % NANOS_PER_SEC) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions() -> PixResult<()> {
+        let mut sheet = SpriteSheet::new(Image::rgba(32, 32));
+        sheet
+            .add_region("walk_0", [0, 0, 16, 16])
+            .add_region("walk_1", [16, 0, 16, 16])
+            .add_region("walk_2", [0, 16, 16, 16])
+            .add_region("idle", [16, 16, 16, 16]);
+
+        assert_eq!(sheet.region("walk_1")?, rect![16, 0, 16, 16]);
+        assert_eq!(sheet.region("idle")?, rect![16, 16, 16, 16]);
+        assert_eq!(
+            sheet.region_sequence("walk_", 0..3)?,
+            [
+                rect![0, 0, 16, 16],
+                rect![16, 0, 16, 16],
+                rect![0, 16, 16, 16]
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_region() {
+        let mut sheet = SpriteSheet::default();
+        sheet
+            .add_region("b", [0, 0, 1, 1])
+            .add_region("a", [1, 0, 1, 1]);
+        match sheet.region("c") {
+            Ok(rect) => panic!("unexpected region: {rect:?}"),
+            Err(err) => assert_eq!(
+                err.to_string(),
+                "invalid sprite region `c`, available regions: a, b"
+            ),
+        }
+        assert!(sheet.region_sequence("b", 0..1).is_err());
+    }
+
+    fn animation(mode: PlayMode) -> Animation {
+        let mut animation = Animation::new(Image::rgba(48, 16));
+        for i in 0..3 {
+            animation.add_frame([i * 16, 0, 16, 16], Duration::from_millis(100));
+        }
+        animation.set_play_mode(mode);
+        animation
+    }
+
+    fn step(animation: &mut Animation, millis: u64, steps: usize) -> Vec<usize> {
+        (0..steps)
+            .map(|_| {
+                animation.update(Duration::from_millis(millis));
+                animation.current_frame()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn animation_loop() {
+        let mut animation = animation(PlayMode::Loop);
+        assert_eq!(animation.current_frame(), 0);
+        assert_eq!(step(&mut animation, 50, 8), [0, 1, 1, 2, 2, 0, 0, 1]);
+        // Large deltas wrap around multiple frames
+        assert_eq!(step(&mut animation, 250, 1), [0]);
+        assert_eq!(step(&mut animation, 100, 1), [1]);
+        assert!(!animation.is_finished());
+    }
+
+    #[test]
+    fn animation_ping_pong() {
+        let mut animation = animation(PlayMode::PingPong);
+        assert_eq!(step(&mut animation, 100, 7), [1, 2, 1, 0, 1, 2, 1]);
+    }
+
+    #[test]
+    fn animation_once() {
+        let mut animation = animation(PlayMode::Once);
+        assert_eq!(step(&mut animation, 100, 4), [1, 2, 2, 2]);
+        assert!(animation.is_finished());
+        animation.reset();
+        assert_eq!(animation.current_frame(), 0);
+        assert!(!animation.is_finished());
+    }
+
+    #[test]
+    fn animation_speed_and_pause() {
+        let mut animation = animation(PlayMode::Loop);
+        animation.set_speed(2.0);
+        assert_eq!(step(&mut animation, 50, 2), [1, 2]);
+        animation.pause();
+        assert_eq!(step(&mut animation, 100, 2), [2, 2]);
+        animation.resume();
+        assert_eq!(step(&mut animation, 50, 1), [0]);
+    }
+
+    #[test]
+    fn animation_large_deltas() {
+        let mut looping = animation(PlayMode::Loop);
+        assert_eq!(step(&mut looping, 100 + 300 * 1_000_000_000, 1), [1]);
+        looping.set_speed(f64::MAX);
+        looping.update(Duration::MAX);
+        assert!(looping.current_frame() < 3);
+
+        let mut ping_pong = animation(PlayMode::PingPong);
+        assert_eq!(step(&mut ping_pong, 100 + 400 * 1_000_000_000, 1), [1]);
+        assert_eq!(step(&mut ping_pong, 100, 2), [2, 1]);
+
+        let mut once = animation(PlayMode::Once);
+        once.update(Duration::MAX);
+        assert_eq!(once.current_frame(), 2);
+        assert!(once.is_finished());
+    }
+
+    #[test]
+    fn animation_default_speed() {
+        let mut animation = Animation::default();
+        assert_eq!(animation.speed(), 1.0);
+        animation.add_frame([0, 0, 1, 1], Duration::from_millis(100));
+        animation.add_frame([1, 0, 1, 1], Duration::from_millis(100));
+        assert_eq!(step(&mut animation, 100, 1), [1]);
+    }
+
+    #[test]
+    fn animation_non_finite_speed() {
+        let mut animation = animation(PlayMode::Loop);
+        animation.set_speed(2.0);
+        for speed in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            animation.set_speed(speed);
+            assert_eq!(animation.speed(), 2.0);
+        }
+        assert_eq!(step(&mut animation, 50, 2), [1, 2]);
+    }
+
+    #[test]
+    fn animation_zero_duration() {
+        let mut animation = Animation::new(Image::rgba(48, 16));
+        animation
+            .add_frame([0, 0, 16, 16], Duration::from_millis(100))
+            .add_frame([16, 0, 16, 16], Duration::ZERO)
+            .add_frame([32, 0, 16, 16], Duration::from_millis(100));
+        assert_eq!(step(&mut animation, 100, 3), [2, 0, 2]);
+
+        // All zero-duration frames never advance
+        let mut animation = Animation::new(Image::rgba(16, 16));
+        animation.add_frame([0, 0, 16, 16], Duration::ZERO);
+        assert_eq!(step(&mut animation, 100, 2), [0, 0]);
+
+        let mut animation = Animation::default();
+        animation.update(Duration::from_millis(100));
+        assert_eq!(animation.current_src(), None);
+    }
+}
@@ -15,15 +15,25 @@
 //! - [`PixState::set_title`]: Set new window title.
 //! - [`PixState::mouse_pos`]: [Mouse] position this frame.
 //! - [`PixState::pmouse_pos`]: [Mouse] position previous frame.
+//! - [`PixState::set_mouse_pos`]: Move the [Mouse] cursor to a new position.
 //! - [`PixState::mouse_pressed`]: Whether any [Mouse] button was pressed this frame.
 //! - [`PixState::mouse_clicked`]: Whether a given [Mouse] button was clicked this frame.
+//! - [`PixState::mouse_clicks`]: The number of consecutive [Mouse] clicks ending this frame.
 //! - [`PixState::mouse_down`]: Whether a given [Mouse] button was pressed this frame.
 //! - [`PixState::mouse_buttons`]: A [`HashSet`] of [Mouse] buttons pressed this frame.
+//! - [`PixState::active_touches`]: A [`HashMap`] of [`TouchPoint`]s touching this frame.
 //! - [`PixState::key_pressed`]: Whether a given [Key] was pressed this frame.
 //! - [`PixState::key_down`]: Whether a given [Key] was pressed this frame.
 //! - [`PixState::keys`]: Whether any [Key] was pressed this frame.
 //! - [`PixState::keymod_down`]: Whether a given [key modifier][`KeyMod`] was pressed this frame.
 //! - [`PixState::keymod`]: The [`KeyMod`]s pressed this frame.
+//! - [`PixState::register_shortcut`]: Map a [Key] and [`KeyMod`] combination to a named action.
+//! - [`PixState::shortcut_triggered`]: Whether a named action's shortcut was pressed this frame.
+//! - [`PixState::gamepad_axis`]: The value of a given controller [Axis] this frame.
+//! - [`PixState::gamepad_button_down`]: Whether a given [`ControllerButton`] was pressed this
+//!   frame.
+//! - [`PixState::controllers`]: The [`ControllerId`]s of connected controllers.
+//! - [`PixState::events`]: All [Event]s received this frame, in order.
 //!
 //! # Example
 //!
@@ -43,14 +53,22 @@
 //! ```
 
 use crate::{
+    audio::Mixer,
     gui::state::UiState,
+    image::gif::Recording,
+    math::NoiseTable,
     prelude::*,
     renderer::{Renderer, RendererSettings, Rendering, WindowRenderer},
     texture::TextureRenderer,
 };
 use environment::Environment;
 use settings::Settings;
-use std::{collections::HashSet, mem, time::Instant};
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    time::Duration,
+};
+use web_time::Instant;
 
 pub mod environment;
 pub mod settings;
@@ -64,7 +82,14 @@ pub struct PixState {
     pub(crate) ui: UiState,
     pub(crate) settings: Settings,
     pub(crate) setting_stack: Vec<Settings>,
+    pub(crate) matrix_stack: Vec<Transform>,
+    pub(crate) blend_mode_stack: Vec<BlendMode>,
+    pub(crate) clip_stack: Vec<Option<Rect<i32>>>,
     pub(crate) theme: Theme,
+    pub(crate) fonts: HashMap<FontId, (Font, u32)>,
+    pub(crate) recording: Option<Recording>,
+    pub(crate) mixer: Mixer,
+    pub(crate) noise: NoiseTable,
 }
 
 impl PixState {
@@ -134,6 +159,29 @@ impl PixState {
         self.ui.mouse_pos()
     }
 
+    /// Returns the last mouse position coordinates within a given window as `(x, y)`, or `None` if
+    /// the mouse hasn't moved over that window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { tool_window: WindowId }
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if let Some(pos) = s.mouse_pos_in(self.tool_window) {
+    ///         s.text(format!("Tool window mouse: {pos:?}"))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mouse_pos_in(&self, window_id: WindowId) -> Option<Point<i32>> {
+        self.ui.window_mouse.get(&window_id).copied()
+    }
+
     /// Returns the previous mouse position coordinates last frame as `(x, y)`.
     ///
     /// # Example
@@ -155,6 +203,44 @@ impl PixState {
         self.ui.pmouse_pos()
     }
 
+    /// Move the mouse cursor to a position within the current window target. The position is in
+    /// the same scaled coordinates as [`PixState::mouse_pos`], which along with
+    /// [`PixState::pmouse_pos`] returns the new position immediately, even if the cursor is hidden.
+    ///
+    /// # Errors
+    ///
+    /// If the current window target has been closed or is invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { yaw: i32 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Mouse-look: turn by how far the mouse moved, then re-center it
+    ///     let center = s.center()?;
+    ///     self.yaw += s.mouse_pos().x() - center.x();
+    ///     s.set_mouse_pos(center)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn set_mouse_pos<P: Into<Point<i32>>>(&mut self, pos: P) -> PixResult<()> {
+        let pos = pos.into();
+        // The cursor is warped in window coordinates, while mouse positions are reported in
+        // scaled drawing coordinates
+        let (scale_x, scale_y) = (self.settings.scale_x, self.settings.scale_y);
+        let window_pos = point![
+            (pos.x() as f32 * scale_x).round() as i32,
+            (pos.y() as f32 * scale_y).round() as i32
+        ];
+        self.renderer.set_mouse_pos(window_pos)?;
+        self.ui.pmouse.pos = pos;
+        self.ui.mouse.pos = pos;
+        Ok(())
+    }
+
     /// Returns if any [Mouse] button was pressed this frame.
     ///
     /// # Example
@@ -221,6 +307,56 @@ impl PixState {
         self.ui.mouse_dbl_clicked(btn)
     }
 
+    /// Returns the number of consecutive times a [Mouse] button was clicked, ending with a click
+    /// this frame, or `0` if it wasn't clicked this frame. Clicks are consecutive when each
+    /// follows the previous one within the double click interval without the mouse moving more
+    /// than a few pixels. See [`PixState::set_dbl_click_interval`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { selection: &'static str };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     match s.mouse_clicks(Mouse::Left) {
+    ///         1 => self.selection = "cursor",
+    ///         2 => self.selection = "word",
+    ///         3 => self.selection = "line",
+    ///         _ => (),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mouse_clicks(&self, btn: Mouse) -> u8 {
+        self.ui.mouse_clicks(btn)
+    }
+
+    /// Set the maximum time between [Mouse] clicks for them to count as a double click. The default
+    /// is `400ms`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # use std::time::Duration;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.set_dbl_click_interval(Duration::from_millis(250));
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_dbl_click_interval(&mut self, interval: Duration) {
+        self.ui.mouse.dbl_click_interval = interval;
+    }
+
     /// Returns if a specific [Mouse] button was pressed this frame.
     ///
     /// # Example
@@ -268,6 +404,31 @@ impl PixState {
         self.ui.mouse_buttons()
     }
 
+    /// Returns the fingers currently touching a touch device this frame, by finger identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let (width, height) = s.dimensions()?;
+    ///     for touch in s.active_touches().values().copied().collect::<Vec<_>>() {
+    ///         let x = (touch.x * width as f32) as i32;
+    ///         let y = (touch.y * height as f32) as i32;
+    ///         s.circle([x, y, (touch.pressure * 50.0) as i32])?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn active_touches(&self) -> &HashMap<i64, TouchPoint> {
+        &self.ui.touches
+    }
+
     /// Returns if any [Key] was pressed this frame.
     ///
     /// # Example
@@ -312,6 +473,100 @@ impl PixState {
         self.ui.key_down(key)
     }
 
+    /// Returns if a specific [Key] went down this frame, ignoring key repeat. Unlike
+    /// [`PixState::key_down`], this is only `true` for the frame the key was pressed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { jumping: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.key_just_pressed(Key::Space) {
+    ///         self.jumping = true;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn key_just_pressed(&self, key: Key) -> bool {
+        self.ui.key_just_pressed(key)
+    }
+
+    /// Returns if a specific [Key] was released this frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { charging: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.key_released(Key::Space) {
+    ///         self.charging = false;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn key_released(&self, key: Key) -> bool {
+        self.ui.key_released(key)
+    }
+
+    /// Returns if a specific [Key] went down this frame or was repeated by the operating system
+    /// while held, which is useful for navigating menus and lists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { selected: usize };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.key_typed(Key::Down) {
+    ///         self.selected += 1;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn key_typed(&self, key: Key) -> bool {
+        self.ui.key_typed(key)
+    }
+
+    /// Returns the last [Key] that went down, ignoring key repeat, or `None` if no key has been
+    /// pressed yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { jump: Key, remapping: bool };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if self.remapping && s.key_pressed() {
+    ///         if let Some(key) = s.last_key_pressed() {
+    ///             self.jump = key;
+    ///             self.remapping = false;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn last_key_pressed(&self) -> Option<Key> {
+        self.ui.last_key_pressed()
+    }
+
     /// Returns a list of the current keys pressed this frame.
     ///
     /// # Example
@@ -378,6 +633,204 @@ impl PixState {
     pub const fn keymod(&self) -> &KeyMod {
         self.ui.keymod()
     }
+
+    /// Map a [Key] and [`KeyMod`] combination to a named `action`, checked with
+    /// [`PixState::shortcut_triggered`]. An action can be mapped to multiple shortcuts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.register_shortcut(Key::S, KeyMod::CTRL, "save");
+    ///     s.register_shortcut(Key::F2, KeyMod::NONE, "save");
+    ///     Ok(())
+    /// }
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn register_shortcut(&mut self, key: Key, mods: KeyMod, action: &'static str) {
+        self.ui.register_shortcut(key, mods, action);
+    }
+
+    /// Returns if any shortcut registered for `action` with [`PixState::register_shortcut`] was
+    /// pressed this frame with exactly its key modifiers held.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.register_shortcut(Key::S, KeyMod::CTRL, "save");
+    ///     Ok(())
+    /// }
+    ///
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.shortcut_triggered("save") {
+    ///         s.text("Saved!")?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn shortcut_triggered(&self, action: &str) -> bool {
+        self.ui.shortcut_triggered(action)
+    }
+
+    /// Returns the current value of a controller [Axis], ranging from `i16::MIN..=i16::MAX`, or
+    /// `0` within the joystick deadzone or if the controller is not connected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { player1: ControllerId, x: f32 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let axis = s.gamepad_axis(self.player1, Axis::LeftX);
+    ///     self.x += f32::from(axis) / f32::from(i16::MAX);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn gamepad_axis(&self, id: ControllerId, axis: Axis) -> i16 {
+        self.ui.controllers.axis(id, axis)
+    }
+
+    /// Returns if a specific [`ControllerButton`] is currently being held.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { player1: ControllerId };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.gamepad_button_down(self.player1, ControllerButton::A) {
+    ///         s.background(Color::random());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_down(&self, id: ControllerId, button: ControllerButton) -> bool {
+        self.ui.controllers.is_down(id, button)
+    }
+
+    /// Returns the IDs of the connected controllers, in the order they were connected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     for id in s.controllers().to_vec() {
+    ///         s.text(format!("Player {}: {}", *id + 1, s.gamepad_axis(id, Axis::LeftX)))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn controllers(&self) -> &[ControllerId] {
+        self.ui.controllers.connected()
+    }
+
+    /// Returns an iterator over all [Event]s received this frame, in the order they were
+    /// received. Includes events handled by [`PixEngine::on_event`] and the other event
+    /// callbacks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { files: Vec<std::path::PathBuf> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     for event in s.events() {
+    ///         if let Event::DropFile { path, .. } = event {
+    ///             self.files.push(path.clone());
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.ui.events.iter()
+    }
+
+    /// Set the joystick deadzone applied to controller [Axis] values, within which the value is
+    /// reported as `0`. Defaults to `8000`. See also
+    /// [`crate::engine::EngineBuilder::deadzone`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.set_gamepad_deadzone(4000);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_gamepad_deadzone(&mut self, value: i32) {
+        self.ui.controllers.deadzone = value.clamp(0, i32::from(i16::MAX));
+    }
+
+    /// Start a rumble effect on a controller for a given `duration`, with `low` and `high`
+    /// frequency motor intensities ranging from `0..=u16::MAX`. Calling this again replaces any
+    /// rumble effect in progress, and an intensity of `0` stops it.
+    ///
+    /// # Errors
+    ///
+    /// If the controller is not connected or does not support rumble, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # use std::time::Duration;
+    /// # struct App { player1: ControllerId };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_controller_pressed(&mut self, s: &mut PixState, event: ControllerEvent) -> PixResult<bool> {
+    ///     if event.button == ControllerButton::A {
+    ///         s.controller_rumble(event.controller_id, 0x4000, 0xC000, Duration::from_millis(250))?;
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn controller_rumble(
+        &mut self,
+        id: ControllerId,
+        low: u16,
+        high: u16,
+        duration: Duration,
+    ) -> PixResult<()> {
+        self.renderer.rumble_controller(id, low, high, duration)
+    }
 }
 
 impl PixState {
@@ -393,7 +846,14 @@ impl PixState {
             ui: UiState::default(),
             settings: Settings::default(),
             setting_stack: Vec::new(),
+            matrix_stack: Vec::new(),
+            blend_mode_stack: Vec::new(),
+            clip_stack: Vec::new(),
             theme: theme.clone(),
+            fonts: HashMap::new(),
+            recording: None,
+            mixer: Mixer::default(),
+            noise: NoiseTable::default(),
         };
         state.background(theme.colors.background);
         state.fill(theme.colors.on_background());
@@ -411,15 +871,33 @@ impl PixState {
         // Reset mouse cursor icon to the current setting
         // Ignore any errors, as setting cursor in the first place should have succeeded.
         let _ignore_result = self.renderer.cursor(self.settings.cursor.as_ref());
+        self.reset_matrix();
+        self.matrix_stack.clear();
+        self.ui.target_window = Some(self.renderer.window_id());
         self.ui.pre_update(&self.theme);
     }
 
     /// Handle state updates for this frame.
     #[inline]
     pub(crate) fn on_update(&mut self) -> PixResult<()> {
-        for texture in self.ui.textures.iter_mut().filter(|t| t.visible) {
-            self.renderer
-                .texture(texture.id, texture.src, texture.dst, 0.0, None, None, None)?;
+        // Overlays, like tooltips, are drawn above all other UI textures
+        for overlay in [false, true] {
+            for texture in self
+                .ui
+                .textures
+                .iter()
+                .filter(|t| t.visible && t.overlay == overlay)
+            {
+                self.renderer.texture(
+                    texture.id,
+                    texture.src,
+                    texture.dst,
+                    0.0,
+                    None,
+                    None,
+                    None,
+                )?;
+            }
         }
         Ok(())
     }
@@ -465,10 +943,10 @@ impl PixState {
 
     /// Updates the mouse click state this frame.
     #[inline]
-    pub(crate) fn on_mouse_click(&mut self, btn: Mouse, time: Instant) {
+    pub(crate) fn on_mouse_click(&mut self, btn: Mouse, pos: Point<i32>, time: Instant) {
         self.ui.pmouse.clicked = mem::take(&mut self.ui.mouse.clicked);
-        self.ui.pmouse.last_clicked = mem::take(&mut self.ui.mouse.last_clicked);
-        self.ui.mouse.click(btn, time);
+        self.ui.pmouse.last_clicked = self.ui.mouse.last_clicked.clone();
+        self.ui.mouse.click(btn, pos, time);
     }
 
     /// Updates the mouse double click state this frame.
@@ -483,6 +961,9 @@ impl PixState {
     pub(crate) fn on_mouse_pressed(&mut self, btn: Mouse) {
         self.ui.pmouse.pressed = mem::take(&mut self.ui.mouse.pressed);
         self.ui.mouse.press(btn);
+        if btn == Mouse::Left {
+            self.ui.drag.press(self.ui.mouse.pos);
+        }
     }
 
     /// Updates the mouse released state this frame.
@@ -514,12 +995,16 @@ impl PixState {
     /// error is returned.
     #[inline]
     pub fn open_controller(&mut self, id: ControllerId) -> PixResult<()> {
-        self.renderer.open_controller(id)
+        self.renderer.open_controller(id)?;
+        self.ui.controllers.connect(id);
+        Ok(())
     }
 
-    /// Close a controller with a given ID to stop handling events.
+    /// Close a controller with a given ID to stop handling events, clearing its axis and button
+    /// state.
     #[inline]
     pub fn close_controller(&mut self, id: ControllerId) {
         self.renderer.close_controller(id);
+        self.ui.controllers.remove(id);
     }
 }
@@ -8,6 +8,10 @@
 //! - [`PixState::delta_time`]: [Duration] elapsed since last frame.
 //! - [`PixState::elapsed`]: [Duration] elapsed since application start.
 //! - [`PixState::frame_count`]: Total number of frames since application start.
+//! - [`PixState::fixed_timestep`]: [Duration] between calls to [`PixEngine::on_fixed_update`].
+//! - [`PixState::set_fixed_timestep`]: Set the [Duration] between calls to
+//!   [`PixEngine::on_fixed_update`].
+//! - [`PixState::frame_interpolation`]: Fraction of a fixed timestep left over this frame.
 //! - [`PixState::redraw`]: Run render loop 1 time, calling [`PixEngine::on_update`].
 //! - [`PixState::run_times`]: Run render loop N times, calling [`PixEngine::on_update`].
 //! - [`PixState::avg_frame_rate`]: Average frames per second rendered.
@@ -24,37 +28,107 @@ use crate::{
     prelude::*,
     renderer::{Rendering, WindowRenderer},
 };
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use time::OffsetDateTime;
+use web_time::Instant;
 
 const ONE_SECOND: Duration = Duration::from_secs(1);
 
+/// Default [Duration] between calls to [`PixEngine::on_fixed_update`].
+pub(crate) const DEFAULT_FIXED_TIMESTEP: Duration = Duration::from_nanos(16_666_667);
+/// Maximum number of fixed timesteps run in a single frame. Time accumulated beyond this after a
+/// long stall is dropped so the simulation can catch up.
+pub(crate) const MAX_FIXED_STEPS: u32 = 8;
+
+/// Accumulates frame time to be consumed in fixed timesteps.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct FixedTimestep {
+    step: Duration,
+    accumulator: Duration,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(DEFAULT_FIXED_TIMESTEP)
+    }
+}
+
+impl FixedTimestep {
+    /// Constructs a `FixedTimestep` with a given `step`.
+    pub(crate) const fn new(step: Duration) -> Self {
+        Self {
+            step,
+            accumulator: Duration::ZERO,
+        }
+    }
+
+    /// Accumulate `elapsed` time, returning the number of fixed steps to run, up to
+    /// [`MAX_FIXED_STEPS`].
+    pub(crate) fn advance(&mut self, elapsed: Duration) -> u32 {
+        if self.step.is_zero() {
+            return 0;
+        }
+        let max = self.step.saturating_mul(MAX_FIXED_STEPS);
+        self.accumulator = self.accumulator.saturating_add(elapsed).min(max);
+        let mut steps = 0;
+        while self.accumulator >= self.step {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+        steps
+    }
+
+    /// The fraction of a fixed step left in the accumulator, between `0.0` and `1.0`.
+    pub(crate) fn alpha(&self) -> f64 {
+        if self.step.is_zero() {
+            0.0
+        } else {
+            self.accumulator.as_secs_f64() / self.step.as_secs_f64()
+        }
+    }
+}
+
 /// Environment values for [`PixState`]
 #[derive(Debug, Clone)]
 pub(crate) struct Environment {
-    focused_window: Option<WindowId>,
     delta_time: Duration,
     start: Instant,
     frame_rate: f32,
     frame_count: usize,
+    frame_rate_count: usize,
     run_count: usize,
     quit: bool,
     last_frame_time: Instant,
     frame_timer: Duration,
+    fixed_timestep: FixedTimestep,
+    minimized: bool,
 }
 
 impl Default for Environment {
     fn default() -> Self {
         Self {
-            focused_window: None,
             delta_time: Duration::default(),
             start: Instant::now(),
             frame_rate: 0.0,
             frame_count: 0,
+            frame_rate_count: 0,
             run_count: 0,
             quit: false,
             last_frame_time: Instant::now(),
             frame_timer: Duration::default(),
+            fixed_timestep: FixedTimestep::default(),
+            minimized: false,
+        }
+    }
+}
+
+impl Environment {
+    /// Track whether the primary window is minimized from its [`WindowEvent`]s.
+    pub(crate) fn update_minimized(&mut self, event: WindowEvent) {
+        match event {
+            WindowEvent::Minimized => self.minimized = true,
+            WindowEvent::Restored | WindowEvent::Maximized => self.minimized = false,
+            _ => (),
         }
     }
 }
@@ -87,7 +161,7 @@ impl PixState {
     #[inline]
     #[must_use]
     pub fn focused(&self) -> bool {
-        self.env
+        self.ui
             .focused_window
             .map(|focused_window| focused_window == self.renderer.window_id())
             .unwrap_or_default()
@@ -114,7 +188,52 @@ impl PixState {
     #[inline]
     #[must_use]
     pub fn focused_window(&self, window_id: WindowId) -> bool {
-        matches!(self.env.focused_window, Some(id) if id == window_id)
+        matches!(self.ui.focused_window, Some(id) if id == window_id)
+    }
+
+    /// Returns the window with keyboard focus, if any. Keyboard events are sent to this window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if let Some(window_id) = s.focused_window_id() {
+    ///         s.text(format!("Window {window_id} has focus"))?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn focused_window_id(&self) -> Option<WindowId> {
+        self.ui.focused_window
+    }
+
+    /// Returns the window the mouse is currently over, if any. Mouse events are sent to this
+    /// window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.hovered_window() == Some(s.window_id()) {
+    ///         s.circle([s.mouse_pos().x(), s.mouse_pos().y(), 10])?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn hovered_window(&self) -> Option<WindowId> {
+        self.ui.hovered_window
     }
 
     /// The [Duration] elapsed since last frame.
@@ -184,6 +303,75 @@ impl PixState {
         self.env.frame_count
     }
 
+    /// The [Duration] between calls to [`PixEngine::on_fixed_update`]. Defaults to `1/60` of a
+    /// second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let steps_per_second = 1.0 / s.fixed_timestep().as_secs_f64();
+    ///     s.text(format!("Simulation rate: {steps_per_second:.0}Hz"))?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn fixed_timestep(&self) -> Duration {
+        self.env.fixed_timestep.step
+    }
+
+    /// Set the [Duration] between calls to [`PixEngine::on_fixed_update`]. A zero `timestep`
+    /// disables calling [`PixEngine::on_fixed_update`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # use std::time::Duration;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Simulate physics at 120Hz
+    ///     s.set_fixed_timestep(Duration::from_secs(1) / 120);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_fixed_timestep(&mut self, timestep: Duration) {
+        self.env.fixed_timestep = FixedTimestep::new(timestep);
+    }
+
+    /// The fraction of a fixed timestep, between `0.0` and `1.0`, accumulated but not yet
+    /// simulated by [`PixEngine::on_fixed_update`] this frame. Used to interpolate rendering
+    /// between the previous and current simulated states.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { pos: f64, prev_pos: f64 };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let alpha = s.frame_interpolation();
+    ///     let x = self.prev_pos + (self.pos - self.prev_pos) * alpha;
+    ///     s.circle([x as i32, 100, 10])?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn frame_interpolation(&self) -> f64 {
+        self.env.fixed_timestep.alpha()
+    }
+
     /// Run the render loop 1 time by calling [`PixEngine::on_update`].
     ///
     /// This can be used to only redraw in response to user actions such as
@@ -381,6 +569,13 @@ impl PixState {
         self.env.last_frame_time = now;
     }
 
+    /// Accumulate `elapsed` frame time, returning the number of times to call
+    /// [`PixEngine::on_fixed_update`] this frame.
+    #[inline]
+    pub(crate) fn advance_fixed_timestep(&mut self, elapsed: Duration) -> u32 {
+        self.env.fixed_timestep.advance(elapsed)
+    }
+
     /// Whether the current render loop should be running or not.
     #[inline]
     pub(crate) const fn is_running(&self) -> bool {
@@ -393,8 +588,8 @@ impl PixState {
         self.env.quit
     }
 
-    /// Increment the internal frame counter. If the `show_frame_rate` option is set, update the
-    /// title at most once every second.
+    /// Increment the internal frame counter and update the average frame rate at most once every
+    /// second. If the `show_frame_rate` option is set, also update the title.
     #[inline]
     pub(crate) fn increment_frame(&mut self, time_since_last: Duration) -> PixResult<()> {
         let s = &self.settings;
@@ -405,13 +600,16 @@ impl PixState {
         }
         env.frame_count += 1;
 
-        if s.running && s.show_frame_rate {
+        if s.running {
+            env.frame_rate_count += 1;
             env.frame_timer += time_since_last;
             if env.frame_timer >= ONE_SECOND {
-                env.frame_rate = env.frame_count as f32 / env.frame_timer.as_secs_f32();
+                env.frame_rate = env.frame_rate_count as f32 / env.frame_timer.as_secs_f32();
                 env.frame_timer -= ONE_SECOND;
-                env.frame_count = 0;
-                self.renderer.set_fps(env.frame_rate)?;
+                env.frame_rate_count = 0;
+                if s.show_frame_rate {
+                    self.renderer.set_fps(env.frame_rate)?;
+                }
             }
         }
 
@@ -420,6 +618,75 @@ impl PixState {
 
     /// Focus a given window.
     pub(crate) fn focus_window(&mut self, id: Option<WindowId>) {
-        self.env.focused_window = id;
+        self.ui.focus_window(id);
+    }
+
+    /// Whether rendering is paused because the primary window is minimized.
+    #[inline]
+    pub(crate) const fn rendering_paused(&self) -> bool {
+        self.settings.pause_rendering_when_minimized && self.env.minimized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_timestep_accumulates() {
+        let step = Duration::from_millis(10);
+        let mut fixed = FixedTimestep::new(step);
+
+        assert_eq!(fixed.advance(Duration::from_millis(4)), 0);
+        assert!((fixed.alpha() - 0.4).abs() < 1e-9);
+        assert_eq!(fixed.advance(Duration::from_millis(25)), 2);
+        assert!((fixed.alpha() - 0.9).abs() < 1e-9);
+        assert_eq!(fixed.advance(Duration::from_millis(1)), 1);
+        assert!(fixed.alpha().abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_timestep_clamps_stalls() {
+        let step = Duration::from_millis(10);
+        let mut fixed = FixedTimestep::new(step);
+
+        // A long stall only runs the maximum number of steps and drops the rest
+        assert_eq!(fixed.advance(Duration::from_secs(5)), MAX_FIXED_STEPS);
+        assert!(fixed.alpha().abs() < 1e-9);
+        assert_eq!(fixed.advance(Duration::from_millis(15)), 1);
+        assert!((fixed.alpha() - 0.5).abs() < 1e-9);
+
+        let mut disabled = FixedTimestep::new(Duration::ZERO);
+        assert_eq!(disabled.advance(Duration::from_secs(1)), 0);
+        assert!(disabled.alpha().abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_timestep_large_durations() {
+        let mut fixed = FixedTimestep::new(Duration::MAX);
+        assert_eq!(fixed.advance(Duration::MAX), 1);
+        assert_eq!(fixed.advance(Duration::MAX), 1);
+
+        let mut fixed = FixedTimestep::new(Duration::from_millis(10));
+        assert_eq!(fixed.advance(Duration::MAX), MAX_FIXED_STEPS);
+        assert_eq!(fixed.advance(Duration::MAX), MAX_FIXED_STEPS);
+    }
+
+    #[test]
+    fn minimized_tracks_window_events() {
+        let mut env = Environment::default();
+        assert!(!env.minimized);
+
+        env.update_minimized(WindowEvent::Minimized);
+        assert!(env.minimized);
+        env.update_minimized(WindowEvent::FocusLost);
+        env.update_minimized(WindowEvent::Moved(10, 20));
+        assert!(env.minimized);
+        env.update_minimized(WindowEvent::Restored);
+        assert!(!env.minimized);
+
+        env.update_minimized(WindowEvent::Minimized);
+        env.update_minimized(WindowEvent::Maximized);
+        assert!(!env.minimized);
     }
 }
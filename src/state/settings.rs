@@ -9,6 +9,8 @@
 //! - [`EllipseMode`]: Alias for `DrawMode`.
 //! - [`ImageMode`]: Alias for `DrawMode`.
 //! - [`ArcMode`]: Determines how arcs are rendered.
+//! - [`Align`]: Determines how text is aligned horizontally.
+//! - [`Baseline`]: Determines how text is aligned vertically.
 //! - [`BlendMode`]: Determines how images and textures are blended.
 //! - [`AngleMode`]: Determines how angles are interpreted.
 //! - [`FontStyle`]: Determines how text is rendered.
@@ -18,21 +20,34 @@
 //! - [`PixState::background`]: Sets the [Color] used by [`PixState::clear`] to clear the canvas.
 //! - [`PixState::fill`]: Sets the [Color] used to fill shapes.
 //! - [`PixState::stroke`]: Sets the [Color] used to stroke shapes and text.
-//! - [`PixState::stroke_weight`]: Sets the stroke line thickness for lines and text.
+//! - [`PixState::stroke_weight`]: Sets the stroke line thickness for lines, shapes and text.
+//! - [`PixState::font_fallbacks`]: Sets fonts used to draw characters missing from the current
+//!   font.
+//! - [`PixState::add_font`]: Add a [Font] for use with [`PixState::set_font`].
+//! - [`PixState::load_font`]: Load a font file for use with [`PixState::set_font`].
+//! - [`PixState::set_font`]: Sets the font family and size used for drawing text.
 //! - [`PixState::text_shadow`]: Sets the shadow distance for drawing text.
 //! - [`PixState::smooth`]: Enables the anti-alias smoothing option for drawing shapes.
 //! - [`PixState::bezier_detail`]: Set the resolution at which Bezier curves are dispalyed.
 //! - [`PixState::wrap`]: Sets the wrap width for rendering text.
+//! - [`PixState::text_align`]: Sets the horizontal and vertical alignment for rendering text.
 //! - [`PixState::clip`]: Sets a clip rectangle for rendering.
+//! - [`PixState::push_clip`]: Push the current clip rectangle to a stack and clip to the
+//!   intersection with a new rectangle.
+//! - [`PixState::pop_clip`]: Pop the previously pushed clip rectangle, restoring it.
 //! - [`PixState::fullscreen`]: Sets fullscreen mode to enabled or disabled.
 //! - [`PixState::toggle_fullscreen`]: Toggles fullscreen.
 //! - [`PixState::vsync`]: Sets vertical sync mode to enabled or disabled.
 //! - [`PixState::toggle_vsync`]: Toggles vertical sync.
+//! - [`PixState::render_driver`]: The graphics backend used for rendering.
 //! - [`PixState::cursor`]: Set a custom window cursor or hide the cursor.
+//! - [`PixState::confine_cursor`]: Confine the cursor to the window or release it.
+//! - [`PixState::is_cursor_confined`]: Whether the cursor is confined to the window.
 //! - [`PixState::disable`]: Disable UI elements from being interactive.
 //! - [`PixState::running`]: Whether the render loop is running (calling [`PixEngine::on_update`]).
 //! - [`PixState::run`]: Enable or disable the render loop.
 //! - [`PixState::show_frame_rate`]: Display the average frame rate in the title bar.
+//! - [`PixState::pause_rendering_when_minimized`]: Skip rendering while the window is minimized.
 //! - [`PixState::target_frame_rate`]: Return the current targeted frame rate.
 //! - [`PixState::frame_rate`]: Set or clear a targeted frame rate.
 //! - [`PixState::scale`]: Set the rendering scale of the current canvas.
@@ -43,6 +58,8 @@
 //! - [`PixState::arc_mode`]: Change the [`ArcMode`] for rendering arcs.
 //! - [`PixState::angle_mode`]: Change the [`AngleMode`] for angle interpretation.
 //! - [`PixState::blend_mode`]: Change the [`BlendMode`] for rendering images and textures.
+//! - [`PixState::push_blend_mode`]: Push the current [`BlendMode`] to a stack and change it.
+//! - [`PixState::pop_blend_mode`]: Pop the previously pushed [`BlendMode`], restoring it.
 //! - [`PixState::push`]: Push a copy of all the current settings to a stack.
 //! - [`PixState::pop`]: Pop the previously pushed settings off the stack, restoring them.
 
@@ -51,8 +68,11 @@ use crate::{
     renderer::{Rendering, WindowRenderer},
 };
 use bitflags::bitflags;
+use log::warn;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
 use std::time::Duration;
 
 /// Drawing mode which changes how `(x, y)` coordinates are interpreted.
@@ -86,6 +106,32 @@ pub enum ArcMode {
     Pie,
 }
 
+/// Horizontal alignment of text relative to the drawing position.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Align {
+    /// Align text to the right of the drawing position. Default.
+    Left,
+    /// Center text on the drawing position.
+    Center,
+    /// Align text to the left of the drawing position.
+    Right,
+}
+
+/// Vertical alignment of text relative to the drawing position.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Baseline {
+    /// Align the top of the text with the drawing position. Default.
+    Top,
+    /// Center text vertically on the drawing position.
+    Middle,
+    /// Align the bottom of the text with the drawing position.
+    Bottom,
+}
+
 /// Drawing mode which determines how textures are blended together.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -145,10 +191,15 @@ pub(crate) struct Settings {
     pub(crate) text_shadow: Option<u16>,
     pub(crate) smooth: bool,
     pub(crate) bezier_detail: i32,
+    pub(crate) noise_octaves: u32,
+    pub(crate) noise_falloff: f64,
     pub(crate) wrap_width: Option<u32>,
+    pub(crate) text_align: Align,
+    pub(crate) text_baseline: Baseline,
     pub(crate) clip: Option<Rect<i32>>,
     pub(crate) running: bool,
     pub(crate) show_frame_rate: bool,
+    pub(crate) pause_rendering_when_minimized: bool,
     pub(crate) target_frame_rate: Option<usize>,
     pub(crate) target_delta_time: Option<Duration>,
     pub(crate) scale_x: f32,
@@ -162,6 +213,8 @@ pub(crate) struct Settings {
     pub(crate) blend_mode: BlendMode,
     pub(crate) cursor: Option<Cursor>,
     pub(crate) disabled: bool,
+    pub(crate) camera: Option<Camera2D>,
+    pub(crate) transform: Transform,
 }
 
 impl Default for Settings {
@@ -177,10 +230,15 @@ impl Default for Settings {
             text_shadow: None,
             smooth: true,
             bezier_detail: 20,
+            noise_octaves: crate::math::DEFAULT_NOISE_OCTAVES,
+            noise_falloff: crate::math::DEFAULT_NOISE_FALLOFF,
             wrap_width: None,
+            text_align: Align::Left,
+            text_baseline: Baseline::Top,
             clip: None,
             running: true,
             show_frame_rate: false,
+            pause_rendering_when_minimized: true,
             target_frame_rate: None,
             target_delta_time: None,
             scale_x: 1.0,
@@ -194,6 +252,8 @@ impl Default for Settings {
             blend_mode: BlendMode::None,
             cursor: Some(Cursor::default()),
             disabled: false,
+            camera: None,
+            transform: Transform::IDENTITY,
         }
     }
 }
@@ -275,7 +335,8 @@ impl PixState {
         self.settings.stroke = color.into();
     }
 
-    /// Sets the width used to draw lines on the canvas.
+    /// Sets the width used to draw lines and shape outlines on the canvas. Rectangle outlines are
+    /// drawn inside the rectangle bounds. Defaults to `1`.
     ///
     /// # Example
     ///
@@ -288,6 +349,8 @@ impl PixState {
     ///     s.stroke_weight(2);
     ///     // Draws a 2-pixel wide diagonal line
     ///     s.line(line_![0, 0, 100, 100])?;
+    ///     // Draws a circle with a 2-pixel wide outline
+    ///     s.circle([200, 200, 50])?;
     ///     Ok(())
     /// }
     /// # }
@@ -295,6 +358,7 @@ impl PixState {
     #[inline]
     pub fn stroke_weight(&mut self, weight: u16) {
         self.settings.stroke_weight = weight;
+        self.renderer.stroke_weight(weight);
     }
 
     /// Set the font size for drawing to the current canvas.
@@ -349,12 +413,13 @@ impl PixState {
         self.renderer.font_style(style);
     }
 
-    /// Set the font family for drawing to the current canvas.
+    /// Set the font family for drawing to the current canvas. If the font fails to load, for
+    /// example if a font file is missing, a warning is logged and the embedded default font is
+    /// used instead.
     ///
     /// # Errors
     ///
-    /// If the renderer fails to load the given font size from the currently loaded font data, then
-    /// an error is returned.
+    /// If the renderer fails to load the default font, then an error is returned.
     ///
     /// # Example
     ///
@@ -373,8 +438,166 @@ impl PixState {
     /// ```
     #[inline]
     pub fn font_family(&mut self, font: Font) -> PixResult<()> {
+        if let Err(err) = self.renderer.font_family(&font) {
+            warn!(
+                "Failed to load font `{}`, using the default font instead: {}",
+                font.name(),
+                err
+            );
+            self.settings.font_family = Font::default();
+            return self.renderer.font_family(&self.settings.font_family);
+        }
         self.settings.font_family = font;
-        self.renderer.font_family(&self.settings.font_family)
+        Ok(())
+    }
+
+    /// Set a chain of fallback fonts used to draw characters missing from the current font.
+    /// Characters missing from every font are drawn as a replacement character instead of
+    /// failing. Passing an empty list removes any fallback fonts.
+    ///
+    /// Characters outside of the Basic Multilingual Plane, such as most emoji, are always drawn
+    /// using the first fallback font.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to load any of the fallback fonts, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.font_fallbacks([Font::NOTO, Font::from_file("Emoji", "./emoji.ttf")])?;
+    ///     Ok(())
+    /// }
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.text("Snowman: ☃")?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn font_fallbacks<I>(&mut self, fonts: I) -> PixResult<()>
+    where
+        I: IntoIterator<Item = Font>,
+    {
+        let fonts: Vec<Font> = fonts.into_iter().collect();
+        self.renderer.font_fallbacks(&fonts)
+    }
+
+    /// Add a [Font] from embedded bytes or a `.ttf` file with a given font `size`, returning a
+    /// [`FontId`] which can be passed to [`PixState::set_font`]. Adding a font with the same name
+    /// again replaces the previous font and size. Embedded font data can be included with
+    /// [`include_bytes!`].
+    ///
+    /// The current font is not changed.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to load the font data or the given font size, then an error is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { font: FontId };
+    /// # const FONT_TTF: &[u8] = &[];
+    /// // const FONT_TTF: &[u8] = include_bytes!("../assets/custom_font.ttf");
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     self.font = s.add_font(Font::from_bytes("Custom font", FONT_TTF), 16)?;
+    ///     Ok(())
+    /// }
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_font(&mut self, font: Font, size: u32) -> PixResult<FontId> {
+        let id = font.id();
+
+        // Load the font eagerly to surface any errors, then restore the current font
+        let result = self
+            .renderer
+            .font_size(size)
+            .and_then(|_| self.renderer.font_family(&font));
+        let _ = self.renderer.font_size(self.settings.font_size);
+        let _ = self.renderer.font_family(&self.settings.font_family);
+        result?;
+
+        self.fonts.insert(id, (font, size));
+        Ok(id)
+    }
+
+    /// Load a `.ttf` font file with a given font `size`, returning a [`FontId`] which can be passed
+    /// to [`PixState::set_font`]. Loading the same path again replaces the previous size.
+    ///
+    /// The current font is not changed.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to load the font file or the given font size, then an error is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { font: FontId };
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     self.font = s.load_font("./custom_font.ttf", 16)?;
+    ///     Ok(())
+    /// }
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_font<P>(&mut self, path: P, size: u16) -> PixResult<FontId>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        self.add_font(
+            Font::from_file(path.display().to_string(), path),
+            u32::from(size),
+        )
+    }
+
+    /// Set the font family and size for drawing to the current canvas to a font previously added
+    /// with [`PixState::add_font`] or [`PixState::load_font`]. The font can be restored using
+    /// [`PixState::push`] and [`PixState::pop`] like any other setting.
+    ///
+    /// # Errors
+    ///
+    /// If `id` was not returned from [`PixState::add_font`] or [`PixState::load_font`] or the
+    /// renderer fails to load the font, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { font: FontId };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push();
+    ///     s.set_font(self.font)?;
+    ///     s.text("Some custom font text")?;
+    ///     s.pop();
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn set_font(&mut self, id: FontId) -> PixResult<()> {
+        let (font, size) = self
+            .fonts
+            .get(&id)
+            .cloned()
+            .ok_or(PixError::InvalidFont(id))?;
+        self.font_size(size)?;
+        self.font_family(font)
     }
 
     /// Sets the text shadow distance used to draw text on the canvas.
@@ -404,6 +627,10 @@ impl PixState {
     /// Enable or disable the anti-alias option used for drawing shapes on the canvas. `smooth` is
     /// enabled by default.
     ///
+    /// Anti-aliasing applies to the outlines of lines, triangles, quads, polygons, circles and
+    /// ellipses. Arcs, and outlines wider than a single pixel set by [`PixState::stroke_weight`],
+    /// are always drawn aliased.
+    ///
     /// # Example
     ///
     /// ```
@@ -481,6 +708,37 @@ impl PixState {
         self.settings.wrap_width = width.into();
     }
 
+    /// Sets the horizontal and vertical alignment of text drawn with [`PixState::text`],
+    /// [`PixState::text_wrapped`], and [`PixState::text_transformed`] relative to the current
+    /// cursor position. Each line of multi-line or wrapped text is aligned individually. UI
+    /// widgets are unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Center the score at the top of the window
+    ///     s.text_align(Align::Center, Baseline::Top);
+    ///     s.set_cursor_pos([s.width()? as i32 / 2, 10]);
+    ///     s.text("Score: 100")?;
+    ///
+    ///     // Right-align text in the bottom-right corner
+    ///     s.text_align(Align::Right, Baseline::Bottom);
+    ///     s.set_cursor_pos([s.width()? as i32 - 10, s.height()? as i32 - 10]);
+    ///     s.text("Lives: 3\nLevel: 2")?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn text_align(&mut self, align: Align, baseline: Baseline) {
+        self.settings.text_align = align;
+        self.settings.text_baseline = baseline;
+    }
+
     /// Sets the clip [Rect] used by the renderer to draw to the current canvas. `None` disables
     /// clipping.
     ///
@@ -511,6 +769,79 @@ impl PixState {
         self.renderer.clip(self.settings.clip)
     }
 
+    /// Saves the current clip [Rect] to a stack and clips to the intersection of it and `rect`
+    /// until [`PixState::pop_clip`] is called. Nested clips are never able to draw outside of
+    /// their parent clip. If `rect` doesn't overlap the current clip, nothing will be drawn.
+    ///
+    /// # Errors
+    ///
+    /// If the current render target is closed or dropped, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push_clip(rect![0, 0, 100, 100])?;
+    ///     s.push_clip(rect![50, 50, 100, 100])?;
+    ///     // Only renders within `rect![50, 50, 50, 50]`
+    ///     s.circle([100, 100, 200, 200])?;
+    ///     s.pop_clip()?;
+    ///     s.pop_clip()?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[doc(alias = "clip_push")]
+    #[inline]
+    pub fn push_clip<R>(&mut self, rect: R) -> PixResult<()>
+    where
+        R: Into<Rect<i32>>,
+    {
+        let rect = rect.into();
+        let clip = match self.settings.clip {
+            // An empty clip gets clamped to 1x1 by some renderers, so move it off the canvas
+            Some(clip) => clip
+                .intersection(rect)
+                .unwrap_or_else(|| rect![-1, -1, 0, 0]),
+            None => rect,
+        };
+        self.clip_stack.push(self.settings.clip);
+        self.clip(clip)
+    }
+
+    /// Restores the clip [Rect] saved by [`PixState::push_clip`], if present. If the stack is
+    /// empty, the clip will remain unchanged.
+    ///
+    /// # Errors
+    ///
+    /// If the current render target is closed or dropped, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push_clip(rect![0, 0, 100, 100])?;
+    ///     s.circle([100, 100, 200, 200])?;
+    ///     s.pop_clip()?; // Restore previous clip
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[doc(alias = "clip_pop")]
+    #[inline]
+    pub fn pop_clip(&mut self) -> PixResult<()> {
+        match self.clip_stack.pop() {
+            Some(clip) => self.clip(clip),
+            None => Ok(()),
+        }
+    }
+
     /// Set the application to fullscreen or not.
     ///
     /// # Errors
@@ -572,11 +903,10 @@ impl PixState {
     ///
     /// # Note
     ///
-    /// Due to the current limitation with changing `VSync` at runtime, this method creates a new
-    /// window using the properties of the current window and returns the new `WindowId`.
-    ///
-    /// If you are storing and interacting with this window using the `WindowId`, make sure to
-    /// use the newly returned `WindowId`.
+    /// Due to the current limitation with changing `VSync` at runtime, this method recreates the
+    /// window using the properties of the current window. The `WindowId` and texture contents are
+    /// preserved, and a [`WindowEvent::Recreated`] event is sent to
+    /// [`PixEngine::on_window_event`] once the window is recreated.
     ///
     /// [`VSync`]: https://en.wikipedia.org/wiki/Screen_tearing#Vertical_synchronization
     ///
@@ -610,11 +940,10 @@ impl PixState {
     ///
     /// # Note
     ///
-    /// Due to the current limitation with changing `VSync` at runtime, this method creates a new
-    /// window using the properties of the current window and returns the new `WindowId`.
-    ///
-    /// If you are storing and interacting with this window using the `WindowId`, make sure to
-    /// use the newly returned `WindowId`.
+    /// Due to the current limitation with changing `VSync` at runtime, this method recreates the
+    /// window using the properties of the current window. The `WindowId` and texture contents are
+    /// preserved, and a [`WindowEvent::Recreated`] event is sent to
+    /// [`PixEngine::on_window_event`] once the window is recreated.
     ///
     /// [`VSync`]: https://en.wikipedia.org/wiki/Screen_tearing#Vertical_synchronization
     ///
@@ -642,7 +971,33 @@ impl PixState {
     #[inline]
     pub fn toggle_vsync(&mut self) -> PixResult<WindowId> {
         let vsync_enabled = self.renderer.vsync();
-        self.renderer.set_vsync(vsync_enabled)
+        self.renderer.set_vsync(!vsync_enabled)
+    }
+
+    /// Returns the graphics backend used for rendering, which may differ from the one requested
+    /// with [`EngineBuilder::with_render_driver`] if it was unavailable.
+    ///
+    /// [`EngineBuilder::with_render_driver`]: crate::engine::EngineBuilder::with_render_driver
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if s.render_driver() == RenderDriver::Software {
+    ///         s.frame_rate(30);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn render_driver(&self) -> RenderDriver {
+        self.renderer.render_driver()
     }
 
     /// Set the mouse cursor to a predefined symbol or image. `None` hides the cursor.
@@ -678,6 +1033,42 @@ impl PixState {
         self.renderer.cursor(self.settings.cursor.as_ref())
     }
 
+    /// Confine the mouse cursor to the current window, or release it. While confined, the cursor
+    /// can't leave the window and [`PixState::mouse_pos`] and [`PixState::pmouse_pos`] continue to
+    /// track its position within the window.
+    ///
+    /// # Errors
+    ///
+    /// If the current window target is closed or invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_key_pressed(&mut self, s: &mut PixState, event: KeyEvent) -> PixResult<bool> {
+    ///     if let Key::Escape = event.key {
+    ///         s.confine_cursor(!s.is_cursor_confined())?;
+    ///         return Ok(true);
+    ///     }
+    ///     Ok(false)
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn confine_cursor(&mut self, confine: bool) -> PixResult<()> {
+        self.renderer.confine_cursor(confine)
+    }
+
+    /// Returns whether the mouse cursor is confined to the current window.
+    #[inline]
+    #[must_use]
+    pub fn is_cursor_confined(&self) -> bool {
+        self.renderer.cursor_confined()
+    }
+
     /// Disables any UI elements drawn after this is called, preventing them from being interacted
     /// with.
     ///
@@ -775,6 +1166,30 @@ impl PixState {
         self.settings.show_frame_rate = show;
     }
 
+    /// Set whether to skip rendering while the primary window is minimized. Enabled by default.
+    ///
+    /// While paused, [`PixEngine::on_update`] is not called and nothing is presented, but events,
+    /// [`PixEngine::on_fixed_update`] and audio continue to be processed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Keep rendering to record video while minimized
+    ///     s.pause_rendering_when_minimized(false);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn pause_rendering_when_minimized(&mut self, val: bool) {
+        self.settings.pause_rendering_when_minimized = val;
+    }
+
     /// Get the target frame rate to render at.
     ///
     /// # Example
@@ -796,12 +1211,14 @@ impl PixState {
     /// ```
     #[inline]
     #[must_use]
-    pub fn target_frame_rate(&mut self) -> Option<usize> {
+    pub const fn target_frame_rate(&self) -> Option<usize> {
         self.settings.target_frame_rate
     }
 
     /// Set a target frame rate to render at, controls how often [`PixEngine::on_update`] is
-    /// called. `None` clears the target frame rate.
+    /// called. `None` or `0` clears the target frame rate. Can be changed at any time, for example
+    /// to throttle rendering while the window is unfocused. The target frame rate is ignored
+    /// while vertical sync is enabled.
     ///
     /// # Example
     ///
@@ -822,14 +1239,16 @@ impl PixState {
     where
         R: Into<Option<usize>>,
     {
-        let frame_rate = frame_rate.into();
+        let frame_rate = frame_rate.into().filter(|&frame_rate| frame_rate > 0);
         self.settings.target_frame_rate = frame_rate;
-        self.settings.target_delta_time =
-            frame_rate.map(|frame_rate| Duration::from_secs(1) / frame_rate as u32);
+        self.settings.target_delta_time = frame_rate.map(|frame_rate| {
+            Duration::from_secs(1) / u32::try_from(frame_rate).unwrap_or(u32::MAX)
+        });
     }
 
     /// Set the rendering scale of the current canvas. Drawing coordinates are scaled by x/y
-    /// factors before being drawn to the canvas.
+    /// factors before being drawn to the canvas. Mouse positions are reported in the same scaled
+    /// coordinates, so they can be used for drawing without dividing by the scale.
     ///
     /// # Errors
     ///
@@ -1016,6 +1435,56 @@ impl PixState {
         self.renderer.blend_mode(mode);
     }
 
+    /// Saves the current [`BlendMode`] to a stack and changes it to `mode` until
+    /// [`PixState::pop_blend_mode`] is called. Unlike [`PixState::push`], only the blend mode is
+    /// saved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { background: Image, overlay: Image };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.image(&self.background, [0, 0])?;
+    ///     s.push_blend_mode(BlendMode::Add);
+    ///     // Draw overlay with additive blending
+    ///     s.image(&self.overlay, [0, 0])?;
+    ///     s.pop_blend_mode();
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn push_blend_mode(&mut self, mode: BlendMode) {
+        self.blend_mode_stack.push(self.settings.blend_mode);
+        self.blend_mode(mode);
+    }
+
+    /// Restores the [`BlendMode`] saved by [`PixState::push_blend_mode`], if present. If the
+    /// stack is empty, the blend mode will remain unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { sprite: Image };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push_blend_mode(BlendMode::Blend);
+    ///     s.image(&self.sprite, [0, 0])?;
+    ///     s.pop_blend_mode(); // Restore previous blend mode
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn pop_blend_mode(&mut self) {
+        if let Some(mode) = self.blend_mode_stack.pop() {
+            self.blend_mode(mode);
+        }
+    }
+
     /// Saves the current draw settings and transforms.
     ///
     /// # Example
@@ -1087,6 +1556,7 @@ impl PixState {
         self.renderer.font_style(s.font_style);
         let _ = self.renderer.font_family(&s.font_family);
         self.renderer.blend_mode(s.blend_mode);
+        self.renderer.stroke_weight(s.stroke_weight);
     }
 }
 
@@ -7,11 +7,15 @@
 //! - [`PixState::texture`]: Render a portion of a texture to the current canvas.
 //! - [`PixState::texture_transformed`]: Render a transformed portion of a texture to the current
 //!   canvas.
+//! - [`PixState::nine_slice`]: Render a texture scaled using nine-slice scaling to the current
+//!   canvas.
 //! - [`PixState::create_texture`]: Creates a new texture to render to.
 //! - [`PixState::delete_texture`]: Delete a texture.
 //! - [`PixState::update_texture`]: Update texture with [u8] [slice] of pixel data.
 //! - [`PixState::set_texture_target`]: Target a texture for rendering.
 //! - [`PixState::clear_texture_target`]: Clear texture target back to primary canvas for rendering.
+//! - [`PixState::push_target`]: Push a texture target for rendering, allowing nested targets.
+//! - [`PixState::pop_target`]: Restore the previously pushed target for rendering.
 //!
 //! # Example
 //!
@@ -121,8 +125,15 @@ impl PixState {
         R1: Into<Option<Rect<i32>>>,
         R2: Into<Option<Rect<i32>>>,
     {
+        let (dst, angle) = match (self.view_transform(), dst.into()) {
+            (Some(view), Some(dst)) => {
+                let (dst, angle) = view.dst(dst, 0.0, None);
+                (Some(dst), angle)
+            }
+            (_, dst) => (dst, 0.0),
+        };
         self.renderer
-            .texture(texture_id, src.into(), dst.into(), 0.0, None, None, None)
+            .texture(texture_id, src.into(), dst, angle, None, None, None)
     }
 
     /// Draw a transformed portion `src` of a texture to the current render target translated and
@@ -195,17 +206,89 @@ impl PixState {
         if s.angle_mode == AngleMode::Radians {
             angle = angle.to_degrees();
         };
+        let mut dst = dst.into();
+        let mut center = center.into();
+        if let (Some(view), Some(world_dst)) = (self.view_transform(), dst) {
+            let (screen_dst, screen_angle) = view.dst(world_dst, angle, center);
+            dst = Some(screen_dst);
+            angle = screen_angle;
+            center = None;
+        }
         self.renderer.texture(
             texture_id,
             src.into(),
-            dst.into(),
+            dst,
             angle,
-            center.into(),
+            center,
             flipped.into(),
             s.image_tint,
         )
     }
 
+    /// Draw a portion `src` of a texture to the current render target using nine-slice scaling to
+    /// fit the target `dst`. `border` is the `[top, right, bottom, left]` size of the `src` border
+    /// in pixels. The four corners are drawn unscaled, the four edges are stretched along one axis,
+    /// and the center is stretched along both axes. If `dst` is smaller than the combined border
+    /// size, the borders are shrunk proportionally. [`PixState::image_tint`] can optionally add a
+    /// tint color to the rendered texture.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for any of the following:
+    ///     - The current render target is closed or dropped.
+    ///     - The texture being rendered has been dropped.
+    ///     - The target texture is the same as the texture being rendered.
+    ///     - The renderer fails to draw to the texture.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { panel: TextureId };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // 48x48 panel texture with 16 pixel borders, stretched to a 300x200 dialog
+    ///     s.nine_slice(
+    ///         self.panel,
+    ///         rect![0, 0, 48, 48],
+    ///         [16, 16, 16, 16],
+    ///         rect![50, 50, 300, 200],
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn nine_slice<R1, R2>(
+        &mut self,
+        texture_id: TextureId,
+        src: R1,
+        border: [u32; 4],
+        dst: R2,
+    ) -> PixResult<()>
+    where
+        R1: Into<Rect<i32>>,
+        R2: Into<Rect<i32>>,
+    {
+        let s = &self.settings;
+        let view = self.view_transform();
+        for (src, dst) in nine_slice_rects(src.into(), border, dst.into()) {
+            let (dst, angle) = match view {
+                Some(view) => view.dst(dst, 0.0, None),
+                None => (dst, 0.0),
+            };
+            self.renderer.texture(
+                texture_id,
+                Some(src),
+                Some(dst),
+                angle,
+                None,
+                None,
+                s.image_tint,
+            )?;
+        }
+        Ok(())
+    }
+
     /// Constructs a `Texture` to render to. Passing `None` for [`PixelFormat`] will use
     /// [`PixelFormat::default`]. The texture will be created and tied to the current window
     /// target. To create a texture for a window other than the primary window, call
@@ -375,6 +458,117 @@ impl PixState {
             self.pop();
         }
     }
+
+    /// Push a `Texture` as the target for drawing operations, saving the current target, settings,
+    /// and UI cursor to a stack. Unlike [`PixState::set_texture_target`], targets can be nested and
+    /// each call must be matched by a call to [`PixState::pop_target`], which restores the previous
+    /// target and any settings such as clip, blend mode, and colors.
+    ///
+    /// While a target is pushed, [`PixState::dimensions`] reports the size of the active target.
+    ///
+    /// # Errors
+    ///
+    /// If the target has been dropped or is invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { panel: TextureId, icon: TextureId };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push_target(self.panel)?;
+    ///     s.background(Color::GRAY);
+    ///
+    ///     s.push_target(self.icon)?;
+    ///     s.background(Color::RED);
+    ///     s.pop_target();
+    ///
+    ///     // Draw the icon into the panel
+    ///     s.texture(self.icon, None, rect![10, 10, 16, 16])?;
+    ///     s.pop_target();
+    ///
+    ///     s.texture(self.panel, None, None)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn push_target(&mut self, id: TextureId) -> PixResult<()> {
+        self.renderer.push_texture_target(id)?;
+        self.push();
+        self.ui.push_cursor();
+        self.set_cursor_pos(self.theme.spacing.frame_pad);
+        Ok(())
+    }
+
+    /// Restore the target for drawing operations previously saved by [`PixState::push_target`],
+    /// along with its settings and UI cursor. Does nothing if no target has been pushed.
+    pub fn pop_target(&mut self) {
+        if self.renderer.pop_texture_target() {
+            self.ui.pop_cursor();
+            self.pop();
+        }
+    }
+}
+
+/// Returns the `(src, dst)` pairs for each non-empty region of a nine-slice scaled texture.
+fn nine_slice_rects(
+    src: Rect<i32>,
+    border: [u32; 4],
+    dst: Rect<i32>,
+) -> Vec<(Rect<i32>, Rect<i32>)> {
+    let [top, right, bottom, left] = border.map(|b| i32::try_from(b).unwrap_or(i32::MAX));
+    // Borders can't exceed the source size
+    let (left, right) = fit_borders(left, right, src.width());
+    let (top, bottom) = fit_borders(top, bottom, src.height());
+    let (dst_left, dst_right) = fit_borders(left, right, dst.width());
+    let (dst_top, dst_bottom) = fit_borders(top, bottom, dst.height());
+
+    let src_cols = [
+        (src.x(), left),
+        (src.x() + left, src.width() - left - right),
+        (src.right() - right, right),
+    ];
+    let src_rows = [
+        (src.y(), top),
+        (src.y() + top, src.height() - top - bottom),
+        (src.bottom() - bottom, bottom),
+    ];
+    let dst_cols = [
+        (dst.x(), dst_left),
+        (dst.x() + dst_left, dst.width() - dst_left - dst_right),
+        (dst.right() - dst_right, dst_right),
+    ];
+    let dst_rows = [
+        (dst.y(), dst_top),
+        (dst.y() + dst_top, dst.height() - dst_top - dst_bottom),
+        (dst.bottom() - dst_bottom, dst_bottom),
+    ];
+
+    let mut rects = Vec::with_capacity(9);
+    for (&(src_y, src_h), &(dst_y, dst_h)) in src_rows.iter().zip(&dst_rows) {
+        for (&(src_x, src_w), &(dst_x, dst_w)) in src_cols.iter().zip(&dst_cols) {
+            if src_w > 0 && src_h > 0 && dst_w > 0 && dst_h > 0 {
+                rects.push((
+                    Rect::new(src_x, src_y, src_w, src_h),
+                    Rect::new(dst_x, dst_y, dst_w, dst_h),
+                ));
+            }
+        }
+    }
+    rects
+}
+
+/// Shrinks a pair of border sizes proportionally so their sum fits within `size`.
+fn fit_borders(start: i32, end: i32, size: i32) -> (i32, i32) {
+    let (start, end, size) = (start.max(0), end.max(0), size.max(0));
+    let total = i64::from(start) + i64::from(end);
+    if total <= i64::from(size) {
+        (start, end)
+    } else {
+        let start = (i64::from(start) * i64::from(size) / total) as i32;
+        (start, size - start)
+    }
 }
 
 /// Trait for texture operations on the underlying `Renderer`.
@@ -451,9 +645,58 @@ pub(crate) trait TextureRenderer {
     /// Clear `Texture` target back to the window target canvas for drawing operations.
     fn clear_texture_target(&mut self);
 
+    /// Push the current target to a stack and set a `Texture` as the target for drawing
+    /// operations.
+    ///
+    /// # Errors
+    ///
+    /// If the texture has been dropped or is invalid, then an error is returned.
+    fn push_texture_target(&mut self, texture_id: TextureId) -> PixResult<()>;
+
+    /// Restore the previously pushed target for drawing operations. Returns `false` if the stack
+    /// is empty.
+    fn pop_texture_target(&mut self) -> bool;
+
     /// Returns whether a texture is set as the target for drawing operations.
     fn has_texture_target(&self) -> bool;
 
     /// Clear internal texture cache.
     fn clear_texture_cache(&mut self);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nine_slice_regions() {
+        let rects = nine_slice_rects(rect![0, 0, 30, 30], [10, 10, 10, 10], rect![5, 5, 100, 50]);
+        assert_eq!(rects.len(), 9);
+        let expected_dst = [
+            rect![5, 5, 10, 10],
+            rect![15, 5, 80, 10],
+            rect![95, 5, 10, 10],
+            rect![5, 15, 10, 30],
+            rect![15, 15, 80, 30],
+            rect![95, 15, 10, 30],
+            rect![5, 45, 10, 10],
+            rect![15, 45, 80, 10],
+            rect![95, 45, 10, 10],
+        ];
+        for (i, ((src, dst), expected)) in rects.iter().zip(expected_dst).enumerate() {
+            let (col, row) = (i as i32 % 3, i as i32 / 3);
+            assert_eq!(*src, rect![col * 10, row * 10, 10, 10]);
+            assert_eq!(*dst, expected);
+        }
+    }
+
+    #[test]
+    fn nine_slice_small_dst() {
+        let rects = nine_slice_rects(rect![0, 0, 30, 30], [10, 10, 10, 10], rect![0, 0, 10, 30]);
+        // Center column collapses, corners are scaled down to fit
+        assert_eq!(rects.len(), 6);
+        assert_eq!(rects[0].1, rect![0, 0, 5, 10]);
+        assert_eq!(rects[1].1, rect![5, 0, 5, 10]);
+        assert_eq!(rects[2], (rect![0, 10, 10, 10], rect![0, 10, 5, 10]));
+    }
+}
@@ -0,0 +1,617 @@
+//! [`TileMap`] loading and rendering functions for [Tiled](https://www.mapeditor.org/) maps.
+//!
+//! Supports orthogonal, finite TMX maps with `csv` encoded tile layers and tilesets using a single
+//! image. Unsupported features such as infinite maps, compressed layers, or flipped and rotated
+//! tiles return an error instead of rendering incorrectly.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::tilemap`]: Draw the visible tiles of a [`TileMap`] to the current canvas.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use pix_engine::prelude::*;
+//! # struct App { map: TileMap, camera: Point<i32> };
+//! # impl PixEngine for App {
+//! fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     self.map = TileMap::from_file("./assets/level1.tmx")?;
+//!     Ok(())
+//! }
+//!
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     s.tilemap(&self.map, self.camera)?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use crate::prelude::*;
+use std::{collections::HashMap, ops::Range, path::PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::Path};
+
+/// Bits used by TMX to flag flipped or rotated tiles.
+const FLIP_FLAGS: u32 = 0xF000_0000;
+
+/// A tileset image, split into equally sized tiles.
+#[derive(Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct Tileset {
+    /// Global tile ID of the first tile in this tileset.
+    pub first_gid: u32,
+    /// Tile width in pixels.
+    pub tile_width: u32,
+    /// Tile height in pixels.
+    pub tile_height: u32,
+    /// Number of tile columns in the image.
+    pub columns: u32,
+    /// Total number of tiles.
+    pub tile_count: u32,
+    /// Spacing between tiles in pixels.
+    pub spacing: u32,
+    /// Margin around the image edges in pixels.
+    pub margin: u32,
+    /// Image source path, relative to the map file.
+    pub source: PathBuf,
+    /// Tileset [Image], if loaded.
+    pub image: Option<Image>,
+}
+
+impl Tileset {
+    /// Returns the source rectangle in the tileset [Image] for a global tile ID, or `None` if the
+    /// tile is not part of this tileset.
+    #[must_use]
+    pub fn tile_rect(&self, gid: u32) -> Option<Rect<i32>> {
+        let id = gid.checked_sub(self.first_gid)?;
+        if id >= self.tile_count || self.columns == 0 {
+            return None;
+        }
+        let (col, row) = (id % self.columns, id / self.columns);
+        // Tileset attributes come from the map file, so guard against overflow
+        let offset = |index: u32, size: u32| {
+            size.checked_add(self.spacing)?
+                .checked_mul(index)?
+                .checked_add(self.margin)
+                .and_then(|offset| i32::try_from(offset).ok())
+        };
+        Some(rect![
+            offset(col, self.tile_width)?,
+            offset(row, self.tile_height)?,
+            self.tile_width as i32,
+            self.tile_height as i32
+        ])
+    }
+}
+
+/// A layer of global tile IDs, stored in row-major order. A tile ID of `0` is empty.
+#[derive(Default, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TileLayer {
+    /// Layer name.
+    pub name: String,
+    /// Width in tiles.
+    pub width: u32,
+    /// Height in tiles.
+    pub height: u32,
+    /// Layer opacity from `0.0` to `1.0`.
+    pub opacity: f32,
+    /// Whether the layer is drawn.
+    pub visible: bool,
+    /// Global tile IDs.
+    pub tiles: Vec<u32>,
+}
+
+impl TileLayer {
+    /// Returns the global tile ID at a tile `col` and `row`, or `None` if out of bounds.
+    #[must_use]
+    pub fn tile(&self, col: u32, row: u32) -> Option<u32> {
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+        self.tiles.get((row * self.width + col) as usize).copied()
+    }
+}
+
+/// An orthogonal tile map made up of [`TileLayer`]s referencing [`Tileset`] images.
+#[derive(Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct TileMap {
+    /// Width in tiles.
+    pub width: u32,
+    /// Height in tiles.
+    pub height: u32,
+    /// Tile width in pixels.
+    pub tile_width: u32,
+    /// Tile height in pixels.
+    pub tile_height: u32,
+    /// Tilesets, ordered by first global tile ID.
+    pub tilesets: Vec<Tileset>,
+    /// Layers, in drawing order.
+    pub layers: Vec<TileLayer>,
+}
+
+impl TileMap {
+    /// Parse a `TileMap` from a TMX document. Tileset images are not loaded.
+    ///
+    /// # Errors
+    ///
+    /// If the document is not a valid TMX map or uses unsupported features such as infinite maps,
+    /// external tilesets, non-`csv` layer encoding, or flipped and rotated tiles, then an error is
+    /// returned.
+    pub fn from_tmx(tmx: &str) -> PixResult<Self> {
+        let mut map = Self::default();
+        let mut tags = Tags::new(tmx);
+        while let Some(tag) = tags.next_tag()? {
+            match tag.name {
+                "map" => {
+                    if tag.attr("orientation").unwrap_or("orthogonal") != "orthogonal" {
+                        return Err(invalid("only orthogonal maps are supported"));
+                    }
+                    if tag.attr("infinite") == Some("1") {
+                        return Err(invalid("infinite maps are not supported"));
+                    }
+                    map.width = tag.parse("width")?;
+                    map.height = tag.parse("height")?;
+                    map.tile_width = tag.parse("tilewidth")?;
+                    map.tile_height = tag.parse("tileheight")?;
+                }
+                "tileset" => {
+                    if tag.attr("source").is_some() {
+                        return Err(invalid("external tilesets are not supported"));
+                    }
+                    map.tilesets.push(Tileset {
+                        first_gid: tag.parse("firstgid")?,
+                        tile_width: tag.parse("tilewidth")?,
+                        tile_height: tag.parse("tileheight")?,
+                        columns: tag.parse("columns")?,
+                        tile_count: tag.parse("tilecount")?,
+                        spacing: tag.parse_or("spacing", 0)?,
+                        margin: tag.parse_or("margin", 0)?,
+                        ..Tileset::default()
+                    });
+                }
+                "image" => {
+                    let tileset = map
+                        .tilesets
+                        .last_mut()
+                        .ok_or_else(|| invalid("image outside of tileset"))?;
+                    tileset.source = PathBuf::from(tag.required("source")?);
+                }
+                "layer" => map.layers.push(TileLayer {
+                    name: tag.attr("name").unwrap_or_default().to_owned(),
+                    width: tag.parse("width")?,
+                    height: tag.parse("height")?,
+                    opacity: tag.parse_or("opacity", 1.0)?,
+                    visible: tag.attr("visible") != Some("0"),
+                    tiles: Vec::new(),
+                }),
+                "data" => {
+                    let layer = map
+                        .layers
+                        .last_mut()
+                        .ok_or_else(|| invalid("data outside of layer"))?;
+                    match tag.attr("encoding") {
+                        Some("csv") => (),
+                        encoding => {
+                            return Err(invalid(format!(
+                                "unsupported layer encoding: {}",
+                                encoding.unwrap_or("xml")
+                            )))
+                        }
+                    }
+                    if tag.attr("compression").is_some() {
+                        return Err(invalid("compressed layers are not supported"));
+                    }
+                    let tile_count = layer.width.checked_mul(layer.height).ok_or_else(|| {
+                        invalid(format!("layer `{}` dimensions are too large", layer.name))
+                    })?;
+                    layer.tiles = parse_csv(tags.text())?;
+                    if layer.tiles.len() != tile_count as usize {
+                        return Err(invalid(format!(
+                            "layer `{}` has {} tiles, expected {}",
+                            layer.name,
+                            layer.tiles.len(),
+                            tile_count
+                        )));
+                    }
+                }
+                "chunk" => return Err(invalid("infinite maps are not supported")),
+                "objectgroup" | "imagelayer" | "group" => {
+                    return Err(invalid(format!("unsupported layer type: {}", tag.name)))
+                }
+                _ => (),
+            }
+        }
+        if map.tile_width == 0 || map.tile_height == 0 {
+            return Err(invalid("missing map element"));
+        }
+        map.tilesets.sort_by_key(|tileset| tileset.first_gid);
+        Ok(map)
+    }
+
+    /// Load a `TileMap` from a TMX file, including all tileset images. Tileset image paths are
+    /// relative to the map file.
+    ///
+    /// # Errors
+    ///
+    /// If the file fails to load, the map is not a valid or supported TMX map, or any tileset
+    /// image fails to load, then an error is returned.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> PixResult<Self> {
+        let path = path.as_ref();
+        let mut map = Self::from_tmx(&fs::read_to_string(path)?)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        for tileset in &mut map.tilesets {
+            tileset.image = Some(Image::from_file(dir.join(&tileset.source))?);
+        }
+        Ok(map)
+    }
+
+    /// Returns the global tile ID at a world pixel position for the given `layer` index, or
+    /// `None` if the position is out of bounds or the tile is empty. Useful for collision checks.
+    #[must_use]
+    pub fn tile_at(&self, layer: usize, world_x: i32, world_y: i32) -> Option<u32> {
+        if world_x < 0 || world_y < 0 || self.tile_width == 0 || self.tile_height == 0 {
+            return None;
+        }
+        let col = world_x as u32 / self.tile_width;
+        let row = world_y as u32 / self.tile_height;
+        self.layers
+            .get(layer)?
+            .tile(col, row)
+            .filter(|&gid| gid > 0)
+    }
+
+    /// Returns the [`Tileset`] containing a global tile ID.
+    #[must_use]
+    pub fn tileset(&self, gid: u32) -> Option<&Tileset> {
+        self.tilesets
+            .iter()
+            .rev()
+            .find(|tileset| tileset.first_gid <= gid)
+    }
+
+    /// Returns the column and row ranges of tiles intersecting a viewport of the given
+    /// `dimensions` whose top-left corner is at the `camera` world position.
+    #[must_use]
+    pub fn visible_tiles(
+        &self,
+        camera: Point<i32>,
+        dimensions: (u32, u32),
+    ) -> (Range<u32>, Range<u32>) {
+        let range = |start: i32, size: u32, tile_size: u32, count: u32| {
+            if tile_size == 0 {
+                return 0..0;
+            }
+            let tile_size = i64::from(tile_size);
+            let start = i64::from(start);
+            let end = start + i64::from(size);
+            let first = start.div_euclid(tile_size).clamp(0, i64::from(count));
+            let last = (end + tile_size - 1)
+                .div_euclid(tile_size)
+                .clamp(0, i64::from(count));
+            first as u32..last as u32
+        };
+        (
+            range(camera.x(), dimensions.0, self.tile_width, self.width),
+            range(camera.y(), dimensions.1, self.tile_height, self.height),
+        )
+    }
+}
+
+impl PixState {
+    /// Draw the visible layers of a [`TileMap`] to the current canvas with the top-left of the
+    /// canvas positioned at the `camera` world position. Only tiles intersecting the canvas are
+    /// drawn. Layer opacity is applied using [`BlendMode::Blend`].
+    ///
+    /// # Errors
+    ///
+    /// If a tileset image is not loaded or the renderer fails to draw to the current render
+    /// target, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { map: TileMap, camera: Point<i32> };
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.tilemap(&self.map, self.camera)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn tilemap<P>(&mut self, map: &TileMap, camera: P) -> PixResult<()>
+    where
+        P: Into<Point<i32>>,
+    {
+        let camera = camera.into();
+        let (cols, rows) = map.visible_tiles(camera, (self.width()?, self.height()?));
+        let (tile_width, tile_height) = (map.tile_width as i32, map.tile_height as i32);
+
+        self.push();
+        self.image_mode(ImageMode::Corner);
+        let result = (|| {
+            for layer in map.layers.iter().filter(|layer| layer.visible) {
+                if layer.opacity < 1.0 {
+                    self.blend_mode(BlendMode::Blend);
+                }
+                let alpha = (layer.opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+                self.image_tint(Color::rgba(255, 255, 255, alpha));
+                for row in rows.clone() {
+                    for col in cols.clone() {
+                        let gid = match layer.tile(col, row) {
+                            Some(gid) if gid > 0 => gid,
+                            _ => continue,
+                        };
+                        let Some(tileset) = map.tileset(gid) else {
+                            continue;
+                        };
+                        let Some(src) = tileset.tile_rect(gid) else {
+                            return Err(invalid(format!(
+                                "tile gid {gid} is out of range for tileset `{}`",
+                                tileset.source.display()
+                            )));
+                        };
+                        let Some(image) = &tileset.image else {
+                            return Err(invalid(format!(
+                                "tileset image not loaded: {}",
+                                tileset.source.display()
+                            )));
+                        };
+                        let dst = rect![
+                            col as i32 * tile_width - camera.x(),
+                            (row as i32 + 1) * tile_height - src.height() - camera.y(),
+                            src.width(),
+                            src.height()
+                        ];
+                        self.image_transformed(image, src, dst, None, None, None)?;
+                    }
+                }
+            }
+            Ok(())
+        })();
+        self.pop();
+        result
+    }
+}
+
+/// Returns an invalid [`TileMap`] error.
+fn invalid<S: Into<String>>(message: S) -> anyhow::Error {
+    PixError::InvalidTileMap(message.into()).into()
+}
+
+/// Parse `csv` encoded global tile IDs.
+fn parse_csv(text: &str) -> PixResult<Vec<u32>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|gid| !gid.is_empty())
+        .map(|gid| {
+            let gid: u32 = gid
+                .parse()
+                .map_err(|_| invalid(format!("invalid tile id: {gid}")))?;
+            if gid & FLIP_FLAGS != 0 {
+                return Err(invalid("flipped and rotated tiles are not supported"));
+            }
+            Ok(gid)
+        })
+        .collect()
+}
+
+/// An XML start tag with attributes.
+#[derive(Debug)]
+struct Tag<'a> {
+    name: &'a str,
+    attrs: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Tag<'a> {
+    fn attr(&self, name: &str) -> Option<&'a str> {
+        self.attrs.get(name).copied()
+    }
+
+    fn required(&self, name: &str) -> PixResult<&'a str> {
+        self.attr(name)
+            .ok_or_else(|| invalid(format!("{} is missing `{name}`", self.name)))
+    }
+
+    fn parse<T: std::str::FromStr>(&self, name: &str) -> PixResult<T> {
+        let value = self.required(name)?;
+        value
+            .parse()
+            .map_err(|_| invalid(format!("{} has invalid `{name}`: {value}", self.name)))
+    }
+
+    fn parse_or<T: std::str::FromStr>(&self, name: &str, default: T) -> PixResult<T> {
+        match self.attr(name) {
+            Some(_) => self.parse(name),
+            None => Ok(default),
+        }
+    }
+}
+
+/// A minimal XML reader yielding start tags, sufficient for TMX documents.
+struct Tags<'a> {
+    xml: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tags<'a> {
+    const fn new(xml: &'a str) -> Self {
+        Self { xml, pos: 0 }
+    }
+
+    /// Returns the text content up to the next tag.
+    fn text(&mut self) -> &'a str {
+        let rest = &self.xml[self.pos..];
+        let end = rest.find('<').unwrap_or(rest.len());
+        self.pos += end;
+        &rest[..end]
+    }
+
+    /// Returns the next start tag, skipping declarations, comments, and end tags.
+    fn next_tag(&mut self) -> PixResult<Option<Tag<'a>>> {
+        loop {
+            let rest = &self.xml[self.pos..];
+            let Some(start) = rest.find('<') else {
+                return Ok(None);
+            };
+            let rest = &rest[start..];
+            if rest.starts_with("<!--") {
+                let end = rest
+                    .find("-->")
+                    .ok_or_else(|| invalid("unclosed comment"))?;
+                self.pos += start + end + 3;
+                continue;
+            }
+            let end = rest.find('>').ok_or_else(|| invalid("unclosed tag"))?;
+            self.pos += start + end + 1;
+            let tag = rest[1..end].trim_end_matches('/');
+            if tag.starts_with(['?', '!', '/']) {
+                continue;
+            }
+            return parse_tag(tag).map(Some);
+        }
+    }
+}
+
+/// Parse the contents of a start tag between `<` and `>`.
+fn parse_tag(tag: &str) -> PixResult<Tag<'_>> {
+    let (name, mut rest) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+    let mut attrs = HashMap::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let (key, value) = rest
+            .split_once('=')
+            .ok_or_else(|| invalid(format!("invalid attribute in {name}")))?;
+        let value = value.trim_start();
+        let quote = value
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+            .ok_or_else(|| invalid(format!("unquoted attribute in {name}")))?;
+        let value = &value[1..];
+        let end = value
+            .find(quote)
+            .ok_or_else(|| invalid(format!("unclosed attribute in {name}")))?;
+        attrs.insert(key.trim(), &value[..end]);
+        rest = &value[end + 1..];
+    }
+    Ok(Tag { name, attrs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TMX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" renderorder="right-down" width="4" height="3"
+     tilewidth="16" tileheight="16" infinite="0">
+ <tileset firstgid="1" name="terrain" tilewidth="16" tileheight="16" tilecount="8" columns="4"
+          spacing="1" margin="2">
+  <image source="terrain.png" width="70" height="36"/>
+ </tileset>
+ <!-- Comment -->
+ <layer id="1" name="ground" width="4" height="3">
+  <data encoding="csv">
+1,2,3,4,
+5,6,7,8,
+0,0,1,1
+</data>
+ </layer>
+ <layer id="2" name="overlay" width="4" height="3" opacity="0.5" visible="0">
+  <data encoding="csv">
+0,0,0,0,
+0,8,0,0,
+0,0,0,0
+</data>
+ </layer>
+</map>"#;
+
+    #[test]
+    fn parse_tmx() -> PixResult<()> {
+        let map = TileMap::from_tmx(TMX)?;
+        assert_eq!((map.width, map.height), (4, 3));
+        assert_eq!((map.tile_width, map.tile_height), (16, 16));
+        assert_eq!(map.tilesets.len(), 1);
+        assert_eq!(map.tilesets[0].source, PathBuf::from("terrain.png"));
+
+        assert_eq!(map.layers.len(), 2);
+        let ground = &map.layers[0];
+        assert_eq!(ground.name, "ground");
+        assert_eq!((ground.width, ground.height), (4, 3));
+        assert_eq!(ground.tiles, [1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 1, 1]);
+        assert!(ground.visible);
+        let overlay = &map.layers[1];
+        assert!((overlay.opacity - 0.5).abs() < f32::EPSILON);
+        assert!(!overlay.visible);
+
+        assert_eq!(map.tile_at(0, 17, 0), Some(2));
+        assert_eq!(map.tile_at(0, 63, 47), Some(1));
+        assert_eq!(map.tile_at(0, 0, 40), None);
+        assert_eq!(map.tile_at(0, 64, 0), None);
+        assert_eq!(map.tile_at(0, -1, 0), None);
+        assert_eq!(map.tile_at(1, 20, 20), Some(8));
+        assert_eq!(map.tile_at(2, 0, 0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn tile_rects() -> PixResult<()> {
+        let map = TileMap::from_tmx(TMX)?;
+        let tileset = map.tileset(6).ok_or_else(|| invalid("missing tileset"))?;
+        assert_eq!(tileset.tile_rect(1), Some(rect![2, 2, 16, 16]));
+        assert_eq!(tileset.tile_rect(6), Some(rect![19, 19, 16, 16]));
+        assert_eq!(tileset.tile_rect(9), None);
+        assert!(map.tileset(0).is_none());
+
+        let mut tileset = tileset.clone();
+        tileset.spacing = u32::MAX;
+        assert_eq!(tileset.tile_rect(2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn culling() -> PixResult<()> {
+        let map = TileMap::from_tmx(TMX)?;
+        assert_eq!(map.visible_tiles(point!(0, 0), (64, 48)), (0..4, 0..3));
+        assert_eq!(map.visible_tiles(point!(8, 8), (16, 16)), (0..2, 0..2));
+        assert_eq!(map.visible_tiles(point!(16, 16), (16, 16)), (1..2, 1..2));
+        assert_eq!(map.visible_tiles(point!(-20, -20), (30, 30)), (0..1, 0..1));
+        assert_eq!(map.visible_tiles(point!(100, 0), (30, 30)), (4..4, 0..2));
+        assert_eq!(
+            map.visible_tiles(point!(-100, -100), (1000, 1000)),
+            (0..4, 0..3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_features() {
+        let infinite = TMX.replace(r#"infinite="0""#, r#"infinite="1""#);
+        let base64 = TMX.replacen(r#"encoding="csv""#, r#"encoding="base64""#, 1);
+        let flipped = TMX.replacen("1,2,3,4", "2147483649,2,3,4", 1);
+        let truncated = TMX.replacen("0,0,1,1", "0,0,1", 1);
+        let oversized = TMX.replacen(
+            r#"name="ground" width="4" height="3""#,
+            r#"name="ground" width="65536" height="65536""#,
+            1,
+        );
+        for (tmx, message) in [
+            (infinite, "infinite maps are not supported"),
+            (base64, "unsupported layer encoding: base64"),
+            (flipped, "flipped and rotated tiles are not supported"),
+            (truncated, "layer `ground` has 11 tiles, expected 12"),
+            (oversized, "layer `ground` dimensions are too large"),
+        ] {
+            match TileMap::from_tmx(&tmx) {
+                Ok(_) => panic!("expected error: {message}"),
+                Err(err) => assert_eq!(err.to_string(), format!("invalid tile map: {message}")),
+            }
+        }
+    }
+}
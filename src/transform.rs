@@ -1,7 +1,53 @@
 //! Transformation functions and types.
+//!
+//! Provided types:
+//!
+//! - [`Flipped`]: Which direction to flip images and textures.
+//! - [`Transform`]: A 2D affine transformation matrix.
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::push_matrix`]: Push a copy of the current [`Transform`] to a stack.
+//! - [`PixState::pop_matrix`]: Pop the previously pushed [`Transform`] off the stack, restoring it.
+//! - [`PixState::reset_matrix`]: Reset the current [`Transform`] to the identity matrix.
+//! - [`PixState::translate`]: Translate the drawing origin.
+//! - [`PixState::rotate`]: Rotate drawing about the origin.
+//! - [`PixState::scale_by`]: Scale drawing about the origin.
+//! - [`PixState::transform`]: The current [`Transform`].
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! # struct App { angle: f64 };
+//! # impl PixEngine for App {
+//! fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!     // Sun
+//!     s.translate(200, 200);
+//!     s.circle([0, 0, 40])?;
+//!
+//!     // Planet orbiting the sun
+//!     s.push_matrix();
+//!     s.rotate(self.angle);
+//!     s.translate(120, 0);
+//!     s.circle([0, 0, 15])?;
+//!
+//!     // Moon orbiting the planet
+//!     s.rotate(self.angle * 3.0);
+//!     s.translate(30, 0);
+//!     s.circle([0, 0, 5])?;
+//!     s.pop_matrix();
+//!
+//!     self.angle += s.delta_time().as_secs_f64();
+//!     Ok(())
+//! }
+//! # }
+//! ```
 
+use crate::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::ops::{Mul, MulAssign};
 
 /// Enum representing which direction to flip during drawing.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -16,3 +62,465 @@ pub enum Flipped {
     /// Flip in both the horizontal and vertical directions.
     Both,
 }
+
+/// A 2D affine transformation matrix.
+///
+/// Stored as `[a, b, c, d, e, f]`, which maps a point `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`. Multiplying `a * b` returns a transform that applies
+/// `b` first, then `a`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Transform([f64; 6]);
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    /// The identity transform, which leaves points unchanged.
+    pub const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    /// Constructs a `Transform` from matrix values `[a, b, c, d, e, f]`.
+    #[inline]
+    pub const fn new(matrix: [f64; 6]) -> Self {
+        Self(matrix)
+    }
+
+    /// Constructs a `Transform` that translates by `(x, y)`.
+    #[inline]
+    pub const fn translation(x: f64, y: f64) -> Self {
+        Self([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    /// Constructs a `Transform` that rotates clockwise by `angle` radians about the origin.
+    #[inline]
+    pub fn rotation(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    /// Constructs a `Transform` that scales by `(x, y)` about the origin.
+    #[inline]
+    pub const fn scaling(x: f64, y: f64) -> Self {
+        Self([x, 0.0, 0.0, y, 0.0, 0.0])
+    }
+
+    /// Returns the matrix values `[a, b, c, d, e, f]`.
+    #[inline]
+    #[must_use]
+    pub const fn matrix(&self) -> [f64; 6] {
+        self.0
+    }
+
+    /// Returns whether this is the identity transform.
+    #[inline]
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Translate by `(x, y)` before applying this transform.
+    #[inline]
+    pub fn translate(&mut self, x: f64, y: f64) {
+        *self *= Self::translation(x, y);
+    }
+
+    /// Rotate clockwise by `angle` radians before applying this transform.
+    #[inline]
+    pub fn rotate(&mut self, angle: f64) {
+        *self *= Self::rotation(angle);
+    }
+
+    /// Scale by `(x, y)` before applying this transform.
+    #[inline]
+    pub fn scale(&mut self, x: f64, y: f64) {
+        *self *= Self::scaling(x, y);
+    }
+
+    /// Returns the inverse transform, or `None` if this transform can not be inverted.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let [a, b, c, d, e, f] = self.0;
+        let det = a.mul_add(d, -b * c);
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        Some(Self([
+            d / det,
+            -b / det,
+            -c / det,
+            a / det,
+            c.mul_add(f, -d * e) / det,
+            b.mul_add(e, -a * f) / det,
+        ]))
+    }
+
+    /// Apply this transform to a point.
+    pub fn apply<P: Into<Point<f64>>>(&self, p: P) -> Point<f64> {
+        let p = p.into();
+        let [a, b, c, d, e, f] = self.0;
+        point![
+            a.mul_add(p.x(), c.mul_add(p.y(), e)),
+            b.mul_add(p.x(), d.mul_add(p.y(), f))
+        ]
+    }
+
+    /// Returns whether axis-aligned rectangles remain axis-aligned.
+    #[inline]
+    pub(crate) fn is_axis_aligned(&self) -> bool {
+        self.0[1] == 0.0 && self.0[2] == 0.0
+    }
+
+    /// Returns the clockwise rotation of the x-axis in radians.
+    #[inline]
+    pub(crate) fn angle(&self) -> f64 {
+        self.0[1].atan2(self.0[0])
+    }
+
+    /// Returns the scale factors along the x and y axes.
+    #[inline]
+    pub(crate) fn scale_factors(&self) -> (f64, f64) {
+        let [a, b, c, d, ..] = self.0;
+        (a.hypot(b), c.hypot(d))
+    }
+
+    /// Transform a point, rounding to the nearest pixel.
+    #[inline]
+    pub(crate) fn point(&self, p: Point<i32>) -> Point<i32> {
+        let p = self.apply([f64::from(p.x()), f64::from(p.y())]);
+        point![p.x().round() as i32, p.y().round() as i32]
+    }
+
+    /// Transform a rectangle by its corners, ignoring rotation.
+    pub(crate) fn rect(&self, rect: Rect<i32>) -> Rect<i32> {
+        let top_left = self.point(rect.top_left());
+        let bottom_right = self.point(rect.bottom_right());
+        let x = top_left.x().min(bottom_right.x());
+        let y = top_left.y().min(bottom_right.y());
+        rect![
+            x,
+            y,
+            top_left.x().max(bottom_right.x()) - x,
+            top_left.y().max(bottom_right.y()) - y
+        ]
+    }
+
+    /// Transform a rectangle into a [Quad].
+    #[inline]
+    pub(crate) fn quad(&self, rect: Rect<i32>) -> Quad<i32> {
+        Quad::new(
+            self.point(rect.top_left()),
+            self.point(rect.top_right()),
+            self.point(rect.bottom_right()),
+            self.point(rect.bottom_left()),
+        )
+    }
+
+    /// Scale a length by the average scale factor, rounding to the nearest pixel.
+    #[inline]
+    pub(crate) fn length(&self, length: i32) -> i32 {
+        let [a, b, c, d, ..] = self.0;
+        let scale = a.mul_add(d, -b * c).abs().sqrt();
+        (f64::from(length) * scale).round() as i32
+    }
+
+    /// Transform a destination rectangle rotated by `angle` degrees about `center` into a
+    /// rectangle and total angle in degrees rotated about its own center.
+    pub(crate) fn dst(
+        &self,
+        dst: Rect<i32>,
+        angle: f64,
+        center: Option<Point<i32>>,
+    ) -> (Rect<i32>, f64) {
+        let mut mid = [
+            f64::from(dst.x()) + f64::from(dst.width()) / 2.0,
+            f64::from(dst.y()) + f64::from(dst.height()) / 2.0,
+        ];
+        if let Some(center) = center {
+            // Move the rectangle center to where rotating about `center` would place it
+            let cx = f64::from(dst.x() + center.x());
+            let cy = f64::from(dst.y() + center.y());
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let (x, y) = (mid[0] - cx, mid[1] - cy);
+            mid = [x.mul_add(cos, -y * sin) + cx, x.mul_add(sin, y * cos) + cy];
+        }
+        let mid = self.apply(mid);
+        let (sx, sy) = self.scale_factors();
+        let width = f64::from(dst.width()) * sx;
+        let height = f64::from(dst.height()) * sy;
+        let dst = rect![
+            (mid.x() - width / 2.0).round() as i32,
+            (mid.y() - height / 2.0).round() as i32,
+            width.round() as i32,
+            height.round() as i32
+        ];
+        (dst, angle + self.angle().to_degrees())
+    }
+}
+
+impl Mul for Transform {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let [a1, b1, c1, d1, e1, f1] = self.0;
+        let [a2, b2, c2, d2, e2, f2] = rhs.0;
+        Self([
+            a1.mul_add(a2, c1 * b2),
+            b1.mul_add(a2, d1 * b2),
+            a1.mul_add(c2, c1 * d2),
+            b1.mul_add(c2, d1 * d2),
+            a1.mul_add(e2, c1.mul_add(f2, e1)),
+            b1.mul_add(e2, d1.mul_add(f2, f1)),
+        ])
+    }
+}
+
+impl MulAssign for Transform {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl PixState {
+    /// Push a copy of the current [`Transform`] to a stack. [`PixState::push`] also saves the
+    /// current [`Transform`] along with all other settings.
+    ///
+    /// The [`Transform`] applies to points, lines, shapes, images, and textures, but not to text or
+    /// UI widgets. It is reset to the identity matrix at the start of each frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push_matrix(); // Save transform
+    ///     s.translate(100, 100);
+    ///     s.rect([0, 0, 50, 50])?; // Drawn at (100, 100)
+    ///     s.pop_matrix(); // Restore transform
+    ///
+    ///     s.rect([0, 0, 50, 50])?; // Drawn at (0, 0)
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[doc(alias = "push_transform")]
+    #[inline]
+    pub fn push_matrix(&mut self) {
+        self.matrix_stack.push(self.settings.transform);
+    }
+
+    /// Restores the previous [`Transform`], if present. If the matrix stack is empty, the
+    /// [`Transform`] will remain unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.push_matrix(); // Save transform
+    ///     s.scale_by(2.0, 2.0);
+    ///     s.rect([10, 10, 50, 50])?; // Drawn at (20, 20) with size (100, 100)
+    ///     s.pop_matrix(); // Restore transform
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[doc(alias = "pop_transform")]
+    #[inline]
+    pub fn pop_matrix(&mut self) {
+        if let Some(transform) = self.matrix_stack.pop() {
+            self.settings.transform = transform;
+        }
+    }
+
+    /// Reset the current [`Transform`] to the identity matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.translate(100, 100);
+    ///     s.reset_matrix();
+    ///     s.rect([0, 0, 50, 50])?; // Drawn at (0, 0)
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn reset_matrix(&mut self) {
+        self.settings.transform = Transform::IDENTITY;
+    }
+
+    /// Translate the origin of subsequent drawing by `(x, y)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.translate(100, 50);
+    ///     s.point([0, 0])?; // Drawn at (100, 50)
+    ///     s.translate(10, 10);
+    ///     s.point([0, 0])?; // Drawn at (110, 60)
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn translate<T: Into<f64>>(&mut self, x: T, y: T) {
+        self.settings.transform.translate(x.into(), y.into());
+    }
+
+    /// Rotate subsequent drawing clockwise about the origin by `angle`, interpreted based on the
+    /// current [`AngleMode`].
+    ///
+    /// Rectangles are drawn as [Quad]s and ellipses as polygons while rotated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.angle_mode(AngleMode::Degrees);
+    ///     s.translate(100, 100);
+    ///     s.rotate(45.0);
+    ///     s.rect([-25, -25, 50, 50])?; // A diamond centered on (100, 100)
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn rotate(&mut self, angle: f64) {
+        let angle = match self.settings.angle_mode {
+            AngleMode::Degrees => angle.to_radians(),
+            AngleMode::Radians => angle,
+        };
+        self.settings.transform.rotate(angle);
+    }
+
+    /// Scale subsequent drawing about the origin by `(x, y)`.
+    ///
+    /// Not to be confused with [`PixState::scale`], which sets the rendering scale of the current
+    /// canvas. Negative scales mirror shapes, but not images or textures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.scale_by(2.0, 2.0);
+    ///     s.rect([10, 10, 50, 50])?; // Drawn at (20, 20) with size (100, 100)
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[doc(alias = "scale2d")]
+    #[inline]
+    pub fn scale_by(&mut self, x: f64, y: f64) {
+        self.settings.transform.scale(x, y);
+    }
+
+    /// Returns the current [`Transform`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.translate(100, 100);
+    ///     assert_eq!(s.transform().apply([0.0, 0.0]), point!(100.0, 100.0));
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn transform(&self) -> Transform {
+        self.settings.transform
+    }
+
+    /// Returns the combined [`Camera2D`] and [`Transform`] used to draw shapes, images, and
+    /// textures, or `None` if drawing is untransformed.
+    #[inline]
+    pub(crate) fn view_transform(&self) -> Option<Transform> {
+        let s = &self.settings;
+        match s.camera {
+            Some(camera) => Some(camera.transform() * s.transform),
+            None if s.transform.is_identity() => None,
+            None => Some(s.transform),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn translate_rotate() {
+        let (tx, ty, angle) = (50.0, 20.0, 0.3);
+        let mut t = Transform::IDENTITY;
+        t.translate(tx, ty);
+        t.rotate(angle);
+
+        let (x, y) = (7.0, -3.0);
+        let (sin, cos) = f64::sin_cos(angle);
+        let expected = point![x * cos - y * sin + tx, x * sin + y * cos + ty];
+        assert_approx_eq(t.apply([x, y]), expected, 1e-9);
+        assert_eq!(t.point(point![10, 0]), point![60, 23]);
+    }
+
+    #[test]
+    fn compose_nested() {
+        let mut t = Transform::translation(100.0, 100.0);
+        t.rotate(FRAC_PI_2);
+        t.scale(2.0, 3.0);
+        t.translate(10.0, 0.0);
+        // (1, 1) -> (11, 1) -> (22, 3) -> (-3, 22) -> (97, 122)
+        assert_approx_eq(t.apply([1.0, 1.0]), point![97.0, 122.0], 1e-9);
+        assert!(!t.is_axis_aligned());
+        let (sx, sy) = t.scale_factors();
+        assert!((sx - 2.0).abs() < 1e-9 && (sy - 3.0).abs() < 1e-9);
+        assert!((t.angle() - FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse() -> PixResult<()> {
+        let mut t = Transform::translation(-40.0, 15.0);
+        t.rotate(1.2);
+        t.scale(0.5, 4.0);
+        let inverse = t
+            .inverse()
+            .ok_or_else(|| PixError::Other(anyhow::anyhow!("transform not invertible")))?;
+        assert_approx_eq(inverse.apply(t.apply([3.0, -8.0])), point![3.0, -8.0], 1e-9);
+        assert_eq!(Transform::scaling(0.0, 1.0).inverse(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn rect_normalized() {
+        let mut t = Transform::translation(100.0, 0.0);
+        t.scale(-2.0, 1.0);
+        assert!(t.is_axis_aligned());
+        assert_eq!(t.rect(rect![10, 10, 20, 5]), rect![40, 10, 40, 5]);
+    }
+}
@@ -0,0 +1,617 @@
+//! [`Tween`] functions for animating values over time with easing.
+//!
+//! A [`Tween`] interpolates between values of any type implementing [`Lerp`] over a [Duration],
+//! shaping the motion with an [`Easing`] function. Tweens can be chained with [`Tween::then`] and
+//! repeated with [`Repeat::Loop`] or [`Repeat::Yoyo`].
+//!
+//! Provided [`PixState`] methods:
+//!
+//! - [`PixState::tween`]: Advance a [`Tween`] by the frame delta time and return its value.
+//!
+//! # Example
+//!
+//! ```
+//! # use pix_engine::prelude::*;
+//! use pix_engine::tween::ease_out_bounce;
+//! use std::time::Duration;
+//!
+//! struct App {
+//!     ball: Tween<Point<f64>>,
+//! }
+//!
+//! impl App {
+//!     fn new() -> Self {
+//!         let ball = Tween::new(point![100.0, 0.0], point![100.0, 400.0], Duration::from_secs(1))
+//!             .with_easing(ease_out_bounce);
+//!         Self { ball }
+//!     }
+//! }
+//!
+//! impl PixEngine for App {
+//!     fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+//!         let pos = s.tween(&mut self.ball);
+//!         s.circle([pos.x() as i32, pos.y() as i32, 10])?;
+//!         if self.ball.finished() {
+//!             self.ball.reset();
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{f64::consts::PI, fmt, time::Duration};
+
+/// An easing function, mapping progress from `0.0..=1.0` to an eased progress. Eased values
+/// start at `0.0` and end at `1.0`, but may overshoot in between.
+pub type Easing = fn(f64) -> f64;
+
+/// Trait for values that can be linearly interpolated.
+pub trait Lerp {
+    /// Returns the value interpolated between `self` and `other` by `amt`, where `0.0` returns
+    /// `self` and `1.0` returns `other`. Amounts outside `0.0..=1.0` extrapolate, if supported.
+    #[must_use]
+    fn lerp(&self, other: &Self, amt: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    #[inline]
+    fn lerp(&self, other: &Self, amt: f64) -> Self {
+        self + (other - self) * amt
+    }
+}
+
+impl Lerp for f32 {
+    #[inline]
+    fn lerp(&self, other: &Self, amt: f64) -> Self {
+        f64::from(*self).lerp(&f64::from(*other), amt) as f32
+    }
+}
+
+macro_rules! impl_lerp_int {
+    ($($ty:ty),*) => {$(
+        impl Lerp for $ty {
+            /// Interpolates as `f64`, rounding to the nearest integer.
+            #[inline]
+            fn lerp(&self, other: &Self, amt: f64) -> Self {
+                (f64::from(*self) + (f64::from(*other) - f64::from(*self)) * amt).round() as $ty
+            }
+        }
+    )*};
+}
+
+impl_lerp_int!(u8, i16, u16, i32, u32);
+
+impl<T: Lerp + Copy, const N: usize> Lerp for Point<T, N> {
+    #[inline]
+    fn lerp(&self, other: &Self, amt: f64) -> Self {
+        let mut coords = self.coords();
+        for (c, o) in coords.iter_mut().zip(other.iter()) {
+            *c = c.lerp(o, amt);
+        }
+        Self::new(coords)
+    }
+}
+
+impl<T: Lerp + Copy, const N: usize> Lerp for Vector<T, N> {
+    #[inline]
+    fn lerp(&self, other: &Self, amt: f64) -> Self {
+        let mut coords = self.coords();
+        for (c, o) in coords.iter_mut().zip(other.iter()) {
+            *c = c.lerp(o, amt);
+        }
+        Self::new(coords)
+    }
+}
+
+impl<T: Lerp + Copy> Lerp for Rect<T> {
+    #[inline]
+    fn lerp(&self, other: &Self, amt: f64) -> Self {
+        let mut coords = self.coords();
+        for (c, o) in coords.iter_mut().zip(other.coords().iter()) {
+            *c = c.lerp(o, amt);
+        }
+        Self::new(coords[0], coords[1], coords[2], coords[3])
+    }
+}
+
+impl Lerp for Color {
+    /// Interpolates using [`Color::lerp`], which clamps `amt` to `0.0..=1.0`.
+    #[inline]
+    fn lerp(&self, other: &Self, amt: f64) -> Self {
+        Color::lerp(self, *other, amt)
+    }
+}
+
+/// How a [`Tween`] repeats once it reaches the end.
+#[non_exhaustive]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Repeat {
+    /// Play once and stop at the end value.
+    #[default]
+    Once,
+    /// Restart from the beginning.
+    Loop,
+    /// Alternate playing forwards and backwards.
+    Yoyo,
+}
+
+/// A single step of a [`Tween`] chain.
+#[derive(Clone)]
+struct Step<T> {
+    to: T,
+    duration: Duration,
+    easing: Easing,
+}
+
+/// Animates a value of type `T` between two or more values over time.
+///
+/// Please see the [module-level documentation] for examples.
+///
+/// [module-level documentation]: crate::tween
+#[derive(Clone)]
+#[must_use]
+pub struct Tween<T> {
+    from: T,
+    steps: Vec<Step<T>>,
+    repeat: Repeat,
+    elapsed: Duration,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps: Vec<_> = self
+            .steps
+            .iter()
+            .map(|step| (&step.to, step.duration))
+            .collect();
+        f.debug_struct("Tween")
+            .field("from", &self.from)
+            .field("steps", &steps)
+            .field("repeat", &self.repeat)
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
+
+impl<T: Lerp + Clone> Tween<T> {
+    /// Constructs a `Tween` from `from` to `to` over `duration` with [`linear`] easing.
+    pub fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            steps: vec![Step {
+                to,
+                duration,
+                easing: linear,
+            }],
+            repeat: Repeat::Once,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Sets the [`Easing`] function of the most recently added step.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.easing = easing;
+        }
+        self
+    }
+
+    /// Chains another step, animating from the previous end value to `to` over `duration`, using
+    /// the same [`Easing`] function as the previous step.
+    pub fn then(mut self, to: T, duration: Duration) -> Self {
+        let easing = match self.steps.last() {
+            Some(step) => step.easing,
+            None => linear,
+        };
+        self.steps.push(Step {
+            to,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Sets how the `Tween` [`Repeat`]s once it reaches the end.
+    pub const fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Restarts the `Tween` from the beginning each time it ends. Shorthand for
+    /// `with_repeat(Repeat::Loop)`.
+    pub const fn looping(self) -> Self {
+        self.with_repeat(Repeat::Loop)
+    }
+
+    /// Alternates the `Tween` forwards and backwards forever. Shorthand for
+    /// `with_repeat(Repeat::Yoyo)`.
+    pub const fn yoyo(self) -> Self {
+        self.with_repeat(Repeat::Yoyo)
+    }
+
+    /// Returns the [`Repeat`] mode.
+    #[inline]
+    pub const fn repeat(&self) -> Repeat {
+        self.repeat
+    }
+
+    /// Returns the total duration of all chained steps.
+    #[inline]
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.steps.iter().map(|step| step.duration).sum()
+    }
+
+    /// Returns the time elapsed since the `Tween` started.
+    #[inline]
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns whether the `Tween` has reached its end value. Tweens that [`Repeat`] never finish.
+    #[inline]
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        self.repeat == Repeat::Once && self.elapsed >= self.duration()
+    }
+
+    /// Restarts the `Tween` from the beginning.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Advances the `Tween` by `delta` and returns the current value.
+    pub fn update(&mut self, delta: Duration) -> T {
+        self.elapsed = self.elapsed.saturating_add(delta);
+        self.value()
+    }
+
+    /// Returns the current value.
+    #[must_use]
+    pub fn value(&self) -> T {
+        let total = self.duration().as_secs_f64();
+        let elapsed = self.elapsed.as_secs_f64();
+        let last = self.steps.last().map_or(&self.from, |step| &step.to);
+        if total <= 0.0 || self.finished() {
+            return last.clone();
+        }
+        let position = match self.repeat {
+            Repeat::Once => elapsed,
+            Repeat::Loop => elapsed % total,
+            Repeat::Yoyo => {
+                let position = elapsed % (2.0 * total);
+                if position > total {
+                    2.0 * total - position
+                } else {
+                    position
+                }
+            }
+        };
+
+        let mut from = &self.from;
+        let mut start = 0.0;
+        for step in &self.steps {
+            let duration = step.duration.as_secs_f64();
+            if position < start + duration {
+                let t = (position - start) / duration;
+                return from.lerp(&step.to, (step.easing)(t));
+            }
+            start += duration;
+            from = &step.to;
+        }
+        last.clone()
+    }
+}
+
+impl PixState {
+    /// Advance a [`Tween`] by the time elapsed since the previous frame and return its current
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # use std::time::Duration;
+    /// # struct App { fade: Tween<Color> };
+    /// # impl PixEngine for App {
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     self.fade = Tween::new(Color::BLACK, Color::WHITE, Duration::from_secs(2)).yoyo();
+    ///     Ok(())
+    /// }
+    ///
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let background = s.tween(&mut self.fade);
+    ///     s.background(background);
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn tween<T>(&self, tween: &mut Tween<T>) -> T
+    where
+        T: Lerp + Clone,
+    {
+        tween.update(self.delta_time())
+    }
+}
+
+/// No easing.
+#[must_use]
+pub fn linear(t: f64) -> f64 {
+    t
+}
+
+/// Quadratic ease in, accelerating from zero velocity.
+#[must_use]
+pub fn ease_in_quad(t: f64) -> f64 {
+    t * t
+}
+
+/// Quadratic ease out, decelerating to zero velocity.
+#[must_use]
+pub fn ease_out_quad(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(2)
+}
+
+/// Quadratic ease in and out, accelerating until halfway then decelerating.
+#[must_use]
+pub fn ease_in_out_quad(t: f64) -> f64 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+    }
+}
+
+/// Cubic ease in, accelerating from zero velocity.
+#[must_use]
+pub fn ease_in_cubic(t: f64) -> f64 {
+    t.powi(3)
+}
+
+/// Cubic ease out, decelerating to zero velocity.
+#[must_use]
+pub fn ease_out_cubic(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Cubic ease in and out, accelerating until halfway then decelerating.
+#[must_use]
+pub fn ease_in_out_cubic(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t.powi(3)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// Quartic ease in, accelerating from zero velocity.
+#[must_use]
+pub fn ease_in_quart(t: f64) -> f64 {
+    t.powi(4)
+}
+
+/// Quartic ease out, decelerating to zero velocity.
+#[must_use]
+pub fn ease_out_quart(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(4)
+}
+
+/// Quartic ease in and out, accelerating until halfway then decelerating.
+#[must_use]
+pub fn ease_in_out_quart(t: f64) -> f64 {
+    if t < 0.5 {
+        8.0 * t.powi(4)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(4) / 2.0
+    }
+}
+
+/// Exponential ease in, accelerating from zero velocity.
+#[must_use]
+pub fn ease_in_expo(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2f64.powf(10.0 * t - 10.0)
+    }
+}
+
+/// Exponential ease out, decelerating to zero velocity.
+#[must_use]
+pub fn ease_out_expo(t: f64) -> f64 {
+    if t >= 1.0 {
+        1.0
+    } else {
+        1.0 - 2f64.powf(-10.0 * t)
+    }
+}
+
+/// Exponential ease in and out, accelerating until halfway then decelerating.
+#[must_use]
+pub fn ease_in_out_expo(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        2f64.powf(20.0 * t - 10.0) / 2.0
+    } else {
+        (2.0 - 2f64.powf(-20.0 * t + 10.0)) / 2.0
+    }
+}
+
+/// Elastic ease in, winding up like a spring before accelerating.
+#[must_use]
+pub fn ease_in_elastic(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        -(2f64.powf(10.0 * t - 10.0)) * ((10.0 * t - 10.75) * (2.0 * PI / 3.0)).sin()
+    }
+}
+
+/// Elastic ease out, overshooting and oscillating like a spring before settling.
+#[must_use]
+pub fn ease_out_elastic(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        2f64.powf(-10.0 * t) * ((10.0 * t - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+    }
+}
+
+/// Elastic ease in and out, oscillating at both ends.
+#[must_use]
+pub fn ease_in_out_elastic(t: f64) -> f64 {
+    let c = 2.0 * PI / 4.5;
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        -(2f64.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * c).sin()) / 2.0
+    } else {
+        2f64.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * c).sin() / 2.0 + 1.0
+    }
+}
+
+/// Bounce ease in, bouncing with increasing height before accelerating.
+#[must_use]
+pub fn ease_in_bounce(t: f64) -> f64 {
+    1.0 - ease_out_bounce(1.0 - t)
+}
+
+/// Bounce ease out, bouncing with decreasing height before settling like a dropped ball.
+#[must_use]
+pub fn ease_out_bounce(t: f64) -> f64 {
+    const N: f64 = 7.5625;
+    const D: f64 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984_375
+    }
+}
+
+/// Bounce ease in and out, bouncing at both ends.
+#[must_use]
+pub fn ease_in_out_bounce(t: f64) -> f64 {
+    if t < 0.5 {
+        (1.0 - ease_out_bounce(1.0 - 2.0 * t)) / 2.0
+    } else {
+        (1.0 + ease_out_bounce(2.0 * t - 1.0)) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 19] = [
+        linear,
+        ease_in_quad,
+        ease_out_quad,
+        ease_in_out_quad,
+        ease_in_cubic,
+        ease_out_cubic,
+        ease_in_out_cubic,
+        ease_in_quart,
+        ease_out_quart,
+        ease_in_out_quart,
+        ease_in_expo,
+        ease_out_expo,
+        ease_in_out_expo,
+        ease_in_elastic,
+        ease_out_elastic,
+        ease_in_out_elastic,
+        ease_in_bounce,
+        ease_out_bounce,
+        ease_in_out_bounce,
+    ];
+
+    #[test]
+    fn easing_endpoints() {
+        for (i, easing) in EASINGS.iter().enumerate() {
+            assert!(
+                easing(0.0).abs() < 1e-9,
+                "easing {i} at 0.0: {}",
+                easing(0.0)
+            );
+            assert!(
+                (easing(1.0) - 1.0).abs() < 1e-9,
+                "easing {i} at 1.0: {}",
+                easing(1.0)
+            );
+        }
+    }
+
+    #[test]
+    fn monotonic_easings() {
+        // Elastic and bounce easings intentionally overshoot and reverse
+        for (i, easing) in EASINGS.iter().take(13).enumerate() {
+            let mut prev = easing(0.0);
+            for step in 1..=1000 {
+                let value = easing(f64::from(step) / 1000.0);
+                assert!(value >= prev, "easing {i} decreased at step {step}");
+                prev = value;
+            }
+        }
+    }
+
+    #[test]
+    fn color_tween() {
+        let (from, to) = (rgb!(255, 0, 0), rgb!(0, 100, 255));
+        let mut tween = Tween::new(from, to, Duration::from_secs(2));
+        assert_eq!(tween.value(), from);
+        assert_eq!(tween.update(Duration::from_secs(1)), from.lerp(to, 0.5));
+        assert!(!tween.finished());
+        assert_eq!(tween.update(Duration::from_secs(5)), to);
+        assert!(tween.finished());
+    }
+
+    #[test]
+    fn chained_and_repeating() {
+        let second = Duration::from_secs(1);
+        let mut tween = Tween::new(0.0f64, 10.0, second).then(20.0, second);
+        assert_eq!(tween.duration(), Duration::from_secs(2));
+        assert!((tween.update(second / 2) - 5.0).abs() < 1e-9);
+        assert!((tween.update(second) - 15.0).abs() < 1e-9);
+        assert!((tween.update(second) - 20.0).abs() < f64::EPSILON);
+        assert!(tween.finished());
+
+        let mut tween = Tween::new(point![0, 0], point![10, 20], second).looping();
+        assert_eq!(tween.update(second / 4), point![3, 5]);
+        assert_eq!(tween.update(second), point![3, 5]);
+        assert!(!tween.finished());
+
+        let mut tween = Tween::new(
+            rect![0.0, 0.0, 10.0, 10.0],
+            rect![10.0, 10.0, 20.0, 20.0],
+            second,
+        )
+        .with_easing(ease_in_quad)
+        .yoyo();
+        assert_eq!(tween.update(second / 2), rect![2.5, 2.5, 12.5, 12.5]);
+        assert_eq!(tween.update(second), rect![2.5, 2.5, 12.5, 12.5]);
+        assert_eq!(tween.update(second / 2), rect![0.0, 0.0, 10.0, 10.0]);
+        tween.reset();
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+    }
+}
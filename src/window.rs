@@ -10,7 +10,12 @@
 //! - [`PixState::dimensions`]: Get the current render target (window or texture) dimensions as
 //!   `(width, height)`.
 //! - [`PixState::window_dimensions`]: Get the current window target dimensions as `(width, height)`.
-//! - [`PixState::set_window_dimensions`]: Set the current window target dimensions.
+//! - [`PixState::set_window_dimensions`]: Set the current window target dimensions, constrained
+//!   by its minimum and maximum dimensions.
+//! - [`PixState::set_window_min_size`]: Set the current window target minimum dimensions.
+//! - [`PixState::set_window_max_size`]: Set the current window target maximum dimensions.
+//! - [`PixState::window_opacity`]: Get the current window target opacity.
+//! - [`PixState::set_window_opacity`]: Set the current window target opacity.
 //! - [`PixState::viewport`]: Get the current render target (window or texture) viewport.
 //! - [`PixState::set_viewport`]: Set the current render target (window or texture) viewport.
 //! - [`PixState::clear_viewport`]: Clear the current render target (window or texture) viewport
@@ -26,13 +31,27 @@
 //! - [`PixState::display_dimensions`]: Get the primary display dimensions as `(width, height)`.
 //! - [`PixState::display_width`]: Get the primary display width.
 //! - [`PixState::display_height`]: Get the primary display height.
+//! - [`PixState::display_count`]: Get the number of connected displays.
+//! - [`PixState::display_info`]: Get the name, bounds and [`DisplayMode`]s of a connected display.
 //! - [`PixState::show_window`]: Show the current window target if it is hidden.
 //! - [`PixState::hide_window`]: Hide the current window target if it is shown.
+//! - [`PixState::raise_window`]: Raise the current window target above other windows and focus it.
+//! - [`PixState::minimize_window`]: Minimize the current window target.
+//! - [`PixState::maximize_window`]: Maximize the current window target.
+//! - [`PixState::restore_window`]: Restore the size and position of a minimized or maximized
+//!   current window target.
+//! - [`PixState::window_position`]: Get the current window target position as `(x, y)`.
+//! - [`PixState::set_window_position`]: Move the current window target.
+//! - [`PixState::set_window_hit_test`]: Set a callback classifying regions of the current window
+//!   target as draggable or resizable.
+//! - [`PixState::clear_window_hit_test`]: Remove the hit test callback of the current window
+//!   target.
 //! - [`PixState::set_window_target`]: Set a window as the primary target for drawing operations.
 //! - [`PixState::reset_window_target`]: Reset window target back to the primary window for drawing
 //!   operations.
 
 use crate::{
+    error::Error as PixError,
     image::Icon,
     ops::clamp_dimensions,
     prelude::*,
@@ -63,6 +82,42 @@ impl Default for Position {
     }
 }
 
+/// A display mode supported by a display.
+#[non_exhaustive]
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisplayMode {
+    /// Width of the display mode resolution, in pixels.
+    pub width: u32,
+    /// Height of the display mode resolution, in pixels.
+    pub height: u32,
+    /// Refresh rate in Hz, or `0` if unknown.
+    pub refresh_rate: u32,
+}
+
+impl DisplayMode {
+    pub(crate) const fn new(width: u32, height: u32, refresh_rate: u32) -> Self {
+        Self {
+            width,
+            height,
+            refresh_rate,
+        }
+    }
+}
+
+/// Information about a connected display.
+#[non_exhaustive]
+#[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisplayInfo {
+    /// Name of the display.
+    pub name: String,
+    /// Bounds of the display, in desktop coordinates.
+    pub bounds: Rect<i32>,
+    /// Display modes supported by the display, from largest to smallest.
+    pub modes: Vec<DisplayMode>,
+}
+
 /// Window Identifier.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct WindowId(pub(crate) u32);
@@ -86,11 +141,84 @@ impl DerefMut for WindowId {
     }
 }
 
+/// How a region of a window behaves when clicked, as returned from a callback passed to
+/// [`PixState::set_window_hit_test`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HitTestResult {
+    /// Region has no special behavior.
+    Normal,
+    /// Region moves the window when dragged, like a title bar.
+    Draggable,
+    /// Region resizes the window from the top-left corner.
+    ResizeTopLeft,
+    /// Region resizes the window from the top edge.
+    ResizeTop,
+    /// Region resizes the window from the top-right corner.
+    ResizeTopRight,
+    /// Region resizes the window from the right edge.
+    ResizeRight,
+    /// Region resizes the window from the bottom-right corner.
+    ResizeBottomRight,
+    /// Region resizes the window from the bottom edge.
+    ResizeBottom,
+    /// Region resizes the window from the bottom-left corner.
+    ResizeBottomLeft,
+    /// Region resizes the window from the left edge.
+    ResizeLeft,
+}
+
+impl HitTestResult {
+    /// Classify a position within a window of `(width, height)` dimensions as a resize region
+    /// when within `border` pixels of an edge, as draggable when within `title_height` pixels of
+    /// the top, and as normal otherwise. Corners take precedence over edges, and edges take
+    /// precedence over the title bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// let size = (640, 480);
+    /// assert_eq!(HitTestResult::classify([2, 2], size, 4, 24), HitTestResult::ResizeTopLeft);
+    /// assert_eq!(HitTestResult::classify([320, 10], size, 4, 24), HitTestResult::Draggable);
+    /// assert_eq!(HitTestResult::classify([320, 240], size, 4, 24), HitTestResult::Normal);
+    /// ```
+    pub fn classify<P>(pos: P, (width, height): (u32, u32), border: u32, title_height: u32) -> Self
+    where
+        P: Into<Point<i32>>,
+    {
+        let pos = pos.into();
+        let (x, y) = (i64::from(pos.x()), i64::from(pos.y()));
+        let (width, height) = (i64::from(width), i64::from(height));
+        let border = i64::from(border);
+        let left = x < border;
+        let right = x >= width - border;
+        let top = y < border;
+        let bottom = y >= height - border;
+        match (left, right, top, bottom) {
+            (true, _, true, _) => Self::ResizeTopLeft,
+            (_, true, true, _) => Self::ResizeTopRight,
+            (true, _, _, true) => Self::ResizeBottomLeft,
+            (_, true, _, true) => Self::ResizeBottomRight,
+            (true, ..) => Self::ResizeLeft,
+            (_, true, ..) => Self::ResizeRight,
+            (_, _, true, _) => Self::ResizeTop,
+            (.., true) => Self::ResizeBottom,
+            _ if y < i64::from(title_height) => Self::Draggable,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Callback classifying window regions, passed the position within the window and the window
+/// dimensions.
+pub(crate) type HitTestCallback = Box<dyn Fn(Point<i32>, (u32, u32)) -> HitTestResult>;
+
 /// A window cursor indicating the position of the mouse.
 #[non_exhaustive]
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(target_arch = "wasm32", derive(Copy))]
 #[allow(variant_size_differences)]
 pub enum Cursor {
     /// A system supported cursor. e.g. Arrow, Hand, etc.
@@ -98,6 +226,9 @@ pub enum Cursor {
     #[cfg(not(target_arch = "wasm32"))]
     /// A custom cursor from a image path starting at `(x, y)`.
     Image(PathBuf, (i32, i32)),
+    /// A custom cursor from [Image] pixel data with a hot spot at `(x, y)`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Pixels(Image, (i32, i32)),
 }
 
 impl Default for Cursor {
@@ -114,6 +245,53 @@ impl Cursor {
         Self::Image(path.into(), (x, y))
     }
 
+    /// Constructs a `Cursor` from [Image] pixel data with a hot spot at `(x, y)`, without reading
+    /// from the filesystem.
+    ///
+    /// # Errors
+    ///
+    /// If the hot spot lies outside of the image, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     let pixels = vec![Color::RED; 16 * 16];
+    ///     let image = Image::from_pixels(16, 16, &pixels, PixelFormat::Rgba)?;
+    ///     s.cursor(Cursor::from_image(image, (8, 8))?)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn from_image(image: Image, hotspot: (i32, i32)) -> PixResult<Self> {
+        let (x, y) = hotspot;
+        let (width, height) = image.dimensions();
+        if x < 0 || y < 0 || x as u32 >= width || y as u32 >= height {
+            return Err(PixError::InvalidCursorHotspot {
+                hotspot,
+                dimensions: (width, height),
+            }
+            .into());
+        }
+        Ok(Self::Pixels(image, hotspot))
+    }
+
+    /// Constructs a `Cursor` from encoded [png](https://en.wikipedia.org/wiki/Portable_Network_Graphics)
+    /// bytes with a hot spot at `(x, y)`, e.g. embedded with [`include_bytes!`].
+    ///
+    /// # Errors
+    ///
+    /// If the bytes fail to decode as a `png` image or the hot spot lies outside of the image,
+    /// then an error is returned.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_bytes<B: AsRef<[u8]>>(bytes: B, hotspot: (i32, i32)) -> PixResult<Self> {
+        Self::from_image(Image::from_read(bytes.as_ref())?, hotspot)
+    }
+
     /// Constructs a `Cursor` with `SystemCursor::Arrow`.
     #[inline]
     #[must_use]
@@ -194,6 +372,15 @@ pub(crate) trait WindowRenderer {
     /// Set the mouse cursor to a predefined symbol or image, or hides cursor if `None`.
     fn cursor(&mut self, cursor: Option<&Cursor>) -> PixResult<()>;
 
+    /// Returns whether the mouse cursor is confined to the current window target.
+    fn cursor_confined(&self) -> bool;
+
+    /// Confine the mouse cursor to the current window target or release it.
+    fn confine_cursor(&mut self, confine: bool) -> PixResult<()>;
+
+    /// Move the mouse cursor to a position within the current window target.
+    fn set_mouse_pos(&mut self, pos: Point<i32>) -> PixResult<()>;
+
     /// Returns a single event or None if the event pump is empty.
     fn poll_event(&mut self) -> Option<Event>;
 
@@ -215,8 +402,39 @@ pub(crate) trait WindowRenderer {
     /// Position of the current window target as `(x, y)`.
     fn window_position(&self) -> PixResult<(i32, i32)>;
 
-    /// Set dimensions of the current window target as `(width, height)`.
-    fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> PixResult<()>;
+    /// Set position of the current window target as `(x, y)`.
+    fn set_window_position(&mut self, position: (i32, i32)) -> PixResult<()>;
+
+    /// Set or clear the hit test callback of the current window target.
+    fn set_window_hit_test(&mut self, callback: Option<HitTestCallback>) -> PixResult<()>;
+
+    /// Set dimensions of the current window target as `(width, height)`, constrained by its
+    /// minimum and maximum dimensions. Returns the constrained dimensions.
+    fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> PixResult<(u32, u32)>;
+
+    /// Set minimum dimensions of the current window target as `(width, height)`.
+    fn set_window_min_size(&mut self, size: (u32, u32)) -> PixResult<()>;
+
+    /// Set maximum dimensions of the current window target as `(width, height)`.
+    fn set_window_max_size(&mut self, size: (u32, u32)) -> PixResult<()>;
+
+    /// Opacity of the current window target, from `0.0` to `1.0`.
+    fn window_opacity(&self) -> PixResult<f32>;
+
+    /// Set the opacity of the current window target, from `0.0` to `1.0`.
+    fn set_window_opacity(&mut self, opacity: f32) -> PixResult<()>;
+
+    /// Raise the current window target above other windows and focus it.
+    fn raise_window(&mut self) -> PixResult<()>;
+
+    /// Minimize the current window target.
+    fn minimize_window(&mut self) -> PixResult<()>;
+
+    /// Maximize the current window target.
+    fn maximize_window(&mut self) -> PixResult<()>;
+
+    /// Restore the size and position of a minimized or maximized current window target.
+    fn restore_window(&mut self) -> PixResult<()>;
 
     /// Returns the rendering viewport of the current render target.
     fn viewport(&self) -> PixResult<Rect<i32>>;
@@ -227,6 +445,12 @@ pub(crate) trait WindowRenderer {
     /// Dimensions of the primary display as `(width, height)`.
     fn display_dimensions(&self) -> PixResult<(u32, u32)>;
 
+    /// Number of connected displays.
+    fn display_count(&self) -> PixResult<u32>;
+
+    /// Information about the connected display at `index`.
+    fn display_info(&self, index: u32) -> PixResult<DisplayInfo>;
+
     /// Returns whether the application is fullscreen or not.
     fn fullscreen(&self) -> PixResult<bool>;
 
@@ -240,11 +464,9 @@ pub(crate) trait WindowRenderer {
     ///
     /// # Note
     ///
-    /// Due to the current limitation with changing `VSync` at runtime, this method creates a new
-    /// window using the properties of the current window and returns the new `WindowId`.
-    ///
-    /// If you are storing and interacting with this window using the `WindowId`, make sure to
-    /// use the newly returned `WindowId`.
+    /// Due to the current limitation with changing `VSync` at runtime, this method recreates the
+    /// window using the properties of the current window. The `WindowId` and texture contents are
+    /// preserved, and a [`WindowEvent::Recreated`] event is sent once the window is recreated.
     fn set_vsync(&mut self, val: bool) -> PixResult<WindowId>;
 
     /// Set window as the target for drawing operations.
@@ -260,6 +482,21 @@ pub(crate) trait WindowRenderer {
     fn hide(&mut self) -> PixResult<()>;
 }
 
+/// Constrain window `(width, height)` dimensions between optional minimum and maximum dimensions.
+/// Minimum dimensions take precedence over conflicting maximum dimensions.
+pub(crate) fn constrain_dimensions(
+    (width, height): (u32, u32),
+    min: Option<(u32, u32)>,
+    max: Option<(u32, u32)>,
+) -> (u32, u32) {
+    let (max_width, max_height) = max.unwrap_or((u32::MAX, u32::MAX));
+    let (min_width, min_height) = min.unwrap_or((0, 0));
+    (
+        width.min(max_width).max(min_width),
+        height.min(max_height).max(min_height),
+    )
+}
+
 /// Opens a new window by providing several window configuration functions.
 ///
 /// In addition to the primary window created for you when calling [`Engine::run`], you can open
@@ -362,6 +599,47 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    /// Open the window on the display at `index`. Centered windows are centered on the display
+    /// and positions set with [`WindowBuilder::position`] are relative to the top-left of the
+    /// display. See [`PixState::display_count`].
+    #[inline]
+    pub fn on_display(&mut self, index: u32) -> &mut Self {
+        self.settings.display = Some(index);
+        self
+    }
+
+    /// Set the minimum dimensions the window can be resized to.
+    #[doc(alias = "min_dimensions")]
+    #[inline]
+    pub fn min_size(&mut self, width: u32, height: u32) -> &mut Self {
+        self.settings.min_size = Some((width, height));
+        self
+    }
+
+    /// Set the maximum dimensions the window can be resized to.
+    #[doc(alias = "max_dimensions")]
+    #[inline]
+    pub fn max_size(&mut self, width: u32, height: u32) -> &mut Self {
+        self.settings.max_size = Some((width, height));
+        self
+    }
+
+    /// Set the window opacity, from `0.0` (transparent) to `1.0` (opaque). A warning is logged
+    /// if window opacity isn't supported on the current platform.
+    #[inline]
+    pub fn opacity(&mut self, opacity: f32) -> &mut Self {
+        self.settings.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Keep the window above all other windows. A warning is logged if this isn't supported on
+    /// the current platform.
+    #[inline]
+    pub fn always_on_top(&mut self) -> &mut Self {
+        self.settings.always_on_top = true;
+        self
+    }
+
     /// Set a window icon.
     #[inline]
     pub fn icon<I>(&mut self, icon: I) -> &mut Self
@@ -411,6 +689,7 @@ impl PixState {
             self.quit();
             return Ok(());
         }
+        self.ui.remove_window(id);
         self.renderer.close_window(id)
     }
 
@@ -434,26 +713,192 @@ impl PixState {
         self.renderer.window_dimensions()
     }
 
-    /// Set the dimensions of the current window target from `(width, height)`.
+    /// Set the dimensions of the current window target from `(width, height)`. Dimensions are
+    /// clamped between the minimum and maximum window dimensions, if set, and the clamped
+    /// dimensions are returned.
     ///
     /// # Errors
     ///
     /// If the window has been closed or is invalid, then an error is returned.
     #[inline]
-    pub fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> PixResult<()> {
+    pub fn set_window_dimensions(&mut self, dimensions: (u32, u32)) -> PixResult<(u32, u32)> {
         self.renderer.set_window_dimensions(dimensions)
     }
 
+    /// Set the minimum dimensions of the current window target from `(width, height)`.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the dimensions are invalid, then an
+    /// error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     s.set_window_min_size((320, 240))?;
+    ///     s.set_window_max_size((1280, 720))?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_window_min_size(&mut self, size: (u32, u32)) -> PixResult<()> {
+        self.renderer.set_window_min_size(size)
+    }
+
+    /// Set the maximum dimensions of the current window target from `(width, height)`.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the dimensions are invalid, then an
+    /// error is returned.
+    #[inline]
+    pub fn set_window_max_size(&mut self, size: (u32, u32)) -> PixResult<()> {
+        self.renderer.set_window_max_size(size)
+    }
+
+    /// The opacity of the current window target, from `0.0` (transparent) to `1.0` (opaque).
+    /// Always `1.0` on platforms that don't support window opacity.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, then an error is returned.
+    #[inline]
+    pub fn window_opacity(&self) -> PixResult<f32> {
+        self.renderer.window_opacity()
+    }
+
+    /// Set the opacity of the current window target, from `0.0` (transparent) to `1.0` (opaque).
+    /// A warning is logged if window opacity isn't supported on the current platform.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Fade the window in
+    ///     let opacity = s.window_opacity()?;
+    ///     if opacity < 1.0 {
+    ///         s.set_window_opacity(opacity + s.delta_time().as_secs_f32())?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_window_opacity(&mut self, opacity: f32) -> PixResult<()> {
+        self.renderer.set_window_opacity(opacity.clamp(0.0, 1.0))
+    }
+
     /// The position of the current window target as `(x, y)`.
     ///
     /// # Errors
     ///
     /// If the window has been closed or is invalid, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { position: (i32, i32) };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_stop(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Save the position to restore it on the next launch
+    ///     self.position = s.window_position()?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
     #[inline]
     pub fn window_position(&self) -> PixResult<(i32, i32)> {
         self.renderer.window_position()
     }
 
+    /// Move the current window target to `(x, y)` display coordinates.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the renderer doesn't support moving
+    /// windows, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App { saved_position: Option<(i32, i32)> };
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     if let Some(position) = self.saved_position {
+    ///         s.set_window_position(position)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_window_position(&mut self, position: (i32, i32)) -> PixResult<()> {
+        self.renderer.set_window_position(position)
+    }
+
+    /// Set a callback classifying regions of the current window target, allowing windows created
+    /// with [`WindowBuilder::borderless`] to be moved and resized by dragging custom title bars or
+    /// borders. The callback is passed a position in window coordinates along with the window
+    /// dimensions, and is called while handling events. See [`HitTestResult::classify`] for a
+    /// helper covering typical title bars and borders. A panicking callback is treated as
+    /// returning [`HitTestResult::Normal`].
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the renderer or platform doesn't
+    /// support hit testing, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// # fn on_update(&mut self, s: &mut PixState) -> PixResult<()> { Ok(()) }
+    /// fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     // Drag from the top 30 pixels and resize from 5 pixel borders
+    ///     s.set_window_hit_test(|pos, size| HitTestResult::classify(pos, size, 5, 30))?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_window_hit_test<F>(&mut self, f: F) -> PixResult<()>
+    where
+        F: Fn(Point<i32>, (u32, u32)) -> HitTestResult + 'static,
+    {
+        self.renderer.set_window_hit_test(Some(Box::new(f)))
+    }
+
+    /// Remove the hit test callback of the current window target set with
+    /// [`PixState::set_window_hit_test`].
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the renderer or platform doesn't
+    /// support hit testing, then an error is returned.
+    #[inline]
+    pub fn clear_window_hit_test(&mut self) -> PixResult<()> {
+        self.renderer.set_window_hit_test(None)
+    }
+
     /// Returns the rendering viewport of the current render target.
     ///
     /// # Errors
@@ -517,7 +962,8 @@ impl PixState {
     #[inline]
     pub fn set_window_width(&mut self, width: u32) -> PixResult<()> {
         let (_, height) = self.window_dimensions()?;
-        self.renderer.set_window_dimensions((width, height))
+        self.renderer.set_window_dimensions((width, height))?;
+        Ok(())
     }
 
     /// The height of the current render target.
@@ -550,7 +996,8 @@ impl PixState {
     #[inline]
     pub fn set_window_height(&mut self, height: u32) -> PixResult<()> {
         let (width, _) = self.window_dimensions()?;
-        self.renderer.set_window_dimensions((width, height))
+        self.renderer.set_window_dimensions((width, height))?;
+        Ok(())
     }
 
     /// The x of the current window.
@@ -631,6 +1078,47 @@ impl PixState {
         Ok(height)
     }
 
+    /// The number of connected displays.
+    ///
+    /// # Errors
+    ///
+    /// If the renderer fails to query connected displays, then an error is returned.
+    #[inline]
+    pub fn display_count(&self) -> PixResult<u32> {
+        self.renderer.display_count()
+    }
+
+    /// Information about the connected display at `index`, including its name, bounds and
+    /// supported [`DisplayMode`]s.
+    ///
+    /// # Errors
+    ///
+    /// If `index` is not a connected display or the renderer fails to query the display, then an
+    /// error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pix_engine::prelude::*;
+    /// # struct App;
+    /// # impl PixEngine for App {
+    /// fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+    ///     for index in 0..s.display_count()? {
+    ///         let display = s.display_info(index)?;
+    ///         s.text(format!("{}: {:?}", display.name, display.bounds))?;
+    ///         for mode in display.modes {
+    ///             s.text(format!("  {}x{} @ {}Hz", mode.width, mode.height, mode.refresh_rate))?;
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn display_info(&self, index: u32) -> PixResult<DisplayInfo> {
+        self.renderer.display_info(index)
+    }
+
     /// Show the current window target if it is hidden.
     ///
     /// # Errors
@@ -651,6 +1139,49 @@ impl PixState {
         self.renderer.hide()
     }
 
+    /// Raise the current window target above other windows and give it input focus.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, then an error is returned.
+    #[inline]
+    pub fn raise_window(&mut self) -> PixResult<()> {
+        self.renderer.raise_window()
+    }
+
+    /// Minimize the current window target.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the renderer doesn't support
+    /// minimizing windows, then an error is returned.
+    #[inline]
+    pub fn minimize_window(&mut self) -> PixResult<()> {
+        self.renderer.minimize_window()
+    }
+
+    /// Maximize the current window target.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the renderer doesn't support
+    /// maximizing windows, then an error is returned.
+    #[inline]
+    pub fn maximize_window(&mut self) -> PixResult<()> {
+        self.renderer.maximize_window()
+    }
+
+    /// Restore the size and position of a minimized or maximized current window target.
+    ///
+    /// # Errors
+    ///
+    /// If the window has been closed or is invalid, or if the renderer doesn't support
+    /// restoring windows, then an error is returned.
+    #[inline]
+    pub fn restore_window(&mut self) -> PixResult<()> {
+        self.renderer.restore_window()
+    }
+
     /// Set a `Window` as the primary target for drawing operations. Pushes current settings and UI
     /// cursor to the stack, so any changes made while a window target is set will be in effect
     /// until [`PixState::reset_window_target`] is called.
@@ -663,10 +1194,10 @@ impl PixState {
             self.push();
             self.ui.push_cursor();
             self.set_cursor_pos(self.theme.spacing.frame_pad);
-            self.renderer.set_window_target(id)
-        } else {
-            Ok(())
+            self.renderer.set_window_target(id)?;
+            self.ui.target_window = Some(id);
         }
+        Ok(())
     }
 
     /// Reset `Window` target back to the primary window for drawing operations. Pops previous
@@ -675,8 +1206,68 @@ impl PixState {
     pub fn reset_window_target(&mut self) {
         if self.window_id() != self.renderer.primary_window_id() {
             self.renderer.reset_window_target();
+            self.ui.target_window = Some(self.renderer.window_id());
             self.ui.pop_cursor();
             self.pop();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_dimensions_are_constrained() {
+        let (min, max) = (Some((320, 240)), Some((1280, 720)));
+        assert_eq!(constrain_dimensions((800, 600), min, max), (800, 600));
+        assert_eq!(constrain_dimensions((100, 600), min, max), (320, 600));
+        assert_eq!(constrain_dimensions((800, 100), min, max), (800, 240));
+        assert_eq!(constrain_dimensions((1920, 1080), min, max), (1280, 720));
+        assert_eq!(constrain_dimensions((0, 4000), min, None), (320, 4000));
+        assert_eq!(constrain_dimensions((4000, 0), None, max), (1280, 0));
+        assert_eq!(constrain_dimensions((10, 10), None, None), (10, 10));
+        // Minimum takes precedence over a smaller maximum
+        assert_eq!(
+            constrain_dimensions((500, 500), Some((400, 400)), Some((200, 200))),
+            (400, 400)
+        );
+    }
+
+    #[test]
+    fn hit_test_regions() {
+        use HitTestResult::*;
+        let size = (200, 100);
+        let classify = |x, y| HitTestResult::classify([x, y], size, 5, 20);
+        assert_eq!(classify(0, 0), ResizeTopLeft);
+        assert_eq!(classify(199, 0), ResizeTopRight);
+        assert_eq!(classify(0, 99), ResizeBottomLeft);
+        assert_eq!(classify(199, 99), ResizeBottomRight);
+        assert_eq!(classify(2, 50), ResizeLeft);
+        assert_eq!(classify(196, 50), ResizeRight);
+        assert_eq!(classify(100, 4), ResizeTop);
+        assert_eq!(classify(100, 95), ResizeBottom);
+        // Title bar below the top border
+        assert_eq!(classify(100, 5), Draggable);
+        assert_eq!(classify(100, 19), Draggable);
+        assert_eq!(classify(100, 20), Normal);
+        assert_eq!(classify(100, 50), Normal);
+        // No borders or title bar
+        assert_eq!(HitTestResult::classify([0, 0], size, 0, 0), Normal);
+        // Outside the window
+        assert_eq!(classify(-10, 50), ResizeLeft);
+    }
+
+    #[test]
+    fn image_cursor_hotspot() -> PixResult<()> {
+        let pixels: Vec<_> = (0..16 * 16)
+            .map(|i| Color::rgb(i as u8, 0, 255 - i as u8))
+            .collect();
+        let image = Image::from_pixels(16, 16, &pixels, PixelFormat::Rgba)?;
+        let cursor = Cursor::from_image(image.clone(), (15, 0))?;
+        assert!(matches!(cursor, Cursor::Pixels(_, (15, 0))));
+        assert!(Cursor::from_image(image.clone(), (16, 0)).is_err());
+        assert!(Cursor::from_image(image, (0, -1)).is_err());
+        Ok(())
+    }
+}
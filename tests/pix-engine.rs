@@ -8,6 +8,9 @@
 
 use pix_engine::prelude::*;
 
+#[path = "../src/image/gif/decode.rs"]
+mod gif_decode;
+
 #[derive(Default, Debug)]
 struct App {
     quit_on_start: bool,
@@ -101,3 +104,392 @@ fn single_thread_engine_stop() -> PixResult<()> {
     assert_eq!(app.stop_count, 2, "on_stop was called");
     Ok(())
 }
+
+#[derive(Default, Debug)]
+struct NestedTargetApp {
+    dimensions: Vec<(u32, u32)>,
+    composited: Option<Image>,
+}
+
+impl NestedTargetApp {
+    const INNER_DST: [i32; 4] = [5, 10, 10, 5];
+}
+
+impl PixEngine for NestedTargetApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        let outer = s.create_texture(40, 30, None)?;
+        let inner = s.create_texture(10, 5, None)?;
+        self.dimensions.push(s.dimensions()?);
+        s.push_target(outer)?;
+        self.dimensions.push(s.dimensions()?);
+        s.background(Color::BLUE);
+        s.clear()?;
+        s.push_target(inner)?;
+        self.dimensions.push(s.dimensions()?);
+        s.background(Color::RED);
+        s.clear()?;
+        s.pop_target();
+        self.dimensions.push(s.dimensions()?);
+        s.texture(inner, None, Rect::from(Self::INNER_DST))?;
+        let (pixels, format) = s.read_pixels(None)?;
+        self.composited = Some(Image::from_bytes(40, 30, pixels, format)?);
+        s.pop_target();
+        self.dimensions.push(s.dimensions()?);
+        s.delete_texture(inner)?;
+        s.delete_texture(outer)?;
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_nested_targets() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = NestedTargetApp::default();
+    eng.run(&mut app)?;
+    let window = app.dimensions[0];
+    assert_eq!(
+        app.dimensions,
+        [window, (40, 30), (10, 5), (40, 30), window],
+        "dimensions match the active target"
+    );
+    let composited = app.composited.expect("outer target read back");
+    let inner = Rect::from(NestedTargetApp::INNER_DST);
+    for y in 0..30 {
+        for x in 0..40 {
+            let expected = if inner.contains(point![x as i32, y as i32]) {
+                Color::RED
+            } else {
+                Color::BLUE
+            };
+            assert_eq!(composited.get_pixel(x, y), expected, "pixel at ({x}, {y})");
+        }
+    }
+    Ok(())
+}
+
+#[derive(Default, Debug)]
+struct ReadPixelsApp {
+    center: Option<Color>,
+    out_of_bounds: bool,
+}
+
+impl PixEngine for ReadPixelsApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.background(Color::BLACK);
+        s.clear()?;
+        s.stroke(None);
+        s.fill(Color::RED);
+        s.rect([10, 10, 20, 20])?;
+        self.center = Some(s.get_pixel([20, 20])?);
+        let (width, height) = s.dimensions()?;
+        self.out_of_bounds = s.get_pixel([width as i32, height as i32]).is_err();
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_read_pixels() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = ReadPixelsApp::default();
+    eng.run(&mut app)?;
+    assert_eq!(app.center, Some(Color::RED), "read back rect color");
+    assert!(app.out_of_bounds, "out of bounds read errors");
+    Ok(())
+}
+
+#[derive(Default, Debug)]
+struct VsyncApp {
+    window_ids: Option<(WindowId, WindowId)>,
+    pixel: Option<Color>,
+    recreated: bool,
+}
+
+impl PixEngine for VsyncApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        if self.window_ids.is_none() {
+            let texture_id = s.create_texture(10, 10, None)?;
+            s.push_target(texture_id)?;
+            s.background(Color::RED);
+            s.clear()?;
+            s.pop_target();
+            let window_id = s.window_id();
+            self.window_ids = Some((window_id, s.toggle_vsync()?));
+            s.push_target(texture_id)?;
+            self.pixel = Some(s.get_pixel([5, 5])?);
+            s.pop_target();
+        } else {
+            s.quit();
+        }
+        Ok(())
+    }
+
+    fn on_window_event(
+        &mut self,
+        _s: &mut PixState,
+        _window_id: WindowId,
+        event: WindowEvent,
+    ) -> PixResult<()> {
+        if event == WindowEvent::Recreated {
+            self.recreated = true;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_toggle_vsync() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = VsyncApp::default();
+    eng.run(&mut app)?;
+    let (window_id, new_window_id) = app.window_ids.expect("vsync toggled");
+    assert_eq!(window_id, new_window_id, "window id is preserved");
+    assert_eq!(
+        app.pixel,
+        Some(Color::RED),
+        "texture contents are preserved"
+    );
+    assert!(app.recreated, "recreated event is sent");
+    Ok(())
+}
+
+#[derive(Default, Debug)]
+struct ImageCursorApp {
+    cursor_set: bool,
+}
+
+impl PixEngine for ImageCursorApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        let pixels: Vec<_> = (0..16 * 16)
+            .map(|i| {
+                if i % 2 == 0 {
+                    Color::WHITE
+                } else {
+                    Color::BLACK
+                }
+            })
+            .collect();
+        let image = Image::from_pixels(16, 16, &pixels, PixelFormat::Rgba)?;
+        s.cursor(Cursor::from_image(image, (8, 8))?)?;
+        self.cursor_set = true;
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_image_cursor() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = ImageCursorApp::default();
+    eng.run(&mut app)?;
+    assert!(app.cursor_set, "image cursor was set");
+    Ok(())
+}
+
+#[derive(Default, Debug)]
+struct ScreenshotApp {
+    dimensions: (u32, u32),
+    screenshot: Option<std::path::PathBuf>,
+}
+
+impl PixEngine for ScreenshotApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        let dir = std::env::temp_dir().join("pix_engine_screenshots");
+        self.dimensions = s.window_dimensions()?;
+        self.screenshot = Some(s.save_screenshot(dir)?);
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_save_screenshot() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = ScreenshotApp::default();
+    eng.run(&mut app)?;
+    let path = app.screenshot.expect("screenshot saved");
+    let image = Image::from_file(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(
+        image.dimensions(),
+        app.dimensions,
+        "screenshot matches window"
+    );
+    Ok(())
+}
+
+#[derive(Default)]
+struct RecordingApp {
+    frame: usize,
+}
+
+impl RecordingApp {
+    fn path() -> std::path::PathBuf {
+        std::env::temp_dir().join("pix_engine_recording.gif")
+    }
+}
+
+impl PixEngine for RecordingApp {
+    fn on_start(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.start_recording(Self::path(), 3, 0)
+    }
+
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.background([Color::RED, Color::GREEN, Color::BLUE][self.frame % 3]);
+        s.clear()?;
+        self.frame += 1;
+        if self.frame > 3 {
+            s.quit();
+        }
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_record_gif() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = RecordingApp::default();
+    eng.run(&mut app)?;
+    let bytes = std::fs::read(RecordingApp::path())?;
+    std::fs::remove_file(RecordingApp::path())?;
+    let frames = gif_decode::decode(&bytes);
+    assert_eq!(frames.len(), 3, "recorded 3 frames");
+    for (frame, color) in frames.iter().zip([Color::RED, Color::GREEN, Color::BLUE]) {
+        let [r, g, b, _] = color.channels();
+        assert!(frame.iter().all(|&p| p == [r, g, b]), "frame is {color}");
+    }
+    Ok(())
+}
+
+#[derive(Default, Debug)]
+struct FontApp {
+    missing_font_error: bool,
+    fallback_size: (u32, u32),
+    embedded_size: (u32, u32),
+}
+
+impl PixEngine for FontApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        let missing = Font::from_file("Missing", "does_not_exist.ttf");
+        self.missing_font_error = s.add_font(missing.clone(), 16).is_err();
+
+        // Missing fonts fall back to the embedded default font
+        s.font_family(missing)?;
+        self.fallback_size = s.text("Fallback")?;
+
+        let id = s.add_font(Font::INCONSOLATA, 16)?;
+        s.set_font(id)?;
+        self.embedded_size = s.text("Embedded")?;
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_fonts() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = FontApp::default();
+    eng.run(&mut app)?;
+    assert!(app.missing_font_error, "adding a missing font file errors");
+    assert!(app.fallback_size.0 > 0, "fallback font renders text");
+    assert!(app.embedded_size.0 > 0, "embedded font renders text");
+    Ok(())
+}
+
+#[derive(Default)]
+struct FontFallbackApp {
+    sizes: Vec<(u32, u32)>,
+}
+
+impl PixEngine for FontFallbackApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        // Codepoints missing from the default font render as replacement glyphs
+        let text = "Hi ☃ 😀";
+        self.sizes.push(s.text_size(text)?);
+        self.sizes.push(s.text(text)?);
+
+        s.font_fallbacks([Font::NOTO])?;
+        self.sizes.push(s.text_size(text)?);
+        self.sizes.push(s.text(text)?);
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_font_fallbacks() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = FontFallbackApp::default();
+    eng.run(&mut app)?;
+    assert_eq!(app.sizes.len(), 4);
+    for (width, height) in app.sizes {
+        assert!(width > 0 && height > 0, "mixed text has a size");
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct OpacityApp {
+    opacity: Option<f32>,
+}
+
+impl PixEngine for OpacityApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        s.set_window_opacity(0.5)?;
+        self.opacity = Some(s.window_opacity()?);
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_window_opacity() -> PixResult<()> {
+    let mut eng = create_engine()?;
+    let mut app = OpacityApp::default();
+    eng.run(&mut app)?;
+    // Platforms without window opacity support always report fully opaque
+    let opacity = app.opacity.unwrap_or_default();
+    assert!(
+        (opacity - 0.5).abs() < 0.01 || (opacity - 1.0).abs() < f32::EPSILON,
+        "opacity is {opacity}"
+    );
+    Ok(())
+}
+
+#[derive(Default)]
+struct RenderDriverApp {
+    driver: Option<RenderDriver>,
+}
+
+impl PixEngine for RenderDriverApp {
+    fn on_update(&mut self, s: &mut PixState) -> PixResult<()> {
+        self.driver = Some(s.render_driver());
+        s.quit();
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "engine can only be tested in the main thread. --test-threads=1"]
+fn single_thread_engine_render_driver() -> PixResult<()> {
+    let mut eng = Engine::builder()
+        .title("pix-engine integration test")
+        .position_centered()
+        .hidden()
+        .with_render_driver(RenderDriver::Software)
+        .build()?;
+    let mut app = RenderDriverApp::default();
+    eng.run(&mut app)?;
+    assert_eq!(app.driver, Some(RenderDriver::Software));
+    Ok(())
+}